@@ -0,0 +1,349 @@
+//! Graph Analytics
+//!
+//! Loads dependency edges from the knowledge graph into memory and computes
+//! what per-row SQL aggregates cannot:
+//!
+//! - PageRank centrality, so a node depended on by other central nodes ranks
+//!   above one with many unimportant callers;
+//! - strongly connected components, whose members depend on each other in a
+//!   cycle;
+//! - communities found by label propagation, groups of files more tightly
+//!   linked to each other than to the rest of the project.
+//!
+//! The graph is built either over symbols or over files, where every edge
+//! between symbols of two different files becomes an edge between the files.
+
+use std::collections::{BTreeSet, HashMap};
+
+use crate::analyzer::resolver::normalize_path;
+use crate::storage::GraphStore;
+use crate::types::{EdgeType, Result};
+
+/// Edges that make one piece of code depend on another. Ownership and
+/// exposure edges only relate a file to its own contents.
+pub const DEPENDENCY_EDGES: &[EdgeType] = &[
+    EdgeType::DependsOn,
+    EdgeType::Calls,
+    EdgeType::Implements,
+    EdgeType::Extends,
+    EdgeType::Renders,
+    EdgeType::RoutesTo,
+    EdgeType::UsesType,
+];
+
+const DAMPING: f64 = 0.85;
+const MAX_ITERATIONS: usize = 100;
+const TOLERANCE: f64 = 1e-9;
+
+const UNVISITED: usize = usize::MAX;
+
+/// A directed graph over node IDs or file paths, without parallel edges or
+/// self-loops.
+#[derive(Debug, Default, Clone)]
+pub struct DependencyGraph {
+    ids: Vec<String>,
+    index: HashMap<String, usize>,
+    out: Vec<Vec<usize>>,
+}
+
+impl DependencyGraph {
+    /// Build a graph from `(from, to)` pairs.
+    pub fn from_edges<I, S>(edges: I) -> Self
+    where
+        I: IntoIterator<Item = (S, S)>,
+        S: Into<String>,
+    {
+        let mut graph = Self::default();
+        for (from, to) in edges {
+            let from = graph.add_node(from.into());
+            let to = graph.add_node(to.into());
+            if from != to && !graph.out[from].contains(&to) {
+                graph.out[from].push(to);
+            }
+        }
+        graph
+    }
+
+    /// Graph of the stored symbols and the dependency edges between them.
+    pub fn symbols(store: &GraphStore) -> Result<Self> {
+        let edges = store.get_linked_edges(DEPENDENCY_EDGES)?;
+        Ok(Self::from_edges(
+            edges.into_iter().map(|e| (e.source_id, e.target_id)),
+        ))
+    }
+
+    /// Graph of files (normalized paths such as `src/lib.rs`), with an edge
+    /// wherever a symbol of one file depends on a symbol of another.
+    pub fn files(store: &GraphStore) -> Result<Self> {
+        let edges = store.get_linked_edges(DEPENDENCY_EDGES)?;
+        Ok(Self::from_edges(edges.into_iter().map(|e| {
+            (
+                normalize_path(&e.source_path),
+                normalize_path(&e.target_path),
+            )
+        })))
+    }
+
+    fn add_node(&mut self, id: String) -> usize {
+        if let Some(&i) = self.index.get(&id) {
+            return i;
+        }
+        let i = self.ids.len();
+        self.index.insert(id.clone(), i);
+        self.ids.push(id);
+        self.out.push(Vec::new());
+        i
+    }
+
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    pub fn contains(&self, id: &str) -> bool {
+        self.index.contains_key(id)
+    }
+
+    /// Every edge as `(from, to)`.
+    pub fn edges(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.out
+            .iter()
+            .enumerate()
+            .flat_map(move |(from, targets)| {
+                targets
+                    .iter()
+                    .map(move |&to| (self.ids[from].as_str(), self.ids[to].as_str()))
+            })
+    }
+
+    /// PageRank of every node; scores sum to 1.
+    ///
+    /// Rank flows along edges from dependents to their dependencies, so
+    /// widely used code scores highest. Nodes without outgoing edges spread
+    /// their rank evenly over the whole graph.
+    pub fn pagerank(&self) -> HashMap<String, f64> {
+        let n = self.len();
+        if n == 0 {
+            return HashMap::new();
+        }
+
+        let base = (1.0 - DAMPING) / n as f64;
+        let mut rank = vec![1.0 / n as f64; n];
+        for _ in 0..MAX_ITERATIONS {
+            let dangling: f64 = (0..n)
+                .filter(|&v| self.out[v].is_empty())
+                .map(|v| rank[v])
+                .sum();
+            let mut next = vec![base + DAMPING * dangling / n as f64; n];
+            for (v, targets) in self.out.iter().enumerate() {
+                let share = DAMPING * rank[v] / targets.len().max(1) as f64;
+                for &w in targets {
+                    next[w] += share;
+                }
+            }
+
+            let delta: f64 = rank.iter().zip(&next).map(|(a, b)| (a - b).abs()).sum();
+            rank = next;
+            if delta < TOLERANCE {
+                break;
+            }
+        }
+
+        self.ids.iter().cloned().zip(rank).collect()
+    }
+
+    /// Strongly connected components (Tarjan's algorithm, iteratively so
+    /// deep graphs cannot overflow the stack), including single nodes.
+    pub fn strongly_connected(&self) -> Vec<Vec<String>> {
+        let n = self.len();
+        let mut index = vec![UNVISITED; n];
+        let mut low = vec![0; n];
+        let mut on_stack = vec![false; n];
+        let mut stack = Vec::new();
+        let mut next_index = 0;
+        let mut components = Vec::new();
+
+        for start in 0..n {
+            if index[start] != UNVISITED {
+                continue;
+            }
+            index[start] = next_index;
+            low[start] = next_index;
+            next_index += 1;
+            stack.push(start);
+            on_stack[start] = true;
+
+            // (node, position of the next successor to visit)
+            let mut work = vec![(start, 0)];
+            while let Some((v, i)) = work.pop() {
+                if let Some(&w) = self.out[v].get(i) {
+                    work.push((v, i + 1));
+                    if index[w] == UNVISITED {
+                        index[w] = next_index;
+                        low[w] = next_index;
+                        next_index += 1;
+                        stack.push(w);
+                        on_stack[w] = true;
+                        work.push((w, 0));
+                    } else if on_stack[w] {
+                        low[v] = low[v].min(index[w]);
+                    }
+                    continue;
+                }
+
+                if let Some(&(parent, _)) = work.last() {
+                    low[parent] = low[parent].min(low[v]);
+                }
+                if low[v] == index[v] {
+                    let mut component = Vec::new();
+                    while let Some(w) = stack.pop() {
+                        on_stack[w] = false;
+                        component.push(self.ids[w].clone());
+                        if w == v {
+                            break;
+                        }
+                    }
+                    components.push(component);
+                }
+            }
+        }
+
+        components
+    }
+
+    /// Groups of nodes that depend on each other in a cycle, largest first.
+    pub fn cycles(&self) -> Vec<Vec<String>> {
+        let mut cycles: Vec<Vec<String>> = self
+            .strongly_connected()
+            .into_iter()
+            .filter(|c| c.len() > 1)
+            .map(|mut c| {
+                c.sort();
+                c
+            })
+            .collect();
+        cycles.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        cycles
+    }
+
+    /// Communities of two or more nodes, largest first, found by label
+    /// propagation over the graph with edge directions ignored.
+    ///
+    /// Nodes are visited in a fixed order and ties go to the current label,
+    /// then the smallest, so the result is deterministic.
+    pub fn communities(&self) -> Vec<Vec<String>> {
+        let n = self.len();
+        let mut neighbors: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); n];
+        for (v, targets) in self.out.iter().enumerate() {
+            for &w in targets {
+                neighbors[v].insert(w);
+                neighbors[w].insert(v);
+            }
+        }
+
+        let mut labels: Vec<usize> = (0..n).collect();
+        for _ in 0..MAX_ITERATIONS {
+            let mut changed = false;
+            for v in 0..n {
+                let mut counts: HashMap<usize, usize> = HashMap::new();
+                for &w in &neighbors[v] {
+                    *counts.entry(labels[w]).or_default() += 1;
+                }
+                let Some(&max) = counts.values().max() else {
+                    continue;
+                };
+                if counts.get(&labels[v]) == Some(&max) {
+                    continue;
+                }
+                let best = counts
+                    .iter()
+                    .filter(|&(_, &c)| c == max)
+                    .map(|(&label, _)| label)
+                    .min()
+                    .unwrap_or(labels[v]);
+                labels[v] = best;
+                changed = true;
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        let mut groups: HashMap<usize, Vec<String>> = HashMap::new();
+        for (v, label) in labels.into_iter().enumerate() {
+            groups.entry(label).or_default().push(self.ids[v].clone());
+        }
+        let mut communities: Vec<Vec<String>> = groups
+            .into_values()
+            .filter(|g| g.len() > 1)
+            .map(|mut g| {
+                g.sort();
+                g
+            })
+            .collect();
+        communities.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        communities
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pagerank_favors_shared_dependencies() {
+        let graph = DependencyGraph::from_edges([
+            ("a", "util"),
+            ("b", "util"),
+            ("c", "util"),
+            ("util", "log"),
+            ("a", "b"),
+            ("a", "a"),
+        ]);
+        assert_eq!(graph.len(), 5);
+        assert_eq!(graph.edges().count(), 5, "self-loops are dropped");
+
+        let rank = graph.pagerank();
+        let total: f64 = rank.values().sum();
+        assert!((total - 1.0).abs() < 1e-6);
+        assert!(rank["util"] > rank["a"]);
+        assert!(rank["log"] > rank["b"], "rank flows through util to log");
+    }
+
+    #[test]
+    fn test_cycles_and_communities() {
+        let graph = DependencyGraph::from_edges([
+            ("api/routes", "api/handlers"),
+            ("api/handlers", "api/routes"),
+            ("api/handlers", "api/auth"),
+            ("api/auth", "api/routes"),
+            ("db/pool", "db/query"),
+            ("db/query", "db/schema"),
+            ("db/schema", "db/pool"),
+            ("api/handlers", "db/query"),
+            ("cli/main", "cli/args"),
+        ]);
+
+        assert_eq!(
+            graph.cycles(),
+            vec![
+                vec!["api/auth", "api/handlers", "api/routes"],
+                vec!["db/pool", "db/query", "db/schema"],
+            ]
+        );
+        assert_eq!(graph.strongly_connected().len(), 4);
+
+        let communities = graph.communities();
+        assert_eq!(communities.len(), 3);
+        assert!(communities.contains(&vec!["cli/args".to_string(), "cli/main".to_string()]));
+        assert!(
+            communities
+                .iter()
+                .any(|c| c.contains(&"db/pool".to_string())
+                    && !c.iter().any(|f| f.starts_with("api")))
+        );
+    }
+}
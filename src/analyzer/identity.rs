@@ -0,0 +1,307 @@
+//! Node Identity Across Builds
+//!
+//! Node IDs embed the file path and the symbol name. Before an incremental
+//! build replaces the nodes of changed and removed files, [`carry_forward`]
+//! pairs the IDs that disappear with the IDs that appear by their
+//! [`Fingerprint`], first by body and then by name and signature, so that
+//! moved and renamed code inherits its verification state. Inferred nodes
+//! hanging off a file, such as stored insights, follow the file to its new
+//! path.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use super::parser::ParseResult;
+use super::parser::fingerprint::Fingerprint;
+use crate::types::{InformationTier, Node, NodeType};
+
+/// Metadata key holding the ID a node had before it moved.
+pub const MOVED_FROM_KEY: &str = "moved_from";
+
+/// Files and symbols found under new IDs, as `(old ID, new ID)` pairs.
+#[derive(Debug, Default)]
+pub struct Moves {
+    /// Moved files as `(old path, new path)`
+    pub files: Vec<(String, String)>,
+    pub symbols: Vec<(String, String)>,
+    /// Inferred nodes of re-parsed and moved files, rewritten for their
+    /// current path
+    pub carried: Vec<Node>,
+}
+
+/// Pair the nodes of `previous` (the stored nodes of the files being
+/// replaced) that no longer exist with new nodes of `results`, and carry
+/// their state over.
+///
+/// Matched nodes keep the tier, confidence, status and verification time
+/// of their previous ID, which they record as `moved_from`.
+pub fn carry_forward(previous: &[Node], results: &mut [ParseResult]) -> Moves {
+    let new_ids: HashSet<&str> = results
+        .iter()
+        .flat_map(|r| &r.nodes)
+        .map(|n| n.id.as_str())
+        .collect();
+    let old_ids: HashSet<&str> = previous.iter().map(|n| n.id.as_str()).collect();
+
+    let vanished: Vec<&Node> = previous
+        .iter()
+        .filter(|n| n.tier == InformationTier::Fact && !new_ids.contains(n.id.as_str()))
+        .collect();
+    let appeared: Vec<&Node> = results
+        .iter()
+        .flat_map(|r| &r.nodes)
+        .filter(|n| !old_ids.contains(n.id.as_str()))
+        .collect();
+
+    let mut pairs = match_unique(&vanished, &appeared, |n| {
+        Fingerprint::of(n).map(|f| (n.node_type == NodeType::File, f.body))
+    });
+    let matched: HashSet<&str> = pairs
+        .iter()
+        .flat_map(|(o, n)| [o.id.as_str(), n.id.as_str()])
+        .collect();
+    let vanished: Vec<&Node> = vanished
+        .into_iter()
+        .filter(|n| !matched.contains(n.id.as_str()))
+        .collect();
+    let appeared: Vec<&Node> = appeared
+        .into_iter()
+        .filter(|n| !matched.contains(n.id.as_str()))
+        .collect();
+    pairs.extend(match_unique(&vanished, &appeared, |n| {
+        let fingerprint = Fingerprint::of(n)?;
+        (n.node_type != NodeType::File)
+            .then(|| (false, format!("{}|{}", n.name, fingerprint.signature)))
+    }));
+
+    let mut moves = Moves::default();
+    for (old, new) in &pairs {
+        if old.node_type == NodeType::File {
+            moves.files.push((old.path.clone(), new.path.clone()));
+        } else {
+            moves.symbols.push((old.id.clone(), new.id.clone()));
+        }
+    }
+    moves.files.extend(files_moved_with_symbols(
+        previous, &pairs, &vanished, &appeared,
+    ));
+    moves.files.sort();
+    moves.symbols.sort();
+
+    let mut inherited: HashMap<String, Node> = pairs
+        .iter()
+        .map(|(old, new)| (new.id.clone(), (*old).clone()))
+        .collect();
+    for (old_path, new_path) in &moves.files {
+        if let (Some(old), Some(new)) = (
+            file_node(previous, old_path),
+            file_node(appeared.iter().copied(), new_path),
+        ) {
+            inherited
+                .entry(new.id.clone())
+                .or_insert_with(|| old.clone());
+        }
+    }
+    for node in results.iter_mut().flat_map(|r| &mut r.nodes) {
+        if let Some(old) = inherited.get(&node.id) {
+            node.tier = old.tier;
+            node.confidence = old.confidence;
+            node.status = old.status;
+            node.last_verified = old.last_verified;
+            node.metadata
+                .extra
+                .insert(MOVED_FROM_KEY.to_string(), serde_json::json!(old.id));
+        }
+    }
+
+    moves.carried = carried_nodes(previous, results, &moves.files);
+    moves
+}
+
+fn file_node<'a>(nodes: impl IntoIterator<Item = &'a Node>, path: &str) -> Option<&'a Node> {
+    nodes
+        .into_iter()
+        .find(|n| n.node_type == NodeType::File && n.path == path)
+}
+
+/// Pair nodes whose key is shared by exactly one node on each side.
+fn match_unique<'a, K: std::hash::Hash + Eq>(
+    old: &[&'a Node],
+    new: &[&'a Node],
+    key: impl Fn(&Node) -> Option<K>,
+) -> Vec<(&'a Node, &'a Node)> {
+    let mut by_key: HashMap<K, (Vec<&Node>, Vec<&Node>)> = HashMap::new();
+    for node in old {
+        if let Some(k) = key(node) {
+            by_key.entry(k).or_default().0.push(node);
+        }
+    }
+    for node in new {
+        if let Some(k) = key(node) {
+            by_key.entry(k).or_default().1.push(node);
+        }
+    }
+
+    let mut pairs: Vec<(&Node, &Node)> = by_key
+        .into_values()
+        .filter_map(|(old, new)| match (old.as_slice(), new.as_slice()) {
+            ([o], [n]) if o.node_type == n.node_type => Some((*o, *n)),
+            _ => None,
+        })
+        .collect();
+    pairs.sort_by(|a, b| a.0.id.cmp(&b.0.id));
+    pairs
+}
+
+/// Files whose content changed as they moved: a removed file is taken to
+/// live on in the new file that received at least half of its symbols.
+/// `vanished` and `appeared` are the nodes left unmatched by content.
+fn files_moved_with_symbols(
+    previous: &[Node],
+    pairs: &[(&Node, &Node)],
+    vanished: &[&Node],
+    appeared: &[&Node],
+) -> Vec<(String, String)> {
+    let removed: BTreeSet<&str> = vanished
+        .iter()
+        .filter(|n| n.node_type == NodeType::File)
+        .map(|n| n.path.as_str())
+        .collect();
+    let new_files: HashSet<&str> = appeared
+        .iter()
+        .filter(|n| n.node_type == NodeType::File)
+        .map(|n| n.path.as_str())
+        .collect();
+    if new_files.is_empty() {
+        return Vec::new();
+    }
+
+    let mut symbol_counts: HashMap<&str, usize> = HashMap::new();
+    for node in previous {
+        if node.node_type != NodeType::File && Fingerprint::of(node).is_some() {
+            *symbol_counts.entry(&node.path).or_default() += 1;
+        }
+    }
+
+    let mut files = Vec::new();
+    let mut taken: HashSet<&str> = HashSet::new();
+    for old_path in removed {
+        let mut landed: HashMap<&str, usize> = HashMap::new();
+        for (old, new) in pairs {
+            if old.node_type != NodeType::File
+                && old.path == old_path
+                && new_files.contains(new.path.as_str())
+            {
+                *landed.entry(&new.path).or_default() += 1;
+            }
+        }
+        let total = symbol_counts.get(old_path).copied().unwrap_or(0);
+        let best = landed
+            .into_iter()
+            .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(a.0)));
+        if let Some((new_path, count)) = best
+            && count * 2 >= total
+            && taken.insert(new_path)
+        {
+            files.push((old_path.to_string(), new_path.to_string()));
+        }
+    }
+    files
+}
+
+/// Inferred nodes (stored insights) of the previous nodes whose file is
+/// still present, under a moved file's new path where it moved.
+fn carried_nodes(
+    previous: &[Node],
+    results: &[ParseResult],
+    moved: &[(String, String)],
+) -> Vec<Node> {
+    let present: HashSet<&str> = results
+        .iter()
+        .flat_map(|r| &r.nodes)
+        .filter(|n| n.node_type == NodeType::File)
+        .map(|n| n.path.as_str())
+        .collect();
+    let moved: HashMap<&str, &str> = moved
+        .iter()
+        .map(|(o, n)| (o.as_str(), n.as_str()))
+        .collect();
+
+    previous
+        .iter()
+        .filter(|n| n.tier != InformationTier::Fact)
+        .filter_map(|node| {
+            if let Some(new_path) = moved.get(node.path.as_str()) {
+                let mut node = node.clone();
+                node.id = node.id.replacen(&node.path, new_path, 1);
+                if node.evidence.file == node.path {
+                    node.evidence.file = new_path.to_string();
+                }
+                node.path = new_path.to_string();
+                Some(node)
+            } else {
+                present.contains(node.path.as_str()).then(|| node.clone())
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::parser::{Parser, RustParser};
+    use crate::types::NodeStatus;
+
+    fn parse(path: &str, content: &str) -> ParseResult {
+        RustParser::new().unwrap().parse(path, content).unwrap()
+    }
+
+    fn node<'a>(result: &'a ParseResult, name: &str) -> &'a Node {
+        result.nodes.iter().find(|n| n.name == name).unwrap()
+    }
+
+    #[test]
+    fn test_carry_forward_follows_moved_file() {
+        let mut previous = parse(
+            "./src/util.rs",
+            "pub fn pad(s: &str) -> String {\n    s.to_string()\n}\n\n\
+             pub fn trim(s: &str) -> &str {\n    s.trim_end()\n}\n",
+        )
+        .nodes;
+        let verified = chrono::DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        for node in &mut previous {
+            node.status = NodeStatus::Stale;
+            node.last_verified = verified;
+        }
+        let mut insight = Node::new(NodeType::Entity, "./src/util.rs".to_string(), "x".into())
+            .with_tier(InformationTier::Inference);
+        insight.id = "doc:./src/util.rs".to_string();
+        previous.push(insight);
+
+        // Moved to text.rs, `trim` renamed and `pad` edited
+        let mut results = vec![parse(
+            "./src/text.rs",
+            "pub fn pad(s: &str) -> String {\n    format!(\"{}\", s)\n}\n\n\
+             pub fn strip(s: &str) -> &str {\n    s.trim_end()\n}\n",
+        )];
+        let moves = carry_forward(&previous, &mut results);
+
+        assert_eq!(
+            moves.files,
+            vec![("./src/util.rs".to_string(), "./src/text.rs".to_string())]
+        );
+        assert_eq!(moves.symbols.len(), 2);
+        let strip = node(&results[0], "strip");
+        assert_eq!(strip.status, NodeStatus::Stale);
+        assert_eq!(strip.last_verified, verified);
+        let trim = previous.iter().find(|n| n.name == "trim").unwrap();
+        assert_eq!(
+            strip.metadata.extra[MOVED_FROM_KEY],
+            serde_json::json!(trim.id)
+        );
+        assert_eq!(moves.carried.len(), 1);
+        assert_eq!(moves.carried[0].id, "doc:./src/text.rs");
+        assert_eq!(moves.carried[0].path, "./src/text.rs");
+    }
+}
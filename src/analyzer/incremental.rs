@@ -0,0 +1,314 @@
+//! Incremental Graph Builds
+//!
+//! Every build records a SHA-256 of each parsed file. The next build hashes
+//! the tree again and only re-parses files whose content differs, plus the
+//! unchanged files whose cross-file edges depend on them:
+//!
+//! - files with an edge into a changed or removed file, so that imports and
+//!   route handlers are resolved against the new declarations;
+//! - files with an unresolved import, when new files appear that it may now
+//!   resolve to;
+//! - every Rust file once any of them changes, since `use` paths resolve
+//!   against the whole module tree and its re-exports.
+//!
+//! Hashing and parsing run on a pool of scoped threads, each holding its own
+//! parser per language.
+
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use sha2::{Digest, Sha256};
+
+use super::parser::{ConfigParser, Language, ParseResult, Parser, config_kind, create_parser};
+use crate::storage::GraphStore;
+use crate::types::Result;
+
+/// How the files of a build differ from the previous one.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FileChanges {
+    pub added: Vec<String>,
+    pub changed: Vec<String>,
+    pub removed: Vec<String>,
+    pub unchanged: Vec<String>,
+}
+
+impl FileChanges {
+    /// Compare stored hashes with the current ones, both keyed by path.
+    pub fn diff(stored: &HashMap<String, String>, current: &HashMap<String, String>) -> Self {
+        let mut changes = Self::default();
+        for (path, hash) in current {
+            match stored.get(path) {
+                None => changes.added.push(path.clone()),
+                Some(old) if old != hash => changes.changed.push(path.clone()),
+                Some(_) => changes.unchanged.push(path.clone()),
+            }
+        }
+        changes.removed = stored
+            .keys()
+            .filter(|p| !current.contains_key(*p))
+            .cloned()
+            .collect();
+
+        changes.added.sort();
+        changes.changed.sort();
+        changes.removed.sort();
+        changes.unchanged.sort();
+        changes
+    }
+
+    /// Whether the graph already matches the tree.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.changed.is_empty() && self.removed.is_empty()
+    }
+
+    /// Files to parse in this build: new and modified files plus the
+    /// unchanged files whose cross-file edges may now resolve differently.
+    pub fn files_to_parse(&self, store: &GraphStore) -> Result<BTreeSet<String>> {
+        let mut parse: BTreeSet<String> = self.added.iter().chain(&self.changed).cloned().collect();
+        if self.is_empty() {
+            return Ok(parse);
+        }
+
+        let touched: Vec<String> = self.changed.iter().chain(&self.removed).cloned().collect();
+        parse.extend(store.get_dependent_files(&touched)?);
+        if !self.added.is_empty() {
+            parse.extend(store.get_files_with_unresolved_imports()?);
+        }
+        let rust_touched = self
+            .added
+            .iter()
+            .chain(&touched)
+            .any(|p| p.ends_with(".rs"));
+        if rust_touched {
+            parse.extend(
+                self.unchanged
+                    .iter()
+                    .filter(|p| p.ends_with(".rs"))
+                    .cloned(),
+            );
+        }
+
+        // Dependents may themselves have been removed
+        parse.retain(|p| !self.removed.contains(p));
+        Ok(parse)
+    }
+}
+
+/// SHA-256 of a file's content as lowercase hex.
+pub fn content_hash(content: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(content))
+}
+
+/// Hash every readable file, returning `path -> hash`.
+pub fn hash_files(paths: &[String]) -> HashMap<String, String> {
+    parallel_map(
+        paths,
+        || (),
+        |_, path| {
+            let content = fs::read(path).ok()?;
+            Some((path.clone(), content_hash(&content)))
+        },
+    )
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Parse `paths` in parallel, reusing one parser per language and thread.
+///
+/// Results keep the order of `paths`; configuration files go to the
+/// [`ConfigParser`], unreadable files and languages without a parser are
+/// skipped.
+pub fn parse_files(paths: &[String]) -> Result<Vec<ParseResult>> {
+    let results = parallel_map(paths, HashMap::new, |parsers, path| {
+        parse_file(parsers, path)
+    });
+    results.into_iter().filter_map(Result::transpose).collect()
+}
+
+fn parse_file(
+    parsers: &mut HashMap<Language, Box<dyn Parser>>,
+    path: &str,
+) -> Result<Option<ParseResult>> {
+    let lang = Language::from_path(path);
+    let config = config_kind(path);
+    if config.is_none() && !lang.has_parser_support() {
+        return Ok(None);
+    }
+    let Ok(content) = fs::read_to_string(path) else {
+        return Ok(None);
+    };
+    if let Some(kind) = config {
+        return ConfigParser::new(kind).parse(path, &content).map(Some);
+    }
+
+    let parser = match parsers.entry(lang) {
+        Entry::Occupied(entry) => entry.into_mut(),
+        Entry::Vacant(entry) => entry.insert(create_parser(lang)?),
+    };
+    parser.parse(path, &content).map(Some)
+}
+
+/// Map `f` over `items` on scoped worker threads. Each worker builds its
+/// own state with `init` (a parser cache, for instance); the output keeps
+/// the order of `items`.
+fn parallel_map<T, R, S>(
+    items: &[T],
+    init: impl Fn() -> S + Sync,
+    f: impl Fn(&mut S, &T) -> R + Sync,
+) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+{
+    let workers = thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+        .min(items.len());
+    if workers <= 1 {
+        let mut state = init();
+        return items.iter().map(|item| f(&mut state, item)).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let slots: Mutex<Vec<Option<R>>> = Mutex::new((0..items.len()).map(|_| None).collect());
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| {
+                let mut state = init();
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(index) else {
+                        break;
+                    };
+                    let output = f(&mut state, item);
+                    if let Ok(mut slots) = slots.lock() {
+                        slots[index] = Some(output);
+                    }
+                }
+            });
+        }
+    });
+
+    slots
+        .into_inner()
+        .unwrap_or_else(|e| e.into_inner())
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::parser::TypeScriptParser;
+    use crate::storage::Database;
+    use crate::types::EdgeType;
+
+    fn hashes(entries: &[(&str, &str)]) -> HashMap<String, String> {
+        entries
+            .iter()
+            .map(|(p, h)| (p.to_string(), h.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_diff_classifies_files() {
+        let stored = hashes(&[("./a.ts", "1"), ("./b.ts", "2"), ("./c.ts", "3")]);
+        let current = hashes(&[("./a.ts", "1"), ("./b.ts", "20"), ("./d.ts", "4")]);
+
+        let changes = FileChanges::diff(&stored, &current);
+        assert_eq!(changes.added, vec!["./d.ts"]);
+        assert_eq!(changes.changed, vec!["./b.ts"]);
+        assert_eq!(changes.removed, vec!["./c.ts"]);
+        assert_eq!(changes.unchanged, vec!["./a.ts"]);
+        assert!(!changes.is_empty());
+        assert!(FileChanges::diff(&stored, &stored).is_empty());
+    }
+
+    #[test]
+    fn test_files_to_parse_includes_dependents() {
+        let db = Database::open_in_memory().unwrap();
+        db.initialize().unwrap();
+        let store = GraphStore::new(&db);
+
+        let parser = TypeScriptParser::new().unwrap();
+        let util = parser
+            .parse("./src/util.ts", "export function pad() {}\n")
+            .unwrap();
+        let mut app = parser
+            .parse("./src/app.ts", "import { pad } from './util';\npad();\n")
+            .unwrap();
+        let other = parser.parse("./src/other.ts", "const x = 1;\n").unwrap();
+        // Point the import at the util file, as the resolver would
+        for edge in &mut app.edges {
+            if edge.edge_type == EdgeType::DependsOn {
+                edge.target_id = "file:./src/util.ts".to_string();
+            }
+        }
+        let results = [util, app, other];
+        let nodes: Vec<_> = results.iter().flat_map(|r| &r.nodes).collect();
+        let edges: Vec<_> = results.iter().flat_map(|r| &r.edges).collect();
+        let stored = hashes(&[
+            ("./src/util.ts", "1"),
+            ("./src/app.ts", "2"),
+            ("./src/other.ts", "3"),
+        ]);
+        let stored_list: Vec<(String, String)> = stored.clone().into_iter().collect();
+        store
+            .replace_files(&[], &[], &[], &stored_list, &nodes, &edges)
+            .unwrap();
+        assert_eq!(store.get_file_hashes().unwrap(), stored);
+
+        let current = hashes(&[
+            ("./src/util.ts", "10"),
+            ("./src/app.ts", "2"),
+            ("./src/other.ts", "3"),
+        ]);
+        let changes = FileChanges::diff(&stored, &current);
+        let parse: Vec<String> = changes
+            .files_to_parse(&store)
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(parse, vec!["./src/app.ts", "./src/util.ts"]);
+
+        // Dropping util removes its nodes and the import edge into it
+        store
+            .replace_files(&["./src/util.ts".to_string()], &[], &[], &[], &[], &[])
+            .unwrap();
+        assert!(store.get_nodes_by_path("./src/util.ts").unwrap().is_empty());
+        assert!(
+            store
+                .get_dependents("file:./src/util.ts")
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            !store
+                .get_file_hashes()
+                .unwrap()
+                .contains_key("./src/util.ts")
+        );
+        assert!(!store.get_nodes_by_path("./src/app.ts").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parallel_map_keeps_order() {
+        let items: Vec<u32> = (0..100).collect();
+        let doubled = parallel_map(
+            &items,
+            || 0u32,
+            |calls, n| {
+                *calls += 1;
+                n * 2
+            },
+        );
+        assert_eq!(doubled, items.iter().map(|n| n * 2).collect::<Vec<_>>());
+        assert_eq!(content_hash(b"abc").len(), 64);
+    }
+}
@@ -2,11 +2,23 @@
 //!
 //! Provides language-agnostic code analysis capabilities:
 //! - Multi-language parsing (AST extraction)
+//! - Cross-file import resolution
+//! - Incremental, parallel graph builds keyed on content hashes
+//! - Symbol fingerprints that follow renamed and moved code
+//! - Workspace/package discovery (Cargo, npm, Go)
 //! - File scanning with gitignore support
+//! - Graph analytics (PageRank, dependency cycles, communities)
 //! - Universal structure analysis
 
+pub mod graph;
+pub mod identity;
+pub mod incremental;
 pub mod parser;
+pub mod resolver;
 pub mod scanner;
 pub mod structure;
+pub mod workspace;
 
+pub use graph::{DEPENDENCY_EDGES, DependencyGraph};
 pub use structure::{StructureAnalysis, StructureAnalyzer};
+pub use workspace::{Package, PackageKind, Workspace};
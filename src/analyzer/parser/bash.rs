@@ -1,12 +1,63 @@
 use chrono::Utc;
 use tree_sitter::{Parser as TsParser, Query, QueryCursor, StreamingIterator};
 
-use super::{Language, ParseResult, Parser, create_file_node};
+use super::{
+    CallSyntax, DocSyntax, Language, MetricsSyntax, ParseResult, Parser, TestSyntax,
+    create_file_node, extract_calls, extract_docs, extract_metrics, extract_settings,
+    extract_tests, fingerprint_nodes,
+};
 use crate::types::{
     EvidenceLocation, FunctionSignature, InformationTier, Node, NodeMetadata, NodeStatus, NodeType,
     Result, Visibility, WeaveError,
 };
 
+const CALL_SYNTAX: CallSyntax = CallSyntax {
+    scope_kinds: &["function_definition"],
+    call_kinds: &[("command", Some("name"))],
+    async_kinds: &[],
+};
+
+const DOC_SYNTAX: DocSyntax = DocSyntax {
+    item_kinds: &["function_definition"],
+    file_item_kinds: &[],
+    comment_kinds: &["comment"],
+    doc_prefixes: &["#"],
+    inner_prefixes: &[],
+    skip_kinds: &[],
+    wrapper_kinds: &[],
+    docstrings: false,
+};
+
+// Tests here are found by file name only
+const TEST_SYNTAX: TestSyntax = TestSyntax {
+    item_kinds: &[],
+    marker_kinds: &[],
+    markers: &[],
+    name_prefixes: &[],
+    calls: &CALL_SYNTAX,
+};
+
+const METRICS_SYNTAX: MetricsSyntax = MetricsSyntax {
+    branch_kinds: &[
+        "if_statement",
+        "elif_clause",
+        "for_statement",
+        "c_style_for_statement",
+        "while_statement",
+        "case_item",
+        "ternary_expression",
+    ],
+    logical_kinds: &["list", "binary_expression"],
+    nesting_kinds: &[
+        "if_statement",
+        "for_statement",
+        "c_style_for_statement",
+        "while_statement",
+        "case_statement",
+    ],
+    return_kinds: &[],
+};
+
 pub struct BashParser;
 
 impl BashParser {
@@ -47,6 +98,12 @@ impl Parser for BashParser {
         result.nodes.push(file_node);
 
         extract_functions(root, content, path, &mut result);
+        extract_calls(root, content, path, &mut result, &CALL_SYNTAX);
+        extract_docs(root, content, &mut result, &DOC_SYNTAX);
+        extract_tests(root, content, path, &mut result, &TEST_SYNTAX);
+        extract_settings(root, content, path, &mut result, &CALL_SYNTAX);
+        extract_metrics(root, content, &mut result, &METRICS_SYNTAX);
+        fingerprint_nodes(root, content, &mut result);
 
         Ok(result)
     }
@@ -5,12 +5,62 @@
 use chrono::Utc;
 use tree_sitter::{Parser as TsParser, Query, QueryCursor, StreamingIterator};
 
-use super::{Language, ParseResult, Parser, create_file_node};
+use super::{
+    CallSyntax, DocSyntax, Language, MetricsSyntax, ParseResult, Parser, TestSyntax,
+    create_file_node, extract_calls, extract_docs, extract_metrics, extract_settings,
+    extract_tests, fingerprint_nodes,
+};
 use crate::types::{
     Edge, EdgeMetadata, EdgeType, EvidenceLocation, FunctionSignature, ImportType, InformationTier,
     Node, NodeMetadata, NodeStatus, NodeType, Parameter, Result, Visibility, WeaveError,
 };
 
+const CALL_SYNTAX: CallSyntax = CallSyntax {
+    scope_kinds: &["function_definition"],
+    call_kinds: &[("call_expression", Some("function"))],
+    async_kinds: &[],
+};
+
+const DOC_SYNTAX: DocSyntax = DocSyntax {
+    item_kinds: &["function_definition", "struct_specifier"],
+    file_item_kinds: &[],
+    comment_kinds: &["comment"],
+    doc_prefixes: &[],
+    inner_prefixes: &[],
+    skip_kinds: &[],
+    wrapper_kinds: &["declaration", "type_definition"],
+    docstrings: false,
+};
+
+// Tests here are found by file name only
+const TEST_SYNTAX: TestSyntax = TestSyntax {
+    item_kinds: &[],
+    marker_kinds: &[],
+    markers: &[],
+    name_prefixes: &[],
+    calls: &CALL_SYNTAX,
+};
+
+const METRICS_SYNTAX: MetricsSyntax = MetricsSyntax {
+    branch_kinds: &[
+        "if_statement",
+        "for_statement",
+        "while_statement",
+        "do_statement",
+        "case_statement",
+        "conditional_expression",
+    ],
+    logical_kinds: &["binary_expression"],
+    nesting_kinds: &[
+        "if_statement",
+        "for_statement",
+        "while_statement",
+        "do_statement",
+        "switch_statement",
+    ],
+    return_kinds: &["return_statement"],
+};
+
 pub struct CLangParser;
 
 impl CLangParser {
@@ -53,6 +103,12 @@ impl Parser for CLangParser {
         extract_includes(root, content, path, &mut result);
         extract_structs(root, content, path, &mut result);
         extract_functions(root, content, path, &mut result);
+        extract_calls(root, content, path, &mut result, &CALL_SYNTAX);
+        extract_docs(root, content, &mut result, &DOC_SYNTAX);
+        extract_tests(root, content, path, &mut result, &TEST_SYNTAX);
+        extract_settings(root, content, path, &mut result, &CALL_SYNTAX);
+        extract_metrics(root, content, &mut result, &METRICS_SYNTAX);
+        fingerprint_nodes(root, content, &mut result);
 
         Ok(result)
     }
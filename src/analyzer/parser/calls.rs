@@ -0,0 +1,415 @@
+//! Call Graph Extraction
+//!
+//! Language-agnostic call-site walker shared by all tree-sitter parsers.
+//! Each parser describes its grammar with a [`CallSyntax`] table; call sites
+//! are attributed to the innermost enclosing function/method that the parser
+//! already emitted as a node, and callees are resolved against the nodes
+//! declared in the same file.
+//!
+//! A call through a receiver other than `self`/`this` only resolves to a
+//! method, never to a free function that happens to share its name. Calls
+//! left unresolved are kept on the parse result for
+//! [`resolve_calls`](crate::analyzer::resolver::resolve_calls), which looks
+//! them up in the files the caller imports; whatever it cannot find
+//! (stdlib, dynamic dispatch) is dropped rather than guessed, so every
+//! `calls` edge points at a real node.
+
+use std::collections::HashMap;
+
+use super::traits::{ParseResult, UnresolvedCall, create_code_edge, get_node_text};
+use crate::types::{EdgeMetadata, EdgeType, Node, NodeType};
+
+/// Receivers naming the enclosing object or class.
+const SELF_RECEIVERS: &[&str] = &["self", "this", "Self", "cls", "super"];
+
+/// Grammar description for call extraction.
+pub struct CallSyntax {
+    /// Node kinds that open a named scope (functions, methods, classes).
+    pub scope_kinds: &'static [&'static str],
+    /// Call node kinds and the field holding the callee expression.
+    /// `None` means the first named child (grammars without field names).
+    pub call_kinds: &'static [(&'static str, Option<&'static str>)],
+    /// Parent kinds that mark a call as asynchronous (`await`, `go`, ...).
+    pub async_kinds: &'static [&'static str],
+}
+
+/// What a call site calls: a node of this file, or a name to look up in
+/// the files it imports.
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Callee {
+    Local(String),
+    Pending {
+        name: String,
+        qualifier: Option<String>,
+    },
+}
+
+struct CallSite<'t> {
+    caller: String,
+    callee: Callee,
+    node: tree_sitter::Node<'t>,
+    is_async: bool,
+}
+
+/// Extract `calls` edges from every call site under `root`.
+///
+/// Must run after the parser has pushed its declaration nodes, since both
+/// callers and callees are resolved by name against `result.nodes`.
+/// Repeated calls between the same pair collapse into one edge whose
+/// `call_count` records the number of sites; `is_async` is set when any site
+/// is awaited. Recursive self-calls are skipped. Calls to names the file
+/// does not declare go to `result.unresolved_calls`, aggregated the same way.
+pub fn extract_calls(
+    root: tree_sitter::Node,
+    content: &str,
+    path: &str,
+    result: &mut ParseResult,
+    syntax: &CallSyntax,
+) {
+    let file_id = format!("file:{}", path);
+    let symbols = scope_symbols(&result.nodes);
+
+    let mut sites = Vec::new();
+    collect_call_sites(
+        root,
+        content.as_bytes(),
+        syntax,
+        &mut |node, callee_text| {
+            let Some(callee) = resolve_callee(callee_text, &symbols) else {
+                return;
+            };
+            let caller = enclosing_scope(node, content.as_bytes(), syntax, &symbols)
+                .unwrap_or_else(|| file_id.clone());
+            if callee == Callee::Local(caller.clone()) {
+                return;
+            }
+            let is_async = node
+                .parent()
+                .is_some_and(|p| syntax.async_kinds.contains(&p.kind()));
+            sites.push(CallSite {
+                caller,
+                callee,
+                node,
+                is_async,
+            });
+        },
+    );
+
+    // Aggregate per (caller, callee), keeping the first site as evidence
+    let mut index: HashMap<(String, Callee), usize> = HashMap::new();
+    let mut calls: Vec<(Callee, crate::types::Edge)> = Vec::new();
+    for site in sites {
+        let key = (site.caller.clone(), site.callee.clone());
+        if let Some(&i) = index.get(&key) {
+            let edge = &mut calls[i].1;
+            edge.metadata.call_count = edge.metadata.call_count.map(|c| c + 1);
+            if site.is_async {
+                edge.metadata.is_async = Some(true);
+            }
+            continue;
+        }
+
+        let target = match &site.callee {
+            Callee::Local(id) => id.clone(),
+            Callee::Pending { name, .. } => name.clone(),
+        };
+        let mut edge = create_code_edge(
+            format!("call:{}->{}", site.caller, target),
+            EdgeType::Calls,
+            site.caller,
+            target,
+            site.node,
+            path,
+        );
+        edge.metadata = EdgeMetadata {
+            call_count: Some(1),
+            is_async: Some(site.is_async),
+            ..Default::default()
+        };
+        index.insert(key, calls.len());
+        calls.push((site.callee, edge));
+    }
+
+    for (callee, edge) in calls {
+        match callee {
+            Callee::Local(_) => result.edges.push(edge),
+            Callee::Pending { name, qualifier } => {
+                result.unresolved_calls.push(UnresolvedCall {
+                    name,
+                    qualifier,
+                    edge,
+                });
+            }
+        }
+    }
+}
+
+/// Names called anywhere under `node`: the final segment of each callee,
+/// plus its qualifier when that names a type (`Parser::new` → `new`,
+/// `Parser`). Sorted and deduplicated.
+pub(super) fn callee_names(
+    node: tree_sitter::Node,
+    content: &[u8],
+    syntax: &CallSyntax,
+) -> Vec<String> {
+    let mut names = Vec::new();
+    collect_call_sites(node, content, syntax, &mut |_, callee| {
+        let callee = callee.split('<').next().unwrap_or(callee);
+        let mut segments = callee
+            .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+            .filter(|s| !s.is_empty())
+            .rev();
+        if let Some(name) = segments.next() {
+            names.push(name.to_string());
+        }
+        if let Some(qualifier) = segments.next()
+            && qualifier.starts_with(|c: char| c.is_uppercase())
+        {
+            names.push(qualifier.to_string());
+        }
+    });
+    names.sort();
+    names.dedup();
+    names
+}
+
+fn collect_call_sites<'t, F>(
+    root: tree_sitter::Node<'t>,
+    content: &[u8],
+    syntax: &CallSyntax,
+    callback: &mut F,
+) where
+    F: FnMut(tree_sitter::Node<'t>, &str),
+{
+    let mut cursor = root.walk();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        if let Some((_, field)) = syntax.call_kinds.iter().find(|(k, _)| *k == node.kind()) {
+            let callee = match field {
+                Some(f) => node.child_by_field_name(f),
+                None => node.named_child(0),
+            };
+            if let Some(callee) = callee {
+                callback(node, get_node_text(callee, content));
+            }
+        }
+
+        stack.extend(node.named_children(&mut cursor));
+    }
+}
+
+/// Resolve a callee expression against the symbols declared in this file.
+///
+/// Bare names and calls on `self`/`this` resolve by their final segment
+/// (`self.save` → `save`). A type qualifier resolves to the type's member,
+/// or failing that the type itself (`Foo::new`, `Foo.new` → `Foo`). Any
+/// other receiver only resolves to a method, so `api.load()` never lands on
+/// a local function `load`. Names not found here are left pending.
+fn resolve_callee(text: &str, symbols: &HashMap<&str, (&str, NodeType)>) -> Option<Callee> {
+    let text = text.split('<').next().unwrap_or(text);
+    let mut segments = text
+        .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+        .filter(|s| !s.is_empty())
+        .rev();
+
+    let name = segments.next()?;
+    let qualifier = segments.next();
+    let local = symbols.get(name);
+    let found = match qualifier {
+        None => local,
+        // Members of the enclosing object are declared here or nowhere
+        Some(q) if SELF_RECEIVERS.contains(&q) => {
+            return local.map(|(id, _)| Callee::Local(id.to_string()));
+        }
+        Some(q) => match symbols.get(q) {
+            Some(class @ (_, NodeType::Class)) => {
+                local.filter(|(_, t)| *t != NodeType::Class).or(Some(class))
+            }
+            _ => local.filter(|(_, t)| *t == NodeType::Method),
+        },
+    };
+    Some(match found {
+        Some((id, _)) => Callee::Local(id.to_string()),
+        None => Callee::Pending {
+            name: name.to_string(),
+            qualifier: qualifier.map(String::from),
+        },
+    })
+}
+
+/// Functions, methods and classes by name, the first declaration winning.
+pub(super) fn scope_symbols(nodes: &[Node]) -> HashMap<&str, (&str, NodeType)> {
+    let mut symbols: HashMap<&str, (&str, NodeType)> = HashMap::new();
+    for node in nodes {
+        if matches!(
+            node.node_type,
+            NodeType::Function | NodeType::Method | NodeType::Class
+        ) {
+            symbols
+                .entry(node.name.as_str())
+                .or_insert((node.id.as_str(), node.node_type));
+        }
+    }
+    symbols
+}
+
+/// Find the innermost enclosing scope that the parser emitted as a node.
+pub(super) fn enclosing_scope(
+    node: tree_sitter::Node,
+    content: &[u8],
+    syntax: &CallSyntax,
+    symbols: &HashMap<&str, (&str, NodeType)>,
+) -> Option<String> {
+    let mut current = node.parent();
+    while let Some(scope) = current {
+        if syntax.scope_kinds.contains(&scope.kind())
+            && let Some(name) = scope_name(scope, content)
+            && let Some((id, _)) = symbols.get(name)
+        {
+            return Some(id.to_string());
+        }
+        current = scope.parent();
+    }
+    None
+}
+
+/// Name of a scope node: the `name` field, the innermost `declarator`
+/// (C/C++), or the first identifier child (Kotlin).
+///
+/// Qualified names (Lua `function M.greet()`, `function M:greet()`) are cut
+/// to their last segment, which is what the declaration is named.
+pub(super) fn scope_name<'c>(scope: tree_sitter::Node, content: &'c [u8]) -> Option<&'c str> {
+    if let Some(name) = scope.child_by_field_name("name") {
+        let name = get_node_text(name, content);
+        return name.rsplit(['.', ':']).next();
+    }
+
+    if let Some(mut declarator) = scope.child_by_field_name("declarator") {
+        while let Some(inner) = declarator.child_by_field_name("declarator") {
+            declarator = inner;
+        }
+        let name = declarator.child_by_field_name("name").unwrap_or(declarator);
+        return Some(get_node_text(name, content));
+    }
+
+    let mut cursor = scope.walk();
+    let ident = scope
+        .named_children(&mut cursor)
+        .find(|c| c.kind().ends_with("identifier"));
+    ident.map(|n| get_node_text(n, content))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{Parser, PythonParser, RustParser, TypeScriptParser};
+    use crate::types::{Edge, EdgeType};
+
+    fn call_edges(edges: &[Edge]) -> Vec<&Edge> {
+        edges
+            .iter()
+            .filter(|e| e.edge_type == EdgeType::Calls)
+            .collect()
+    }
+
+    #[test]
+    fn test_rust_calls_aggregate_and_await() {
+        let code = r#"
+fn helper() {}
+async fn fetch() {}
+async fn run() {
+    helper();
+    helper();
+    fetch().await;
+}
+"#;
+        let result = RustParser::new()
+            .unwrap()
+            .parse("src/lib.rs", code)
+            .unwrap();
+        let calls = call_edges(&result.edges);
+
+        let helper = calls
+            .iter()
+            .find(|e| e.target_id == "function:src/lib.rs:helper")
+            .expect("helper call edge");
+        assert_eq!(helper.source_id, "function:src/lib.rs:run");
+        assert_eq!(helper.metadata.call_count, Some(2));
+        assert_eq!(helper.metadata.is_async, Some(false));
+
+        let fetch = calls
+            .iter()
+            .find(|e| e.target_id == "function:src/lib.rs:fetch")
+            .expect("fetch call edge");
+        assert_eq!(fetch.metadata.is_async, Some(true));
+    }
+
+    #[test]
+    fn test_unresolved_and_recursive_calls_skipped() {
+        let code = r#"
+fn walk(n: u32) {
+    println!("{}", n);
+    std::mem::drop(n);
+    walk(n - 1);
+}
+"#;
+        let result = RustParser::new()
+            .unwrap()
+            .parse("src/lib.rs", code)
+            .unwrap();
+        assert!(call_edges(&result.edges).is_empty());
+    }
+
+    #[test]
+    fn test_python_constructor_and_module_level_calls() {
+        let code = r#"
+class Store:
+    pass
+
+def build():
+    return Store()
+
+build()
+"#;
+        let result = PythonParser::new().unwrap().parse("app.py", code).unwrap();
+        let calls = call_edges(&result.edges);
+
+        assert!(
+            calls
+                .iter()
+                .any(|e| e.source_id == "function:app.py:build"
+                    && e.target_id == "class:app.py:Store")
+        );
+        assert!(
+            calls
+                .iter()
+                .any(|e| e.source_id == "file:app.py" && e.target_id == "function:app.py:build")
+        );
+    }
+
+    #[test]
+    fn test_typescript_member_call() {
+        let code = r#"
+function load() {}
+async function main() {
+    await api.load();
+    this.reset();
+}
+"#;
+        let result = TypeScriptParser::new()
+            .unwrap()
+            .parse("src/index.ts", code)
+            .unwrap();
+
+        // `api.load()` calls whatever `api` is, not the local function
+        assert!(call_edges(&result.edges).is_empty());
+        assert_eq!(result.unresolved_calls.len(), 1);
+        let pending = &result.unresolved_calls[0];
+        assert_eq!(
+            (pending.name.as_str(), pending.qualifier.as_deref()),
+            ("load", Some("api"))
+        );
+        assert_eq!(pending.edge.source_id, "function:src/index.ts:main");
+        assert_eq!(pending.edge.metadata.is_async, Some(true));
+    }
+}
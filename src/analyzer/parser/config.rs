@@ -0,0 +1,1140 @@
+//! Configuration and Infrastructure Parser
+//!
+//! Package manifests (`Cargo.toml`, `package.json`, `pyproject.toml`),
+//! container and deployment files (`Dockerfile`, Compose files, Kubernetes
+//! manifests), GitHub Actions workflows and `.env.example` files are read
+//! for the facts they declare: dependencies with their versions, exposed
+//! ports, environment variables, services and scripts. Lockfiles
+//! (`Cargo.lock`, `package-lock.json`, `poetry.lock`, `go.sum`) contribute
+//! the versions they pin. Each recognized file
+//! gets one `config` node owned by the file, with the facts in its
+//! `config_metadata`.
+//!
+//! TOML, JSON and YAML documents are all read as JSON values; Dockerfiles
+//! and env files are scanned line by line. A file that fails to parse keeps
+//! its file node without a config node.
+
+use std::path::Path;
+
+use chrono::Utc;
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::{Language, ParseResult, Parser, create_file_node};
+use crate::types::{
+    ConfigKind, ConfigMetadata, DeclaredDependency, Edge, EdgeMetadata, EdgeType, EvidenceLocation,
+    InformationTier, Node, NodeMetadata, NodeStatus, NodeType, ScriptDefinition, ServiceDefinition,
+};
+
+/// Directories whose YAML files are taken for Kubernetes manifests.
+const KUBERNETES_DIRS: &[&str] = &[
+    "k8s",
+    "kubernetes",
+    "kube",
+    "manifests",
+    "deploy",
+    "deployment",
+    "deployments",
+    "kustomize",
+    "overlays",
+];
+
+/// YAML file stems naming a Kubernetes object wherever they live.
+const KUBERNETES_STEMS: &[&str] = &[
+    "deployment",
+    "service",
+    "ingress",
+    "statefulset",
+    "daemonset",
+    "cronjob",
+    "configmap",
+    "kustomization",
+];
+
+/// Suffixes of committed env templates (`.env.example`, `.env.sample`).
+const ENV_TEMPLATES: &[&str] = &["example", "sample", "template", "dist", "defaults"];
+
+/// The kind of configuration file at `path`, if it is one.
+///
+/// YAML files outside well-known locations are only taken for Kubernetes
+/// manifests by name or directory; the parser still checks their content.
+pub fn config_kind(path: &str) -> Option<ConfigKind> {
+    let path = Path::new(path);
+    let name = path.file_name()?.to_str()?.to_lowercase();
+    let is_yaml = name.ends_with(".yml") || name.ends_with(".yaml");
+    let dirs: Vec<String> = path
+        .parent()
+        .into_iter()
+        .flat_map(|p| p.components())
+        .filter_map(|c| c.as_os_str().to_str().map(str::to_lowercase))
+        .collect();
+
+    match name.as_str() {
+        "cargo.toml" => return Some(ConfigKind::Cargo),
+        "package.json" => return Some(ConfigKind::Npm),
+        "pyproject.toml" => return Some(ConfigKind::Pyproject),
+        "cargo.lock" | "package-lock.json" | "poetry.lock" | "go.sum" => {
+            return Some(ConfigKind::Lockfile);
+        }
+        _ => {}
+    }
+    if Language::from_path(path) == Language::Dockerfile || name.ends_with(".dockerfile") {
+        return Some(ConfigKind::Dockerfile);
+    }
+    if let Some(suffix) = name.strip_prefix(".env.")
+        && ENV_TEMPLATES.contains(&suffix)
+    {
+        return Some(ConfigKind::Env);
+    }
+    if !is_yaml {
+        return None;
+    }
+
+    let stem = name.trim_end_matches(".yaml").trim_end_matches(".yml");
+    if stem == "compose"
+        || stem == "docker-compose"
+        || stem.starts_with("docker-compose.")
+        || stem.starts_with("compose.")
+    {
+        return Some(ConfigKind::Compose);
+    }
+    if dirs
+        .windows(2)
+        .any(|w| w[0] == ".github" && w[1] == "workflows")
+    {
+        return Some(ConfigKind::GithubActions);
+    }
+    let kubernetes_dir = dirs.iter().any(|d| KUBERNETES_DIRS.contains(&d.as_str()));
+    let kubernetes_stem = KUBERNETES_STEMS
+        .iter()
+        .any(|s| stem == *s || stem.ends_with(&format!("-{}", s)));
+    (kubernetes_dir || kubernetes_stem).then_some(ConfigKind::Kubernetes)
+}
+
+pub struct ConfigParser {
+    kind: ConfigKind,
+}
+
+impl ConfigParser {
+    pub fn new(kind: ConfigKind) -> Self {
+        Self { kind }
+    }
+}
+
+impl Parser for ConfigParser {
+    fn parse(&self, path: &str, content: &str) -> crate::types::Result<ParseResult> {
+        let mut result = ParseResult::new();
+        let file = create_file_node(path);
+
+        let config = match self.kind {
+            ConfigKind::Cargo => toml_value(content).map(|v| cargo(&v)),
+            ConfigKind::Npm => serde_json::from_str(content).ok().map(|v| npm(&v)),
+            ConfigKind::Pyproject => toml_value(content).map(|v| pyproject(&v)),
+            ConfigKind::Dockerfile => Some(dockerfile(content)),
+            ConfigKind::Compose => yaml_documents(content)
+                .and_then(|docs| docs.into_iter().next())
+                .map(|v| compose(&v)),
+            ConfigKind::Kubernetes => yaml_documents(content).and_then(|docs| kubernetes(&docs)),
+            ConfigKind::GithubActions => yaml_documents(content)
+                .and_then(|docs| docs.into_iter().next())
+                .map(|v| github_actions(&v)),
+            ConfigKind::Env => Some(env_file(content)),
+            ConfigKind::Lockfile => lockfile(path, content),
+        };
+
+        if let Some(config) = config {
+            let node = config_node(path, &file, content, config);
+            result.edges.push(Edge {
+                id: format!("owns:{}", node.id),
+                edge_type: EdgeType::Owns,
+                source_id: file.id.clone(),
+                target_id: node.id.clone(),
+                metadata: EdgeMetadata::default(),
+                evidence: node.evidence.clone(),
+                tier: InformationTier::Fact,
+                confidence: 1.0,
+                last_verified: Utc::now(),
+            });
+            result.nodes.push(node);
+        }
+        result.nodes.insert(0, file);
+        Ok(result)
+    }
+
+    fn language(&self) -> Language {
+        match self.kind {
+            ConfigKind::Cargo | ConfigKind::Pyproject => Language::Toml,
+            ConfigKind::Npm => Language::Json,
+            ConfigKind::Dockerfile => Language::Dockerfile,
+            ConfigKind::Compose | ConfigKind::Kubernetes | ConfigKind::GithubActions => {
+                Language::Yaml
+            }
+            ConfigKind::Env | ConfigKind::Lockfile => Language::Unknown,
+        }
+    }
+}
+
+fn config_node(path: &str, file: &Node, content: &str, mut config: ConfigMetadata) -> Node {
+    for values in [&mut config.ports, &mut config.env_vars] {
+        values.sort();
+        values.dedup();
+    }
+    Node {
+        id: format!("config:{}", path),
+        node_type: NodeType::Config,
+        path: path.to_string(),
+        name: file.name.clone(),
+        metadata: NodeMetadata {
+            config_metadata: Some(config),
+            ..Default::default()
+        },
+        evidence: EvidenceLocation {
+            file: path.to_string(),
+            start_line: 1,
+            end_line: content.lines().count().max(1) as u32,
+            start_column: None,
+            end_column: None,
+        },
+        tier: InformationTier::Fact,
+        confidence: 1.0,
+        last_verified: Utc::now(),
+        status: NodeStatus::Verified,
+    }
+}
+
+fn empty(kind: ConfigKind) -> ConfigMetadata {
+    ConfigMetadata {
+        kind,
+        name: None,
+        dependencies: Vec::new(),
+        ports: Vec::new(),
+        env_vars: Vec::new(),
+        services: Vec::new(),
+        scripts: Vec::new(),
+    }
+}
+
+// =============================================================================
+// Value Helpers
+// =============================================================================
+
+fn toml_value(content: &str) -> Option<Value> {
+    let table: toml::Table = toml::from_str(content).ok()?;
+    serde_json::to_value(table).ok()
+}
+
+fn yaml_documents(content: &str) -> Option<Vec<Value>> {
+    let mut documents = Vec::new();
+    for document in serde_yaml::Deserializer::from_str(content) {
+        let value = serde_yaml::Value::deserialize(document).ok()?;
+        if !value.is_null() {
+            documents.push(serde_json::to_value(value).ok()?);
+        }
+    }
+    Some(documents)
+}
+
+/// A scalar as text (`8080` and `"8080"` alike).
+fn scalar(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+/// A scalar or a list of scalars.
+fn scalars(value: &Value) -> Vec<String> {
+    match value {
+        Value::Array(items) => items.iter().filter_map(scalar).collect(),
+        other => scalar(other).into_iter().collect(),
+    }
+}
+
+fn entries(value: &Value) -> impl Iterator<Item = (&String, &Value)> {
+    value.as_object().into_iter().flatten()
+}
+
+/// Names set by an environment block: a map of names or a list of
+/// `NAME=value` strings.
+fn env_names(value: &Value) -> Vec<String> {
+    match value {
+        Value::Object(map) => map.keys().cloned().collect(),
+        Value::Array(items) => items
+            .iter()
+            .filter_map(scalar)
+            .filter_map(|item| {
+                let name = item.split('=').next()?.trim();
+                (!name.is_empty()).then(|| name.to_string())
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn dependency(name: &str, version: Option<String>, scope: &str) -> DeclaredDependency {
+    DeclaredDependency {
+        name: name.to_string(),
+        version: version.filter(|v| !v.is_empty()),
+        scope: scope.to_string(),
+    }
+}
+
+/// Split an image reference into name and tag (`redis:7-alpine`,
+/// `ghcr.io/org/app@sha256:...`); a registry port is not a tag.
+fn image_dependency(image: &str) -> DeclaredDependency {
+    let (name, version) = match image.split_once('@') {
+        Some((name, digest)) => (name, Some(digest.to_string())),
+        None => match image.rsplit_once(':') {
+            Some((name, tag)) if !tag.contains('/') => (name, Some(tag.to_string())),
+            _ => (image, None),
+        },
+    };
+    dependency(name, version, "image")
+}
+
+// =============================================================================
+// Package Manifests
+// =============================================================================
+
+fn cargo(manifest: &Value) -> ConfigMetadata {
+    let mut config = empty(ConfigKind::Cargo);
+    config.name = manifest["package"]["name"].as_str().map(str::to_string);
+
+    let tables = [
+        ("dependencies", "runtime"),
+        ("dev-dependencies", "dev"),
+        ("build-dependencies", "build"),
+    ];
+    let targets = entries(&manifest["target"]).map(|(_, target)| target);
+    for section in std::iter::once(manifest).chain(targets) {
+        for (table, scope) in tables {
+            for (name, spec) in entries(&section[table]) {
+                config
+                    .dependencies
+                    .push(dependency(name, cargo_version(spec), scope));
+            }
+        }
+    }
+    for (name, spec) in entries(&manifest["workspace"]["dependencies"]) {
+        config
+            .dependencies
+            .push(dependency(name, cargo_version(spec), "runtime"));
+    }
+
+    if let Some(build) = manifest["package"]["build"].as_str() {
+        config.scripts.push(ScriptDefinition {
+            name: "build".to_string(),
+            command: build.to_string(),
+        });
+    }
+    for bin in manifest["bin"].as_array().into_iter().flatten() {
+        if let (Some(name), Some(path)) = (bin["name"].as_str(), bin["path"].as_str()) {
+            config.scripts.push(ScriptDefinition {
+                name: name.to_string(),
+                command: path.to_string(),
+            });
+        }
+    }
+    config
+}
+
+/// `"1.0"` or `{ version = "1.0", features = [...] }`; path, git and
+/// workspace dependencies have no version.
+fn cargo_version(spec: &Value) -> Option<String> {
+    scalar(spec).or_else(|| scalar(&spec["version"]))
+}
+
+fn npm(manifest: &Value) -> ConfigMetadata {
+    let mut config = empty(ConfigKind::Npm);
+    config.name = manifest["name"].as_str().map(str::to_string);
+
+    let tables = [
+        ("dependencies", "runtime"),
+        ("devDependencies", "dev"),
+        ("peerDependencies", "peer"),
+        ("optionalDependencies", "optional"),
+    ];
+    for (table, scope) in tables {
+        for (name, version) in entries(&manifest[table]) {
+            config
+                .dependencies
+                .push(dependency(name, scalar(version), scope));
+        }
+    }
+    for (name, command) in entries(&manifest["scripts"]) {
+        if let Some(command) = scalar(command) {
+            config.scripts.push(ScriptDefinition {
+                name: name.clone(),
+                command,
+            });
+        }
+    }
+    config
+}
+
+fn pyproject(manifest: &Value) -> ConfigMetadata {
+    let mut config = empty(ConfigKind::Pyproject);
+    let project = &manifest["project"];
+    let poetry = &manifest["tool"]["poetry"];
+    config.name = project["name"]
+        .as_str()
+        .or_else(|| poetry["name"].as_str())
+        .map(str::to_string);
+
+    // PEP 621 and PEP 735 requirement strings
+    let requirements = |value: &Value, scope: &str, deps: &mut Vec<DeclaredDependency>| {
+        for requirement in value.as_array().into_iter().flatten() {
+            if let Some(dep) = requirement
+                .as_str()
+                .and_then(|r| requirement_dependency(r, scope))
+            {
+                deps.push(dep);
+            }
+        }
+    };
+    requirements(
+        &project["dependencies"],
+        "runtime",
+        &mut config.dependencies,
+    );
+    for (_, group) in entries(&project["optional-dependencies"]) {
+        requirements(group, "optional", &mut config.dependencies);
+    }
+    for (_, group) in entries(&manifest["dependency-groups"]) {
+        requirements(group, "dev", &mut config.dependencies);
+    }
+
+    // Poetry tables of name = version
+    let mut poetry_table = |value: &Value, scope: &str| {
+        for (name, spec) in entries(value) {
+            if name != "python" {
+                config
+                    .dependencies
+                    .push(dependency(name, cargo_version(spec), scope));
+            }
+        }
+    };
+    poetry_table(&poetry["dependencies"], "runtime");
+    poetry_table(&poetry["dev-dependencies"], "dev");
+    for (_, group) in entries(&poetry["group"]) {
+        poetry_table(&group["dependencies"], "dev");
+    }
+
+    for scripts in [&project["scripts"], &poetry["scripts"]] {
+        for (name, target) in entries(scripts) {
+            if let Some(command) = scalar(target) {
+                config.scripts.push(ScriptDefinition {
+                    name: name.clone(),
+                    command,
+                });
+            }
+        }
+    }
+    config
+}
+
+/// `requests[socks]>=2.28; python_version > "3.8"` → `requests`, `>=2.28`.
+fn requirement_dependency(requirement: &str, scope: &str) -> Option<DeclaredDependency> {
+    let requirement = requirement.split(';').next()?.trim();
+    let end = requirement
+        .find(|c: char| !(c.is_alphanumeric() || matches!(c, '-' | '_' | '.')))
+        .unwrap_or(requirement.len());
+    let name = &requirement[..end];
+    if name.is_empty() {
+        return None;
+    }
+    let rest = requirement[end..].trim_start();
+    let rest = match rest.strip_prefix('[') {
+        Some(extras) => extras.split_once(']').map_or("", |(_, r)| r),
+        None => rest,
+    };
+    let version = rest.trim().trim_matches(|c| c == '(' || c == ')').trim();
+    Some(dependency(name, Some(version.to_string()), scope))
+}
+
+// =============================================================================
+// Containers and Deployment
+// =============================================================================
+
+fn dockerfile(content: &str) -> ConfigMetadata {
+    let mut config = empty(ConfigKind::Dockerfile);
+    let mut stages: Vec<String> = Vec::new();
+
+    for instruction in dockerfile_instructions(content) {
+        let (keyword, args) = instruction
+            .split_once(char::is_whitespace)
+            .unwrap_or((instruction.as_str(), ""));
+        let args = args.trim();
+        match keyword.to_ascii_uppercase().as_str() {
+            "FROM" => {
+                let words: Vec<&str> = args
+                    .split_whitespace()
+                    .filter(|w| !w.starts_with("--"))
+                    .collect();
+                let Some(image) = words.first() else {
+                    continue;
+                };
+                // Later stages build on earlier ones by name
+                if *image != "scratch" && !stages.iter().any(|s| s.eq_ignore_ascii_case(image)) {
+                    config.dependencies.push(image_dependency(image));
+                }
+                if let [_, r#as, stage] = words.as_slice()
+                    && r#as.eq_ignore_ascii_case("as")
+                {
+                    stages.push(stage.to_string());
+                }
+            }
+            "EXPOSE" => config
+                .ports
+                .extend(args.split_whitespace().map(str::to_string)),
+            "ENV" => config.env_vars.extend(dockerfile_env(args)),
+            "CMD" | "ENTRYPOINT" => config.scripts.push(ScriptDefinition {
+                name: keyword.to_ascii_lowercase(),
+                command: exec_form(args),
+            }),
+            _ => {}
+        }
+    }
+    config
+}
+
+/// Instructions with comments dropped and `\` continuations joined.
+fn dockerfile_instructions(content: &str) -> Vec<String> {
+    let mut instructions = Vec::new();
+    let mut current = String::new();
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('#') {
+            continue;
+        }
+        match trimmed.strip_suffix('\\') {
+            Some(part) => {
+                current.push_str(part);
+                current.push(' ');
+            }
+            None => {
+                current.push_str(trimmed);
+                if !current.trim().is_empty() {
+                    instructions.push(current.trim().to_string());
+                }
+                current.clear();
+            }
+        }
+    }
+    if !current.trim().is_empty() {
+        instructions.push(current.trim().to_string());
+    }
+    instructions
+}
+
+/// `ENV A=1 B="two words"` or the legacy `ENV A 1`.
+fn dockerfile_env(args: &str) -> Vec<String> {
+    if !args
+        .split_whitespace()
+        .next()
+        .is_some_and(|w| w.contains('='))
+    {
+        return args
+            .split_whitespace()
+            .next()
+            .map(str::to_string)
+            .into_iter()
+            .collect();
+    }
+    let mut names = Vec::new();
+    let mut quote = None;
+    let mut token_start = true;
+    let mut name = String::new();
+    for c in args.chars() {
+        match (quote, c) {
+            (Some(q), _) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, c) if c.is_whitespace() => {
+                token_start = true;
+                name.clear();
+            }
+            (None, '=') if token_start => {
+                names.push(std::mem::take(&mut name));
+                token_start = false;
+            }
+            (None, c) if token_start => name.push(c),
+            _ => {}
+        }
+    }
+    names
+}
+
+/// `["node", "server.js"]` → `node server.js`; shell form as written.
+fn exec_form(args: &str) -> String {
+    serde_json::from_str::<Vec<String>>(args)
+        .map(|words| words.join(" "))
+        .unwrap_or_else(|_| args.to_string())
+}
+
+fn compose(document: &Value) -> ConfigMetadata {
+    let mut config = empty(ConfigKind::Compose);
+    config.name = document["name"].as_str().map(str::to_string);
+
+    for (name, service) in entries(&document["services"]) {
+        let image = service["image"].as_str().map(str::to_string);
+        if let Some(image) = &image {
+            config.dependencies.push(image_dependency(image));
+        }
+        let ports: Vec<String> = service["ports"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(compose_port)
+            .chain(scalars(&service["expose"]))
+            .collect();
+        config.ports.extend(ports.iter().cloned());
+        config.env_vars.extend(env_names(&service["environment"]));
+
+        let depends_on = match &service["depends_on"] {
+            Value::Object(map) => map.keys().cloned().collect(),
+            other => scalars(other),
+        };
+        if let Some(command) = scalar(&service["command"]).or_else(|| {
+            service["command"]
+                .as_array()
+                .map(|_| scalars(&service["command"]).join(" "))
+        }) {
+            config.scripts.push(ScriptDefinition {
+                name: name.clone(),
+                command,
+            });
+        }
+        config.services.push(ServiceDefinition {
+            name: name.clone(),
+            kind: None,
+            image,
+            ports,
+            depends_on,
+        });
+    }
+    config
+}
+
+/// `"8080:80"`, `3000` or `{ target: 80, published: 8080 }`.
+fn compose_port(port: &Value) -> Option<String> {
+    scalar(port).or_else(|| {
+        let target = scalar(&port["target"])?;
+        Some(match scalar(&port["published"]) {
+            Some(published) => format!("{}:{}", published, target),
+            None => target,
+        })
+    })
+}
+
+/// Objects of a Kubernetes manifest, or `None` when no document is one.
+fn kubernetes(documents: &[Value]) -> Option<ConfigMetadata> {
+    let mut config = empty(ConfigKind::Kubernetes);
+    let objects = documents.iter().flat_map(|doc| match doc["kind"].as_str() {
+        Some("List") => doc["items"].as_array().cloned().unwrap_or_default(),
+        _ => vec![doc.clone()],
+    });
+
+    for object in objects {
+        let (Some(kind), Some(name)) =
+            (object["kind"].as_str(), object["metadata"]["name"].as_str())
+        else {
+            continue;
+        };
+        if object["apiVersion"].as_str().is_none() {
+            continue;
+        }
+
+        let spec = &object["spec"];
+        let pod = [
+            &spec["template"]["spec"],
+            &spec["jobTemplate"]["spec"]["template"]["spec"],
+            spec,
+        ]
+        .into_iter()
+        .find(|p| p["containers"].is_array());
+
+        let mut service = ServiceDefinition {
+            name: name.to_string(),
+            kind: Some(kind.to_string()),
+            image: None,
+            ports: Vec::new(),
+            depends_on: Vec::new(),
+        };
+        if let Some(pod) = pod {
+            let containers = ["initContainers", "containers"]
+                .into_iter()
+                .flat_map(|key| pod[key].as_array().cloned().unwrap_or_default());
+            for container in containers {
+                if let Some(image) = container["image"].as_str() {
+                    config.dependencies.push(image_dependency(image));
+                    service.image.get_or_insert_with(|| image.to_string());
+                }
+                for port in container["ports"].as_array().into_iter().flatten() {
+                    service.ports.extend(scalar(&port["containerPort"]));
+                }
+                for env in container["env"].as_array().into_iter().flatten() {
+                    config.env_vars.extend(scalar(&env["name"]));
+                }
+            }
+        }
+        if kind == "Service" {
+            for port in spec["ports"].as_array().into_iter().flatten() {
+                let Some(exposed) = scalar(&port["port"]) else {
+                    continue;
+                };
+                service.ports.push(match scalar(&port["targetPort"]) {
+                    Some(target) if target != exposed => format!("{}:{}", exposed, target),
+                    _ => exposed,
+                });
+            }
+        }
+        if kind == "ConfigMap" {
+            config
+                .env_vars
+                .extend(entries(&object["data"]).map(|(key, _)| key.clone()));
+        }
+        config.ports.extend(service.ports.iter().cloned());
+        config.services.push(service);
+    }
+
+    (!config.services.is_empty()).then_some(config)
+}
+
+fn github_actions(workflow: &Value) -> ConfigMetadata {
+    let mut config = empty(ConfigKind::GithubActions);
+    config.name = workflow["name"].as_str().map(str::to_string);
+    config.env_vars.extend(env_names(&workflow["env"]));
+
+    for (job_id, job) in entries(&workflow["jobs"]) {
+        config.env_vars.extend(env_names(&job["env"]));
+        let image = scalar(&job["container"]).or_else(|| scalar(&job["container"]["image"]));
+        if let Some(image) = &image {
+            config.dependencies.push(image_dependency(image));
+        }
+        for (_, service) in entries(&job["services"]) {
+            if let Some(image) = service["image"].as_str() {
+                config.dependencies.push(image_dependency(image));
+            }
+        }
+
+        for (index, step) in job["steps"].as_array().into_iter().flatten().enumerate() {
+            config.env_vars.extend(env_names(&step["env"]));
+            if let Some(action) = step["uses"].as_str() {
+                // Local actions (`./.github/actions/x`) are not dependencies
+                if !action.starts_with("./") {
+                    let (name, version) = match action.split_once('@') {
+                        Some((name, version)) => (name, Some(version.to_string())),
+                        None => (action, None),
+                    };
+                    config
+                        .dependencies
+                        .push(dependency(name, version, "action"));
+                }
+            }
+            if let Some(run) = step["run"].as_str() {
+                let step_name = step["name"]
+                    .as_str()
+                    .map_or_else(|| format!("step {}", index + 1), str::to_string);
+                config.scripts.push(ScriptDefinition {
+                    name: format!("{}: {}", job_id, step_name),
+                    command: run.trim().to_string(),
+                });
+            }
+        }
+
+        config.services.push(ServiceDefinition {
+            name: job_id.clone(),
+            kind: Some("job".to_string()),
+            image,
+            ports: Vec::new(),
+            depends_on: scalars(&job["needs"]),
+        });
+    }
+    config
+}
+
+fn env_file(content: &str) -> ConfigMetadata {
+    let mut config = empty(ConfigKind::Env);
+    for line in content.lines() {
+        let line = line.trim();
+        if line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        if let Some((name, _)) = line.split_once('=') {
+            let name = name.trim();
+            if !name.is_empty() && !name.contains(char::is_whitespace) {
+                config.env_vars.push(name.to_string());
+            }
+        }
+    }
+    config
+}
+
+// =============================================================================
+// Lockfiles
+// =============================================================================
+
+/// Versions pinned by a lockfile, one `locked` dependency per package.
+/// Packages of the project itself (Cargo path packages, npm workspace
+/// links) are left out.
+fn lockfile(path: &str, content: &str) -> Option<ConfigMetadata> {
+    let name = Path::new(path).file_name()?.to_str()?.to_lowercase();
+    let mut config = empty(ConfigKind::Lockfile);
+    match name.as_str() {
+        "cargo.lock" | "poetry.lock" => {
+            let lock = toml_value(content)?;
+            for package in lock["package"].as_array().into_iter().flatten() {
+                let local = name == "cargo.lock" && package.get("source").is_none();
+                if let (Some(name), false) = (package["name"].as_str(), local) {
+                    config.dependencies.push(dependency(
+                        name,
+                        scalar(&package["version"]),
+                        "locked",
+                    ));
+                }
+            }
+        }
+        "package-lock.json" => {
+            let lock: Value = serde_json::from_str(content).ok()?;
+            // v2/v3 key installed paths; v1 only has the dependency tree
+            for (key, package) in entries(&lock["packages"]) {
+                let Some(name) = key.strip_prefix("node_modules/") else {
+                    continue;
+                };
+                if !name.contains("/node_modules/") && package["link"].as_bool() != Some(true) {
+                    config.dependencies.push(dependency(
+                        name,
+                        scalar(&package["version"]),
+                        "locked",
+                    ));
+                }
+            }
+            if config.dependencies.is_empty() {
+                for (name, package) in entries(&lock["dependencies"]) {
+                    config.dependencies.push(dependency(
+                        name,
+                        scalar(&package["version"]),
+                        "locked",
+                    ));
+                }
+            }
+        }
+        "go.sum" => {
+            // `module version[/go.mod] hash`, usually twice per version
+            for line in content.lines() {
+                let mut fields = line.split_whitespace();
+                let (Some(module), Some(version)) = (fields.next(), fields.next()) else {
+                    continue;
+                };
+                let version = version.trim_end_matches("/go.mod");
+                match config.dependencies.iter_mut().find(|d| d.name == module) {
+                    Some(existing) => existing.version = Some(version.to_string()),
+                    None => config.dependencies.push(dependency(
+                        module,
+                        Some(version.to_string()),
+                        "locked",
+                    )),
+                }
+            }
+        }
+        _ => return None,
+    }
+    Some(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(path: &str, content: &str) -> ConfigMetadata {
+        let kind = config_kind(path).unwrap();
+        let result = ConfigParser::new(kind).parse(path, content).unwrap();
+        assert!(
+            result
+                .edges
+                .iter()
+                .all(|e| e.edge_type == EdgeType::Owns && e.source_id == format!("file:{}", path))
+        );
+        result
+            .nodes
+            .into_iter()
+            .find(|n| n.node_type == NodeType::Config)
+            .and_then(|n| n.metadata.config_metadata)
+            .unwrap()
+    }
+
+    fn deps(config: &ConfigMetadata) -> Vec<(&str, Option<&str>, &str)> {
+        config
+            .dependencies
+            .iter()
+            .map(|d| (d.name.as_str(), d.version.as_deref(), d.scope.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_config_kind() {
+        assert_eq!(config_kind("./Cargo.toml"), Some(ConfigKind::Cargo));
+        assert_eq!(config_kind("web/package.json"), Some(ConfigKind::Npm));
+        assert_eq!(
+            config_kind("docker/Dockerfile.prod"),
+            Some(ConfigKind::Dockerfile)
+        );
+        assert_eq!(
+            config_kind("docker-compose.dev.yml"),
+            Some(ConfigKind::Compose)
+        );
+        assert_eq!(
+            config_kind(".github/workflows/ci.yml"),
+            Some(ConfigKind::GithubActions)
+        );
+        assert_eq!(config_kind("k8s/api.yaml"), Some(ConfigKind::Kubernetes));
+        assert_eq!(
+            config_kind("api-deployment.yaml"),
+            Some(ConfigKind::Kubernetes)
+        );
+        assert_eq!(config_kind(".env.example"), Some(ConfigKind::Env));
+        assert_eq!(config_kind("Cargo.lock"), Some(ConfigKind::Lockfile));
+        assert_eq!(
+            config_kind("web/package-lock.json"),
+            Some(ConfigKind::Lockfile)
+        );
+        assert_eq!(config_kind(".env"), None);
+        assert_eq!(config_kind("openapi.yaml"), None);
+        assert_eq!(config_kind("tsconfig.json"), None);
+    }
+
+    #[test]
+    fn test_package_manifests() {
+        let cargo = parse(
+            "./Cargo.toml",
+            r#"
+[package]
+name = "weavewiki"
+build = "build.rs"
+
+[dependencies]
+serde = { version = "1.0", features = ["derive"] }
+tokio = "1"
+local = { path = "../local" }
+
+[dev-dependencies]
+tempfile = "3"
+
+[target.'cfg(unix)'.dependencies]
+libc = "0.2"
+"#,
+        );
+        assert_eq!(cargo.name.as_deref(), Some("weavewiki"));
+        assert_eq!(
+            deps(&cargo),
+            vec![
+                ("local", None, "runtime"),
+                ("serde", Some("1.0"), "runtime"),
+                ("tokio", Some("1"), "runtime"),
+                ("tempfile", Some("3"), "dev"),
+                ("libc", Some("0.2"), "runtime"),
+            ]
+        );
+        assert_eq!(cargo.scripts[0].command, "build.rs");
+
+        let npm = parse(
+            "./web/package.json",
+            r#"{"name": "web", "scripts": {"dev": "vite"},
+                "dependencies": {"react": "^18.2.0"}, "devDependencies": {"vite": "^5"}}"#,
+        );
+        assert_eq!(
+            deps(&npm),
+            vec![
+                ("react", Some("^18.2.0"), "runtime"),
+                ("vite", Some("^5"), "dev")
+            ]
+        );
+        assert_eq!(
+            (
+                npm.scripts[0].name.as_str(),
+                npm.scripts[0].command.as_str()
+            ),
+            ("dev", "vite")
+        );
+
+        let python = parse(
+            "./pyproject.toml",
+            r#"
+[project]
+name = "app"
+dependencies = ["requests[socks]>=2.28; python_version > '3.8'", "click"]
+
+[project.optional-dependencies]
+pg = ["psycopg (>=3.1)"]
+
+[project.scripts]
+app = "app.cli:main"
+"#,
+        );
+        assert_eq!(
+            deps(&python),
+            vec![
+                ("requests", Some(">=2.28"), "runtime"),
+                ("click", None, "runtime"),
+                ("psycopg", Some(">=3.1"), "optional"),
+            ]
+        );
+        assert_eq!(python.scripts[0].command, "app.cli:main");
+    }
+
+    #[test]
+    fn test_lockfiles() {
+        let cargo = parse(
+            "./Cargo.lock",
+            "version = 3\n\n[[package]]\nname = \"app\"\nversion = \"0.1.0\"\n\n\
+             [[package]]\nname = \"serde\"\nversion = \"1.0.210\"\n\
+             source = \"registry+https://github.com/rust-lang/crates.io-index\"\n",
+        );
+        assert_eq!(deps(&cargo), vec![("serde", Some("1.0.210"), "locked")]);
+
+        let npm = parse(
+            "./package-lock.json",
+            r#"{"lockfileVersion": 3, "packages": {
+                "": {"name": "web"},
+                "node_modules/react": {"version": "18.3.1"},
+                "node_modules/@org/ui": {"resolved": "packages/ui", "link": true},
+                "node_modules/a/node_modules/b": {"version": "1.0.0"}}}"#,
+        );
+        assert_eq!(deps(&npm), vec![("react", Some("18.3.1"), "locked")]);
+
+        let go = parse(
+            "./go.sum",
+            "github.com/spf13/viper v1.18.0 h1:abc=\n\
+             github.com/spf13/viper v1.18.0/go.mod h1:def=\n",
+        );
+        assert_eq!(
+            deps(&go),
+            vec![("github.com/spf13/viper", Some("v1.18.0"), "locked")]
+        );
+    }
+
+    #[test]
+    fn test_deployment_files() {
+        let docker = parse(
+            "./Dockerfile",
+            "# build\nFROM rust:1.80 AS builder\nRUN cargo build --release\n\n\
+             FROM gcr.io/distroless/cc@sha256:abc\nCOPY --from=builder /app /app\n\
+             ENV RUST_LOG=info \\\n    PORT=\"8080\"\nEXPOSE 8080 9090/udp\n\
+             ENTRYPOINT [\"/app\", \"serve\"]\n",
+        );
+        assert_eq!(
+            deps(&docker),
+            vec![
+                ("rust", Some("1.80"), "image"),
+                ("gcr.io/distroless/cc", Some("sha256:abc"), "image"),
+            ]
+        );
+        assert_eq!(docker.env_vars, ["PORT", "RUST_LOG"]);
+        assert_eq!(docker.ports, ["8080", "9090/udp"]);
+        assert_eq!(docker.scripts[0].command, "/app serve");
+
+        let compose = parse(
+            "./docker-compose.yml",
+            r#"
+services:
+  api:
+    build: .
+    ports: ["8080:80"]
+    environment:
+      DATABASE_URL: postgres://db/app
+    depends_on: [db]
+  db:
+    image: postgres:16
+    environment:
+      - POSTGRES_PASSWORD=secret
+"#,
+        );
+        assert_eq!(deps(&compose), vec![("postgres", Some("16"), "image")]);
+        assert_eq!(compose.ports, ["8080:80"]);
+        assert_eq!(compose.env_vars, ["DATABASE_URL", "POSTGRES_PASSWORD"]);
+        assert_eq!(compose.services[0].depends_on, ["db"]);
+
+        let k8s = parse(
+            "./k8s/api.yaml",
+            r#"
+apiVersion: apps/v1
+kind: Deployment
+metadata: {name: api}
+spec:
+  template:
+    spec:
+      containers:
+        - name: api
+          image: ghcr.io/acme/api:1.4.0
+          ports: [{containerPort: 8080}]
+          env: [{name: DATABASE_URL, value: x}]
+---
+apiVersion: v1
+kind: Service
+metadata: {name: api}
+spec:
+  ports: [{port: 80, targetPort: 8080}]
+"#,
+        );
+        let services: Vec<_> = k8s
+            .services
+            .iter()
+            .map(|s| (s.kind.as_deref().unwrap(), s.ports.clone()))
+            .collect();
+        assert_eq!(
+            services,
+            vec![
+                ("Deployment", vec!["8080".to_string()]),
+                ("Service", vec!["80:8080".to_string()]),
+            ]
+        );
+        assert_eq!(
+            k8s.services[0].image.as_deref(),
+            Some("ghcr.io/acme/api:1.4.0")
+        );
+        assert_eq!(k8s.env_vars, ["DATABASE_URL"]);
+
+        let ci = parse(
+            "./.github/workflows/ci.yml",
+            r#"
+on: [push]
+jobs:
+  test:
+    runs-on: ubuntu-latest
+    env: {CARGO_TERM_COLOR: always}
+    steps:
+      - uses: actions/checkout@v4
+      - name: Test
+        run: cargo test
+  release:
+    needs: test
+    steps:
+      - uses: ./.github/actions/publish
+"#,
+        );
+        assert_eq!(deps(&ci), vec![("actions/checkout", Some("v4"), "action")]);
+        assert_eq!(ci.scripts[0].name, "test: Test");
+        // Keys come back sorted: `release` before `test`
+        assert_eq!(ci.services[0].depends_on, ["test"]);
+        assert_eq!(ci.env_vars, ["CARGO_TERM_COLOR"]);
+
+        let env = parse(
+            "./.env.example",
+            "# database\nDATABASE_URL=\nexport API_KEY=changeme\n",
+        );
+        assert_eq!(env.env_vars, ["API_KEY", "DATABASE_URL"]);
+    }
+
+    #[test]
+    fn test_yaml_that_is_not_a_manifest() {
+        let result = ConfigParser::new(ConfigKind::Kubernetes)
+            .parse("./deploy/values.yaml", "replicas: 3\nimage: api\n")
+            .unwrap();
+        assert_eq!(result.nodes.len(), 1);
+        assert!(result.edges.is_empty());
+    }
+}
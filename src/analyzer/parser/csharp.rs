@@ -0,0 +1,540 @@
+use tree_sitter::{Query, QueryCursor, StreamingIterator};
+
+use super::{
+    CallSyntax, DocSyntax, Language, MetricsSyntax, ParseResult, Parser, TestSyntax,
+    create_code_edge, create_code_node, create_file_node, create_ts_parser, extract_calls,
+    extract_docs, extract_metrics, extract_settings, extract_tests, fingerprint_nodes,
+    get_node_text,
+};
+use crate::types::{
+    EdgeType, FunctionSignature, ImportType, NodeMetadata, NodeType, Parameter, Result, Visibility,
+    WeaveError,
+};
+
+const CALL_SYNTAX: CallSyntax = CallSyntax {
+    scope_kinds: &[
+        "method_declaration",
+        "constructor_declaration",
+        "local_function_statement",
+        "class_declaration",
+        "struct_declaration",
+        "record_declaration",
+    ],
+    call_kinds: &[
+        ("invocation_expression", Some("function")),
+        ("object_creation_expression", Some("type")),
+    ],
+    async_kinds: &["await_expression"],
+};
+
+const DOC_SYNTAX: DocSyntax = DocSyntax {
+    item_kinds: &[
+        "class_declaration",
+        "struct_declaration",
+        "record_declaration",
+        "interface_declaration",
+        "enum_declaration",
+        "method_declaration",
+        "constructor_declaration",
+    ],
+    file_item_kinds: &[],
+    comment_kinds: &["comment"],
+    doc_prefixes: &["///", "/**"],
+    inner_prefixes: &[],
+    skip_kinds: &["attribute_list"],
+    wrapper_kinds: &[],
+    docstrings: false,
+};
+
+const TEST_SYNTAX: TestSyntax = TestSyntax {
+    item_kinds: &["method_declaration"],
+    marker_kinds: &["attribute"],
+    markers: &[
+        "Test",
+        "TestCase",
+        "Fact",
+        "Theory",
+        "TestMethod",
+        "DataTestMethod",
+    ],
+    name_prefixes: &[],
+    calls: &CALL_SYNTAX,
+};
+
+const METRICS_SYNTAX: MetricsSyntax = MetricsSyntax {
+    branch_kinds: &[
+        "if_statement",
+        "for_statement",
+        "foreach_statement",
+        "while_statement",
+        "do_statement",
+        "switch_section",
+        "switch_expression_arm",
+        "catch_clause",
+        "conditional_expression",
+    ],
+    logical_kinds: &["binary_expression"],
+    nesting_kinds: &[
+        "if_statement",
+        "for_statement",
+        "foreach_statement",
+        "while_statement",
+        "do_statement",
+        "switch_statement",
+        "switch_expression",
+        "try_statement",
+    ],
+    return_kinds: &["return_statement"],
+};
+
+/// Declarations that own members, with the ID prefix and node type they map to.
+const TYPE_KINDS: &[(&str, &str, NodeType)] = &[
+    ("class_declaration", "class", NodeType::Class),
+    ("struct_declaration", "class", NodeType::Class),
+    ("record_declaration", "class", NodeType::Class),
+    ("interface_declaration", "interface", NodeType::Interface),
+    ("enum_declaration", "enum", NodeType::Enum),
+];
+
+pub struct CSharpParser;
+
+impl CSharpParser {
+    pub fn new() -> Result<Self> {
+        let _ = create_ts_parser(tree_sitter_c_sharp::LANGUAGE, "C#")?;
+        Ok(Self)
+    }
+}
+
+impl Parser for CSharpParser {
+    fn parse(&self, path: &str, content: &str) -> Result<ParseResult> {
+        let mut parser =
+            create_ts_parser(tree_sitter_c_sharp::LANGUAGE, "C#").map_err(|mut e| {
+                if let WeaveError::Parse {
+                    path: ref mut p, ..
+                } = e
+                {
+                    *p = path.to_string();
+                }
+                e
+            })?;
+
+        let tree = parser
+            .parse(content, None)
+            .ok_or_else(|| WeaveError::Parse {
+                message: "Failed to parse C# file".to_string(),
+                path: path.to_string(),
+            })?;
+
+        let mut result = ParseResult::new();
+        let root = tree.root_node();
+
+        result.nodes.push(create_file_node(path));
+
+        extract_namespaces(root, content, path, &mut result);
+        extract_usings(root, content, path, &mut result);
+        extract_types(root, content, path, &mut result);
+        extract_methods(root, content, path, &mut result);
+        extract_calls(root, content, path, &mut result, &CALL_SYNTAX);
+        extract_docs(root, content, &mut result, &DOC_SYNTAX);
+        extract_tests(root, content, path, &mut result, &TEST_SYNTAX);
+        extract_settings(root, content, path, &mut result, &CALL_SYNTAX);
+        extract_metrics(root, content, &mut result, &METRICS_SYNTAX);
+        fingerprint_nodes(root, content, &mut result);
+
+        Ok(result)
+    }
+
+    fn language(&self) -> Language {
+        Language::CSharp
+    }
+}
+
+fn extract_namespaces(
+    root: tree_sitter::Node,
+    content: &str,
+    path: &str,
+    result: &mut ParseResult,
+) {
+    let query_str = r#"
+        [
+            (namespace_declaration name: (_) @namespace)
+            (file_scoped_namespace_declaration name: (_) @namespace)
+        ]
+    "#;
+
+    if let Ok(query) = Query::new(&tree_sitter_c_sharp::LANGUAGE.into(), query_str) {
+        let mut cursor = QueryCursor::new();
+        let mut matches = cursor.matches(&query, root, content.as_bytes());
+
+        while let Some(m) = matches.next() {
+            for cap in m.captures.iter() {
+                let node = cap.node;
+                let name = get_node_text(node, content.as_bytes()).to_string();
+                result.nodes.push(create_code_node(
+                    format!("namespace:{}:{}", path, name),
+                    NodeType::Module,
+                    path,
+                    name,
+                    node,
+                ));
+            }
+        }
+    }
+}
+
+/// `using A.B;`, `using static A.B;` and `using Alias = A.B;`
+fn extract_usings(root: tree_sitter::Node, content: &str, path: &str, result: &mut ParseResult) {
+    let query_str = r#"
+        (using_directive) @using
+    "#;
+
+    if let Ok(query) = Query::new(&tree_sitter_c_sharp::LANGUAGE.into(), query_str) {
+        let mut cursor = QueryCursor::new();
+        let mut matches = cursor.matches(&query, root, content.as_bytes());
+
+        while let Some(m) = matches.next() {
+            for cap in m.captures.iter() {
+                let node = cap.node;
+                let bytes = content.as_bytes();
+                let alias = node.child_by_field_name("name");
+                let mut cursor = node.walk();
+                let Some(target) = node
+                    .named_children(&mut cursor)
+                    .filter(|c| Some(c.id()) != alias.map(|a| a.id()))
+                    .last()
+                else {
+                    continue;
+                };
+                let namespace = get_node_text(target, bytes);
+
+                let mut edge = create_code_edge(
+                    format!("import:{}:{}", path, namespace),
+                    EdgeType::DependsOn,
+                    format!("file:{}", path),
+                    format!("module:{}", namespace),
+                    node,
+                    path,
+                );
+                edge.metadata.import_type = Some(ImportType::Static);
+                edge.metadata.exposed_as = alias.map(|a| get_node_text(a, bytes).to_string());
+                result.edges.push(edge);
+            }
+        }
+    }
+}
+
+fn extract_types(root: tree_sitter::Node, content: &str, path: &str, result: &mut ParseResult) {
+    let query_str = r#"
+        [
+            (class_declaration)
+            (struct_declaration)
+            (record_declaration)
+            (interface_declaration)
+            (enum_declaration)
+        ] @decl
+    "#;
+
+    if let Ok(query) = Query::new(&tree_sitter_c_sharp::LANGUAGE.into(), query_str) {
+        let mut cursor = QueryCursor::new();
+        let mut matches = cursor.matches(&query, root, content.as_bytes());
+
+        while let Some(m) = matches.next() {
+            for cap in m.captures.iter() {
+                let decl = cap.node;
+                let bytes = content.as_bytes();
+                let Some((_, prefix, node_type)) =
+                    TYPE_KINDS.iter().find(|(k, _, _)| *k == decl.kind())
+                else {
+                    continue;
+                };
+                let Some(name_node) = decl.child_by_field_name("name") else {
+                    continue;
+                };
+                let name = get_node_text(name_node, bytes).to_string();
+                let id = format!("{}:{}:{}", prefix, path, name);
+
+                // Nested types default to private, top-level ones to internal
+                let default = if owning_type(decl, content, path).is_some() {
+                    Visibility::Private
+                } else {
+                    Visibility::Internal
+                };
+                let mut type_node =
+                    create_code_node(id.clone(), *node_type, path, name.clone(), decl);
+                type_node.metadata.visibility = Some(csharp_visibility(decl, content, default));
+
+                let mut cursor = decl.walk();
+                let bases = decl
+                    .named_children(&mut cursor)
+                    .filter(|c| c.kind() == "base_list")
+                    .flat_map(|list| {
+                        let mut inner = list.walk();
+                        list.named_children(&mut inner).collect::<Vec<_>>()
+                    })
+                    .filter(|b| b.kind() != "argument_list");
+                let mut implements = Vec::new();
+                for (i, base) in bases.enumerate() {
+                    let base_name = get_node_text(base, bytes);
+                    let (edge_type, label, target) = match base_edge(decl.kind(), i, base_name) {
+                        EdgeType::Extends if *node_type == NodeType::Interface => {
+                            (EdgeType::Extends, "extends", "interface")
+                        }
+                        EdgeType::Extends => {
+                            type_node.metadata.extends = Some(base_name.to_string());
+                            (EdgeType::Extends, "extends", "class")
+                        }
+                        _ => {
+                            implements.push(base_name.to_string());
+                            (EdgeType::Implements, "implements", "interface")
+                        }
+                    };
+                    result.edges.push(create_code_edge(
+                        format!("{}:{}:{}:{}", label, path, name, base_name),
+                        edge_type,
+                        id.clone(),
+                        format!("{}:{}", target, base_name),
+                        base,
+                        path,
+                    ));
+                }
+                if !implements.is_empty() {
+                    type_node.metadata.implements = Some(implements);
+                }
+                result.nodes.push(type_node);
+            }
+        }
+    }
+}
+
+/// C# puts the base class and interfaces in one list. Only the first entry of
+/// a class or record can be a base class, and interfaces follow the `IName`
+/// convention closely enough to tell them apart.
+fn base_edge(kind: &str, index: usize, base: &str) -> EdgeType {
+    let is_interface_name = {
+        let mut chars = base.chars();
+        chars.next() == Some('I') && chars.next().is_some_and(|c| c.is_ascii_uppercase())
+    };
+    match kind {
+        "interface_declaration" => EdgeType::Extends,
+        "class_declaration" | "record_declaration" if index == 0 && !is_interface_name => {
+            EdgeType::Extends
+        }
+        _ => EdgeType::Implements,
+    }
+}
+
+fn extract_methods(root: tree_sitter::Node, content: &str, path: &str, result: &mut ParseResult) {
+    let query_str = r#"
+        [
+            (method_declaration)
+            (constructor_declaration)
+        ] @method
+    "#;
+
+    if let Ok(query) = Query::new(&tree_sitter_c_sharp::LANGUAGE.into(), query_str) {
+        let mut cursor = QueryCursor::new();
+        let mut matches = cursor.matches(&query, root, content.as_bytes());
+
+        while let Some(m) = matches.next() {
+            for cap in m.captures.iter() {
+                let method = cap.node;
+                let bytes = content.as_bytes();
+                let Some(name_node) = method.child_by_field_name("name") else {
+                    continue;
+                };
+                let Some((owner_name, owner_id)) = owning_type(method, content, path) else {
+                    continue;
+                };
+                let name = get_node_text(name_node, bytes).to_string();
+                let id = format!("method:{}:{}:{}", path, owner_name, name);
+
+                // Interface members are implicitly public
+                let default = if owner_id.starts_with("interface:") {
+                    Visibility::Public
+                } else {
+                    Visibility::Private
+                };
+                let mut method_node =
+                    create_code_node(id.clone(), NodeType::Method, path, name.clone(), method);
+                method_node.metadata = NodeMetadata {
+                    visibility: Some(csharp_visibility(method, content, default)),
+                    signature: Some(FunctionSignature {
+                        parameters: csharp_parameters(method, content),
+                        return_type: method
+                            .child_by_field_name("returns")
+                            .map(|t| get_node_text(t, bytes).to_string()),
+                        is_async: modifiers(method, content).any(|m| m == "async"),
+                        generator: false,
+                    }),
+                    ..Default::default()
+                };
+                result.nodes.push(method_node);
+
+                result.edges.push(create_code_edge(
+                    format!("member:{}:{}:{}", path, owner_name, name),
+                    EdgeType::Owns,
+                    owner_id,
+                    id,
+                    method,
+                    path,
+                ));
+            }
+        }
+    }
+}
+
+/// Name and node ID of the nearest enclosing type declaration.
+fn owning_type(node: tree_sitter::Node, content: &str, path: &str) -> Option<(String, String)> {
+    let mut current = node.parent();
+    while let Some(parent) = current {
+        if let Some((_, prefix, _)) = TYPE_KINDS.iter().find(|(k, _, _)| *k == parent.kind()) {
+            let name = get_node_text(parent.child_by_field_name("name")?, content.as_bytes());
+            return Some((name.to_string(), format!("{}:{}:{}", prefix, path, name)));
+        }
+        current = parent.parent();
+    }
+    None
+}
+
+fn modifiers<'a>(decl: tree_sitter::Node<'a>, content: &'a str) -> impl Iterator<Item = &'a str> {
+    let mut cursor = decl.walk();
+    decl.named_children(&mut cursor)
+        .filter(|c| c.kind() == "modifier")
+        .map(|c| get_node_text(c, content.as_bytes()))
+        .collect::<Vec<_>>()
+        .into_iter()
+}
+
+fn csharp_visibility(decl: tree_sitter::Node, content: &str, default: Visibility) -> Visibility {
+    let mods: Vec<&str> = modifiers(decl, content).collect();
+    // Combined levels (`protected internal`, `private protected`) map to the widest keyword
+    if mods.contains(&"public") {
+        Visibility::Public
+    } else if mods.contains(&"protected") {
+        Visibility::Protected
+    } else if mods.contains(&"internal") {
+        Visibility::Internal
+    } else if mods.contains(&"private") {
+        Visibility::Private
+    } else {
+        default
+    }
+}
+
+fn csharp_parameters(method: tree_sitter::Node, content: &str) -> Vec<Parameter> {
+    let bytes = content.as_bytes();
+    let Some(params) = method.child_by_field_name("parameters") else {
+        return Vec::new();
+    };
+
+    let mut cursor = params.walk();
+    params
+        .named_children(&mut cursor)
+        .filter(|p| p.kind() == "parameter")
+        .filter_map(|param| {
+            let name = param.child_by_field_name("name")?;
+            let param_type = param.child_by_field_name("type");
+
+            // The default value is the only other named child after the name
+            let mut inner = param.walk();
+            let default_value = param
+                .named_children(&mut inner)
+                .skip_while(|c| c.id() != name.id())
+                .nth(1)
+                .map(|v| get_node_text(v, bytes).to_string());
+
+            Some(Parameter {
+                name: get_node_text(name, bytes).to_string(),
+                param_type: param_type.map(|t| get_node_text(t, bytes).to_string()),
+                optional: default_value.is_some(),
+                default_value,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csharp_types_and_visibility() {
+        let code = r#"
+using System.Collections.Generic;
+
+namespace Shop.Orders
+{
+    /// <summary>Handles orders.</summary>
+    public class OrderService : BaseService, IOrderService
+    {
+        public OrderService(IRepo repo) { }
+        public async Task<Order> PlaceAsync(int id, string name = "x") { return null; }
+        void Log() { }
+    }
+    class Internal { }
+    public interface IOrderService : IDisposable { void Cancel(); }
+}
+"#;
+        let result = CSharpParser::new()
+            .unwrap()
+            .parse("Orders.cs", code)
+            .unwrap();
+
+        assert!(
+            result
+                .nodes
+                .iter()
+                .any(|n| n.id == "namespace:Orders.cs:Shop.Orders")
+        );
+        assert!(
+            result
+                .edges
+                .iter()
+                .any(|e| e.target_id == "module:System.Collections.Generic")
+        );
+
+        let service = result
+            .nodes
+            .iter()
+            .find(|n| n.id == "class:Orders.cs:OrderService")
+            .unwrap();
+        assert_eq!(service.metadata.extends.as_deref(), Some("BaseService"));
+        assert_eq!(
+            service.metadata.implements,
+            Some(vec!["IOrderService".to_string()])
+        );
+
+        let internal = result
+            .nodes
+            .iter()
+            .find(|n| n.id == "class:Orders.cs:Internal")
+            .unwrap();
+        assert_eq!(internal.metadata.visibility, Some(Visibility::Internal));
+
+        let place = result
+            .nodes
+            .iter()
+            .find(|n| n.id == "method:Orders.cs:OrderService:PlaceAsync")
+            .unwrap();
+        let sig = place.metadata.signature.as_ref().unwrap();
+        assert!(sig.is_async);
+        assert_eq!(sig.return_type.as_deref(), Some("Task<Order>"));
+        assert_eq!(sig.parameters[1].default_value.as_deref(), Some("\"x\""));
+
+        let log = result
+            .nodes
+            .iter()
+            .find(|n| n.id == "method:Orders.cs:OrderService:Log")
+            .unwrap();
+        assert_eq!(log.metadata.visibility, Some(Visibility::Private));
+
+        let cancel = result
+            .nodes
+            .iter()
+            .find(|n| n.id == "method:Orders.cs:IOrderService:Cancel")
+            .unwrap();
+        assert_eq!(cancel.metadata.visibility, Some(Visibility::Public));
+        assert!(result.edges.iter().any(|e| e.edge_type == EdgeType::Extends
+            && e.target_id == "interface:IDisposable"));
+    }
+}
@@ -0,0 +1,497 @@
+//! Documentation Extraction
+//!
+//! Language-agnostic doc-comment reader shared by all tree-sitter parsers.
+//! Each parser describes its grammar with a [`DocSyntax`] table; the leading
+//! documentation of every item (`///`, `/** */`, Go/Ruby/Bash comment blocks,
+//! Python docstrings) is stored in `NodeMetadata.description` of the node the
+//! parser emitted for that item, matched by start line.
+//!
+//! Only comments directly above an item count: a blank line between the
+//! comment and the item (or between comment lines) ends the block, so file
+//! headers and commented-out code are not mistaken for documentation.
+
+use std::collections::HashMap;
+
+use super::traits::{ParseResult, get_node_text};
+use crate::types::NodeType;
+
+/// Grammar description for doc-comment extraction.
+pub struct DocSyntax {
+    /// Node kinds whose leading comments document a graph node.
+    pub item_kinds: &'static [&'static str],
+    /// Items whose documentation describes the whole file (Go `package`).
+    pub file_item_kinds: &'static [&'static str],
+    /// Comment node kinds.
+    pub comment_kinds: &'static [&'static str],
+    /// Prefixes marking a comment as documentation. Empty accepts any comment.
+    pub doc_prefixes: &'static [&'static str],
+    /// Prefixes of inner comments documenting the enclosing file (`//!`).
+    pub inner_prefixes: &'static [&'static str],
+    /// Siblings allowed between the comment and the item (attributes).
+    pub skip_kinds: &'static [&'static str],
+    /// Wrappers whose leading comment documents the wrapped item
+    /// (`export_statement`, `decorated_definition`, `template_declaration`).
+    pub wrapper_kinds: &'static [&'static str],
+    /// Read a string literal at the top of a `body` as the docstring.
+    pub docstrings: bool,
+}
+
+/// Fill `NodeMetadata.description` from leading documentation.
+///
+/// Must run after the parser has pushed its declaration nodes. Nodes that
+/// already carry a description are left untouched.
+pub fn extract_docs(
+    root: tree_sitter::Node,
+    content: &str,
+    result: &mut ParseResult,
+    syntax: &DocSyntax,
+) {
+    let bytes = content.as_bytes();
+    let mut by_line: HashMap<u32, String> = HashMap::new();
+    let mut file_doc = module_doc(root, bytes, syntax);
+
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        let kind = node.kind();
+        let is_item = syntax.item_kinds.contains(&kind);
+        let is_file_item = syntax.file_item_kinds.contains(&kind);
+        if (is_item || is_file_item)
+            && let Some(doc) = item_doc(node, bytes, syntax)
+        {
+            if is_file_item {
+                file_doc.get_or_insert(doc);
+            } else {
+                // Some parsers locate nodes by their name rather than the item
+                for row in [Some(node.start_position().row), name_row(node)]
+                    .into_iter()
+                    .flatten()
+                {
+                    by_line.entry(row as u32 + 1).or_insert_with(|| doc.clone());
+                }
+            }
+        }
+
+        let mut cursor = node.walk();
+        let children: Vec<_> = node.named_children(&mut cursor).collect();
+        stack.extend(children.into_iter().rev());
+    }
+
+    for node in &mut result.nodes {
+        if node.metadata.description.is_some() {
+            continue;
+        }
+        node.metadata.description = if node.node_type == NodeType::File {
+            file_doc.clone()
+        } else {
+            by_line.get(&node.evidence.start_line).cloned()
+        };
+    }
+}
+
+/// Documentation attached to a single item.
+fn item_doc(node: tree_sitter::Node, content: &[u8], syntax: &DocSyntax) -> Option<String> {
+    if syntax.docstrings
+        && let Some(doc) = node
+            .child_by_field_name("body")
+            .and_then(|b| docstring(b, content))
+    {
+        return Some(doc);
+    }
+
+    // Comments sit before the outermost wrapper (`export`, decorators), or
+    // before the body that opens with this item (Ruby `class` bodies)
+    let mut anchor = node;
+    while let Some(parent) = anchor.parent()
+        && parent.parent().is_some()
+        && (syntax.wrapper_kinds.contains(&parent.kind())
+            || anchor.prev_sibling().is_none() && !syntax.item_kinds.contains(&parent.kind()))
+    {
+        anchor = parent;
+    }
+
+    let mut lines: Vec<String> = Vec::new();
+    let mut next_row = anchor.start_position().row;
+    let mut sibling = anchor.prev_sibling();
+    while let Some(prev) = sibling {
+        if prev.end_position().row + 1 < next_row {
+            break;
+        }
+        if syntax.skip_kinds.contains(&prev.kind()) {
+            next_row = prev.start_position().row;
+            sibling = prev.prev_sibling();
+            continue;
+        }
+        if !syntax.comment_kinds.contains(&prev.kind()) {
+            break;
+        }
+        let text = get_node_text(prev, content);
+        if text.starts_with("#!") {
+            break;
+        }
+        let is_doc = syntax.doc_prefixes.is_empty()
+            || syntax.doc_prefixes.iter().any(|p| text.starts_with(p));
+        if !is_doc || is_inner(text, syntax) {
+            break;
+        }
+        lines.splice(0..0, comment_lines(text));
+        next_row = prev.start_position().row;
+        sibling = prev.prev_sibling();
+    }
+
+    join_lines(xml_doc(lines))
+}
+
+/// Documentation of the file itself: inner doc comments or a docstring at
+/// the top of the file.
+fn module_doc(root: tree_sitter::Node, content: &[u8], syntax: &DocSyntax) -> Option<String> {
+    if syntax.docstrings
+        && let Some(doc) = docstring(root, content)
+    {
+        return Some(doc);
+    }
+    if syntax.inner_prefixes.is_empty() {
+        return None;
+    }
+
+    let mut lines = Vec::new();
+    let mut cursor = root.walk();
+    for child in root.named_children(&mut cursor) {
+        if !syntax.comment_kinds.contains(&child.kind()) {
+            break;
+        }
+        let text = get_node_text(child, content);
+        if is_inner(text, syntax) {
+            lines.extend(comment_lines(text));
+        } else if !lines.is_empty() {
+            break;
+        }
+    }
+    join_lines(lines)
+}
+
+/// Row of the item's name, when it differs from the item's first row
+/// (attributes or annotations before the name).
+fn name_row(node: tree_sitter::Node) -> Option<usize> {
+    let name = node.child_by_field_name("name").or_else(|| {
+        let mut cursor = node.walk();
+        node.named_children(&mut cursor)
+            .find(|c| c.kind().ends_with("identifier"))
+    })?;
+    let row = name.start_position().row;
+    (row != node.start_position().row).then_some(row)
+}
+
+fn is_inner(text: &str, syntax: &DocSyntax) -> bool {
+    syntax.inner_prefixes.iter().any(|p| text.starts_with(p))
+}
+
+/// A string literal as the first statement of `block`.
+fn docstring(block: tree_sitter::Node, content: &[u8]) -> Option<String> {
+    let first = block.named_child(0)?;
+    if first.kind() != "expression_statement" {
+        return None;
+    }
+    let string = first.named_child(0).filter(|s| s.kind() == "string")?;
+
+    let mut cursor = string.walk();
+    let text: String = string
+        .named_children(&mut cursor)
+        .filter(|c| c.kind() == "string_content")
+        .map(|c| get_node_text(c, content))
+        .collect();
+    join_lines(dedent(&text))
+}
+
+/// Strip comment markers (`///`, `//`, `#`, `/** */`, leading `*`) from a
+/// comment node's text.
+fn comment_lines(text: &str) -> Vec<String> {
+    let text = text.trim();
+    if let Some(block) = text.strip_prefix("/*") {
+        let block = block.trim_start_matches(['*', '!']);
+        let block = block.strip_suffix("*/").unwrap_or(block);
+        return block
+            .lines()
+            .map(|line| {
+                let line = line.trim();
+                let line = line.strip_prefix('*').unwrap_or(line);
+                line.strip_prefix(' ').unwrap_or(line).to_string()
+            })
+            .collect();
+    }
+
+    text.lines()
+        .map(|line| {
+            let line = line.trim_start();
+            let line = match line.strip_prefix("--") {
+                // Lua and SQL: `-- text`, `--- text`
+                Some(rest) => rest.trim_start_matches('-'),
+                None => line.trim_start_matches('/').trim_start_matches(['!', '#']),
+            };
+            line.strip_prefix(' ')
+                .unwrap_or(line)
+                .trim_end()
+                .to_string()
+        })
+        .collect()
+}
+
+/// Text of C# XML documentation (`<summary>`, `<param name="x">`), other
+/// comments unchanged. References (`<see cref="T"/>`) keep their target,
+/// parameters read as `name: text`, and lines holding only tags are dropped.
+fn xml_doc(lines: Vec<String>) -> Vec<String> {
+    if !lines.iter().any(|l| l.contains("<summary>")) {
+        return lines;
+    }
+
+    let mut text = Vec::new();
+    for line in lines {
+        let mut out = String::new();
+        let mut rest = line.as_str();
+        while let Some(open) = rest.find('<') {
+            out.push_str(&rest[..open]);
+            let Some(close) = rest[open..].find('>') else {
+                out.push_str(&rest[open..]);
+                rest = "";
+                break;
+            };
+            let tag = &rest[open + 1..open + close];
+            let target = ["cref=\"", "name=\""]
+                .iter()
+                .find_map(|attr| tag.split_once(attr))
+                .and_then(|(_, value)| value.split('"').next());
+            if let Some(target) = target {
+                out.push_str(target);
+                if tag.starts_with("param ") || tag.starts_with("typeparam ") {
+                    out.push_str(": ");
+                }
+            }
+            rest = &rest[open + close + 1..];
+        }
+        out.push_str(rest);
+
+        let only_tags = !line.trim().is_empty() && out.trim().is_empty();
+        if !only_tags {
+            text.push(out.trim_end().to_string());
+        }
+    }
+    text
+}
+
+/// Remove the indentation shared by all lines after the first (PEP 257).
+fn dedent(text: &str) -> Vec<String> {
+    let mut lines = text.lines();
+    let first = lines.next().unwrap_or("").trim().to_string();
+    let rest: Vec<&str> = lines.collect();
+    let indent = rest
+        .iter()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| l.len() - l.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    std::iter::once(first)
+        .chain(
+            rest.iter()
+                .map(|l| l.get(indent..).unwrap_or("").trim_end().to_string()),
+        )
+        .collect()
+}
+
+/// Join cleaned lines, dropping leading and trailing blank lines.
+fn join_lines(lines: Vec<String>) -> Option<String> {
+    let text = lines.join("\n");
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::parser::{
+        CSharpParser, GoParser, JavaParser, Parser, PythonParser, RustParser, TypeScriptParser,
+    };
+
+    fn description<'a>(result: &'a ParseResult, name: &str) -> Option<&'a str> {
+        result
+            .nodes
+            .iter()
+            .find(|n| n.name == name)
+            .and_then(|n| n.metadata.description.as_deref())
+    }
+
+    #[test]
+    fn test_comment_lines() {
+        assert_eq!(comment_lines("/// Parses input."), vec!["Parses input."]);
+        assert_eq!(comment_lines("//! Crate docs"), vec!["Crate docs"]);
+        assert_eq!(comment_lines("# Ruby doc"), vec!["Ruby doc"]);
+        assert_eq!(comment_lines("--- Lua doc"), vec!["Lua doc"]);
+        assert_eq!(
+            join_lines(comment_lines("/**\n * First.\n *\n * Second.\n */")),
+            Some("First.\n\nSecond.".to_string())
+        );
+    }
+
+    #[test]
+    fn test_dedent() {
+        assert_eq!(
+            join_lines(dedent(
+                "Summary.\n\n    Details here.\n      Indented.\n    "
+            )),
+            Some("Summary.\n\nDetails here.\n  Indented.".to_string())
+        );
+    }
+
+    #[test]
+    fn test_rust_outer_and_inner_docs() {
+        let code = r#"//! Graph storage.
+
+/// A stored node.
+///
+/// Nodes are immutable.
+#[derive(Debug)]
+pub struct Node;
+
+// Not documentation.
+fn helper() {}
+
+/** Block doc. */
+pub fn build() {}
+"#;
+        let result = RustParser::new()
+            .unwrap()
+            .parse("src/graph.rs", code)
+            .unwrap();
+
+        assert_eq!(
+            description(&result, "Node"),
+            Some("A stored node.\n\nNodes are immutable.")
+        );
+        assert_eq!(description(&result, "helper"), None);
+        assert_eq!(description(&result, "build"), Some("Block doc."));
+        assert_eq!(
+            result.nodes[0].metadata.description.as_deref(),
+            Some("Graph storage.")
+        );
+    }
+
+    #[test]
+    fn test_python_docstrings() {
+        let code = r#""""Billing helpers."""
+
+
+class Invoice:
+    """An invoice.
+
+    Totals are cached.
+    """
+
+    def total(self):
+        '''Sum of line items.'''
+        return 0
+"#;
+        let result = PythonParser::new()
+            .unwrap()
+            .parse("billing.py", code)
+            .unwrap();
+
+        assert_eq!(
+            description(&result, "Invoice"),
+            Some("An invoice.\n\nTotals are cached.")
+        );
+        assert_eq!(description(&result, "total"), Some("Sum of line items."));
+        assert_eq!(
+            result.nodes[0].metadata.description.as_deref(),
+            Some("Billing helpers.")
+        );
+    }
+
+    #[test]
+    fn test_go_comments_and_package_doc() {
+        let code = r#"// Package store persists records.
+package store
+
+// Open connects to the database.
+// It retries once.
+func Open() {}
+
+// unrelated
+
+func Close() {}
+"#;
+        let result = GoParser::new().unwrap().parse("store/db.go", code).unwrap();
+
+        assert_eq!(
+            description(&result, "Open"),
+            Some("Open connects to the database.\nIt retries once.")
+        );
+        assert_eq!(description(&result, "Close"), None);
+        assert_eq!(
+            result.nodes[0].metadata.description.as_deref(),
+            Some("Package store persists records.")
+        );
+    }
+
+    #[test]
+    fn test_jsdoc_and_javadoc() {
+        let ts = r#"/**
+ * Formats a price.
+ * @param cents amount
+ */
+export function formatPrice(cents: number) {}
+
+/* plain comment */
+function other() {}
+"#;
+        let result = TypeScriptParser::new()
+            .unwrap()
+            .parse("src/price.ts", ts)
+            .unwrap();
+        assert_eq!(
+            description(&result, "formatPrice"),
+            Some("Formats a price.\n@param cents amount")
+        );
+        assert_eq!(description(&result, "other"), None);
+
+        let java = r#"/** Handles orders. */
+public class OrderService {
+    /** Places an order. */
+    public void place() {}
+}
+"#;
+        let result = JavaParser::new()
+            .unwrap()
+            .parse("OrderService.java", java)
+            .unwrap();
+        assert_eq!(
+            description(&result, "OrderService"),
+            Some("Handles orders.")
+        );
+        assert_eq!(description(&result, "place"), Some("Places an order."));
+    }
+
+    #[test]
+    fn test_csharp_xml_docs() {
+        let code = r#"/// <summary>
+/// Places orders for a <see cref="Customer"/>.
+/// </summary>
+public class OrderService {
+    /// <summary>Places an order.</summary>
+    /// <param name="id">The order id.</param>
+    public void Place(int id) {}
+}
+"#;
+        let result = CSharpParser::new()
+            .unwrap()
+            .parse("OrderService.cs", code)
+            .unwrap();
+
+        assert_eq!(
+            description(&result, "OrderService"),
+            Some("Places orders for a Customer.")
+        );
+        assert_eq!(
+            description(&result, "Place"),
+            Some("Places an order.\nid: The order id.")
+        );
+    }
+}
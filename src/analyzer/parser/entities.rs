@@ -0,0 +1,1499 @@
+//! ORM Entity Extraction
+//!
+//! Recognizes persistent model declarations and turns them into `entity`
+//! nodes carrying [`EntityMetadata`]:
+//!
+//! | Dialect    | ORMs               | Shapes                                                    |
+//! |------------|--------------------|-----------------------------------------------------------|
+//! | JavaScript | TypeORM            | `@Entity()` classes with `@Column`/`@ManyToOne` fields     |
+//! | Python     | SQLAlchemy, Django | `__tablename__` + `Column(...)`, `models.Model` subclasses |
+//! | Java       | JPA                | `@Entity` classes with `@Id`/`@Column`/`@OneToMany` fields |
+//! | Rust       | diesel, sea-orm    | `table! { users (id) { ... } }`, `DeriveEntityModel`      |
+//! | Ruby       | ActiveRecord       | `ApplicationRecord` subclasses, `create_table` blocks     |
+//!
+//! An entity mapped by a class gets a `persists` edge from that class; tables
+//! declared by a schema DSL (`table!`, `create_table`, SQL, Prisma) are owned
+//! by their file. Relation targets are kept as written, model or table name,
+//! since the target is often declared in another file.
+
+use std::collections::HashSet;
+
+use chrono::Utc;
+
+use super::routes::{
+    annotation_name, callee_name, class_decorators, decorator_args, decorator_name,
+    java_annotations, keyword_arg, last_segment, named_args, preorder, rails_option, rails_pair,
+    ruby_name, string_value,
+};
+use super::traits::{ParseResult, evidence_from_node, get_node_text};
+use crate::types::{
+    Edge, EdgeMetadata, EdgeType, EntityMetadata, EvidenceLocation, FieldDefinition,
+    InformationTier, Node, NodeMetadata, NodeStatus, NodeType, RelationDefinition, Visibility,
+};
+
+/// ORM family followed by a parser's files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntityDialect {
+    /// TypeORM decorators
+    JavaScript,
+    /// SQLAlchemy declarative models and Django models
+    Python,
+    /// JPA annotations
+    Java,
+    /// diesel `table!` schemas and sea-orm entities
+    Rust,
+    /// ActiveRecord models and schema/migration `create_table` blocks
+    Ruby,
+}
+
+const ONE_TO_ONE: &str = "one_to_one";
+const ONE_TO_MANY: &str = "one_to_many";
+const MANY_TO_ONE: &str = "many_to_one";
+const MANY_TO_MANY: &str = "many_to_many";
+
+/// An entity found in one file, before it becomes a node.
+pub(super) struct EntityDecl {
+    pub name: String,
+    /// Class mapped to the entity; `None` for tables declared by a schema DSL
+    pub class: Option<String>,
+    pub table: Option<String>,
+    pub fields: Vec<FieldDefinition>,
+    pub relations: Vec<RelationDefinition>,
+    pub evidence: EvidenceLocation,
+}
+
+impl EntityDecl {
+    pub fn new(name: impl Into<String>, evidence: EvidenceLocation) -> Self {
+        Self {
+            name: name.into(),
+            class: None,
+            table: None,
+            fields: Vec::new(),
+            relations: Vec::new(),
+            evidence,
+        }
+    }
+
+    pub fn field(&mut self, name: &str, field_type: &str, nullable: bool, primary_key: bool) {
+        if self.fields.iter().any(|f| f.name == name) {
+            return;
+        }
+        self.fields.push(FieldDefinition {
+            name: name.to_string(),
+            field_type: field_type.to_string(),
+            nullable: nullable && !primary_key,
+            primary_key: primary_key.then_some(true),
+        });
+    }
+
+    pub fn relation(&mut self, name: &str, relation_type: &str, target: &str) {
+        self.relations.push(RelationDefinition {
+            name: name.to_string(),
+            relation_type: relation_type.to_string(),
+            target: target.to_string(),
+        });
+    }
+
+    pub fn mark_primary_key(&mut self, column: &str) {
+        if let Some(field) = self.fields.iter_mut().find(|f| f.name == column) {
+            field.primary_key = Some(true);
+            field.nullable = false;
+        }
+    }
+}
+
+/// Extract `entity` nodes from the ORM declarations under `root`.
+///
+/// Must run after the parser has pushed its class nodes so `persists` edges
+/// can point at them.
+pub fn extract_entities(
+    root: tree_sitter::Node,
+    content: &str,
+    path: &str,
+    result: &mut ParseResult,
+    dialect: EntityDialect,
+) {
+    let bytes = content.as_bytes();
+    let mut decls = Vec::new();
+
+    match dialect {
+        EntityDialect::JavaScript => typeorm_entities(root, bytes, path, &mut decls),
+        EntityDialect::Python => python_entities(root, bytes, path, &mut decls),
+        EntityDialect::Java => jpa_entities(root, bytes, path, &mut decls),
+        EntityDialect::Rust => rust_entities(root, bytes, path, &mut decls),
+        EntityDialect::Ruby => ruby_entities(root, bytes, path, &mut decls),
+    }
+
+    emit_entities(decls, path, result);
+}
+
+pub(super) fn emit_entities(decls: Vec<EntityDecl>, path: &str, result: &mut ParseResult) {
+    let file_id = result
+        .nodes
+        .iter()
+        .find(|n| n.node_type == NodeType::File)
+        .map(|n| n.id.clone());
+
+    let mut seen = HashSet::new();
+    for decl in decls {
+        let id = format!("entity:{}:{}", path, decl.name);
+        if !seen.insert(id.clone()) {
+            continue;
+        }
+
+        let class_id = decl.class.as_deref().and_then(|class| {
+            result
+                .nodes
+                .iter()
+                .find(|n| n.node_type == NodeType::Class && n.name == class)
+                .map(|n| n.id.clone())
+        });
+        let link = match (class_id, &file_id) {
+            (Some(class_id), _) => Some((
+                format!("persists:{}:{}", path, decl.name),
+                EdgeType::Persists,
+                class_id,
+            )),
+            (None, Some(file_id)) => {
+                Some((format!("owns:{}", id), EdgeType::Owns, file_id.clone()))
+            }
+            (None, None) => None,
+        };
+
+        if let Some((edge_id, edge_type, source_id)) = link {
+            result.edges.push(Edge {
+                id: edge_id,
+                edge_type,
+                source_id,
+                target_id: id.clone(),
+                metadata: EdgeMetadata::default(),
+                evidence: decl.evidence.clone(),
+                tier: InformationTier::Fact,
+                confidence: 1.0,
+                last_verified: Utc::now(),
+            });
+        }
+
+        result.nodes.push(Node {
+            id,
+            node_type: NodeType::Entity,
+            path: path.to_string(),
+            name: decl.name,
+            metadata: NodeMetadata {
+                visibility: Some(Visibility::Public),
+                entity_metadata: Some(EntityMetadata {
+                    table_name: decl.table,
+                    fields: (!decl.fields.is_empty()).then_some(decl.fields),
+                    relations: (!decl.relations.is_empty()).then_some(decl.relations),
+                }),
+                ..Default::default()
+            },
+            evidence: decl.evidence,
+            tier: InformationTier::Fact,
+            confidence: 1.0,
+            last_verified: Utc::now(),
+            status: NodeStatus::Verified,
+        });
+    }
+}
+
+// =============================================================================
+// Shared helpers
+// =============================================================================
+
+/// Bare model name from a qualified or quoted reference
+/// (`"auth.User"` → `User`, `super::fruit::Entity` → `fruit`).
+fn model_name(reference: &str) -> &str {
+    let reference = reference.trim_matches(|c| matches!(c, '"' | '\'' | '`'));
+    let reference = reference.strip_suffix("::Entity").unwrap_or(reference);
+    last_segment(reference)
+}
+
+/// Element type of a collection type (`List<Order>`, `Photo[]`), or the type itself.
+fn element_type(type_text: &str) -> &str {
+    let text = type_text.trim().trim_end_matches("[]");
+    match (text.find(['<', '[']), text.rfind(['>', ']'])) {
+        (Some(open), Some(close)) if open < close => element_type(&text[open + 1..close]),
+        _ => text,
+    }
+}
+
+fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 {
+                snake.push('_');
+            }
+            snake.push(c.to_ascii_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+fn camel_case(name: &str) -> String {
+    name.split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// English plural, close enough for table names derived by Rails.
+fn pluralize(word: &str) -> String {
+    if let Some(stem) = word.strip_suffix('y')
+        && !stem.ends_with(['a', 'e', 'i', 'o', 'u'])
+    {
+        return format!("{}ies", stem);
+    }
+    if word.ends_with(['s', 'x', 'z']) || word.ends_with("ch") || word.ends_with("sh") {
+        return format!("{}es", word);
+    }
+    format!("{}s", word)
+}
+
+fn singularize(word: &str) -> String {
+    if let Some(stem) = word.strip_suffix("ies") {
+        return format!("{}y", stem);
+    }
+    for suffix in ["sses", "xes", "zes", "ches", "shes"] {
+        if word.ends_with(suffix) {
+            return word[..word.len() - 2].to_string();
+        }
+    }
+    match word.strip_suffix('s') {
+        Some(stem) if !stem.ends_with('s') => stem.to_string(),
+        _ => word.to_string(),
+    }
+}
+
+fn is_true(node: Option<tree_sitter::Node>, bytes: &[u8]) -> bool {
+    node.is_some_and(|n| matches!(get_node_text(n, bytes), "true" | "True"))
+}
+
+fn is_false(node: Option<tree_sitter::Node>, bytes: &[u8]) -> bool {
+    node.is_some_and(|n| matches!(get_node_text(n, bytes), "false" | "False"))
+}
+
+// =============================================================================
+// TypeScript: TypeORM
+// =============================================================================
+
+fn typeorm_entities(root: tree_sitter::Node, bytes: &[u8], path: &str, out: &mut Vec<EntityDecl>) {
+    for node in preorder(root) {
+        if !matches!(
+            node.kind(),
+            "class_declaration" | "abstract_class_declaration"
+        ) {
+            continue;
+        }
+        let decorators = class_decorators(node);
+        let Some(entity) = decorators
+            .iter()
+            .find(|d| matches!(decorator_name(**d, bytes), "Entity" | "ViewEntity"))
+        else {
+            continue;
+        };
+        let (Some(name), Some(body)) = (
+            node.child_by_field_name("name"),
+            node.child_by_field_name("body"),
+        ) else {
+            continue;
+        };
+
+        let class = get_node_text(name, bytes).to_string();
+        let mut decl = EntityDecl::new(class.clone(), evidence_from_node(node, path));
+        decl.class = Some(class);
+        decl.table = decorator_args(*entity).first().and_then(|arg| {
+            string_value(*arg, bytes)
+                .or_else(|| object_value(*arg, "name", bytes).and_then(|v| string_value(v, bytes)))
+        });
+
+        let mut cursor = body.walk();
+        for member in body.named_children(&mut cursor) {
+            if member.kind() == "public_field_definition" {
+                typeorm_column(member, bytes, &mut decl);
+            }
+        }
+        out.push(decl);
+    }
+}
+
+fn typeorm_column(member: tree_sitter::Node, bytes: &[u8], decl: &mut EntityDecl) {
+    let Some(name) = member.child_by_field_name("name") else {
+        return;
+    };
+    let name = get_node_text(name, bytes);
+    let ts_type = member
+        .child_by_field_name("type")
+        .and_then(|t| t.named_child(0))
+        .map(|t| get_node_text(t, bytes))
+        .unwrap_or("unknown");
+    let mut cursor = member.walk();
+    let optional = member.children(&mut cursor).any(|c| c.kind() == "?");
+
+    let mut cursor = member.walk();
+    let decorators: Vec<_> = member
+        .children(&mut cursor)
+        .filter(|c| c.kind() == "decorator")
+        .collect();
+    for decorator in decorators {
+        let args = decorator_args(decorator);
+        let options = args.iter().find(|a| a.kind() == "object");
+        let nullable = optional
+            || options.is_some_and(|o| is_true(object_value(*o, "nullable", bytes), bytes));
+        match decorator_name(decorator, bytes) {
+            "PrimaryColumn" | "PrimaryGeneratedColumn" | "ObjectIdColumn" => {
+                decl.field(name, ts_type, false, true)
+            }
+            "Column" | "CreateDateColumn" | "UpdateDateColumn" | "VersionColumn" => {
+                decl.field(name, ts_type, nullable, false)
+            }
+            "DeleteDateColumn" => decl.field(name, ts_type, true, false),
+            relation @ ("OneToOne" | "OneToMany" | "ManyToOne" | "ManyToMany") => {
+                let target = args
+                    .first()
+                    .and_then(|a| match a.kind() {
+                        "arrow_function" => a
+                            .child_by_field_name("body")
+                            .map(|b| get_node_text(b, bytes).to_string()),
+                        _ => string_value(*a, bytes),
+                    })
+                    .unwrap_or_else(|| element_type(ts_type).to_string());
+                decl.relation(name, &snake_case(relation), model_name(&target));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Value of `key` in an object literal (`{ nullable: true }`).
+fn object_value<'t>(
+    object: tree_sitter::Node<'t>,
+    key: &str,
+    bytes: &[u8],
+) -> Option<tree_sitter::Node<'t>> {
+    if object.kind() != "object" {
+        return None;
+    }
+    let mut cursor = object.walk();
+    object
+        .named_children(&mut cursor)
+        .filter(|p| p.kind() == "pair")
+        .find(|p| {
+            p.child_by_field_name("key")
+                .is_some_and(|k| get_node_text(k, bytes).trim_matches(['"', '\'']) == key)
+        })
+        .and_then(|p| p.child_by_field_name("value"))
+}
+
+// =============================================================================
+// Python: SQLAlchemy and Django
+// =============================================================================
+
+/// `name[: annotation] = value` statements directly in a class body.
+struct ClassAssignment<'t> {
+    name: &'t str,
+    annotation: Option<&'t str>,
+    value: tree_sitter::Node<'t>,
+}
+
+fn class_assignments<'t>(body: tree_sitter::Node<'t>, bytes: &'t [u8]) -> Vec<ClassAssignment<'t>> {
+    let mut cursor = body.walk();
+    body.named_children(&mut cursor)
+        .filter(|s| s.kind() == "expression_statement")
+        .filter_map(|s| s.named_child(0).filter(|a| a.kind() == "assignment"))
+        .filter_map(|assignment| {
+            let left = assignment
+                .child_by_field_name("left")
+                .filter(|l| l.kind() == "identifier")?;
+            Some(ClassAssignment {
+                name: get_node_text(left, bytes),
+                annotation: assignment
+                    .child_by_field_name("type")
+                    .map(|t| get_node_text(t, bytes)),
+                value: assignment.child_by_field_name("right")?,
+            })
+        })
+        .collect()
+}
+
+fn python_entities(root: tree_sitter::Node, bytes: &[u8], path: &str, out: &mut Vec<EntityDecl>) {
+    for node in preorder(root) {
+        if node.kind() != "class_definition" {
+            continue;
+        }
+        let (Some(name), Some(body)) = (
+            node.child_by_field_name("name"),
+            node.child_by_field_name("body"),
+        ) else {
+            continue;
+        };
+        let class = get_node_text(name, bytes);
+        let assignments = class_assignments(body, bytes);
+
+        let decl = if assignments.iter().any(|a| is_django_field(a.value, bytes)) {
+            django_model(class, body, &assignments, bytes)
+        } else if assignments.iter().any(|a| {
+            a.name == "__tablename__"
+                || a.value.kind() == "call"
+                    && matches!(callee_name(a.value, bytes), "Column" | "mapped_column")
+        }) {
+            Some(sqlalchemy_model(&assignments, bytes))
+        } else {
+            None
+        };
+
+        if let Some(mut decl) = decl {
+            decl.name = class.to_string();
+            decl.class = Some(class.to_string());
+            decl.evidence = evidence_from_node(node, path);
+            out.push(decl);
+        }
+    }
+}
+
+/// `models.CharField(...)`, `models.ForeignKey(...)`
+fn is_django_field(value: tree_sitter::Node, bytes: &[u8]) -> bool {
+    value.kind() == "call"
+        && value
+            .child_by_field_name("function")
+            .and_then(|f| f.child_by_field_name("object"))
+            .is_some_and(|o| get_node_text(o, bytes) == "models")
+}
+
+/// Django model fields; `None` for abstract models, which have no table.
+fn django_model(
+    class: &str,
+    body: tree_sitter::Node,
+    assignments: &[ClassAssignment],
+    bytes: &[u8],
+) -> Option<EntityDecl> {
+    let mut decl = EntityDecl::new(class, EvidenceLocation::empty());
+
+    let mut cursor = body.walk();
+    let meta = body
+        .named_children(&mut cursor)
+        .filter(|c| c.kind() == "class_definition")
+        .find(|c| {
+            c.child_by_field_name("name")
+                .is_some_and(|n| get_node_text(n, bytes) == "Meta")
+        })
+        .and_then(|m| m.child_by_field_name("body"));
+    if let Some(meta) = meta {
+        for option in class_assignments(meta, bytes) {
+            match option.name {
+                "abstract" if is_true(Some(option.value), bytes) => return None,
+                "db_table" => decl.table = string_value(option.value, bytes),
+                _ => {}
+            }
+        }
+    }
+
+    for field in assignments
+        .iter()
+        .filter(|a| is_django_field(a.value, bytes))
+    {
+        let kind = callee_name(field.value, bytes);
+        let args = named_args(field.value.child_by_field_name("arguments"));
+        let relation = match kind {
+            "ForeignKey" => Some(MANY_TO_ONE),
+            "OneToOneField" => Some(ONE_TO_ONE),
+            "ManyToManyField" => Some(MANY_TO_MANY),
+            _ => None,
+        };
+        if let Some(relation) = relation {
+            let target = args
+                .first()
+                .filter(|a| a.kind() != "keyword_argument")
+                .or(keyword_arg(&args, "to", bytes).as_ref())
+                .map(|a| string_value(*a, bytes).unwrap_or_else(|| get_node_text(*a, bytes).into()))
+                .unwrap_or_default();
+            let target = if target == "self" {
+                class
+            } else {
+                model_name(&target)
+            };
+            decl.relation(field.name, relation, target);
+            continue;
+        }
+        decl.field(
+            field.name,
+            kind,
+            is_true(keyword_arg(&args, "null", bytes), bytes),
+            is_true(keyword_arg(&args, "primary_key", bytes), bytes),
+        );
+    }
+
+    // Django adds an auto primary key unless a field claims it
+    if !decl.fields.iter().any(|f| f.primary_key == Some(true)) {
+        decl.fields.insert(
+            0,
+            FieldDefinition {
+                name: "id".to_string(),
+                field_type: "AutoField".to_string(),
+                nullable: false,
+                primary_key: Some(true),
+            },
+        );
+    }
+    Some(decl)
+}
+
+fn sqlalchemy_model(assignments: &[ClassAssignment], bytes: &[u8]) -> EntityDecl {
+    let mut decl = EntityDecl::new("", EvidenceLocation::empty());
+
+    for assignment in assignments {
+        let value = assignment.value;
+        if assignment.name == "__tablename__" {
+            decl.table = string_value(value, bytes);
+            continue;
+        }
+        if value.kind() != "call" {
+            continue;
+        }
+        // `Mapped[Optional[str]]` → `Optional[str]`
+        let mapped = assignment.annotation.map(|a| {
+            a.strip_prefix("Mapped[")
+                .and_then(|a| a.strip_suffix(']'))
+                .unwrap_or(a)
+        });
+        let args = named_args(value.child_by_field_name("arguments"));
+
+        match callee_name(value, bytes) {
+            "Column" | "mapped_column" => {
+                let positional: Vec<_> = args
+                    .iter()
+                    .filter(|a| !matches!(a.kind(), "keyword_argument" | "string"))
+                    .copied()
+                    .collect();
+                let foreign_key = positional
+                    .iter()
+                    .find(|a| a.kind() == "call" && callee_name(**a, bytes) == "ForeignKey");
+                let column_type = positional
+                    .iter()
+                    .find(|a| a.kind() != "call" || callee_name(**a, bytes) != "ForeignKey")
+                    .map(|a| callee_name(*a, bytes))
+                    .or(mapped.map(element_type))
+                    .unwrap_or("unknown");
+
+                let primary_key = is_true(keyword_arg(&args, "primary_key", bytes), bytes);
+                let nullable = match keyword_arg(&args, "nullable", bytes) {
+                    Some(flag) => is_true(Some(flag), bytes),
+                    None => mapped.is_none_or(|m| m.starts_with("Optional[") || m.contains("None")),
+                };
+                decl.field(assignment.name, column_type, nullable, primary_key);
+
+                if let Some(target) = foreign_key
+                    .and_then(|fk| {
+                        named_args(fk.child_by_field_name("arguments"))
+                            .first()
+                            .copied()
+                    })
+                    .and_then(|t| string_value(t, bytes))
+                {
+                    let table = target.split('.').next().unwrap_or(&target);
+                    decl.relation(assignment.name, MANY_TO_ONE, table);
+                }
+            }
+            "relationship" => {
+                let target = args
+                    .iter()
+                    .find(|a| a.kind() != "keyword_argument")
+                    .map(|a| {
+                        string_value(*a, bytes).unwrap_or_else(|| get_node_text(*a, bytes).into())
+                    })
+                    .or(mapped.map(|m| element_type(m).to_string()))
+                    .unwrap_or_default();
+                let relation = if is_false(keyword_arg(&args, "uselist", bytes), bytes) {
+                    ONE_TO_ONE
+                } else {
+                    match mapped {
+                        Some(m) if !m.contains('[') => MANY_TO_ONE,
+                        Some(m) if m.starts_with("Optional[") => MANY_TO_ONE,
+                        _ => ONE_TO_MANY,
+                    }
+                };
+                decl.relation(assignment.name, relation, model_name(&target));
+            }
+            _ => {}
+        }
+    }
+    decl
+}
+
+// =============================================================================
+// Java: JPA
+// =============================================================================
+
+fn jpa_entities(root: tree_sitter::Node, bytes: &[u8], path: &str, out: &mut Vec<EntityDecl>) {
+    for node in preorder(root) {
+        if node.kind() != "class_declaration" {
+            continue;
+        }
+        let annotations = java_annotations(node);
+        if !annotations
+            .iter()
+            .any(|a| annotation_name(*a, bytes) == "Entity")
+        {
+            continue;
+        }
+        let (Some(name), Some(body)) = (
+            node.child_by_field_name("name"),
+            node.child_by_field_name("body"),
+        ) else {
+            continue;
+        };
+
+        let class = get_node_text(name, bytes).to_string();
+        let mut decl = EntityDecl::new(class.clone(), evidence_from_node(node, path));
+        decl.class = Some(class);
+        decl.table = annotations
+            .iter()
+            .find(|a| annotation_name(**a, bytes) == "Table")
+            .and_then(|a| annotation_value(*a, "name", bytes))
+            .and_then(|v| string_value(v, bytes));
+
+        let mut cursor = body.walk();
+        for field in body.named_children(&mut cursor) {
+            if field.kind() == "field_declaration" {
+                jpa_field(field, bytes, &mut decl);
+            }
+        }
+        out.push(decl);
+    }
+}
+
+fn jpa_field(field: tree_sitter::Node, bytes: &[u8], decl: &mut EntityDecl) {
+    let mut cursor = field.walk();
+    let is_static = field
+        .children(&mut cursor)
+        .find(|c| c.kind() == "modifiers")
+        .is_some_and(|m| get_node_text(m, bytes).contains("static"));
+    let annotations = java_annotations(field);
+    let names: Vec<&str> = annotations
+        .iter()
+        .map(|a| annotation_name(*a, bytes))
+        .collect();
+    if is_static || names.contains(&"Transient") {
+        return;
+    }
+    let Some(field_type) = field.child_by_field_name("type") else {
+        return;
+    };
+    let type_text = get_node_text(field_type, bytes);
+
+    let relation = names.iter().find_map(|n| match *n {
+        "OneToOne" => Some(ONE_TO_ONE),
+        "OneToMany" => Some(ONE_TO_MANY),
+        "ManyToOne" => Some(MANY_TO_ONE),
+        "ManyToMany" => Some(MANY_TO_MANY),
+        _ => None,
+    });
+    let primary_key = names.iter().any(|n| matches!(*n, "Id" | "EmbeddedId"));
+    let not_null = names.iter().any(|n| matches!(*n, "NotNull" | "NonNull"))
+        || annotations
+            .iter()
+            .filter(|a| matches!(annotation_name(**a, bytes), "Column" | "JoinColumn"))
+            .any(|a| is_false(annotation_value(*a, "nullable", bytes), bytes))
+        || matches!(
+            field_type.kind(),
+            "integral_type" | "floating_point_type" | "boolean_type"
+        );
+
+    let mut cursor = field.walk();
+    for declarator in field.children_by_field_name("declarator", &mut cursor) {
+        let Some(name) = declarator.child_by_field_name("name") else {
+            continue;
+        };
+        let name = get_node_text(name, bytes);
+        match relation {
+            Some(relation) => decl.relation(name, relation, element_type(type_text)),
+            None => decl.field(name, type_text, !not_null, primary_key),
+        }
+    }
+}
+
+/// Value of `key = ...` in an annotation's arguments.
+fn annotation_value<'t>(
+    annotation: tree_sitter::Node<'t>,
+    key: &str,
+    bytes: &[u8],
+) -> Option<tree_sitter::Node<'t>> {
+    named_args(annotation.child_by_field_name("arguments"))
+        .into_iter()
+        .filter(|a| a.kind() == "element_value_pair")
+        .find(|a| {
+            a.child_by_field_name("key")
+                .is_some_and(|k| get_node_text(k, bytes) == key)
+        })
+        .and_then(|a| a.child_by_field_name("value"))
+}
+
+// =============================================================================
+// Rust: diesel and sea-orm
+// =============================================================================
+
+fn rust_entities(root: tree_sitter::Node, bytes: &[u8], path: &str, out: &mut Vec<EntityDecl>) {
+    let mut joins = Vec::new();
+    let mut relations = Vec::new();
+
+    for node in preorder(root) {
+        match node.kind() {
+            "macro_invocation" => {
+                let Some(tokens) = node
+                    .named_children(&mut node.walk())
+                    .find(|c| c.kind() == "token_tree")
+                else {
+                    continue;
+                };
+                let tokens = get_node_text(tokens, bytes);
+                let evidence = evidence_from_node(node, path);
+                match node
+                    .child_by_field_name("macro")
+                    .map(|m| last_segment(get_node_text(m, bytes)))
+                {
+                    Some("table") => out.extend(diesel_table(tokens, evidence)),
+                    Some("joinable") => joins.extend(diesel_joinable(tokens)),
+                    _ => {}
+                }
+            }
+            "struct_item" => {
+                let attributes = outer_attributes(node, bytes);
+                if attributes.iter().any(|a| a.contains("DeriveEntityModel")) {
+                    out.extend(sea_orm_model(node, &attributes, bytes, path));
+                }
+            }
+            "enum_item" => {
+                let attributes = outer_attributes(node, bytes);
+                if attributes.iter().any(|a| a.contains("DeriveRelation")) {
+                    relations.extend(sea_orm_relations(node, bytes));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // `joinable!(posts -> users (user_id))` belongs to the `posts` table
+    for (child, parent, column) in joins {
+        if let Some(decl) = out.iter_mut().find(|d| d.name == child) {
+            decl.relation(&column, MANY_TO_ONE, &parent);
+        }
+    }
+    // A sea-orm file holds one entity; its `Relation` enum describes that entity
+    if let Some(decl) = out.iter_mut().find(|d| d.class.as_deref() == Some("Model")) {
+        for (name, relation, target) in relations {
+            decl.relation(&name, relation, &target);
+        }
+    }
+}
+
+/// `users (id) { id -> Int4, email -> Nullable<Varchar>, }`
+fn diesel_table(tokens: &str, evidence: EvidenceLocation) -> Option<EntityDecl> {
+    let inner = tokens.trim().strip_prefix('{')?.strip_suffix('}')?;
+    let inner = strip_attributes(inner);
+    let body_start = inner.find('{')?;
+    let header = inner[..body_start].trim();
+    let body = inner[body_start + 1..].rsplit_once('}')?.0;
+
+    let (name, keys) = match header.split_once('(') {
+        Some((name, keys)) => (name.trim(), keys.trim_end_matches(')')),
+        None => (header, "id"),
+    };
+    let name = last_segment(name.trim());
+    let mut decl = EntityDecl::new(name, evidence);
+    decl.table = Some(name.to_string());
+
+    for column in body.split(',') {
+        let Some((column, sql_type)) = column.split_once("->") else {
+            continue;
+        };
+        let sql_type: String = sql_type.split_whitespace().collect();
+        let (sql_type, nullable) = match sql_type
+            .strip_prefix("Nullable<")
+            .and_then(|t| t.strip_suffix('>'))
+        {
+            Some(inner) => (inner.to_string(), true),
+            None => (sql_type, false),
+        };
+        decl.field(column.trim(), &sql_type, nullable, false);
+    }
+    for key in keys.split(',') {
+        decl.mark_primary_key(key.trim());
+    }
+    Some(decl)
+}
+
+/// Drop `#[...]` attributes inside a macro body (`#[max_length = 255]`).
+fn strip_attributes(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("#[") {
+        stripped.push_str(&rest[..start]);
+        rest = rest[start..].split_once(']').map_or("", |(_, after)| after);
+    }
+    stripped.push_str(rest);
+    stripped
+}
+
+/// `posts -> users (user_id)` → `(posts, users, user_id)`
+fn diesel_joinable(tokens: &str) -> Option<(String, String, String)> {
+    let inner = tokens.trim().trim_start_matches('(').trim_end_matches(')');
+    let (child, rest) = inner.split_once("->")?;
+    let (parent, column) = rest.split_once('(')?;
+    Some((
+        child.trim().to_string(),
+        parent.trim().to_string(),
+        column.trim_end_matches(')').trim().to_string(),
+    ))
+}
+
+/// Text of the `#[...]` attributes directly above an item.
+fn outer_attributes<'b>(item: tree_sitter::Node, bytes: &'b [u8]) -> Vec<&'b str> {
+    let mut attributes = Vec::new();
+    let mut prev = item.prev_named_sibling();
+    while let Some(sibling) = prev
+        && matches!(
+            sibling.kind(),
+            "attribute_item" | "line_comment" | "block_comment"
+        )
+    {
+        if sibling.kind() == "attribute_item" {
+            attributes.push(get_node_text(sibling, bytes));
+        }
+        prev = sibling.prev_named_sibling();
+    }
+    attributes
+}
+
+/// `key = "value"` inside attribute text.
+fn attribute_value(attribute: &str, key: &str) -> Option<String> {
+    let (_, rest) = attribute.split_once(key)?;
+    let rest = rest.trim_start().strip_prefix('=')?.trim_start();
+    let rest = rest.strip_prefix('"')?;
+    rest.split_once('"').map(|(value, _)| value.to_string())
+}
+
+fn sea_orm_model(
+    item: tree_sitter::Node,
+    attributes: &[&str],
+    bytes: &[u8],
+    path: &str,
+) -> Option<EntityDecl> {
+    let class = get_node_text(item.child_by_field_name("name")?, bytes);
+    let table = attributes
+        .iter()
+        .find_map(|a| attribute_value(a, "table_name"))?;
+    let mut decl = EntityDecl::new(table.clone(), evidence_from_node(item, path));
+    decl.class = Some(class.to_string());
+    decl.table = Some(table);
+
+    let body = item.child_by_field_name("body")?;
+    let mut primary_key = false;
+    let mut cursor = body.walk();
+    for member in body.named_children(&mut cursor) {
+        match member.kind() {
+            "attribute_item" => {
+                primary_key |= get_node_text(member, bytes).contains("primary_key");
+            }
+            "field_declaration" => {
+                let (Some(name), Some(field_type)) = (
+                    member.child_by_field_name("name"),
+                    member.child_by_field_name("type"),
+                ) else {
+                    continue;
+                };
+                let type_text = get_node_text(field_type, bytes);
+                let (type_text, nullable) = match type_text
+                    .strip_prefix("Option<")
+                    .and_then(|t| t.strip_suffix('>'))
+                {
+                    Some(inner) => (inner, true),
+                    None => (type_text, false),
+                };
+                decl.field(get_node_text(name, bytes), type_text, nullable, primary_key);
+                primary_key = false;
+            }
+            _ => {}
+        }
+    }
+    Some(decl)
+}
+
+/// Variants of a `DeriveRelation` enum:
+/// `#[sea_orm(has_many = "super::fruit::Entity")] Fruit`.
+fn sea_orm_relations(item: tree_sitter::Node, bytes: &[u8]) -> Vec<(String, &'static str, String)> {
+    let mut relations = Vec::new();
+    let Some(body) = item.child_by_field_name("body") else {
+        return relations;
+    };
+    let mut pending: Option<(&'static str, String)> = None;
+    let mut cursor = body.walk();
+    for member in body.named_children(&mut cursor) {
+        match member.kind() {
+            "attribute_item" => {
+                let text = get_node_text(member, bytes);
+                pending = [
+                    ("has_many", ONE_TO_MANY),
+                    ("has_one", ONE_TO_ONE),
+                    ("belongs_to", MANY_TO_ONE),
+                ]
+                .into_iter()
+                .find_map(|(key, relation)| {
+                    attribute_value(text, key).map(|target| (relation, target))
+                })
+                .or(pending);
+            }
+            "enum_variant" => {
+                if let (Some((relation, target)), Some(name)) =
+                    (pending.take(), member.child_by_field_name("name"))
+                {
+                    relations.push((
+                        snake_case(get_node_text(name, bytes)),
+                        relation,
+                        model_name(&target).to_string(),
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+    relations
+}
+
+// =============================================================================
+// Ruby: ActiveRecord
+// =============================================================================
+
+fn ruby_entities(root: tree_sitter::Node, bytes: &[u8], path: &str, out: &mut Vec<EntityDecl>) {
+    let mut foreign_keys = Vec::new();
+
+    for node in preorder(root) {
+        match node.kind() {
+            "class" => {
+                if let Some(decl) = active_record_model(node, bytes, path) {
+                    out.push(decl);
+                }
+            }
+            "call" => {
+                let method = node
+                    .child_by_field_name("method")
+                    .map(|m| get_node_text(m, bytes));
+                let args = named_args(node.child_by_field_name("arguments"));
+                match method {
+                    Some("create_table") => out.extend(create_table(node, &args, bytes, path)),
+                    Some("add_foreign_key") => {
+                        let tables: Vec<String> =
+                            args.iter().filter_map(|a| ruby_name(*a, bytes)).collect();
+                        if let [from, to] = tables.as_slice() {
+                            let column = rails_option(&args, "column", bytes)
+                                .unwrap_or_else(|| format!("{}_id", singularize(to)));
+                            foreign_keys.push((from.clone(), column, to.clone()));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    for (from, column, to) in foreign_keys {
+        if let Some(decl) = out.iter_mut().find(|d| d.name == from)
+            && !decl.relations.iter().any(|r| r.name == column)
+        {
+            decl.relation(&column, MANY_TO_ONE, &to);
+        }
+    }
+}
+
+fn active_record_model(class: tree_sitter::Node, bytes: &[u8], path: &str) -> Option<EntityDecl> {
+    let superclass = class.child_by_field_name("superclass")?.named_child(0)?;
+    if !matches!(
+        get_node_text(superclass, bytes),
+        "ApplicationRecord" | "ActiveRecord::Base"
+    ) {
+        return None;
+    }
+    let class_name = get_node_text(class.child_by_field_name("name")?, bytes);
+    let name = last_segment(class_name);
+
+    let mut decl = EntityDecl::new(name, evidence_from_node(class, path));
+    decl.class = Some(class_name.to_string());
+    decl.table = Some(pluralize(&snake_case(name)));
+
+    let Some(body) = class.child_by_field_name("body") else {
+        return Some(decl);
+    };
+    let mut cursor = body.walk();
+    for statement in body.named_children(&mut cursor) {
+        match statement.kind() {
+            // self.table_name = "people"
+            "assignment" => {
+                let left = statement.child_by_field_name("left");
+                if left.is_some_and(|l| get_node_text(l, bytes) == "self.table_name")
+                    && let Some(table) = statement
+                        .child_by_field_name("right")
+                        .and_then(|r| string_value(r, bytes))
+                {
+                    decl.table = Some(table);
+                }
+            }
+            "call" => {
+                let Some(method) = statement.child_by_field_name("method") else {
+                    continue;
+                };
+                let relation = match get_node_text(method, bytes) {
+                    "has_many" => ONE_TO_MANY,
+                    "has_one" => ONE_TO_ONE,
+                    "belongs_to" => MANY_TO_ONE,
+                    "has_and_belongs_to_many" => MANY_TO_MANY,
+                    _ => continue,
+                };
+                let args = named_args(statement.child_by_field_name("arguments"));
+                let Some(association) = args.first().and_then(|a| ruby_name(*a, bytes)) else {
+                    continue;
+                };
+                let target = rails_option(&args, "class_name", bytes).unwrap_or_else(|| {
+                    if matches!(relation, ONE_TO_MANY | MANY_TO_MANY) {
+                        camel_case(&singularize(&association))
+                    } else {
+                        camel_case(&association)
+                    }
+                });
+                decl.relation(&association, relation, last_segment(&target));
+            }
+            _ => {}
+        }
+    }
+    Some(decl)
+}
+
+/// `create_table "users" do |t| ... end` in `db/schema.rb` or a migration.
+fn create_table(
+    call: tree_sitter::Node,
+    args: &[tree_sitter::Node],
+    bytes: &[u8],
+    path: &str,
+) -> Option<EntityDecl> {
+    let table = args.first().and_then(|a| ruby_name(*a, bytes))?;
+    let mut decl = EntityDecl::new(table.clone(), evidence_from_node(call, path));
+    decl.table = Some(table);
+
+    let id = rails_pair(args, "id", bytes);
+    if !is_false(id, bytes) {
+        let id_type = id.and_then(|i| ruby_name(i, bytes));
+        decl.field("id", id_type.as_deref().unwrap_or("bigint"), false, true);
+    }
+
+    let body = call
+        .child_by_field_name("block")
+        .and_then(|b| b.child_by_field_name("body"));
+    let Some(body) = body else {
+        return Some(decl);
+    };
+    let mut cursor = body.walk();
+    for column in body.named_children(&mut cursor) {
+        if column.kind() != "call" || column.child_by_field_name("receiver").is_none() {
+            continue;
+        }
+        let Some(column_type) = column
+            .child_by_field_name("method")
+            .map(|m| get_node_text(m, bytes))
+        else {
+            continue;
+        };
+        let column_args = named_args(column.child_by_field_name("arguments"));
+        let name = column_args.first().and_then(|a| ruby_name(*a, bytes));
+        let nullable = !is_false(rails_pair(&column_args, "null", bytes), bytes);
+
+        match (column_type, name) {
+            ("timestamps", _) => {
+                decl.field("created_at", "datetime", false, false);
+                decl.field("updated_at", "datetime", false, false);
+            }
+            ("references" | "belongs_to", Some(name)) => {
+                let column_name = format!("{}_id", name);
+                decl.field(&column_name, "bigint", nullable, false);
+                decl.relation(&column_name, MANY_TO_ONE, &pluralize(&name));
+            }
+            ("index" | "check_constraint", _) => {}
+            (column_type, Some(name)) => decl.field(&name, column_type, nullable, false),
+            (_, None) => {}
+        }
+    }
+    Some(decl)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{
+        JavaParser, Parser, PythonParser, RubyParser, RustParser, TypeScriptParser,
+    };
+    use super::*;
+
+    fn entity<'a>(result: &'a ParseResult, id: &str) -> &'a EntityMetadata {
+        result
+            .nodes
+            .iter()
+            .find(|n| n.id == id)
+            .and_then(|n| n.metadata.entity_metadata.as_ref())
+            .unwrap_or_else(|| panic!("missing {}", id))
+    }
+
+    fn fields(entity: &EntityMetadata) -> Vec<(&str, &str, bool, bool)> {
+        entity
+            .fields
+            .iter()
+            .flatten()
+            .map(|f| {
+                (
+                    f.name.as_str(),
+                    f.field_type.as_str(),
+                    f.nullable,
+                    f.primary_key == Some(true),
+                )
+            })
+            .collect()
+    }
+
+    fn relations(entity: &EntityMetadata) -> Vec<(&str, &str, &str)> {
+        entity
+            .relations
+            .iter()
+            .flatten()
+            .map(|r| (r.name.as_str(), r.relation_type.as_str(), r.target.as_str()))
+            .collect()
+    }
+
+    fn persists(result: &ParseResult) -> Vec<(&str, &str)> {
+        result
+            .edges
+            .iter()
+            .filter(|e| e.edge_type == EdgeType::Persists)
+            .map(|e| (e.source_id.as_str(), e.target_id.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_jpa_entity() {
+        let code = r#"
+@Entity
+@Table(name = "users")
+public class User {
+    @Id @GeneratedValue
+    private Long id;
+    @Column(nullable = false)
+    private String email;
+    private String nickname;
+    private int age;
+    @OneToMany(mappedBy = "user")
+    private List<Order> orders;
+    @ManyToOne
+    private Team team;
+    @Transient
+    private String scratch;
+    private static final long serialVersionUID = 1L;
+}
+"#;
+        let result = JavaParser::new().unwrap().parse("User.java", code).unwrap();
+        let user = entity(&result, "entity:User.java:User");
+
+        assert_eq!(user.table_name.as_deref(), Some("users"));
+        assert_eq!(
+            fields(user),
+            vec![
+                ("id", "Long", false, true),
+                ("email", "String", false, false),
+                ("nickname", "String", true, false),
+                ("age", "int", false, false),
+            ]
+        );
+        assert_eq!(
+            relations(user),
+            vec![
+                ("orders", "one_to_many", "Order"),
+                ("team", "many_to_one", "Team")
+            ]
+        );
+        assert_eq!(
+            persists(&result),
+            vec![("class:User.java:User", "entity:User.java:User")]
+        );
+    }
+
+    #[test]
+    fn test_typeorm_entity() {
+        let code = r#"
+@Entity("photos")
+export class Photo {
+  @PrimaryGeneratedColumn()
+  id: number;
+
+  @Column({ nullable: true })
+  caption: string;
+
+  @Column()
+  url?: string;
+
+  @ManyToOne(() => User, (user) => user.photos)
+  owner: User;
+
+  @ManyToMany(type => Tag)
+  tags: Tag[];
+
+  helper: string;
+}
+"#;
+        let result = TypeScriptParser::new()
+            .unwrap()
+            .parse("src/photo.entity.ts", code)
+            .unwrap();
+        let photo = entity(&result, "entity:src/photo.entity.ts:Photo");
+
+        assert_eq!(photo.table_name.as_deref(), Some("photos"));
+        assert_eq!(
+            fields(photo),
+            vec![
+                ("id", "number", false, true),
+                ("caption", "string", true, false),
+                ("url", "string", true, false),
+            ]
+        );
+        assert_eq!(
+            relations(photo),
+            vec![
+                ("owner", "many_to_one", "User"),
+                ("tags", "many_to_many", "Tag")
+            ]
+        );
+        assert_eq!(persists(&result).len(), 1);
+    }
+
+    #[test]
+    fn test_sqlalchemy_and_django_models() {
+        let code = r#"
+class Base(DeclarativeBase):
+    pass
+
+class User(Base):
+    __tablename__ = "users"
+    id = Column(Integer, primary_key=True)
+    team_id = Column(Integer, ForeignKey("teams.id"), nullable=False)
+    name: Mapped[Optional[str]] = mapped_column(String(50))
+    email: Mapped[str] = mapped_column()
+    posts: Mapped[List["Post"]] = relationship(back_populates="author")
+    profile = relationship("Profile", uselist=False)
+
+class TimeStamped(models.Model):
+    created = models.DateTimeField()
+
+    class Meta:
+        abstract = True
+
+class Article(TimeStamped):
+    title = models.CharField(max_length=200)
+    summary = models.TextField(null=True)
+    author = models.ForeignKey("auth.User", on_delete=models.CASCADE)
+    tags = models.ManyToManyField(Tag)
+
+    class Meta:
+        db_table = "articles"
+"#;
+        let result = PythonParser::new()
+            .unwrap()
+            .parse("models.py", code)
+            .unwrap();
+
+        let user = entity(&result, "entity:models.py:User");
+        assert_eq!(user.table_name.as_deref(), Some("users"));
+        assert_eq!(
+            fields(user),
+            vec![
+                ("id", "Integer", false, true),
+                ("team_id", "Integer", false, false),
+                ("name", "String", true, false),
+                ("email", "str", false, false),
+            ]
+        );
+        assert_eq!(
+            relations(user),
+            vec![
+                ("team_id", "many_to_one", "teams"),
+                ("posts", "one_to_many", "Post"),
+                ("profile", "one_to_one", "Profile"),
+            ]
+        );
+
+        let article = entity(&result, "entity:models.py:Article");
+        assert_eq!(article.table_name.as_deref(), Some("articles"));
+        assert_eq!(
+            fields(article),
+            vec![
+                ("id", "AutoField", false, true),
+                ("title", "CharField", false, false),
+                ("summary", "TextField", true, false),
+            ]
+        );
+        assert_eq!(
+            relations(article),
+            vec![
+                ("author", "many_to_one", "User"),
+                ("tags", "many_to_many", "Tag")
+            ]
+        );
+
+        // Declarative bases and abstract models have no table
+        let names: Vec<&str> = result
+            .nodes
+            .iter()
+            .filter(|n| n.node_type == NodeType::Entity)
+            .map(|n| n.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["User", "Article"]);
+    }
+
+    #[test]
+    fn test_active_record_model_and_schema() {
+        let parser = RubyParser::new().unwrap();
+        let model = parser
+            .parse(
+                "app/models/user.rb",
+                r#"
+class User < ApplicationRecord
+  has_many :posts, dependent: :destroy
+  has_many :categories
+  belongs_to :team, optional: true
+  has_one :profile, class_name: "UserProfile"
+end
+"#,
+            )
+            .unwrap();
+        let user = entity(&model, "entity:app/models/user.rb:User");
+        assert_eq!(user.table_name.as_deref(), Some("users"));
+        assert_eq!(
+            relations(user),
+            vec![
+                ("posts", "one_to_many", "Post"),
+                ("categories", "one_to_many", "Category"),
+                ("team", "many_to_one", "Team"),
+                ("profile", "one_to_one", "UserProfile"),
+            ]
+        );
+        assert_eq!(persists(&model).len(), 1);
+
+        let schema = parser
+            .parse(
+                "db/schema.rb",
+                r#"
+ActiveRecord::Schema[7.0].define(version: 2023_01_01) do
+  create_table "posts", force: :cascade do |t|
+    t.string "title", null: false
+    t.text "body"
+    t.references "user"
+    t.timestamps
+  end
+
+  add_foreign_key "posts", "categories"
+end
+"#,
+            )
+            .unwrap();
+        let posts = entity(&schema, "entity:db/schema.rb:posts");
+        assert_eq!(
+            fields(posts),
+            vec![
+                ("id", "bigint", false, true),
+                ("title", "string", false, false),
+                ("body", "text", true, false),
+                ("user_id", "bigint", true, false),
+                ("created_at", "datetime", false, false),
+                ("updated_at", "datetime", false, false),
+            ]
+        );
+        assert_eq!(
+            relations(posts),
+            vec![
+                ("user_id", "many_to_one", "users"),
+                ("category_id", "many_to_one", "categories"),
+            ]
+        );
+        assert!(schema.edges.iter().any(|e| e.edge_type == EdgeType::Owns
+            && e.source_id == "file:db/schema.rb"
+            && e.target_id == "entity:db/schema.rb:posts"));
+    }
+
+    #[test]
+    fn test_diesel_and_sea_orm() {
+        let parser = RustParser::new().unwrap();
+        let schema = parser
+            .parse(
+                "src/schema.rs",
+                r#"
+diesel::table! {
+    posts (id) {
+        id -> Int4,
+        #[max_length = 255]
+        title -> Varchar,
+        body -> Nullable<Text>,
+        user_id -> Int4,
+    }
+}
+
+joinable!(posts -> users (user_id));
+"#,
+            )
+            .unwrap();
+        let posts = entity(&schema, "entity:src/schema.rs:posts");
+        assert_eq!(
+            fields(posts),
+            vec![
+                ("id", "Int4", false, true),
+                ("title", "Varchar", false, false),
+                ("body", "Text", true, false),
+                ("user_id", "Int4", false, false),
+            ]
+        );
+        assert_eq!(relations(posts), vec![("user_id", "many_to_one", "users")]);
+
+        let model = parser
+            .parse(
+                "src/entities/cake.rs",
+                r#"
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "cake")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub name: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::fruit::Entity")]
+    Fruit,
+}
+"#,
+            )
+            .unwrap();
+        let cake = entity(&model, "entity:src/entities/cake.rs:cake");
+        assert_eq!(
+            fields(cake),
+            vec![("id", "i32", false, true), ("name", "String", true, false)]
+        );
+        assert_eq!(relations(cake), vec![("fruit", "one_to_many", "fruit")]);
+        assert_eq!(
+            persists(&model),
+            vec![(
+                "class:src/entities/cake.rs:Model",
+                "entity:src/entities/cake.rs:cake"
+            )]
+        );
+    }
+}
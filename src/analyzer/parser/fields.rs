@@ -0,0 +1,293 @@
+//! Field Type Extraction
+//!
+//! Class, struct and interface nodes are pushed with their name only. This
+//! pass fills `NodeMetadata.fields` with the typed fields each declares:
+//!
+//! - Rust struct fields, named or positional (`0`, `1`, ...);
+//! - TypeScript interface properties and class fields;
+//! - Java class fields, one per declarator;
+//! - Kotlin properties, including `val`/`var` constructor parameters;
+//! - Go struct fields, embedded ones named after their type.
+//!
+//! Untyped fields (`count = 0`) are skipped.
+
+use super::fingerprint::declaration;
+use super::traits::{ParseResult, get_node_text};
+use crate::types::{FieldDefinition, NodeType};
+
+/// Fill the fields of the classes and interfaces of `result`.
+///
+/// Must run after the parser has pushed its declaration nodes.
+pub fn extract_fields(root: tree_sitter::Node, content: &str, result: &mut ParseResult) {
+    let bytes = content.as_bytes();
+    for node in &mut result.nodes {
+        if !matches!(node.node_type, NodeType::Class | NodeType::Interface)
+            || node.metadata.fields.is_some()
+        {
+            continue;
+        }
+        let Some(mut decl) = declaration(root, node, bytes) else {
+            continue;
+        };
+        // Kotlin declarations have no `name` field, leaving the name itself
+        if decl.kind() == "type_identifier"
+            && let Some(parent) = decl.parent()
+        {
+            decl = parent;
+        }
+
+        let fields = declared_fields(decl, bytes);
+        if !fields.is_empty() {
+            node.metadata.fields = Some(fields);
+        }
+    }
+}
+
+fn declared_fields(decl: tree_sitter::Node, bytes: &[u8]) -> Vec<FieldDefinition> {
+    let mut containers = Vec::new();
+    let mut cursor = decl.walk();
+    for child in decl.named_children(&mut cursor) {
+        match child.kind() {
+            // Rust, TypeScript, Java, Kotlin
+            "field_declaration_list"
+            | "ordered_field_declaration_list"
+            | "interface_body"
+            | "object_type"
+            | "class_body"
+            | "primary_constructor" => containers.push(child),
+            // Go: `type Repo struct { ... }`
+            "struct_type" => {
+                let mut inner = child.walk();
+                containers.extend(
+                    child
+                        .named_children(&mut inner)
+                        .filter(|c| c.kind() == "field_declaration_list"),
+                );
+            }
+            _ => {}
+        }
+    }
+
+    let mut fields = Vec::new();
+    for container in containers {
+        if container.kind() == "ordered_field_declaration_list" {
+            let mut cursor = container.walk();
+            for (i, field_type) in container
+                .children_by_field_name("type", &mut cursor)
+                .enumerate()
+            {
+                fields.push(field(i.to_string(), field_type, bytes, false));
+            }
+            continue;
+        }
+
+        let mut cursor = container.walk();
+        for member in container.named_children(&mut cursor) {
+            member_fields(member, bytes, &mut fields);
+        }
+    }
+    fields
+}
+
+/// Fields one member of a declaration body declares.
+fn member_fields(member: tree_sitter::Node, bytes: &[u8], fields: &mut Vec<FieldDefinition>) {
+    match member.kind() {
+        // Rust and Go list names under `name`, Java under its declarators
+        "field_declaration" => {
+            let Some(field_type) = member.child_by_field_name("type") else {
+                return;
+            };
+            let mut cursor = member.walk();
+            let mut names: Vec<String> = member
+                .children_by_field_name("name", &mut cursor)
+                .map(|n| get_node_text(n, bytes).to_string())
+                .collect();
+            let mut cursor = member.walk();
+            names.extend(
+                member
+                    .children_by_field_name("declarator", &mut cursor)
+                    .filter_map(|d| d.child_by_field_name("name"))
+                    .map(|n| get_node_text(n, bytes).to_string()),
+            );
+            // Go embedded field
+            if names.is_empty() && member.child_by_field_name("declarator").is_none() {
+                let text = get_node_text(field_type, bytes).trim_start_matches('*');
+                names.push(text.rsplit('.').next().unwrap_or(text).to_string());
+            }
+            for name in names {
+                fields.push(field(name, field_type, bytes, false));
+            }
+        }
+        // TypeScript
+        "property_signature" | "public_field_definition" => {
+            let (Some(name), Some(field_type)) = (
+                member.child_by_field_name("name"),
+                member.child_by_field_name("type"),
+            ) else {
+                return;
+            };
+            let mut cursor = member.walk();
+            let optional = member.children(&mut cursor).any(|c| c.kind() == "?");
+            fields.push(field(
+                get_node_text(name, bytes).to_string(),
+                field_type,
+                bytes,
+                optional,
+            ));
+        }
+        // Kotlin: properties hold a `variable_declaration`, constructor
+        // parameters are fields only when bound with `val` or `var`
+        "property_declaration" => {
+            let mut cursor = member.walk();
+            if let Some(variable) = member
+                .named_children(&mut cursor)
+                .find(|c| c.kind() == "variable_declaration")
+            {
+                kotlin_field(variable, bytes, fields);
+            }
+        }
+        "class_parameter" => {
+            let mut cursor = member.walk();
+            if member
+                .named_children(&mut cursor)
+                .any(|c| c.kind() == "binding_pattern_kind")
+            {
+                kotlin_field(member, bytes, fields);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn kotlin_field(node: tree_sitter::Node, bytes: &[u8], fields: &mut Vec<FieldDefinition>) {
+    let mut cursor = node.walk();
+    let children: Vec<_> = node.named_children(&mut cursor).collect();
+    let name = children.iter().find(|c| c.kind() == "simple_identifier");
+    let field_type = children
+        .iter()
+        .find(|c| matches!(c.kind(), "user_type" | "nullable_type" | "function_type"));
+    if let (Some(name), Some(field_type)) = (name, field_type) {
+        fields.push(field(
+            get_node_text(*name, bytes).to_string(),
+            *field_type,
+            bytes,
+            false,
+        ));
+    }
+}
+
+fn field(
+    name: String,
+    field_type: tree_sitter::Node,
+    bytes: &[u8],
+    optional: bool,
+) -> FieldDefinition {
+    // TypeScript annotations include their leading `:`
+    let field_type = get_node_text(field_type, bytes)
+        .trim_start_matches(':')
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    let nullable = optional
+        || field_type.ends_with('?')
+        || field_type.starts_with("Option<")
+        || field_type.contains("| null")
+        || field_type.contains("| undefined");
+    FieldDefinition {
+        name,
+        field_type,
+        nullable,
+        primary_key: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::analyzer::parser::{Language, ParseResult, create_parser};
+
+    fn fields(path: &str, content: &str, name: &str) -> Vec<(String, String, bool)> {
+        let result: ParseResult = create_parser(Language::from_path(path))
+            .unwrap()
+            .parse(path, content)
+            .unwrap();
+        let node = result.nodes.iter().find(|n| n.name == name).unwrap();
+        node.metadata
+            .fields
+            .iter()
+            .flatten()
+            .map(|f| (f.name.clone(), f.field_type.clone(), f.nullable))
+            .collect()
+    }
+
+    fn expected(items: &[(&str, &str, bool)]) -> Vec<(String, String, bool)> {
+        items
+            .iter()
+            .map(|(n, t, nullable)| (n.to_string(), t.to_string(), *nullable))
+            .collect()
+    }
+
+    #[test]
+    fn test_field_types() {
+        assert_eq!(
+            fields(
+                "src/a.rs",
+                "pub struct Profile { pub config: Config, last: Option<Run> }\n",
+                "Profile"
+            ),
+            expected(&[("config", "Config", false), ("last", "Option<Run>", true)])
+        );
+        assert_eq!(
+            fields("src/a.rs", "struct Pair(Left, Right);\n", "Pair"),
+            expected(&[("0", "Left", false), ("1", "Right", false)])
+        );
+        assert_eq!(
+            fields(
+                "src/a.ts",
+                "interface User { profile: Profile; name?: string; load(): Config; }\n",
+                "User"
+            ),
+            expected(&[("profile", "Profile", false), ("name", "string", true)])
+        );
+        assert_eq!(
+            fields(
+                "src/a.ts",
+                "class Repo { private store: Store; count = 0; }\n",
+                "Repo"
+            ),
+            expected(&[("store", "Store", false)])
+        );
+        assert_eq!(
+            fields(
+                "src/A.java",
+                "class Repo { private Store store, backup; List<Item> items; }\n",
+                "Repo"
+            ),
+            expected(&[
+                ("store", "Store", false),
+                ("backup", "Store", false),
+                ("items", "List<Item>", false)
+            ])
+        );
+        assert_eq!(
+            fields(
+                "src/A.kt",
+                "class Repo(val db: Db, name: String) {\n    var items: List<Item>? = null\n}\n",
+                "Repo"
+            ),
+            expected(&[("db", "Db", false), ("items", "List<Item>?", true)])
+        );
+        assert_eq!(
+            fields(
+                "pkg/a.go",
+                "package pkg\n\ntype Repo struct {\n\tStore *Store\n\ta, b Item\n\tsync.Mutex\n}\n",
+                "Repo"
+            ),
+            expected(&[
+                ("Store", "*Store", false),
+                ("a", "Item", false),
+                ("b", "Item", false),
+                ("Mutex", "sync.Mutex", false)
+            ])
+        );
+    }
+}
@@ -0,0 +1,205 @@
+//! Symbol Fingerprints
+//!
+//! Node IDs embed the file path and the symbol name, so moving a file or
+//! renaming a function gives its nodes new IDs. A fingerprint identifies a
+//! node by its code instead, stored under `extra.fingerprint`:
+//!
+//! - `signature`: hash of the normalized declaration, from the extracted
+//!   parameters where the parser has them, else the declaration header;
+//! - `body`: hash of the declaration's source with whitespace collapsed and
+//!   its own name blanked out, so a rename alone keeps it. For the file node,
+//!   the hash of the whole file.
+//!
+//! The declaration of a node is the syntax node around its evidence that
+//! carries its name in a `name` field, so parsers that record only the
+//! name's position are covered as well.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::traits::{ParseResult, get_node_text};
+use crate::types::{Node, NodeType};
+
+/// Metadata key holding a node's fingerprint.
+pub const FINGERPRINT_KEY: &str = "fingerprint";
+
+/// Hex digits kept from each hash.
+const HASH_LEN: usize = 16;
+
+/// Content-based identity of a node, independent of its path and name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Fingerprint {
+    pub signature: String,
+    pub body: String,
+}
+
+impl Fingerprint {
+    /// Fingerprint stored on a node, if it has one.
+    pub fn of(node: &Node) -> Option<Self> {
+        let value = node.metadata.extra.get(FINGERPRINT_KEY)?;
+        serde_json::from_value(value.clone()).ok()
+    }
+}
+
+/// Fingerprint the nodes of `result`.
+///
+/// Must run after the parser has pushed all of its nodes.
+pub fn fingerprint_nodes(root: tree_sitter::Node, content: &str, result: &mut ParseResult) {
+    let bytes = content.as_bytes();
+    for node in &mut result.nodes {
+        let fingerprint = if node.node_type == NodeType::File {
+            Some(Fingerprint {
+                signature: hash("file"),
+                body: hash(&normalize(content)),
+            })
+        } else {
+            declaration(root, node, bytes).map(|decl| symbol_fingerprint(node, decl, bytes))
+        };
+        if let Some(value) = fingerprint.and_then(|f| serde_json::to_value(f).ok()) {
+            node.metadata
+                .extra
+                .insert(FINGERPRINT_KEY.to_string(), value);
+        }
+    }
+}
+
+/// The syntax node declaring `node`: the innermost node spanning its
+/// evidence whose `name` field reads the node's name, or the spanning node
+/// itself when none does.
+pub(super) fn declaration<'t>(
+    root: tree_sitter::Node<'t>,
+    node: &Node,
+    content: &[u8],
+) -> Option<tree_sitter::Node<'t>> {
+    let evidence = &node.evidence;
+    if evidence.start_line == 0 {
+        return None;
+    }
+    let start = tree_sitter::Point::new(
+        evidence.start_line as usize - 1,
+        evidence.start_column.unwrap_or(0) as usize,
+    );
+    let end = match evidence.end_column {
+        Some(column) => tree_sitter::Point::new(evidence.end_line as usize - 1, column as usize),
+        None => start,
+    };
+    let spanning = root.descendant_for_point_range(start, end)?;
+
+    let mut current = Some(spanning);
+    while let Some(candidate) = current {
+        let named = candidate
+            .child_by_field_name("name")
+            .is_some_and(|name| get_node_text(name, content) == node.name);
+        if named {
+            return Some(candidate);
+        }
+        current = candidate.parent();
+    }
+    (spanning.id() != root.id()).then_some(spanning)
+}
+
+fn symbol_fingerprint(node: &Node, decl: tree_sitter::Node, content: &[u8]) -> Fingerprint {
+    let source = blank_name(&normalize(get_node_text(decl, content)), &node.name);
+    let kind = crate::types::enum_to_str(&node.node_type);
+    let signature = match &node.metadata.signature {
+        Some(sig) => {
+            let params: Vec<String> = sig
+                .parameters
+                .iter()
+                .map(|p| format!("{}:{}", p.name, p.param_type.as_deref().unwrap_or("")))
+                .collect();
+            format!(
+                "{}({})->{}",
+                if sig.is_async { "async " } else { "" },
+                params.join(","),
+                sig.return_type.as_deref().unwrap_or("")
+            )
+        }
+        None => source.split('{').next().unwrap_or_default().to_string(),
+    };
+
+    Fingerprint {
+        signature: hash(&format!("{}|{}", kind, normalize(&signature))),
+        body: hash(&format!("{}|{}", kind, source)),
+    }
+}
+
+/// Collapse runs of whitespace into one space.
+fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Replace whole-word occurrences of `name` with `_`.
+fn blank_name(text: &str, name: &str) -> String {
+    if name.is_empty() {
+        return text.to_string();
+    }
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(i) = rest.find(name) {
+        output.push_str(&rest[..i]);
+        let before = output.chars().next_back();
+        let after = rest[i + name.len()..].chars().next();
+        if before.is_some_and(is_word) || after.is_some_and(is_word) {
+            output.push_str(name);
+        } else {
+            output.push('_');
+        }
+        rest = &rest[i + name.len()..];
+    }
+    output.push_str(rest);
+    output
+}
+
+fn hash(text: &str) -> String {
+    let mut digest = format!("{:x}", Sha256::digest(text.as_bytes()));
+    digest.truncate(HASH_LEN);
+    digest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::parser::{Parser, PythonParser, RustParser};
+
+    fn fingerprint(result: &ParseResult, name: &str) -> Fingerprint {
+        let node = result.nodes.iter().find(|n| n.name == name).unwrap();
+        Fingerprint::of(node).unwrap()
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_path_name_and_layout() {
+        let rust = RustParser::new().unwrap();
+        let before = rust
+            .parse("./src/a.rs", "pub fn add(a: i32) -> i32 {\n    a + 1\n}\n")
+            .unwrap();
+        let after = rust
+            .parse("./src/b.rs", "pub fn plus(a: i32) -> i32 { a + 1 }\n")
+            .unwrap();
+        let edited = rust
+            .parse("./src/a.rs", "pub fn add(a: i32) -> i32 { a + 2 }\n")
+            .unwrap();
+
+        let add = fingerprint(&before, "add");
+        assert_eq!(add.body, fingerprint(&after, "plus").body);
+        let edited = fingerprint(&edited, "add");
+        assert_ne!(add.body, edited.body);
+        assert_eq!(add.signature, edited.signature);
+
+        let python = PythonParser::new()
+            .unwrap()
+            .parse(
+                "app/util.py",
+                "def pad(s):\n    return s\n\ndef trim(s):\n    return s.strip()\n",
+            )
+            .unwrap();
+        assert_ne!(
+            fingerprint(&python, "pad").body,
+            fingerprint(&python, "trim").body
+        );
+        assert!(Fingerprint::of(&python.nodes[0]).is_some());
+
+        assert_eq!(blank_name("add(adder, add)", "add"), "_(adder, _)");
+    }
+}
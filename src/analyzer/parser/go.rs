@@ -1,14 +1,65 @@
 use tree_sitter::{Query, QueryCursor, StreamingIterator};
 
 use super::{
-    Language, ParseResult, Parser, create_code_edge, create_code_node, create_file_node,
-    create_ts_parser, get_node_text,
+    CallSyntax, DocSyntax, Language, MetricsSyntax, ParseResult, Parser, RouteDialect, TestSyntax,
+    create_code_edge, create_code_node, create_file_node, create_ts_parser, extract_calls,
+    extract_docs, extract_fields, extract_metrics, extract_return_types, extract_routes,
+    extract_settings, extract_tests, fingerprint_nodes, get_node_text,
 };
 use crate::types::{
     EdgeType, FunctionSignature, ImportType, NodeMetadata, NodeType, Parameter, Result, Visibility,
     WeaveError,
 };
 
+const CALL_SYNTAX: CallSyntax = CallSyntax {
+    scope_kinds: &["function_declaration", "method_declaration"],
+    call_kinds: &[("call_expression", Some("function"))],
+    async_kinds: &["go_statement"],
+};
+
+const DOC_SYNTAX: DocSyntax = DocSyntax {
+    item_kinds: &[
+        "function_declaration",
+        "method_declaration",
+        "type_declaration",
+        "type_spec",
+    ],
+    file_item_kinds: &["package_clause"],
+    comment_kinds: &["comment"],
+    doc_prefixes: &[],
+    inner_prefixes: &[],
+    skip_kinds: &[],
+    wrapper_kinds: &[],
+    docstrings: false,
+};
+
+const TEST_SYNTAX: TestSyntax = TestSyntax {
+    item_kinds: &["function_declaration"],
+    marker_kinds: &[],
+    markers: &[],
+    name_prefixes: &["Test", "Benchmark", "Fuzz", "Example"],
+    calls: &CALL_SYNTAX,
+};
+
+const METRICS_SYNTAX: MetricsSyntax = MetricsSyntax {
+    branch_kinds: &[
+        "if_statement",
+        "for_statement",
+        "expression_case",
+        "type_case",
+        "communication_case",
+    ],
+    logical_kinds: &["binary_expression"],
+    nesting_kinds: &[
+        "if_statement",
+        "for_statement",
+        "expression_switch_statement",
+        "type_switch_statement",
+        "select_statement",
+    ],
+    return_kinds: &["return_statement"],
+};
+
 pub struct GoParser;
 
 impl GoParser {
@@ -47,6 +98,15 @@ impl Parser for GoParser {
         extract_interfaces(root, content, path, &mut result);
         extract_functions(root, content, path, &mut result);
         extract_methods(root, content, path, &mut result);
+        extract_calls(root, content, path, &mut result, &CALL_SYNTAX);
+        extract_docs(root, content, &mut result, &DOC_SYNTAX);
+        extract_tests(root, content, path, &mut result, &TEST_SYNTAX);
+        extract_routes(root, content, path, &mut result, RouteDialect::Go);
+        extract_return_types(root, content, &mut result, "result");
+        extract_fields(root, content, &mut result);
+        extract_settings(root, content, path, &mut result, &CALL_SYNTAX);
+        extract_metrics(root, content, &mut result, &METRICS_SYNTAX);
+        fingerprint_nodes(root, content, &mut result);
 
         Ok(result)
     }
@@ -1,12 +1,81 @@
 use chrono::Utc;
 use tree_sitter::{Parser as TsParser, Query, QueryCursor, StreamingIterator};
 
-use super::{Language, ParseResult, Parser, create_file_node};
+use super::{
+    CallSyntax, DocSyntax, EntityDialect, Language, MetricsSyntax, ParseResult, Parser,
+    RouteDialect, TestSyntax, create_file_node, extract_calls, extract_docs, extract_entities,
+    extract_fields, extract_metrics, extract_return_types, extract_routes, extract_settings,
+    extract_tests, fingerprint_nodes,
+};
 use crate::types::{
     Edge, EdgeMetadata, EdgeType, EvidenceLocation, FunctionSignature, ImportType, InformationTier,
     Node, NodeMetadata, NodeStatus, NodeType, Parameter, Result, Visibility, WeaveError,
 };
 
+const CALL_SYNTAX: CallSyntax = CallSyntax {
+    scope_kinds: &[
+        "method_declaration",
+        "constructor_declaration",
+        "class_declaration",
+    ],
+    call_kinds: &[
+        ("method_invocation", Some("name")),
+        ("object_creation_expression", Some("type")),
+    ],
+    async_kinds: &[],
+};
+
+const DOC_SYNTAX: DocSyntax = DocSyntax {
+    item_kinds: &[
+        "class_declaration",
+        "interface_declaration",
+        "enum_declaration",
+        "record_declaration",
+        "method_declaration",
+        "constructor_declaration",
+    ],
+    file_item_kinds: &["package_declaration"],
+    comment_kinds: &["block_comment", "line_comment"],
+    doc_prefixes: &["/**"],
+    inner_prefixes: &[],
+    skip_kinds: &[],
+    wrapper_kinds: &[],
+    docstrings: false,
+};
+
+const TEST_SYNTAX: TestSyntax = TestSyntax {
+    item_kinds: &["method_declaration"],
+    marker_kinds: &["marker_annotation", "annotation"],
+    markers: &["Test", "ParameterizedTest", "RepeatedTest", "TestFactory"],
+    name_prefixes: &["test"],
+    calls: &CALL_SYNTAX,
+};
+
+const METRICS_SYNTAX: MetricsSyntax = MetricsSyntax {
+    branch_kinds: &[
+        "if_statement",
+        "for_statement",
+        "enhanced_for_statement",
+        "while_statement",
+        "do_statement",
+        "switch_label",
+        "catch_clause",
+        "ternary_expression",
+    ],
+    logical_kinds: &["binary_expression"],
+    nesting_kinds: &[
+        "if_statement",
+        "for_statement",
+        "enhanced_for_statement",
+        "while_statement",
+        "do_statement",
+        "switch_expression",
+        "try_statement",
+        "try_with_resources_statement",
+    ],
+    return_kinds: &["return_statement"],
+};
+
 pub struct JavaParser;
 
 impl JavaParser {
@@ -52,6 +121,16 @@ impl Parser for JavaParser {
         extract_interfaces(root, content, path, &mut result);
         extract_enums(root, content, path, &mut result);
         extract_methods(root, content, path, &mut result);
+        extract_calls(root, content, path, &mut result, &CALL_SYNTAX);
+        extract_docs(root, content, &mut result, &DOC_SYNTAX);
+        extract_tests(root, content, path, &mut result, &TEST_SYNTAX);
+        extract_routes(root, content, path, &mut result, RouteDialect::Java);
+        extract_entities(root, content, path, &mut result, EntityDialect::Java);
+        extract_return_types(root, content, &mut result, "type");
+        extract_fields(root, content, &mut result);
+        extract_settings(root, content, path, &mut result, &CALL_SYNTAX);
+        extract_metrics(root, content, &mut result, &METRICS_SYNTAX);
+        fingerprint_nodes(root, content, &mut result);
 
         Ok(result)
     }
@@ -1,12 +1,73 @@
 use chrono::Utc;
 use tree_sitter::{Parser as TsParser, Query, QueryCursor, StreamingIterator};
 
-use super::{Language, ParseResult, Parser, create_file_node};
+use super::{
+    CallSyntax, DocSyntax, Language, MetricsSyntax, ParseResult, Parser, TestSyntax,
+    create_file_node, extract_calls, extract_docs, extract_fields, extract_metrics,
+    extract_settings, extract_tests, fingerprint_nodes,
+};
 use crate::types::{
     Edge, EdgeMetadata, EdgeType, EvidenceLocation, FunctionSignature, ImportType, InformationTier,
     Node, NodeMetadata, NodeStatus, NodeType, Parameter, Result, Visibility, WeaveError,
 };
 
+const CALL_SYNTAX: CallSyntax = CallSyntax {
+    scope_kinds: &[
+        "function_declaration",
+        "class_declaration",
+        "object_declaration",
+    ],
+    call_kinds: &[("call_expression", None)],
+    async_kinds: &[],
+};
+
+const DOC_SYNTAX: DocSyntax = DocSyntax {
+    item_kinds: &[
+        "class_declaration",
+        "object_declaration",
+        "function_declaration",
+    ],
+    file_item_kinds: &["package_header"],
+    comment_kinds: &["multiline_comment", "line_comment"],
+    doc_prefixes: &["/**"],
+    inner_prefixes: &[],
+    // Top-level annotations with arguments parse as a separate expression
+    skip_kinds: &["annotation", "prefix_expression"],
+    wrapper_kinds: &[],
+    docstrings: false,
+};
+
+const TEST_SYNTAX: TestSyntax = TestSyntax {
+    item_kinds: &["function_declaration"],
+    marker_kinds: &["annotation"],
+    markers: &["Test", "ParameterizedTest"],
+    name_prefixes: &[],
+    calls: &CALL_SYNTAX,
+};
+
+const METRICS_SYNTAX: MetricsSyntax = MetricsSyntax {
+    branch_kinds: &[
+        "if_expression",
+        "for_statement",
+        "while_statement",
+        "do_while_statement",
+        "when_entry",
+        "catch_block",
+        "conjunction_expression",
+        "disjunction_expression",
+    ],
+    logical_kinds: &[],
+    nesting_kinds: &[
+        "if_expression",
+        "for_statement",
+        "while_statement",
+        "do_while_statement",
+        "when_expression",
+        "try_expression",
+    ],
+    return_kinds: &["jump_expression"],
+};
+
 pub struct KotlinParser;
 
 impl KotlinParser {
@@ -52,6 +113,13 @@ impl Parser for KotlinParser {
         extract_objects(root, content, path, &mut result);
         extract_interfaces(root, content, path, &mut result);
         extract_functions(root, content, path, &mut result);
+        extract_calls(root, content, path, &mut result, &CALL_SYNTAX);
+        extract_docs(root, content, &mut result, &DOC_SYNTAX);
+        extract_tests(root, content, path, &mut result, &TEST_SYNTAX);
+        extract_fields(root, content, &mut result);
+        extract_settings(root, content, path, &mut result, &CALL_SYNTAX);
+        extract_metrics(root, content, &mut result, &METRICS_SYNTAX);
+        fingerprint_nodes(root, content, &mut result);
 
         Ok(result)
     }
@@ -78,7 +78,7 @@ impl Language {
             Language::Kotlin => {
                 lang_meta!("Kotlin", "kotlin", ["kt", "kts"], ["kotlin", "kt"], true)
             }
-            Language::Scala => lang_meta!("Scala", "scala", ["scala", "sc"], ["scala"], false),
+            Language::Scala => lang_meta!("Scala", "scala", ["scala", "sc"], ["scala"], true),
             Language::Groovy => lang_meta!(
                 "Groovy",
                 "groovy",
@@ -120,8 +120,8 @@ impl Language {
                 ["scss", "sass"],
                 false
             ),
-            Language::Vue => lang_meta!("Vue", "vue", ["vue"], ["vue"], false),
-            Language::Svelte => lang_meta!("Svelte", "svelte", ["svelte"], ["svelte"], false),
+            Language::Vue => lang_meta!("Vue", "vue", ["vue"], ["vue"], true),
+            Language::Svelte => lang_meta!("Svelte", "svelte", ["svelte"], ["svelte"], true),
 
             // Scripting Languages
             Language::Python => lang_meta!(
@@ -143,10 +143,10 @@ impl Language {
                 "php",
                 ["php", "phtml", "php3", "php4", "php5", "phps"],
                 ["php"],
-                false
+                true
             ),
             Language::Perl => lang_meta!("Perl", "perl", ["pl", "pm"], ["perl", "pl"], false),
-            Language::Lua => lang_meta!("Lua", "lua", ["lua"], ["lua"], false),
+            Language::Lua => lang_meta!("Lua", "lua", ["lua"], ["lua"], true),
             Language::R => lang_meta!("R", "r", ["r"], ["r"], false),
 
             // Shell
@@ -166,7 +166,7 @@ impl Language {
             ),
 
             // Mobile
-            Language::Swift => lang_meta!("Swift", "swift", ["swift"], ["swift"], false),
+            Language::Swift => lang_meta!("Swift", "swift", ["swift"], ["swift"], true),
             Language::ObjectiveC => lang_meta!(
                 "Objective-C",
                 "objectivec",
@@ -177,7 +177,7 @@ impl Language {
             Language::Dart => lang_meta!("Dart", "dart", ["dart"], ["dart"], false),
 
             // .NET
-            Language::CSharp => lang_meta!("C#", "csharp", ["cs"], ["csharp", "c#", "cs"], false),
+            Language::CSharp => lang_meta!("C#", "csharp", ["cs"], ["csharp", "c#", "cs"], true),
             Language::FSharp => lang_meta!(
                 "F#",
                 "fsharp",
@@ -206,7 +206,8 @@ impl Language {
             Language::Julia => lang_meta!("Julia", "julia", ["jl"], ["julia", "jl"], false),
 
             // Data/Config
-            Language::Sql => lang_meta!("SQL", "sql", ["sql"], ["sql"], false),
+            Language::Sql => lang_meta!("SQL", "sql", ["sql"], ["sql"], true),
+            Language::Prisma => lang_meta!("Prisma", "prisma", ["prisma"], ["prisma"], true),
             Language::Yaml => lang_meta!("YAML", "yaml", ["yaml", "yml"], ["yaml", "yml"], false),
             Language::Json => lang_meta!("JSON", "json", ["json", "jsonc"], ["json"], false),
             Language::Toml => lang_meta!("TOML", "toml", ["toml"], ["toml"], false),
@@ -330,6 +331,7 @@ pub enum Language {
 
     // Data/Config
     Sql,
+    Prisma,
     Yaml,
     Json,
     Toml,
@@ -456,6 +458,7 @@ impl Language {
             Language::Crystal,
             Language::Julia,
             Language::Sql,
+            Language::Prisma,
             Language::Yaml,
             Language::Json,
             Language::Toml,
@@ -614,6 +617,8 @@ mod tests {
         assert!(Language::Rust.has_parser_support());
         assert!(Language::Python.has_parser_support());
         assert!(Language::TypeScript.has_parser_support());
+        assert!(Language::Swift.has_parser_support());
+        assert!(Language::CSharp.has_parser_support());
         assert!(!Language::Elixir.has_parser_support());
         assert!(!Language::Unknown.has_parser_support());
     }
@@ -645,6 +650,7 @@ mod tests {
         assert_eq!(Language::from_extension("json"), Language::Json);
         assert_eq!(Language::from_extension("toml"), Language::Toml);
         assert_eq!(Language::from_extension("md"), Language::Markdown);
+        assert_eq!(Language::from_extension("prisma"), Language::Prisma);
     }
 
     #[test]
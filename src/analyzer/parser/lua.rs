@@ -0,0 +1,427 @@
+use std::collections::{BTreeMap, HashMap};
+
+use tree_sitter::{Query, QueryCursor, StreamingIterator};
+
+use super::{
+    CallSyntax, DocSyntax, Language, MetricsSyntax, ParseResult, Parser, TestSyntax,
+    create_code_edge, create_code_node, create_file_node, create_ts_parser, extract_calls,
+    extract_docs, extract_metrics, extract_settings, extract_tests, fingerprint_nodes,
+    get_node_text,
+};
+use crate::types::{
+    EdgeType, FunctionSignature, ImportType, NodeMetadata, NodeType, Parameter, Result, Visibility,
+    WeaveError,
+};
+
+const CALL_SYNTAX: CallSyntax = CallSyntax {
+    scope_kinds: &["function_declaration"],
+    call_kinds: &[("function_call", Some("name"))],
+    async_kinds: &[],
+};
+
+const DOC_SYNTAX: DocSyntax = DocSyntax {
+    item_kinds: &[
+        "function_declaration",
+        "variable_declaration",
+        "assignment_statement",
+    ],
+    file_item_kinds: &[],
+    comment_kinds: &["comment"],
+    // LDoc blocks open with `---` and continue with `--`
+    doc_prefixes: &["--"],
+    inner_prefixes: &[],
+    skip_kinds: &[],
+    wrapper_kinds: &[],
+    docstrings: false,
+};
+
+// Tests here are found by file name only
+const TEST_SYNTAX: TestSyntax = TestSyntax {
+    item_kinds: &[],
+    marker_kinds: &[],
+    markers: &[],
+    name_prefixes: &[],
+    calls: &CALL_SYNTAX,
+};
+
+const METRICS_SYNTAX: MetricsSyntax = MetricsSyntax {
+    branch_kinds: &[
+        "if_statement",
+        "elseif_statement",
+        "for_statement",
+        "while_statement",
+        "repeat_statement",
+    ],
+    logical_kinds: &["binary_expression"],
+    nesting_kinds: &[
+        "if_statement",
+        "for_statement",
+        "while_statement",
+        "repeat_statement",
+    ],
+    return_kinds: &["return_statement"],
+};
+
+pub struct LuaParser;
+
+impl LuaParser {
+    pub fn new() -> Result<Self> {
+        let _ = create_ts_parser(tree_sitter_lua::LANGUAGE, "Lua")?;
+        Ok(Self)
+    }
+}
+
+impl Parser for LuaParser {
+    fn parse(&self, path: &str, content: &str) -> Result<ParseResult> {
+        let mut parser = create_ts_parser(tree_sitter_lua::LANGUAGE, "Lua").map_err(|mut e| {
+            if let WeaveError::Parse {
+                path: ref mut p, ..
+            } = e
+            {
+                *p = path.to_string();
+            }
+            e
+        })?;
+
+        let tree = parser
+            .parse(content, None)
+            .ok_or_else(|| WeaveError::Parse {
+                message: "Failed to parse Lua file".to_string(),
+                path: path.to_string(),
+            })?;
+
+        let mut result = ParseResult::new();
+        let root = tree.root_node();
+
+        result.nodes.push(create_file_node(path));
+
+        extract_requires(root, content, path, &mut result);
+        extract_functions(root, content, path, &mut result);
+        extract_calls(root, content, path, &mut result, &CALL_SYNTAX);
+        extract_docs(root, content, &mut result, &DOC_SYNTAX);
+        extract_tests(root, content, path, &mut result, &TEST_SYNTAX);
+        extract_settings(root, content, path, &mut result, &CALL_SYNTAX);
+        extract_metrics(root, content, &mut result, &METRICS_SYNTAX);
+        fingerprint_nodes(root, content, &mut result);
+
+        Ok(result)
+    }
+
+    fn language(&self) -> Language {
+        Language::Lua
+    }
+}
+
+/// `require("a.b")` and `require "a.b"` with a literal module name.
+fn extract_requires(root: tree_sitter::Node, content: &str, path: &str, result: &mut ParseResult) {
+    let query_str = r#"
+        (function_call
+            name: (identifier) @fn
+            arguments: (arguments (string content: (string_content) @module))
+            (#eq? @fn "require")
+        )
+    "#;
+
+    if let Ok(query) = Query::new(&tree_sitter_lua::LANGUAGE.into(), query_str) {
+        let Some(module_idx) = query.capture_index_for_name("module") else {
+            return;
+        };
+        let mut cursor = QueryCursor::new();
+        let mut matches = cursor.matches(&query, root, content.as_bytes());
+
+        while let Some(m) = matches.next() {
+            for cap in m.captures.iter().filter(|c| c.index == module_idx) {
+                let node = cap.node;
+                let module = get_node_text(node, content.as_bytes());
+
+                let mut edge = create_code_edge(
+                    format!("import:{}:{}", path, module),
+                    EdgeType::DependsOn,
+                    format!("file:{}", path),
+                    format!("module:{}", module),
+                    node,
+                    path,
+                );
+                edge.metadata.import_type = Some(ImportType::Static);
+                result.edges.push(edge);
+            }
+        }
+    }
+}
+
+/// Functions, plus `T.f`/`T:f` definitions as methods of the table `T`.
+///
+/// Lua has no class syntax, so a table gets a class node only when it is
+/// created in this file (`local T = {}`) and has at least one function
+/// defined on it.
+fn extract_functions(root: tree_sitter::Node, content: &str, path: &str, result: &mut ParseResult) {
+    let tables = local_tables(root, content);
+    let mut owners: BTreeMap<String, tree_sitter::Node> = BTreeMap::new();
+
+    let query_str = r#"
+        (function_declaration) @func
+    "#;
+
+    if let Ok(query) = Query::new(&tree_sitter_lua::LANGUAGE.into(), query_str) {
+        let mut cursor = QueryCursor::new();
+        let mut matches = cursor.matches(&query, root, content.as_bytes());
+
+        while let Some(m) = matches.next() {
+            for cap in m.captures.iter() {
+                let func = cap.node;
+                let bytes = content.as_bytes();
+                let Some(name_node) = func.child_by_field_name("name") else {
+                    continue;
+                };
+                if is_nested(func) {
+                    continue;
+                }
+
+                let (owner, name) = match name_node.kind() {
+                    "dot_index_expression" => (
+                        name_node.child_by_field_name("table"),
+                        name_node.child_by_field_name("field"),
+                    ),
+                    "method_index_expression" => (
+                        name_node.child_by_field_name("table"),
+                        name_node.child_by_field_name("method"),
+                    ),
+                    _ => (None, Some(name_node)),
+                };
+                let Some(name) = name.map(|n| get_node_text(n, bytes).to_string()) else {
+                    continue;
+                };
+                let owner = owner.map(|o| get_node_text(o, bytes).to_string());
+
+                let (id, node_type, visibility) = match owner {
+                    Some(ref table) => (
+                        format!("method:{}:{}:{}", path, table, name),
+                        NodeType::Method,
+                        Visibility::Public,
+                    ),
+                    None if is_local(func) => (
+                        format!("function:{}:{}", path, name),
+                        NodeType::Function,
+                        Visibility::Private,
+                    ),
+                    None => (
+                        format!("function:{}:{}", path, name),
+                        NodeType::Function,
+                        Visibility::Public,
+                    ),
+                };
+
+                let mut func_node =
+                    create_code_node(id.clone(), node_type, path, name.clone(), func);
+                func_node.metadata = NodeMetadata {
+                    visibility: Some(visibility),
+                    signature: Some(FunctionSignature {
+                        parameters: lua_parameters(func, content),
+                        return_type: None,
+                        is_async: false,
+                        generator: false,
+                    }),
+                    ..Default::default()
+                };
+                result.nodes.push(func_node);
+
+                if let Some(table) = owner
+                    && let Some(decl) = tables.get(&table)
+                {
+                    owners.entry(table.clone()).or_insert(*decl);
+                    result.edges.push(create_code_edge(
+                        format!("member:{}:{}:{}", path, table, name),
+                        EdgeType::Owns,
+                        format!("class:{}:{}", path, table),
+                        id,
+                        func,
+                        path,
+                    ));
+                }
+            }
+        }
+    }
+
+    for (table, decl) in owners {
+        let mut class_node = create_code_node(
+            format!("class:{}:{}", path, table),
+            NodeType::Class,
+            path,
+            table,
+            decl,
+        );
+        class_node.metadata.visibility = Some(if is_local(decl) {
+            Visibility::Private
+        } else {
+            Visibility::Public
+        });
+        result.nodes.push(class_node);
+    }
+}
+
+/// Tables created with a constructor at the top level: `T = {}` or `local T = {}`.
+fn local_tables<'a>(
+    root: tree_sitter::Node<'a>,
+    content: &str,
+) -> HashMap<String, tree_sitter::Node<'a>> {
+    let mut tables = HashMap::new();
+    let mut cursor = root.walk();
+    for stmt in root.named_children(&mut cursor) {
+        let assignment = match stmt.kind() {
+            "assignment_statement" => stmt,
+            "variable_declaration" => match stmt.named_child(0) {
+                Some(inner) if inner.kind() == "assignment_statement" => inner,
+                _ => continue,
+            },
+            _ => continue,
+        };
+        let name = assignment
+            .named_child(0)
+            .filter(|l| l.kind() == "variable_list")
+            .and_then(|l| l.child_by_field_name("name"))
+            .filter(|n| n.kind() == "identifier");
+        let value = assignment
+            .named_child(1)
+            .filter(|l| l.kind() == "expression_list")
+            .and_then(|l| l.child_by_field_name("value"));
+        if let (Some(name), Some(value)) = (name, value)
+            && value.kind() == "table_constructor"
+        {
+            tables.insert(get_node_text(name, content.as_bytes()).to_string(), stmt);
+        }
+    }
+    tables
+}
+
+fn is_local(node: tree_sitter::Node) -> bool {
+    if node.kind() == "variable_declaration" {
+        return true;
+    }
+    let mut cursor = node.walk();
+    node.children(&mut cursor).any(|c| c.kind() == "local")
+}
+
+/// Functions declared inside another function's body are not part of the module surface.
+fn is_nested(func: tree_sitter::Node) -> bool {
+    let mut current = func.parent();
+    while let Some(parent) = current {
+        if matches!(
+            parent.kind(),
+            "function_declaration" | "function_definition"
+        ) {
+            return true;
+        }
+        current = parent.parent();
+    }
+    false
+}
+
+fn lua_parameters(func: tree_sitter::Node, content: &str) -> Vec<Parameter> {
+    let Some(params) = func.child_by_field_name("parameters") else {
+        return Vec::new();
+    };
+
+    let mut cursor = params.walk();
+    params
+        .named_children(&mut cursor)
+        .filter(|p| matches!(p.kind(), "identifier" | "vararg_expression"))
+        .map(|p| Parameter {
+            name: get_node_text(p, content.as_bytes()).to_string(),
+            param_type: None,
+            optional: p.kind() == "vararg_expression",
+            default_value: None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lua_modules_and_tables() {
+        let code = r#"
+local json = require("json")
+local util = require "lib.util"
+
+local Account = {}
+
+--- Create an account.
+-- @param balance number
+function Account.new(balance)
+  return setmetatable({}, Account)
+end
+
+function Account:deposit(v)
+  util.log(v)
+end
+
+local function helper(...) end
+function globalFn(a, b) helper() end
+return Account
+"#;
+        let result = LuaParser::new()
+            .unwrap()
+            .parse("account.lua", code)
+            .unwrap();
+
+        let modules: Vec<&str> = result
+            .edges
+            .iter()
+            .filter(|e| e.id.starts_with("import:"))
+            .map(|e| e.target_id.as_str())
+            .collect();
+        assert_eq!(modules, vec!["module:json", "module:lib.util"]);
+
+        let account = result
+            .nodes
+            .iter()
+            .find(|n| n.id == "class:account.lua:Account")
+            .unwrap();
+        assert_eq!(account.metadata.visibility, Some(Visibility::Private));
+
+        let new = result
+            .nodes
+            .iter()
+            .find(|n| n.id == "method:account.lua:Account:new")
+            .unwrap();
+        assert_eq!(
+            new.metadata.description.as_deref(),
+            Some("Create an account.\n@param balance number")
+        );
+        assert!(result.edges.iter().any(|e| e.edge_type == EdgeType::Owns
+            && e.target_id == "method:account.lua:Account:deposit"));
+
+        let helper = result
+            .nodes
+            .iter()
+            .find(|n| n.id == "function:account.lua:helper")
+            .unwrap();
+        assert_eq!(helper.metadata.visibility, Some(Visibility::Private));
+        assert!(helper.metadata.signature.as_ref().unwrap().parameters[0].optional);
+
+        let global = result
+            .nodes
+            .iter()
+            .find(|n| n.id == "function:account.lua:globalFn")
+            .unwrap();
+        assert_eq!(global.metadata.visibility, Some(Visibility::Public));
+    }
+
+    #[test]
+    fn test_calls_from_table_functions() {
+        let code = "local M = {}\n\nlocal function helper() end\n\n\
+                    function M.greet(name)\n  helper()\nend\n\n\
+                    function M:run()\n  helper()\nend\n\nreturn M\n";
+        let result = LuaParser::new().unwrap().parse("m.lua", code).unwrap();
+
+        let mut callers: Vec<&str> = result
+            .edges
+            .iter()
+            .filter(|e| e.edge_type == EdgeType::Calls)
+            .map(|e| e.source_id.as_str())
+            .collect();
+        callers.sort();
+        assert_eq!(callers, vec!["method:m.lua:M:greet", "method:m.lua:M:run"]);
+    }
+}
@@ -0,0 +1,283 @@
+//! Function Metrics
+//!
+//! Language-agnostic complexity walker shared by all tree-sitter parsers.
+//! Each parser describes its grammar with a [`MetricsSyntax`] table; every
+//! function and method node the parser emitted gets a [`FunctionMetrics`]
+//! computed from its declaration and stored under `extra.metrics`:
+//!
+//! - `cyclomatic`: 1 plus one per branch, loop, case arm, catch clause,
+//!   conditional expression and short-circuit `&&`/`||`;
+//! - `max_nesting`: deepest nesting of branches and loops, where an
+//!   `else if` continues its chain rather than nesting;
+//! - `parameters`, `lines` and `early_returns` (returns followed by more
+//!   code of the function).
+//!
+//! Nested declarations of the same kind are measured on their own and do
+//! not add to the enclosing function; closures and lambdas do.
+
+use serde::{Deserialize, Serialize};
+
+use super::fingerprint::declaration;
+use super::traits::{ParseResult, get_node_text};
+use crate::types::{Node, NodeType};
+
+/// Metadata key holding a function's metrics.
+pub const METRICS_KEY: &str = "metrics";
+
+/// Operators that make a binary expression a decision point.
+const LOGICAL_OPERATORS: &[&str] = &["&&", "||", "and", "or", "??"];
+
+/// Grammar description for metrics extraction.
+pub struct MetricsSyntax {
+    /// Node kinds adding a decision point (branches, loops, case arms,
+    /// catch clauses, conditional expressions).
+    pub branch_kinds: &'static [&'static str],
+    /// Binary expression kinds, counted when their operator is logical.
+    pub logical_kinds: &'static [&'static str],
+    /// Node kinds whose bodies sit one nesting level deeper.
+    pub nesting_kinds: &'static [&'static str],
+    /// Node kinds that return from the function when their text starts with
+    /// `return` (covers generic jump nodes such as Kotlin's `jump_expression`).
+    pub return_kinds: &'static [&'static str],
+}
+
+/// Complexity of one function, from its syntax tree.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionMetrics {
+    pub cyclomatic: u32,
+    pub max_nesting: u32,
+    pub parameters: u32,
+    pub lines: u32,
+    pub early_returns: u32,
+}
+
+impl FunctionMetrics {
+    /// Metrics stored on a node, if it has them.
+    pub fn of(node: &Node) -> Option<Self> {
+        let value = node.metadata.extra.get(METRICS_KEY)?;
+        serde_json::from_value(value.clone()).ok()
+    }
+}
+
+/// Compute the metrics of every function and method of `result`.
+///
+/// Must run after the parser has pushed its declaration nodes.
+pub fn extract_metrics(
+    root: tree_sitter::Node,
+    content: &str,
+    result: &mut ParseResult,
+    syntax: &MetricsSyntax,
+) {
+    let bytes = content.as_bytes();
+    for node in &mut result.nodes {
+        if !matches!(node.node_type, NodeType::Function | NodeType::Method) {
+            continue;
+        }
+        let Some(decl) = declaration(root, node, bytes).and_then(with_body) else {
+            continue;
+        };
+
+        let mut metrics = FunctionMetrics {
+            cyclomatic: 1,
+            lines: (decl.end_position().row - decl.start_position().row + 1) as u32,
+            parameters: node
+                .metadata
+                .signature
+                .as_ref()
+                .map_or(0, |s| s.parameters.len() as u32),
+            ..Default::default()
+        };
+        let tail_start = statements(decl)
+            .named_child(statements(decl).named_child_count().saturating_sub(1))
+            .map_or(decl.end_byte(), |last| last.start_byte());
+        walk(
+            decl,
+            decl.kind(),
+            0,
+            bytes,
+            syntax,
+            tail_start,
+            &mut metrics,
+        );
+
+        if let Ok(value) = serde_json::to_value(metrics) {
+            node.metadata.extra.insert(METRICS_KEY.to_string(), value);
+        }
+    }
+}
+
+/// The declaration itself or the nearest enclosing node with a body: C and
+/// C++ name a function inside its declarator.
+fn with_body(decl: tree_sitter::Node) -> Option<tree_sitter::Node> {
+    let mut current = Some(decl);
+    while let Some(node) = current {
+        if body(node).is_some() {
+            return Some(node);
+        }
+        current = node.parent();
+    }
+    None
+}
+
+/// The node listing the statements of a function body, past wrappers such
+/// as Kotlin's `function_body` and Swift's `statements`.
+fn statements(decl: tree_sitter::Node) -> tree_sitter::Node {
+    let mut body = body(decl).unwrap_or(decl);
+    while body.named_child_count() == 1
+        && let Some(inner) = body.named_child(0)
+        && matches!(
+            inner.kind(),
+            "block" | "statements" | "statement_list" | "function_body" | "body_statement"
+        )
+    {
+        body = inner;
+    }
+    body
+}
+
+fn body(node: tree_sitter::Node) -> Option<tree_sitter::Node> {
+    node.child_by_field_name("body").or_else(|| {
+        (0..node.named_child_count())
+            .filter_map(|i| node.named_child(i))
+            .find(|c| c.kind() == "function_body")
+    })
+}
+
+fn walk(
+    node: tree_sitter::Node,
+    function_kind: &str,
+    depth: u32,
+    content: &[u8],
+    syntax: &MetricsSyntax,
+    tail_start: usize,
+    metrics: &mut FunctionMetrics,
+) {
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        let kind = child.kind();
+        if kind == function_kind {
+            continue;
+        }
+        if syntax.branch_kinds.contains(&kind)
+            || (syntax.logical_kinds.contains(&kind) && is_logical(child, content))
+        {
+            metrics.cyclomatic += 1;
+        }
+        if syntax.return_kinds.contains(&kind)
+            && get_node_text(child, content).starts_with("return")
+            && child.end_byte() <= tail_start
+        {
+            metrics.early_returns += 1;
+        }
+
+        let child_depth = if syntax.nesting_kinds.contains(&kind) && !continues_else(child) {
+            depth + 1
+        } else {
+            depth
+        };
+        metrics.max_nesting = metrics.max_nesting.max(child_depth);
+        walk(
+            child,
+            function_kind,
+            child_depth,
+            content,
+            syntax,
+            tail_start,
+            metrics,
+        );
+    }
+}
+
+fn is_logical(node: tree_sitter::Node, content: &[u8]) -> bool {
+    if let Some(operator) = node.child_by_field_name("operator") {
+        return LOGICAL_OPERATORS.contains(&get_node_text(operator, content));
+    }
+    let mut cursor = node.walk();
+    node.children(&mut cursor)
+        .any(|c| !c.is_named() && LOGICAL_OPERATORS.contains(&c.kind()))
+}
+
+/// Whether `node` is the `if` of an `else if`, which continues the chain of
+/// its parent rather than nesting inside it.
+fn continues_else(node: tree_sitter::Node) -> bool {
+    let is_else = |n: tree_sitter::Node| matches!(n.kind(), "else" | "else_clause");
+    if node.prev_sibling().is_some_and(is_else) {
+        return true;
+    }
+    node.parent().is_some_and(|parent| {
+        is_else(parent)
+            || (parent.named_child_count() == 1 && parent.prev_sibling().is_some_and(is_else))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::parser::{Language, create_parser};
+
+    fn metrics(path: &str, content: &str, name: &str) -> FunctionMetrics {
+        let result = create_parser(Language::from_path(path))
+            .unwrap()
+            .parse(path, content)
+            .unwrap();
+        let node = result.nodes.iter().find(|n| n.name == name).unwrap();
+        FunctionMetrics::of(node).unwrap()
+    }
+
+    #[test]
+    fn test_rust_metrics() {
+        let content = "\
+fn classify(n: i32, strict: bool) -> &'static str {
+    if n < 0 && strict {
+        return \"negative\";
+    } else if n == 0 {
+        return \"zero\";
+    }
+    for i in 0..n {
+        if i % 2 == 0 {
+            match i {
+                2 => {}
+                _ => {}
+            }
+        }
+    }
+    \"positive\"
+}
+";
+        assert_eq!(
+            metrics("src/a.rs", content, "classify"),
+            FunctionMetrics {
+                cyclomatic: 8,
+                max_nesting: 3,
+                parameters: 2,
+                lines: 16,
+                early_returns: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn test_metrics_across_grammars() {
+        let python = metrics(
+            "app/a.py",
+            "def pick(xs):\n    for x in xs:\n        if x or not xs:\n            return x\n    return None\n",
+            "pick",
+        );
+        assert_eq!((python.cyclomatic, python.max_nesting), (4, 2));
+        assert_eq!(python.early_returns, 1);
+
+        let go = metrics(
+            "pkg/a.go",
+            "package pkg\n\nfunc Sign(n int) int {\n\tswitch {\n\tcase n < 0:\n\t\treturn -1\n\tcase n > 0:\n\t\treturn 1\n\t}\n\treturn 0\n}\n",
+            "Sign",
+        );
+        assert_eq!((go.cyclomatic, go.max_nesting, go.early_returns), (3, 1, 2));
+
+        let c = metrics(
+            "src/a.c",
+            "int max(int a, int b) {\n    return a > b ? a : b;\n}\n",
+            "max",
+        );
+        assert_eq!((c.cyclomatic, c.early_returns, c.lines), (2, 0, 3));
+    }
+}
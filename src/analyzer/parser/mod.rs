@@ -15,31 +15,69 @@
 
 pub mod bash;
 pub mod c;
+pub mod calls;
+pub mod config;
 pub mod cpp;
+pub mod csharp;
+pub mod docs;
+pub mod entities;
+pub mod fields;
+pub mod fingerprint;
 pub mod go;
 pub mod java;
 pub mod kotlin;
 pub mod language;
+pub mod lua;
+pub mod metrics;
+pub mod php;
 pub mod python;
+pub mod react;
+pub mod returns;
+pub mod routes;
 pub mod ruby;
 pub mod rust_lang;
+pub mod scala;
+pub mod schema;
+pub mod settings;
+pub mod sfc;
+pub mod swift;
+pub mod testing;
 pub mod traits;
 pub mod typescript;
 
 pub use bash::BashParser;
 pub use c::CLangParser;
+pub use calls::{CallSyntax, extract_calls};
+pub use config::{ConfigParser, config_kind};
 pub use cpp::CppLangParser;
+pub use csharp::CSharpParser;
+pub use docs::{DocSyntax, extract_docs};
+pub use entities::{EntityDialect, extract_entities};
+pub use fields::extract_fields;
+pub use fingerprint::{Fingerprint, fingerprint_nodes};
 pub use go::GoParser;
 pub use java::JavaParser;
 pub use kotlin::KotlinParser;
 pub use language::{Language, detect_language, detect_language_or_text};
+pub use lua::LuaParser;
+pub use metrics::{FunctionMetrics, MetricsSyntax, extract_metrics};
+pub use php::PhpParser;
 pub use python::PythonParser;
+pub use react::extract_components;
+pub use returns::extract_return_types;
+pub use routes::{RouteDialect, extract_routes};
 pub use ruby::RubyParser;
 pub use rust_lang::RustParser;
+pub use scala::ScalaParser;
+pub use schema::SchemaParser;
+pub use settings::extract_settings;
+pub use sfc::SfcParser;
+pub use swift::SwiftParser;
+pub use testing::{TestSyntax, extract_tests, is_test_path};
 pub use traits::{
-    ParseResult, Parser, QueryMatch, create_code_edge, create_code_node, create_dependency_edge,
-    create_file_node, create_ts_parser, evidence_from_node, execute_query, get_node_position,
-    get_node_text, query_captures,
+    ParseResult, Parser, QueryMatch, UnresolvedCall, create_code_edge, create_code_node,
+    create_dependency_edge, create_file_node, create_ts_parser, evidence_from_node, execute_query,
+    get_node_position, get_node_text, query_captures,
 };
 pub use typescript::TypeScriptParser;
 
@@ -59,8 +97,11 @@ pub type SharedParser = Arc<dyn Parser>;
 /// - Rust, Go, C, C++
 /// - Python, Ruby
 /// - TypeScript, JavaScript, TSX, JSX
-/// - Java, Kotlin
+/// - Vue and Svelte single-file components
+/// - Java, Kotlin, Scala
+/// - Swift, C#, PHP, Lua
 /// - Bash
+/// - SQL and Prisma database schemas
 ///
 /// # Example
 ///
@@ -77,11 +118,18 @@ pub fn create_parser(language: Language) -> Result<Box<dyn Parser>> {
         Language::Python => Ok(Box::new(PythonParser::new()?)),
         Language::Ruby => Ok(Box::new(RubyParser::new()?)),
         Language::TypeScript | Language::JavaScript | Language::Tsx | Language::Jsx => {
-            Ok(Box::new(TypeScriptParser::new()?))
+            Ok(Box::new(TypeScriptParser::for_language(language)?))
         }
         Language::Java => Ok(Box::new(JavaParser::new()?)),
         Language::Kotlin => Ok(Box::new(KotlinParser::new()?)),
         Language::Bash => Ok(Box::new(BashParser::new()?)),
+        Language::Swift => Ok(Box::new(SwiftParser::new()?)),
+        Language::Php => Ok(Box::new(PhpParser::new()?)),
+        Language::CSharp => Ok(Box::new(CSharpParser::new()?)),
+        Language::Scala => Ok(Box::new(ScalaParser::new()?)),
+        Language::Lua => Ok(Box::new(LuaParser::new()?)),
+        Language::Vue | Language::Svelte => Ok(Box::new(SfcParser::new(language)?)),
+        Language::Sql | Language::Prisma => Ok(Box::new(SchemaParser::new(language)?)),
         _ => Err(WeaveError::Config(format!(
             "No parser support for language: {}",
             language
@@ -89,6 +137,12 @@ pub fn create_parser(language: Language) -> Result<Box<dyn Parser>> {
     }
 }
 
+/// Whether `build` parses the file at `path`: source in a language with a
+/// parser, or a recognized configuration file.
+pub fn is_parsed(path: &str) -> bool {
+    Language::from_path(path).has_parser_support() || config_kind(path).is_some()
+}
+
 /// Create a shared parser for concurrent access.
 ///
 /// Wraps the parser in an Arc for thread-safe sharing.
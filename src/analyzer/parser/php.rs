@@ -0,0 +1,584 @@
+use std::path::Path;
+
+use tree_sitter::{Query, QueryCursor, StreamingIterator};
+
+use super::{
+    CallSyntax, DocSyntax, Language, MetricsSyntax, ParseResult, Parser, TestSyntax,
+    create_code_edge, create_code_node, create_file_node, create_ts_parser, extract_calls,
+    extract_docs, extract_metrics, extract_settings, extract_tests, fingerprint_nodes,
+    get_node_text,
+};
+use crate::types::{
+    EdgeType, FunctionSignature, ImportType, NodeMetadata, NodeType, Parameter, Result, Visibility,
+    WeaveError,
+};
+
+const CALL_SYNTAX: CallSyntax = CallSyntax {
+    scope_kinds: &[
+        "function_definition",
+        "method_declaration",
+        "class_declaration",
+    ],
+    call_kinds: &[
+        ("function_call_expression", Some("function")),
+        ("member_call_expression", Some("name")),
+        ("nullsafe_member_call_expression", Some("name")),
+        ("scoped_call_expression", Some("name")),
+        ("object_creation_expression", None),
+    ],
+    async_kinds: &[],
+};
+
+const DOC_SYNTAX: DocSyntax = DocSyntax {
+    item_kinds: &[
+        "class_declaration",
+        "interface_declaration",
+        "trait_declaration",
+        "enum_declaration",
+        "function_definition",
+        "method_declaration",
+    ],
+    file_item_kinds: &["namespace_definition"],
+    comment_kinds: &["comment"],
+    doc_prefixes: &["/**"],
+    inner_prefixes: &[],
+    skip_kinds: &["attribute_list"],
+    wrapper_kinds: &[],
+    docstrings: false,
+};
+
+const TEST_SYNTAX: TestSyntax = TestSyntax {
+    item_kinds: &["method_declaration", "function_definition"],
+    marker_kinds: &["attribute"],
+    markers: &["Test"],
+    name_prefixes: &["test"],
+    calls: &CALL_SYNTAX,
+};
+
+const METRICS_SYNTAX: MetricsSyntax = MetricsSyntax {
+    branch_kinds: &[
+        "if_statement",
+        "else_if_clause",
+        "for_statement",
+        "foreach_statement",
+        "while_statement",
+        "do_statement",
+        "case_statement",
+        "catch_clause",
+        "conditional_expression",
+        "match_conditional_expression",
+    ],
+    logical_kinds: &["binary_expression"],
+    nesting_kinds: &[
+        "if_statement",
+        "for_statement",
+        "foreach_statement",
+        "while_statement",
+        "do_statement",
+        "switch_statement",
+        "try_statement",
+        "match_expression",
+    ],
+    return_kinds: &["return_statement"],
+};
+
+/// Declarations that own methods, with the ID prefix and node type they map to.
+const TYPE_KINDS: &[(&str, &str, NodeType)] = &[
+    ("class_declaration", "class", NodeType::Class),
+    ("interface_declaration", "interface", NodeType::Interface),
+    ("trait_declaration", "interface", NodeType::Interface),
+    ("enum_declaration", "enum", NodeType::Enum),
+];
+
+pub struct PhpParser;
+
+impl PhpParser {
+    pub fn new() -> Result<Self> {
+        let _ = create_ts_parser(tree_sitter_php::LANGUAGE_PHP, "PHP")?;
+        Ok(Self)
+    }
+}
+
+impl Parser for PhpParser {
+    fn parse(&self, path: &str, content: &str) -> Result<ParseResult> {
+        let mut parser =
+            create_ts_parser(tree_sitter_php::LANGUAGE_PHP, "PHP").map_err(|mut e| {
+                if let WeaveError::Parse {
+                    path: ref mut p, ..
+                } = e
+                {
+                    *p = path.to_string();
+                }
+                e
+            })?;
+
+        let tree = parser
+            .parse(content, None)
+            .ok_or_else(|| WeaveError::Parse {
+                message: "Failed to parse PHP file".to_string(),
+                path: path.to_string(),
+            })?;
+
+        let mut result = ParseResult::new();
+        let root = tree.root_node();
+
+        result.nodes.push(create_file_node(path));
+
+        extract_namespace(root, content, path, &mut result);
+        extract_uses(root, content, path, &mut result);
+        extract_requires(root, content, path, &mut result);
+        extract_types(root, content, path, &mut result);
+        extract_functions(root, content, path, &mut result);
+        extract_calls(root, content, path, &mut result, &CALL_SYNTAX);
+        extract_docs(root, content, &mut result, &DOC_SYNTAX);
+        extract_tests(root, content, path, &mut result, &TEST_SYNTAX);
+        extract_settings(root, content, path, &mut result, &CALL_SYNTAX);
+        extract_metrics(root, content, &mut result, &METRICS_SYNTAX);
+        fingerprint_nodes(root, content, &mut result);
+
+        Ok(result)
+    }
+
+    fn language(&self) -> Language {
+        Language::Php
+    }
+}
+
+fn extract_namespace(root: tree_sitter::Node, content: &str, path: &str, result: &mut ParseResult) {
+    let query_str = r#"
+        (namespace_definition
+            name: (namespace_name) @namespace
+        )
+    "#;
+
+    if let Ok(query) = Query::new(&tree_sitter_php::LANGUAGE_PHP.into(), query_str) {
+        let mut cursor = QueryCursor::new();
+        let mut matches = cursor.matches(&query, root, content.as_bytes());
+
+        while let Some(m) = matches.next() {
+            for cap in m.captures.iter() {
+                let node = cap.node;
+                let name = get_node_text(node, content.as_bytes()).to_string();
+                result.nodes.push(create_code_node(
+                    format!("namespace:{}:{}", path, name),
+                    NodeType::Module,
+                    path,
+                    name,
+                    node,
+                ));
+            }
+        }
+    }
+}
+
+/// `use A\B\C;`, `use A\B\{C, D as E};` and `use function A\f;`
+fn extract_uses(root: tree_sitter::Node, content: &str, path: &str, result: &mut ParseResult) {
+    let query_str = r#"
+        (namespace_use_declaration) @use
+    "#;
+
+    if let Ok(query) = Query::new(&tree_sitter_php::LANGUAGE_PHP.into(), query_str) {
+        let mut cursor = QueryCursor::new();
+        let mut matches = cursor.matches(&query, root, content.as_bytes());
+
+        while let Some(m) = matches.next() {
+            for cap in m.captures.iter() {
+                let decl = cap.node;
+                let bytes = content.as_bytes();
+
+                let mut cursor = decl.walk();
+                let prefix = decl
+                    .named_children(&mut cursor)
+                    .find(|c| c.kind() == "namespace_name")
+                    .map(|n| get_node_text(n, bytes).to_string());
+                let clauses = match decl.child_by_field_name("body") {
+                    Some(group) => {
+                        let mut inner = group.walk();
+                        group.named_children(&mut inner).collect::<Vec<_>>()
+                    }
+                    None => {
+                        let mut inner = decl.walk();
+                        decl.named_children(&mut inner).collect::<Vec<_>>()
+                    }
+                };
+
+                for clause in clauses
+                    .into_iter()
+                    .filter(|c| c.kind() == "namespace_use_clause")
+                {
+                    let Some(target) = clause.named_child(0) else {
+                        continue;
+                    };
+                    let name = get_node_text(target, bytes).trim_start_matches('\\');
+                    let full = match prefix {
+                        Some(ref p) => format!("{}\\{}", p, name),
+                        None => name.to_string(),
+                    };
+                    let imported = full.rsplit('\\').next().unwrap_or(&full).to_string();
+
+                    let mut edge = create_code_edge(
+                        format!("import:{}:{}", path, full),
+                        EdgeType::DependsOn,
+                        format!("file:{}", path),
+                        format!("module:{}", full),
+                        clause,
+                        path,
+                    );
+                    edge.metadata.import_type = Some(ImportType::Static);
+                    edge.metadata.imported_symbols = Some(vec![imported]);
+                    edge.metadata.exposed_as = clause
+                        .child_by_field_name("alias")
+                        .map(|a| get_node_text(a, bytes).to_string());
+                    result.edges.push(edge);
+                }
+            }
+        }
+    }
+}
+
+/// `require`/`include` of a literal path, resolved relative to the file.
+fn extract_requires(root: tree_sitter::Node, content: &str, path: &str, result: &mut ParseResult) {
+    let query_str = r#"
+        [
+            (require_expression (string (string_content) @path))
+            (require_once_expression (string (string_content) @path))
+            (include_expression (string (string_content) @path))
+            (include_once_expression (string (string_content) @path))
+        ]
+    "#;
+
+    if let Ok(query) = Query::new(&tree_sitter_php::LANGUAGE_PHP.into(), query_str) {
+        let mut cursor = QueryCursor::new();
+        let mut matches = cursor.matches(&query, root, content.as_bytes());
+
+        while let Some(m) = matches.next() {
+            for cap in m.captures.iter() {
+                let node = cap.node;
+                let required = get_node_text(node, content.as_bytes());
+                let target = Path::new(path)
+                    .parent()
+                    .unwrap_or(Path::new(""))
+                    .join(required.trim_start_matches("./"));
+
+                let mut edge = create_code_edge(
+                    format!("require:{}:{}", path, required),
+                    EdgeType::DependsOn,
+                    format!("file:{}", path),
+                    format!("file:{}", target.to_string_lossy()),
+                    node,
+                    path,
+                );
+                edge.metadata.import_type = Some(ImportType::Static);
+                result.edges.push(edge);
+            }
+        }
+    }
+}
+
+fn extract_types(root: tree_sitter::Node, content: &str, path: &str, result: &mut ParseResult) {
+    let query_str = r#"
+        [
+            (class_declaration)
+            (interface_declaration)
+            (trait_declaration)
+            (enum_declaration)
+        ] @decl
+    "#;
+
+    if let Ok(query) = Query::new(&tree_sitter_php::LANGUAGE_PHP.into(), query_str) {
+        let mut cursor = QueryCursor::new();
+        let mut matches = cursor.matches(&query, root, content.as_bytes());
+
+        while let Some(m) = matches.next() {
+            for cap in m.captures.iter() {
+                let decl = cap.node;
+                let bytes = content.as_bytes();
+                let Some((_, prefix, node_type)) =
+                    TYPE_KINDS.iter().find(|(k, _, _)| *k == decl.kind())
+                else {
+                    continue;
+                };
+                let Some(name_node) = decl.child_by_field_name("name") else {
+                    continue;
+                };
+                let name = get_node_text(name_node, bytes).to_string();
+                let id = format!("{}:{}:{}", prefix, path, name);
+
+                let mut type_node =
+                    create_code_node(id.clone(), *node_type, path, name.clone(), decl);
+                type_node.metadata.visibility = Some(Visibility::Public);
+
+                // `extends` on an interface names other interfaces
+                let extends_target = if *node_type == NodeType::Interface {
+                    "interface"
+                } else {
+                    "class"
+                };
+                let mut parents = Vec::new();
+                let mut implements = Vec::new();
+                let mut cursor = decl.walk();
+                for child in decl.named_children(&mut cursor) {
+                    let (edge_type, label, target) = match child.kind() {
+                        "base_clause" => (EdgeType::Extends, "extends", extends_target),
+                        "class_interface_clause" => {
+                            (EdgeType::Implements, "implements", "interface")
+                        }
+                        _ => continue,
+                    };
+                    let mut inner = child.walk();
+                    for parent in child.named_children(&mut inner) {
+                        let parent_name = get_node_text(parent, bytes).trim_start_matches('\\');
+                        if edge_type == EdgeType::Extends {
+                            parents.push(parent_name.to_string());
+                        } else {
+                            implements.push(parent_name.to_string());
+                        }
+                        result.edges.push(create_code_edge(
+                            format!("{}:{}:{}:{}", label, path, name, parent_name),
+                            edge_type,
+                            id.clone(),
+                            format!("{}:{}", target, parent_name),
+                            parent,
+                            path,
+                        ));
+                    }
+                }
+
+                // Traits mixed in with `use Trait;` inside the body
+                if let Some(body) = decl.child_by_field_name("body") {
+                    let mut inner = body.walk();
+                    for used in body
+                        .named_children(&mut inner)
+                        .filter(|c| c.kind() == "use_declaration")
+                    {
+                        let mut names = used.walk();
+                        for trait_name in used
+                            .named_children(&mut names)
+                            .filter(|n| matches!(n.kind(), "name" | "qualified_name"))
+                        {
+                            let trait_name =
+                                get_node_text(trait_name, bytes).trim_start_matches('\\');
+                            result.edges.push(create_code_edge(
+                                format!("implements:{}:{}:{}", path, name, trait_name),
+                                EdgeType::Implements,
+                                id.clone(),
+                                format!("interface:{}", trait_name),
+                                used,
+                                path,
+                            ));
+                        }
+                    }
+                }
+
+                type_node.metadata.extends = parents.first().cloned();
+                if !implements.is_empty() {
+                    type_node.metadata.implements = Some(implements);
+                }
+                result.nodes.push(type_node);
+            }
+        }
+    }
+}
+
+fn extract_functions(root: tree_sitter::Node, content: &str, path: &str, result: &mut ParseResult) {
+    let query_str = r#"
+        [
+            (function_definition)
+            (method_declaration)
+        ] @func
+    "#;
+
+    if let Ok(query) = Query::new(&tree_sitter_php::LANGUAGE_PHP.into(), query_str) {
+        let mut cursor = QueryCursor::new();
+        let mut matches = cursor.matches(&query, root, content.as_bytes());
+
+        while let Some(m) = matches.next() {
+            for cap in m.captures.iter() {
+                let func = cap.node;
+                let bytes = content.as_bytes();
+                let Some(name_node) = func.child_by_field_name("name") else {
+                    continue;
+                };
+                let name = get_node_text(name_node, bytes).to_string();
+                let owner = if func.kind() == "method_declaration" {
+                    owning_type(func, content, path)
+                } else {
+                    None
+                };
+
+                let (id, node_type) = match owner {
+                    Some((ref owner_name, _)) => (
+                        format!("method:{}:{}:{}", path, owner_name, name),
+                        NodeType::Method,
+                    ),
+                    None => (format!("function:{}:{}", path, name), NodeType::Function),
+                };
+
+                let mut func_node =
+                    create_code_node(id.clone(), node_type, path, name.clone(), func);
+                func_node.metadata = NodeMetadata {
+                    visibility: Some(php_visibility(func, content)),
+                    signature: Some(FunctionSignature {
+                        parameters: php_parameters(func, content),
+                        return_type: func
+                            .child_by_field_name("return_type")
+                            .map(|t| get_node_text(t, bytes).to_string()),
+                        is_async: false,
+                        generator: false,
+                    }),
+                    ..Default::default()
+                };
+                result.nodes.push(func_node);
+
+                if let Some((owner_name, owner_id)) = owner {
+                    result.edges.push(create_code_edge(
+                        format!("member:{}:{}:{}", path, owner_name, name),
+                        EdgeType::Owns,
+                        owner_id,
+                        id,
+                        func,
+                        path,
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Name and node ID of the class/interface/trait/enum declaring a method.
+fn owning_type(node: tree_sitter::Node, content: &str, path: &str) -> Option<(String, String)> {
+    let mut current = node.parent();
+    while let Some(parent) = current {
+        if let Some((_, prefix, _)) = TYPE_KINDS.iter().find(|(k, _, _)| *k == parent.kind()) {
+            let name = get_node_text(parent.child_by_field_name("name")?, content.as_bytes());
+            return Some((name.to_string(), format!("{}:{}:{}", prefix, path, name)));
+        }
+        current = parent.parent();
+    }
+    None
+}
+
+/// PHP members without a modifier are public.
+fn php_visibility(decl: tree_sitter::Node, content: &str) -> Visibility {
+    let mut cursor = decl.walk();
+    let modifier = decl
+        .named_children(&mut cursor)
+        .find(|c| c.kind() == "visibility_modifier")
+        .map(|m| get_node_text(m, content.as_bytes()));
+
+    match modifier {
+        Some("private") => Visibility::Private,
+        Some("protected") => Visibility::Protected,
+        _ => Visibility::Public,
+    }
+}
+
+fn php_parameters(func: tree_sitter::Node, content: &str) -> Vec<Parameter> {
+    let bytes = content.as_bytes();
+    let Some(params) = func.child_by_field_name("parameters") else {
+        return Vec::new();
+    };
+
+    let mut cursor = params.walk();
+    params
+        .named_children(&mut cursor)
+        .filter_map(|param| {
+            let name = param.child_by_field_name("name")?;
+            let default_value = param
+                .child_by_field_name("default_value")
+                .map(|v| get_node_text(v, bytes).to_string());
+            Some(Parameter {
+                name: get_node_text(name, bytes)
+                    .trim_start_matches('$')
+                    .to_string(),
+                param_type: param
+                    .child_by_field_name("type")
+                    .map(|t| get_node_text(t, bytes).to_string()),
+                optional: default_value.is_some() || param.kind() == "variadic_parameter",
+                default_value,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_php_classes_and_imports() {
+        let code = r#"<?php
+namespace App\Http;
+
+use App\Models\User;
+use Illuminate\Support\{Str, Arr as A};
+
+/** Handles users. */
+class UserController extends Controller implements Auth {
+    use Loggable;
+
+    protected static function find(int $id, ?string $name = null): ?User {
+        return new User();
+    }
+    function show() {}
+}
+"#;
+        let result = PhpParser::new()
+            .unwrap()
+            .parse("app/UserController.php", code)
+            .unwrap();
+
+        assert!(
+            result
+                .nodes
+                .iter()
+                .any(|n| n.id == "namespace:app/UserController.php:App\\Http")
+        );
+
+        let targets: Vec<&str> = result
+            .edges
+            .iter()
+            .filter(|e| e.id.starts_with("import:"))
+            .map(|e| e.target_id.as_str())
+            .collect();
+        assert_eq!(
+            targets,
+            vec![
+                "module:App\\Models\\User",
+                "module:Illuminate\\Support\\Str",
+                "module:Illuminate\\Support\\Arr"
+            ]
+        );
+
+        let class = result
+            .nodes
+            .iter()
+            .find(|n| n.id == "class:app/UserController.php:UserController")
+            .unwrap();
+        assert_eq!(class.metadata.extends.as_deref(), Some("Controller"));
+        assert_eq!(
+            class.metadata.description.as_deref(),
+            Some("Handles users.")
+        );
+        assert!(result.edges.iter().any(|e| e.edge_type == EdgeType::Implements
+            && e.target_id == "interface:Loggable"));
+
+        let find = result
+            .nodes
+            .iter()
+            .find(|n| n.id == "method:app/UserController.php:UserController:find")
+            .unwrap();
+        assert_eq!(find.metadata.visibility, Some(Visibility::Protected));
+        let sig = find.metadata.signature.as_ref().unwrap();
+        assert_eq!(sig.parameters[0].name, "id");
+        assert_eq!(sig.parameters[1].default_value.as_deref(), Some("null"));
+        assert_eq!(sig.return_type.as_deref(), Some("?User"));
+
+        let show = result
+            .nodes
+            .iter()
+            .find(|n| n.id == "method:app/UserController.php:UserController:show")
+            .unwrap();
+        assert_eq!(show.metadata.visibility, Some(Visibility::Public));
+    }
+}
@@ -1,14 +1,67 @@
 use tree_sitter::{Query, QueryCursor, StreamingIterator};
 
 use super::{
-    Language, ParseResult, Parser, create_code_edge, create_code_node, create_file_node,
-    create_ts_parser, evidence_from_node, get_node_text,
+    CallSyntax, DocSyntax, EntityDialect, Language, MetricsSyntax, ParseResult, Parser,
+    RouteDialect, TestSyntax, create_code_edge, create_code_node, create_file_node,
+    create_ts_parser, evidence_from_node, extract_calls, extract_docs, extract_entities,
+    extract_metrics, extract_return_types, extract_routes, extract_settings, extract_tests,
+    fingerprint_nodes, get_node_text,
 };
 use crate::types::{
     EdgeType, FunctionSignature, ImportType, NodeMetadata, NodeType, Parameter, Result, Visibility,
     WeaveError,
 };
 
+const CALL_SYNTAX: CallSyntax = CallSyntax {
+    scope_kinds: &["function_definition", "class_definition"],
+    call_kinds: &[("call", Some("function"))],
+    async_kinds: &["await"],
+};
+
+const DOC_SYNTAX: DocSyntax = DocSyntax {
+    item_kinds: &["function_definition", "class_definition"],
+    file_item_kinds: &[],
+    comment_kinds: &[],
+    doc_prefixes: &[],
+    inner_prefixes: &[],
+    skip_kinds: &[],
+    wrapper_kinds: &[],
+    docstrings: true,
+};
+
+const TEST_SYNTAX: TestSyntax = TestSyntax {
+    item_kinds: &["function_definition", "class_definition"],
+    marker_kinds: &["decorator"],
+    markers: &[],
+    name_prefixes: &["test", "Test"],
+    calls: &CALL_SYNTAX,
+};
+
+const METRICS_SYNTAX: MetricsSyntax = MetricsSyntax {
+    branch_kinds: &[
+        "if_statement",
+        "elif_clause",
+        "for_statement",
+        "while_statement",
+        "except_clause",
+        "case_clause",
+        "conditional_expression",
+        "boolean_operator",
+        "for_in_clause",
+        "if_clause",
+    ],
+    logical_kinds: &[],
+    nesting_kinds: &[
+        "if_statement",
+        "for_statement",
+        "while_statement",
+        "try_statement",
+        "with_statement",
+        "match_statement",
+    ],
+    return_kinds: &["return_statement"],
+};
+
 pub struct PythonParser;
 
 impl PythonParser {
@@ -47,6 +100,15 @@ impl Parser for PythonParser {
         extract_imports(root, content, path, &mut result);
         extract_classes(root, content, path, &mut result);
         extract_functions(root, content, path, &mut result);
+        extract_calls(root, content, path, &mut result, &CALL_SYNTAX);
+        extract_docs(root, content, &mut result, &DOC_SYNTAX);
+        extract_tests(root, content, path, &mut result, &TEST_SYNTAX);
+        extract_routes(root, content, path, &mut result, RouteDialect::Python);
+        extract_entities(root, content, path, &mut result, EntityDialect::Python);
+        extract_return_types(root, content, &mut result, "return_type");
+        extract_settings(root, content, path, &mut result, &CALL_SYNTAX);
+        extract_metrics(root, content, &mut result, &METRICS_SYNTAX);
+        fingerprint_nodes(root, content, &mut result);
 
         Ok(result)
     }
@@ -61,6 +123,9 @@ fn extract_imports(root: tree_sitter::Node, content: &str, path: &str, result: &
         (import_statement
             name: (dotted_name) @name
         )
+        (import_statement
+            name: (aliased_import name: (dotted_name) @name)
+        )
         (import_from_statement
             module_name: (dotted_name) @module
         )
@@ -75,8 +140,9 @@ fn extract_imports(root: tree_sitter::Node, content: &str, path: &str, result: &
                 let node = cap.node;
                 let module_name = get_node_text(node, content.as_bytes()).to_string();
 
-                // Only track relative imports (starting with .)
-                if module_name.is_empty() || !module_name.starts_with('.') {
+                // Absolute imports name installed packages (or the stdlib);
+                // the external package resolver keeps those it can place
+                if module_name.is_empty() {
                     continue;
                 }
 
@@ -0,0 +1,714 @@
+//! React Components
+//!
+//! Capitalized functions that return JSX (including `memo`/`forwardRef`
+//! wrappers) and classes extending `Component` or `PureComponent` become
+//! `Component` nodes. Props come from the component's type annotations or
+//! its destructured parameter, `useState`/`useReducer` bindings are its
+//! state, and capitalized JSX tags become `Renders` edges.
+//!
+//! A child imported from another file can only be named by its import here,
+//! so its edge points at a `component:{Tag}` placeholder and records the
+//! import's `specifier` and `symbol`; the component resolver retargets it
+//! once the import itself is resolved.
+
+use std::collections::{HashMap, HashSet};
+
+use super::routes::{preorder, string_value};
+use super::sfc::{
+    ComponentInfo, annotation_text, component_name, find_type_body, props_from_signature,
+};
+use super::{ParseResult, create_code_edge, create_code_node, get_node_text};
+use crate::types::{EdgeType, FieldDefinition, NodeType, Visibility};
+
+/// Class component lifecycle methods, recorded as the component's hooks.
+const LIFECYCLE_METHODS: &[&str] = &[
+    "componentDidMount",
+    "componentDidUpdate",
+    "componentWillUnmount",
+    "shouldComponentUpdate",
+    "getDerivedStateFromProps",
+    "getSnapshotBeforeUpdate",
+    "componentDidCatch",
+];
+
+/// Hooks whose destructured first element is a piece of state.
+const STATE_HOOKS: &[&str] = &["useState", "useReducer"];
+
+/// A component declared in the file.
+struct Declaration<'tree> {
+    name: String,
+    /// The function, arrow function or class implementing the component
+    node: tree_sitter::Node<'tree>,
+    /// Props type given outside the function (`React.FC<P>`, `Component<P>`)
+    props_type: Option<tree_sitter::Node<'tree>>,
+    /// State type of a class component (`Component<P, S>`)
+    state_type: Option<tree_sitter::Node<'tree>>,
+}
+
+/// Extract the React components declared in a JSX-capable file.
+pub fn extract_components(
+    root: tree_sitter::Node,
+    content: &str,
+    path: &str,
+    result: &mut ParseResult,
+) {
+    let bytes = content.as_bytes();
+    let declarations = declarations(root, bytes, path);
+    if declarations.is_empty() {
+        return;
+    }
+    let imports = imports(root, bytes);
+    let defaults = default_exports(root, bytes, path);
+    let local: HashSet<&str> = declarations.iter().map(|d| d.name.as_str()).collect();
+
+    for decl in &declarations {
+        let id = format!("component:{}:{}", path, decl.name);
+        let info = describe(decl, content);
+
+        let mut component = create_code_node(
+            id.clone(),
+            NodeType::Component,
+            path,
+            decl.name.clone(),
+            decl.node,
+        );
+        component.metadata.visibility = Some(Visibility::Public);
+        component.metadata.component_metadata = Some(info.metadata());
+        if defaults.contains(&decl.name) {
+            component
+                .metadata
+                .extra
+                .insert("default_export".to_string(), serde_json::json!(true));
+        }
+        result.nodes.push(component);
+
+        if defaults.contains(&decl.name) || is_exported(decl.node) {
+            result.edges.push(create_code_edge(
+                format!("exposes:{}:{}", path, id),
+                EdgeType::Exposes,
+                format!("file:{}", path),
+                id.clone(),
+                decl.node,
+                path,
+            ));
+        }
+
+        let mut seen = HashSet::new();
+        for (tag, element) in child_tags(decl.node, bytes) {
+            if !seen.insert(tag.clone()) {
+                continue;
+            }
+            let mut edge = create_code_edge(
+                format!("renders:{}:{}:{}", path, decl.name, tag),
+                EdgeType::Renders,
+                id.clone(),
+                format!("component:{}", tag),
+                element,
+                path,
+            );
+            if local.contains(tag.as_str()) {
+                edge.target_id = format!("component:{}:{}", path, tag);
+            } else if let Some((specifier, symbol)) = imported_symbol(&tag, &imports) {
+                let extra = &mut edge.metadata.extra;
+                extra.insert("specifier".to_string(), serde_json::json!(specifier));
+                extra.insert("symbol".to_string(), serde_json::json!(symbol));
+            }
+            result.edges.push(edge);
+        }
+    }
+}
+
+fn declarations<'a>(root: tree_sitter::Node<'a>, bytes: &[u8], path: &str) -> Vec<Declaration<'a>> {
+    let mut found = Vec::new();
+
+    for node in preorder(root) {
+        match node.kind() {
+            "function_declaration" => {
+                let Some(name) = node.child_by_field_name("name") else {
+                    continue;
+                };
+                let name = get_node_text(name, bytes);
+                if is_component_name(name) && returns_jsx(node) {
+                    found.push(Declaration {
+                        name: name.to_string(),
+                        node,
+                        props_type: None,
+                        state_type: None,
+                    });
+                }
+            }
+            // `const Card = (props) => ...`, `const Row: FC<P> = memo(...)`
+            "variable_declarator" => {
+                let Some(name) = node.child_by_field_name("name") else {
+                    continue;
+                };
+                let name = get_node_text(name, bytes);
+                let Some(function) = node.child_by_field_name("value").and_then(unwrap_function)
+                else {
+                    continue;
+                };
+                if is_component_name(name) && returns_jsx(function) {
+                    let props_type = node
+                        .child_by_field_name("type")
+                        .and_then(|t| t.named_child(0))
+                        .and_then(|t| t.child_by_field_name("type_arguments"))
+                        .and_then(|args| args.named_child(0));
+                    found.push(Declaration {
+                        name: name.to_string(),
+                        node: function,
+                        props_type,
+                        state_type: None,
+                    });
+                }
+            }
+            "class_declaration" => {
+                let Some(name) = node.child_by_field_name("name") else {
+                    continue;
+                };
+                let Some(extends) = component_superclass(node, bytes) else {
+                    continue;
+                };
+                let args = extends.child_by_field_name("type_arguments");
+                found.push(Declaration {
+                    name: get_node_text(name, bytes).to_string(),
+                    node,
+                    props_type: args.and_then(|a| a.named_child(0)),
+                    state_type: args.and_then(|a| a.named_child(1)),
+                });
+            }
+            // `export default () => <div />` is named after its file
+            "export_statement" => {
+                let Some(function) = node.child_by_field_name("value").and_then(unwrap_function)
+                else {
+                    continue;
+                };
+                if returns_jsx(function) {
+                    found.push(Declaration {
+                        name: component_name(path),
+                        node: function,
+                        props_type: None,
+                        state_type: None,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    found
+}
+
+fn is_component_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase())
+}
+
+/// The function behind a value, looking through wrapper calls such as
+/// `memo(...)`, `forwardRef(...)` and `observer(...)`.
+fn unwrap_function(value: tree_sitter::Node) -> Option<tree_sitter::Node> {
+    match value.kind() {
+        "arrow_function" | "function_expression" | "function" => Some(value),
+        "call_expression" => value
+            .child_by_field_name("arguments")
+            .and_then(|args| args.named_child(0))
+            .and_then(unwrap_function),
+        "parenthesized_expression" => value.named_child(0).and_then(unwrap_function),
+        _ => None,
+    }
+}
+
+fn returns_jsx(function: tree_sitter::Node) -> bool {
+    function
+        .child_by_field_name("body")
+        .is_some_and(|body| preorder(body).into_iter().any(|n| is_jsx_element(n.kind())))
+}
+
+fn is_jsx_element(kind: &str) -> bool {
+    matches!(kind, "jsx_element" | "jsx_self_closing_element")
+}
+
+/// The `extends` clause of a class extending `Component` or `PureComponent`
+/// (bare or as `React.Component`).
+fn component_superclass<'a>(
+    class: tree_sitter::Node<'a>,
+    bytes: &[u8],
+) -> Option<tree_sitter::Node<'a>> {
+    let mut cursor = class.walk();
+    let heritage = class
+        .named_children(&mut cursor)
+        .find(|c| c.kind() == "class_heritage")?;
+    let mut cursor = heritage.walk();
+    let extends = heritage
+        .named_children(&mut cursor)
+        .find(|c| c.kind() == "extends_clause")?;
+    let superclass = get_node_text(extends.child_by_field_name("value")?, bytes);
+    let last = superclass.rsplit('.').next().unwrap_or(superclass);
+    matches!(last, "Component" | "PureComponent").then_some(extends)
+}
+
+/// Props, state and hooks of one component.
+fn describe(decl: &Declaration, content: &str) -> ComponentInfo {
+    let bytes = content.as_bytes();
+    let mut info = ComponentInfo::default();
+
+    if decl.node.kind() == "class_declaration" {
+        for member in decl
+            .props_type
+            .map(|t| type_members(t, content))
+            .unwrap_or_default()
+        {
+            props_from_signature(member, content, &mut info);
+        }
+        for member in decl
+            .state_type
+            .map(|t| type_members(t, content))
+            .unwrap_or_default()
+        {
+            if let Some(field) = state_from_signature(member, content) {
+                info.state.push(field);
+            }
+        }
+        for method in preorder(decl.node)
+            .into_iter()
+            .filter(|n| n.kind() == "method_definition")
+        {
+            let name = method
+                .child_by_field_name("name")
+                .map(|n| get_node_text(n, bytes))
+                .unwrap_or_default();
+            if LIFECYCLE_METHODS.contains(&name) {
+                info.hooks.push(name.to_string());
+            }
+        }
+        return info;
+    }
+
+    let param = first_parameter(decl.node);
+    let props_type = decl.props_type.or_else(|| {
+        param
+            .and_then(|p| p.child_by_field_name("type"))
+            .and_then(|t| t.named_child(0))
+    });
+    let members = props_type
+        .map(|t| type_members(t, content))
+        .unwrap_or_default();
+    for member in &members {
+        props_from_signature(*member, content, &mut info);
+    }
+    // Untyped props are known only by the names destructured from them
+    if members.is_empty()
+        && let Some(pattern) = param
+            .and_then(|p| p.child_by_field_name("pattern"))
+            .filter(|p| p.kind() == "object_pattern")
+    {
+        destructured_props(pattern, bytes, &mut info);
+    }
+
+    if let Some(body) = decl.node.child_by_field_name("body") {
+        for call in preorder(body)
+            .into_iter()
+            .filter(|n| n.kind() == "call_expression")
+        {
+            collect_hook(call, bytes, &mut info);
+        }
+    }
+    info
+}
+
+/// `props` in `(props: P) =>`, or the destructuring pattern in `({ a }) =>`.
+fn first_parameter(function: tree_sitter::Node) -> Option<tree_sitter::Node> {
+    if let Some(single) = function.child_by_field_name("parameter") {
+        return Some(single);
+    }
+    function
+        .child_by_field_name("parameters")?
+        .named_child(0)
+        .filter(|p| matches!(p.kind(), "required_parameter" | "optional_parameter"))
+}
+
+/// Members of an object type, following a local `interface`/`type` name.
+fn type_members<'a>(type_node: tree_sitter::Node<'a>, content: &str) -> Vec<tree_sitter::Node<'a>> {
+    let body = match type_node.kind() {
+        "object_type" => Some(type_node),
+        "type_identifier" => find_type_body(
+            type_node,
+            get_node_text(type_node, content.as_bytes()),
+            content,
+        ),
+        _ => None,
+    };
+    body.map(|b| {
+        let mut cursor = b.walk();
+        b.named_children(&mut cursor).collect()
+    })
+    .unwrap_or_default()
+}
+
+fn state_from_signature(member: tree_sitter::Node, content: &str) -> Option<FieldDefinition> {
+    if member.kind() != "property_signature" {
+        return None;
+    }
+    let name = member.child_by_field_name("name")?;
+    let mut cursor = member.walk();
+    let optional = member.children(&mut cursor).any(|c| c.kind() == "?");
+    Some(FieldDefinition {
+        name: get_node_text(name, content.as_bytes()).to_string(),
+        field_type: member
+            .child_by_field_name("type")
+            .map(|t| annotation_text(t, content))
+            .unwrap_or_else(|| "unknown".to_string()),
+        nullable: optional,
+        primary_key: None,
+    })
+}
+
+/// `({ title, count = 0, onClose: close })`; names with a default are optional.
+fn destructured_props(pattern: tree_sitter::Node, bytes: &[u8], info: &mut ComponentInfo) {
+    let mut cursor = pattern.walk();
+    for field in pattern.named_children(&mut cursor) {
+        let (name, required) = match field.kind() {
+            "shorthand_property_identifier_pattern" => (Some(field), true),
+            "object_assignment_pattern" => (field.child_by_field_name("left"), false),
+            "pair_pattern" => (field.child_by_field_name("key"), true),
+            _ => continue,
+        };
+        if let Some(name) = name {
+            info.add_prop(get_node_text(name, bytes), "unknown", required);
+        }
+    }
+}
+
+/// Record `useX(...)` / `React.useX(...)` calls, and the state declared by
+/// `const [value, setValue] = useState<T>(...)`.
+fn collect_hook(call: tree_sitter::Node, bytes: &[u8], info: &mut ComponentInfo) {
+    let Some(function) = call.child_by_field_name("function") else {
+        return;
+    };
+    let name = match function.kind() {
+        "identifier" => Some(function),
+        "member_expression" => function.child_by_field_name("property"),
+        _ => None,
+    }
+    .map(|n| get_node_text(n, bytes))
+    .unwrap_or_default();
+    let is_hook = name
+        .strip_prefix("use")
+        .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_uppercase()));
+    if !is_hook {
+        return;
+    }
+    if !info.hooks.iter().any(|h| h == name) {
+        info.hooks.push(name.to_string());
+    }
+
+    if !STATE_HOOKS.contains(&name) {
+        return;
+    }
+    let Some(value) = call
+        .parent()
+        .filter(|p| p.kind() == "variable_declarator")
+        .and_then(|p| p.child_by_field_name("name"))
+        .filter(|n| n.kind() == "array_pattern")
+        .and_then(|n| n.named_child(0))
+        .filter(|n| n.kind() == "identifier")
+    else {
+        return;
+    };
+    let field_type = call
+        .child_by_field_name("type_arguments")
+        .map(|t| {
+            get_node_text(t, bytes)
+                .trim_start_matches('<')
+                .trim_end_matches('>')
+                .to_string()
+        })
+        .unwrap_or_else(|| name.to_string());
+    info.state.push(FieldDefinition {
+        name: get_node_text(value, bytes).to_string(),
+        field_type,
+        nullable: false,
+        primary_key: None,
+    });
+}
+
+/// Capitalized (component) JSX tags rendered by a component, with the element
+/// using each. Lowercase tags are DOM elements.
+fn child_tags<'a>(
+    component: tree_sitter::Node<'a>,
+    bytes: &[u8],
+) -> Vec<(String, tree_sitter::Node<'a>)> {
+    preorder(component)
+        .into_iter()
+        .filter(|n| matches!(n.kind(), "jsx_opening_element" | "jsx_self_closing_element"))
+        .filter_map(|element| {
+            let name = element.child_by_field_name("name")?;
+            let tag = get_node_text(name, bytes);
+            let is_component = match name.kind() {
+                "identifier" => is_component_name(tag),
+                "member_expression" => true,
+                _ => false,
+            };
+            is_component.then(|| (tag.to_string(), element))
+        })
+        .collect()
+}
+
+/// Local binding → (import specifier, exported name).
+fn imports(root: tree_sitter::Node, bytes: &[u8]) -> HashMap<String, (String, String)> {
+    let mut imports = HashMap::new();
+    let mut cursor = root.walk();
+    for statement in root.named_children(&mut cursor) {
+        if statement.kind() != "import_statement" {
+            continue;
+        }
+        let Some(source) = statement
+            .child_by_field_name("source")
+            .and_then(|s| string_value(s, bytes))
+        else {
+            continue;
+        };
+        for node in preorder(statement) {
+            let (binding, symbol) = match node.kind() {
+                "import_clause" => match node.named_child(0).filter(|c| c.kind() == "identifier") {
+                    Some(default) => (default, "default".to_string()),
+                    None => continue,
+                },
+                "namespace_import" => match node.named_child(0) {
+                    Some(name) => (name, "*".to_string()),
+                    None => continue,
+                },
+                "import_specifier" => {
+                    let Some(name) = node.child_by_field_name("name") else {
+                        continue;
+                    };
+                    let alias = node.child_by_field_name("alias").unwrap_or(name);
+                    (alias, get_node_text(name, bytes).to_string())
+                }
+                _ => continue,
+            };
+            imports.insert(
+                get_node_text(binding, bytes).to_string(),
+                (source.clone(), symbol),
+            );
+        }
+    }
+    imports
+}
+
+/// The module and exported name a rendered tag comes from: `<Button>` via
+/// `import Button from './Button'`, `<Ui.Icon>` via `import * as Ui`.
+fn imported_symbol(
+    tag: &str,
+    imports: &HashMap<String, (String, String)>,
+) -> Option<(String, String)> {
+    match tag.split_once('.') {
+        None => imports.get(tag).cloned(),
+        Some((binding, member)) => {
+            let (source, symbol) = imports.get(binding)?;
+            (symbol == "*" && !member.contains('.')).then(|| (source.clone(), member.to_string()))
+        }
+    }
+}
+
+/// Names given to `export default`: `export default function App`,
+/// `export default App` and `export default memo(App)`.
+fn default_exports(root: tree_sitter::Node, bytes: &[u8], path: &str) -> HashSet<String> {
+    let mut names = HashSet::new();
+    let mut cursor = root.walk();
+    for statement in root.named_children(&mut cursor) {
+        let mut inner = statement.walk();
+        let is_default = statement.kind() == "export_statement"
+            && statement
+                .children(&mut inner)
+                .any(|c| !c.is_named() && c.kind() == "default");
+        if !is_default {
+            continue;
+        }
+        let named = statement
+            .child_by_field_name("declaration")
+            .and_then(|d| d.child_by_field_name("name"));
+        let mut value = statement.child_by_field_name("value");
+        while let Some(call) = value.filter(|v| v.kind() == "call_expression") {
+            value = call
+                .child_by_field_name("arguments")
+                .and_then(|a| a.named_child(0));
+        }
+        match (named, value) {
+            (Some(name), _) => names.insert(get_node_text(name, bytes).to_string()),
+            (None, Some(v)) if v.kind() == "identifier" => {
+                names.insert(get_node_text(v, bytes).to_string())
+            }
+            // Anonymous components are named after their file
+            (None, Some(v)) if unwrap_function(v).is_some() => names.insert(component_name(path)),
+            _ => false,
+        };
+    }
+    names
+}
+
+/// Whether a top-level declaration is part of an `export` statement.
+fn is_exported(node: tree_sitter::Node) -> bool {
+    let mut current = node.parent();
+    while let Some(parent) = current {
+        match parent.kind() {
+            "export_statement" => return true,
+            "statement_block" | "class_body" | "program" => return false,
+            _ => current = parent.parent(),
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::parser::{Language, Parser, TypeScriptParser};
+    use crate::types::Node;
+
+    fn component<'a>(result: &'a ParseResult, name: &str) -> &'a Node {
+        result
+            .nodes
+            .iter()
+            .find(|n| n.node_type == NodeType::Component && n.name == name)
+            .unwrap_or_else(|| panic!("no component {}", name))
+    }
+
+    #[test]
+    fn test_function_and_class_components() {
+        let code = r#"
+import React, { useState, useEffect } from 'react';
+import Button from './Button';
+import * as Ui from './ui';
+
+interface Props { title: string; count?: number }
+
+export default function App({ title, count = 0 }: Props) {
+  const [open, setOpen] = useState<boolean>(false);
+  useEffect(() => {}, []);
+  return <Layout><Button onClick={() => setOpen(!open)} /><Ui.Icon /><div /></Layout>;
+}
+
+const Layout: React.FC<{ children: React.ReactNode }> = React.memo(({ children }) => <main>{children}</main>);
+
+export class Legacy extends React.Component<Props, { n: number }> {
+  componentDidMount() {}
+  render() { return <App title="x" />; }
+}
+
+function helper() { return 1; }
+"#;
+        let result = TypeScriptParser::for_language(Language::Tsx)
+            .unwrap()
+            .parse("./src/App.tsx", code)
+            .unwrap();
+
+        let components: Vec<&str> = result
+            .nodes
+            .iter()
+            .filter(|n| n.node_type == NodeType::Component)
+            .map(|n| n.name.as_str())
+            .collect();
+        assert_eq!(components, vec!["App", "Layout", "Legacy"]);
+
+        let app = component(&result, "App");
+        assert!(app.metadata.extra.contains_key("default_export"));
+        let meta = app.metadata.component_metadata.as_ref().unwrap();
+        let props: Vec<(&str, &str, bool)> = meta
+            .props
+            .iter()
+            .flatten()
+            .map(|p| (p.name.as_str(), p.prop_type.as_str(), p.required))
+            .collect();
+        assert_eq!(
+            props,
+            vec![("title", "string", true), ("count", "number", false)]
+        );
+        assert_eq!(
+            meta.hooks,
+            Some(vec!["useState".to_string(), "useEffect".to_string()])
+        );
+        let state = &meta.state.as_ref().unwrap().fields;
+        assert_eq!(state[0].name, "open");
+        assert_eq!(state[0].field_type, "boolean");
+
+        let layout = component(&result, "Layout");
+        let props = layout.metadata.component_metadata.as_ref().unwrap();
+        assert_eq!(props.props.as_ref().unwrap()[0].name, "children");
+
+        let legacy = component(&result, "Legacy");
+        let meta = legacy.metadata.component_metadata.as_ref().unwrap();
+        assert_eq!(meta.props.as_ref().unwrap().len(), 2);
+        assert_eq!(meta.state.as_ref().unwrap().fields[0].name, "n");
+        assert_eq!(meta.hooks, Some(vec!["componentDidMount".to_string()]));
+
+        let renders = |from: &str| -> Vec<(&str, Option<&str>)> {
+            result
+                .edges
+                .iter()
+                .filter(|e| {
+                    e.edge_type == EdgeType::Renders
+                        && e.source_id == format!("component:./src/App.tsx:{}", from)
+                })
+                .map(|e| {
+                    let symbol = e.metadata.extra.get("symbol").and_then(|s| s.as_str());
+                    (e.target_id.as_str(), symbol)
+                })
+                .collect()
+        };
+        assert_eq!(
+            renders("App"),
+            vec![
+                ("component:./src/App.tsx:Layout", None),
+                ("component:Button", Some("default")),
+                ("component:Ui.Icon", Some("Icon")),
+            ]
+        );
+        assert_eq!(
+            renders("Legacy"),
+            vec![("component:./src/App.tsx:App", None)]
+        );
+
+        let exposed: Vec<&str> = result
+            .edges
+            .iter()
+            .filter(|e| e.edge_type == EdgeType::Exposes && e.id.starts_with("exposes:"))
+            .map(|e| e.target_id.as_str())
+            .collect();
+        assert_eq!(
+            exposed,
+            vec![
+                "component:./src/App.tsx:App",
+                "component:./src/App.tsx:Legacy"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_untyped_jsx_components() {
+        let code = "export default ({ label, size = 'md' }) => <span>{label}</span>;\n";
+        let result = TypeScriptParser::for_language(Language::Jsx)
+            .unwrap()
+            .parse("./src/badge-label.jsx", code)
+            .unwrap();
+
+        let badge = component(&result, "BadgeLabel");
+        let props = badge
+            .metadata
+            .component_metadata
+            .as_ref()
+            .unwrap()
+            .props
+            .clone()
+            .unwrap();
+        assert_eq!(props[0].name, "label");
+        assert!(props[0].required);
+        assert_eq!(props[1].name, "size");
+        assert!(!props[1].required);
+
+        // Plain TypeScript files don't produce components
+        let ts = TypeScriptParser::new()
+            .unwrap()
+            .parse("./src/util.ts", "export function Util() { return 1; }\n")
+            .unwrap();
+        assert!(ts.nodes.iter().all(|n| n.node_type != NodeType::Component));
+    }
+}
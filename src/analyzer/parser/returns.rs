@@ -0,0 +1,102 @@
+//! Return Type Extraction
+//!
+//! Most parsers read parameters from a captured parameter list but leave
+//! `FunctionSignature.return_type` empty. This pass fills it from the
+//! declaration of each function and method, given the grammar field that
+//! holds its return type (`return_type` in Rust, TypeScript and Python,
+//! `result` in Go, `type` in Java).
+
+use super::fingerprint::declaration;
+use super::traits::{ParseResult, get_node_text};
+use crate::types::NodeType;
+
+/// Fill the missing return types of the functions and methods of `result`.
+///
+/// Must run after the parser has pushed its declaration nodes.
+pub fn extract_return_types(
+    root: tree_sitter::Node,
+    content: &str,
+    result: &mut ParseResult,
+    field: &str,
+) {
+    let bytes = content.as_bytes();
+    for node in &mut result.nodes {
+        if !matches!(node.node_type, NodeType::Function | NodeType::Method) {
+            continue;
+        }
+        let missing = node
+            .metadata
+            .signature
+            .as_ref()
+            .is_some_and(|s| s.return_type.is_none());
+        if !missing {
+            continue;
+        }
+        let Some(decl) = declaration(root, node, bytes) else {
+            continue;
+        };
+        let Some(return_node) = decl.child_by_field_name(field) else {
+            continue;
+        };
+
+        // TypeScript annotations include their leading `:`
+        let text = get_node_text(return_node, bytes)
+            .trim_start_matches(':')
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+        if let (false, Some(signature)) = (text.is_empty(), node.metadata.signature.as_mut()) {
+            signature.return_type = Some(text);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::analyzer::parser::{Language, ParseResult, create_parser};
+
+    fn return_type(path: &str, content: &str, name: &str) -> Option<String> {
+        let result: ParseResult = create_parser(Language::from_path(path))
+            .unwrap()
+            .parse(path, content)
+            .unwrap();
+        let node = result.nodes.iter().find(|n| n.name == name).unwrap();
+        node.metadata.signature.as_ref()?.return_type.clone()
+    }
+
+    #[test]
+    fn test_return_types() {
+        assert_eq!(
+            return_type(
+                "src/a.rs",
+                "fn load(p: &Path) -> Result<Config> { todo!() }\n",
+                "load"
+            ),
+            Some("Result<Config>".to_string())
+        );
+        assert_eq!(
+            return_type(
+                "src/a.ts",
+                "export function find(id: string): User | null { return null; }\n",
+                "find"
+            ),
+            Some("User | null".to_string())
+        );
+        assert_eq!(
+            return_type("app/a.py", "def load(p) -> Config:\n    pass\n", "load"),
+            Some("Config".to_string())
+        );
+        assert_eq!(
+            return_type(
+                "pkg/a.go",
+                "package pkg\n\nfunc Load() (*Config, error) { return nil, nil }\n",
+                "Load"
+            ),
+            Some("(*Config, error)".to_string())
+        );
+        assert_eq!(
+            return_type("app/a.py", "def run():\n    pass\n", "run"),
+            None
+        );
+    }
+}
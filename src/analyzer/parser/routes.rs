@@ -0,0 +1,1937 @@
+//! HTTP Route Extraction
+//!
+//! Recognizes the route declarations of common web frameworks and turns them
+//! into `api` nodes carrying [`ApiMetadata`]:
+//!
+//! | Dialect    | Frameworks              | Shapes                                               |
+//! |------------|-------------------------|------------------------------------------------------|
+//! | JavaScript | Express, Nest           | `app.get('/x', h)`, `@Controller('x')` + `@Get(':id')` |
+//! | Python     | FastAPI, Flask          | `@router.get("/x")`, `@bp.route("/x", methods=[...])` |
+//! | Java       | Spring MVC              | `@RequestMapping` on the class, `@GetMapping` on methods |
+//! | Rust       | axum, actix-web         | `.route("/x", get(h))`, `#[get("/x")]`, `web::resource("/x")` |
+//! | Go         | net/http, gin, echo, chi | `http.HandleFunc("/x", h)`, `r.GET("/x", h)`         |
+//! | Rails      | `config/routes.rb`      | `get 'x', to: 'c#a'`, `resources :x`                 |
+//!
+//! Handlers are linked with `routes_to` edges when they resolve to a function
+//! or method declared in the same file; the handler expression is always kept
+//! in the node's `handler` metadata so cross-file handlers stay visible.
+//! Shared prefixes (Nest and Spring controllers, router groups, Rails
+//! resources) become `route` nodes that own their endpoints.
+
+use std::collections::{HashMap, HashSet};
+
+use super::traits::{ParseResult, create_code_edge, create_code_node, get_node_text};
+use crate::types::{ApiMetadata, AuthRequirement, EdgeType, HttpMethod, NodeType, SchemaReference};
+
+/// Route declaration style followed by a parser's files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RouteDialect {
+    /// Express-style routers and Nest controllers
+    JavaScript,
+    /// FastAPI and Flask decorators
+    Python,
+    /// Spring MVC annotations
+    Java,
+    /// axum and actix-web routers and attribute macros
+    Rust,
+    /// net/http, gin, echo and chi routers
+    Go,
+    /// Rails routing DSL (`config/routes.rb`)
+    Rails,
+}
+
+/// Substrings that mark a middleware, guard, decorator or dependency as
+/// enforcing authentication.
+const AUTH_MARKERS: &[&str] = &[
+    "auth",
+    "login_required",
+    "jwt",
+    "guard",
+    "current_user",
+    "protect",
+];
+
+struct RouteSite<'t> {
+    method: HttpMethod,
+    path: String,
+    /// Handler expression as written (`getUser`, `s.update`, `photos#show`)
+    handler: Option<String>,
+    /// Class declaring the handler, for decorator and annotation routes
+    owner: Option<String>,
+    auth: Option<AuthRequirement>,
+    request_schema: Option<String>,
+    /// Index into [`Routes::groups`] when the route sits under a shared prefix
+    group: Option<usize>,
+    node: tree_sitter::Node<'t>,
+}
+
+impl<'t> RouteSite<'t> {
+    fn new(method: HttpMethod, path: String, node: tree_sitter::Node<'t>) -> Self {
+        Self {
+            method,
+            path,
+            handler: None,
+            owner: None,
+            auth: None,
+            request_schema: None,
+            group: None,
+            node,
+        }
+    }
+}
+
+#[derive(Default)]
+struct Routes<'t> {
+    sites: Vec<RouteSite<'t>>,
+    groups: Vec<(String, tree_sitter::Node<'t>)>,
+}
+
+impl<'t> Routes<'t> {
+    /// Index of the group for `prefix`, registering it on first use.
+    fn group(&mut self, prefix: String, node: tree_sitter::Node<'t>) -> usize {
+        if let Some(i) = self.groups.iter().position(|(p, _)| *p == prefix) {
+            return i;
+        }
+        self.groups.push((prefix, node));
+        self.groups.len() - 1
+    }
+}
+
+/// Extract `api` and `route` nodes from the route declarations under `root`.
+///
+/// Must run after the parser has pushed its declaration nodes, since handlers
+/// are resolved by name against `result.nodes`. Routes are keyed by method
+/// and path, so a route declared twice in one file yields a single node.
+pub fn extract_routes(
+    root: tree_sitter::Node,
+    content: &str,
+    path: &str,
+    result: &mut ParseResult,
+    dialect: RouteDialect,
+) {
+    let bytes = content.as_bytes();
+    let mut routes = Routes::default();
+
+    match dialect {
+        RouteDialect::JavaScript => javascript_routes(root, bytes, &mut routes),
+        RouteDialect::Python => python_routes(root, bytes, &mut routes),
+        RouteDialect::Java => java_routes(root, bytes, &mut routes),
+        RouteDialect::Rust => rust_routes(root, bytes, &mut routes),
+        RouteDialect::Go => go_routes(root, bytes, &mut routes),
+        RouteDialect::Rails if is_rails_routes_file(path) => rails_routes(root, bytes, &mut routes),
+        RouteDialect::Rails => {}
+    }
+
+    emit_routes(routes, path, result);
+}
+
+fn emit_routes(routes: Routes, path: &str, result: &mut ParseResult) {
+    let group_ids: Vec<String> = routes
+        .groups
+        .iter()
+        .map(|(prefix, _)| format!("route:{}:{}", path, prefix))
+        .collect();
+    for ((prefix, node), id) in routes.groups.iter().zip(&group_ids) {
+        result.nodes.push(create_code_node(
+            id.clone(),
+            NodeType::Route,
+            path,
+            prefix.clone(),
+            *node,
+        ));
+    }
+
+    let mut seen = HashSet::new();
+    for site in routes.sites {
+        let method = site.method.as_str();
+        let key = format!("{}:{}:{}", path, method, site.path);
+        if !seen.insert(key.clone()) {
+            continue;
+        }
+        let id = format!("api:{}", key);
+        let target = site
+            .handler
+            .as_deref()
+            .and_then(|h| resolve_handler(result, h, site.owner.as_deref()));
+
+        let mut api = create_code_node(
+            id.clone(),
+            NodeType::Api,
+            path,
+            format!("{} {}", method, site.path),
+            site.node,
+        );
+        api.metadata.api_metadata = Some(ApiMetadata {
+            method: site.method,
+            path: site.path,
+            request_schema: site
+                .request_schema
+                .map(|ref_path| SchemaReference::Ref { ref_path }),
+            response_schema: None,
+            auth: site.auth,
+            rate_limit: None,
+        });
+        if let Some(handler) = site.handler {
+            api.metadata
+                .extra
+                .insert("handler".to_string(), serde_json::Value::String(handler));
+        }
+        result.nodes.push(api);
+
+        if let Some(target) = target {
+            result.edges.push(create_code_edge(
+                format!("handler:{}", key),
+                EdgeType::RoutesTo,
+                id.clone(),
+                target,
+                site.node,
+                path,
+            ));
+        }
+        if let Some(group) = site.group {
+            result.edges.push(create_code_edge(
+                format!("mount:{}", key),
+                EdgeType::Owns,
+                group_ids[group].clone(),
+                id,
+                site.node,
+                path,
+            ));
+        }
+    }
+}
+
+/// Resolve a handler expression to a function or method declared in this
+/// file, falling back to the owning class when the parser does not emit
+/// method nodes for it.
+fn resolve_handler(result: &ParseResult, handler: &str, owner: Option<&str>) -> Option<String> {
+    let name = handler
+        .rsplit(['.', ':'])
+        .next()
+        .filter(|n| !n.is_empty())?;
+    let callables = || {
+        result.nodes.iter().filter(move |n| {
+            matches!(n.node_type, NodeType::Function | NodeType::Method) && n.name == name
+        })
+    };
+
+    if let Some(owner) = owner {
+        let scoped = format!(":{}:{}", owner, name);
+        if let Some(node) = callables().find(|n| n.id.ends_with(&scoped)) {
+            return Some(node.id.clone());
+        }
+    }
+    if let Some(node) = callables().next() {
+        return Some(node.id.clone());
+    }
+
+    let owner = owner?;
+    result
+        .nodes
+        .iter()
+        .find(|n| n.node_type == NodeType::Class && n.name == owner)
+        .map(|n| n.id.clone())
+}
+
+// =============================================================================
+// Shared helpers
+// =============================================================================
+
+/// Join route segments into a normalized path with a single leading `/`.
+pub fn join_route(prefix: &str, path: &str) -> String {
+    let mut joined = String::new();
+    for segment in prefix.split('/').chain(path.split('/')) {
+        if !segment.is_empty() {
+            joined.push('/');
+            joined.push_str(segment);
+        }
+    }
+    if joined.is_empty() {
+        joined.push('/');
+    }
+    joined
+}
+
+fn is_auth_marker(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    AUTH_MARKERS.iter().any(|m| name.contains(m))
+}
+
+fn auth_requirement(auth_type: &str, roles: Vec<String>) -> AuthRequirement {
+    AuthRequirement {
+        required: true,
+        auth_type: Some(auth_type.to_string()),
+        roles: (!roles.is_empty()).then_some(roles),
+    }
+}
+
+/// All nodes under `root` in document order.
+pub(super) fn preorder(root: tree_sitter::Node) -> Vec<tree_sitter::Node> {
+    let mut nodes = Vec::new();
+    let mut stack = vec![root];
+    let mut cursor = root.walk();
+    while let Some(node) = stack.pop() {
+        nodes.push(node);
+        let children: Vec<_> = node.named_children(&mut cursor).collect();
+        stack.extend(children.into_iter().rev());
+    }
+    nodes
+}
+
+/// Named children of an argument list, without comments.
+pub(super) fn named_args(args: Option<tree_sitter::Node>) -> Vec<tree_sitter::Node> {
+    let Some(args) = args else {
+        return Vec::new();
+    };
+    let mut cursor = args.walk();
+    args.named_children(&mut cursor)
+        .filter(|a| !a.kind().contains("comment"))
+        .collect()
+}
+
+/// Value of a string literal without quotes; `None` for interpolated strings.
+pub(super) fn string_value(node: tree_sitter::Node, bytes: &[u8]) -> Option<String> {
+    if !node.kind().contains("string") {
+        return None;
+    }
+    let mut cursor = node.walk();
+    if node.named_children(&mut cursor).any(|c| {
+        matches!(
+            c.kind(),
+            "template_substitution" | "interpolation" | "escape_interpolation"
+        )
+    }) {
+        return None;
+    }
+    let text = get_node_text(node, bytes).trim_start_matches(|c: char| c.is_ascii_alphabetic());
+    Some(
+        text.trim_matches(|c| matches!(c, '"' | '\'' | '`' | '#'))
+            .to_string(),
+    )
+}
+
+/// Last segment of a dotted or scoped name (`passport.authenticate` → `authenticate`).
+pub(super) fn last_segment(text: &str) -> &str {
+    text.rsplit(['.', ':']).next().unwrap_or(text)
+}
+
+/// Name of the function a call invokes, or the expression itself otherwise.
+pub(super) fn callee_name<'b>(node: tree_sitter::Node, bytes: &'b [u8]) -> &'b str {
+    let target = node.child_by_field_name("function").unwrap_or(node);
+    last_segment(get_node_text(target, bytes))
+}
+
+/// Handler reference passed to a router: a name or a member access, not an
+/// inline function.
+fn handler_name(node: tree_sitter::Node, bytes: &[u8]) -> Option<String> {
+    matches!(
+        node.kind(),
+        "identifier"
+            | "member_expression"
+            | "selector_expression"
+            | "attribute"
+            | "scoped_identifier"
+            | "field_expression"
+    )
+    .then(|| get_node_text(node, bytes).to_string())
+}
+
+/// First middleware argument that looks like authentication.
+fn middleware_auth(middleware: &[tree_sitter::Node], bytes: &[u8]) -> Option<AuthRequirement> {
+    middleware
+        .iter()
+        .map(|m| callee_name(*m, bytes))
+        .find(|name| is_auth_marker(name))
+        .map(|name| auth_requirement(name, Vec::new()))
+}
+
+// =============================================================================
+// JavaScript / TypeScript: Express and Nest
+// =============================================================================
+
+fn javascript_routes<'t>(root: tree_sitter::Node<'t>, bytes: &[u8], routes: &mut Routes<'t>) {
+    for node in preorder(root) {
+        match node.kind() {
+            "call_expression" => {
+                if let Some(site) = express_route(node, bytes) {
+                    routes.sites.push(site);
+                }
+            }
+            "class_declaration" | "abstract_class_declaration" => {
+                nest_controller(node, bytes, routes)
+            }
+            _ => {}
+        }
+    }
+}
+
+/// `router.get('/users/:id', requireAuth, getUser)`: a verb-named method call
+/// whose first argument is a path and whose last is the handler.
+///
+/// HTTP clients share the shape (`axios.post('/users', payload)`,
+/// `$.get('/items', function () {})`), so the receiver must be named like an
+/// app or router.
+fn express_route<'t>(call: tree_sitter::Node<'t>, bytes: &[u8]) -> Option<RouteSite<'t>> {
+    let function = call
+        .child_by_field_name("function")
+        .filter(|f| f.kind() == "member_expression")?;
+    let verb = get_node_text(function.child_by_field_name("property")?, bytes);
+    let method = HttpMethod::from_name(verb)?;
+
+    let args = named_args(call.child_by_field_name("arguments"));
+    let (first, rest) = args.split_first()?;
+    let path = string_value(*first, bytes).filter(|p| p.starts_with('/'))?;
+    let (handler, middleware) = rest.split_last()?;
+
+    // HTTP clients share the verbs, with or without an inline callback
+    let receiver = function.child_by_field_name("object")?;
+    if !is_router_name(get_node_text(receiver, bytes)) {
+        return None;
+    }
+
+    let mut site = RouteSite::new(method, path, call);
+    site.handler = handler_name(*handler, bytes);
+    site.auth = middleware_auth(middleware, bytes);
+    Some(site)
+}
+
+/// Receivers Express-style routes are registered on: `app`, `router`,
+/// `server`, `fastify`, `userRouter`, `express.Router()`.
+fn is_router_name(receiver: &str) -> bool {
+    let name = receiver
+        .trim_end_matches("()")
+        .rsplit('.')
+        .next()
+        .unwrap_or(receiver)
+        .to_lowercase();
+    matches!(name.as_str(), "server" | "fastify" | "routes")
+        || name.ends_with("app")
+        || name.ends_with("router")
+}
+
+/// Nest controllers: `@Controller('users')` on the class and verb decorators
+/// (`@Get(':id')`) on its methods, with `@UseGuards`/`@Roles` on either.
+fn nest_controller<'t>(class: tree_sitter::Node<'t>, bytes: &[u8], routes: &mut Routes<'t>) {
+    let decorators = class_decorators(class);
+    let Some(controller) = decorators
+        .iter()
+        .find(|d| decorator_name(**d, bytes) == "Controller")
+    else {
+        return;
+    };
+    let (Some(name), Some(body)) = (
+        class.child_by_field_name("name"),
+        class.child_by_field_name("body"),
+    ) else {
+        return;
+    };
+
+    let owner = get_node_text(name, bytes).to_string();
+    let prefix = decorator_args(*controller)
+        .first()
+        .and_then(|a| string_value(*a, bytes))
+        .unwrap_or_default();
+    let class_auth = nest_auth(&decorators, bytes);
+    let group = (!prefix.is_empty()).then(|| routes.group(join_route("", &prefix), class));
+
+    let mut pending = Vec::new();
+    let mut cursor = body.walk();
+    for member in body.named_children(&mut cursor) {
+        match member.kind() {
+            "decorator" => pending.push(member),
+            "method_definition" => {
+                let decorators = std::mem::take(&mut pending);
+                for decorator in &decorators {
+                    let Some(method) = HttpMethod::from_name(decorator_name(*decorator, bytes))
+                    else {
+                        continue;
+                    };
+                    let path = decorator_args(*decorator)
+                        .first()
+                        .and_then(|a| string_value(*a, bytes))
+                        .unwrap_or_default();
+
+                    let mut site = RouteSite::new(method, join_route(&prefix, &path), member);
+                    site.handler = member
+                        .child_by_field_name("name")
+                        .map(|n| get_node_text(n, bytes).to_string());
+                    site.owner = Some(owner.clone());
+                    site.auth = nest_auth(&decorators, bytes).or_else(|| class_auth.clone());
+                    site.request_schema = nest_body_type(member, bytes);
+                    site.group = group;
+                    routes.sites.push(site);
+                }
+            }
+            _ => pending.clear(),
+        }
+    }
+}
+
+/// Decorators on a class, including those attached to its `export` statement.
+pub(super) fn class_decorators(class: tree_sitter::Node) -> Vec<tree_sitter::Node> {
+    let mut decorators = Vec::new();
+    let owners = [
+        Some(class),
+        class.parent().filter(|p| p.kind() == "export_statement"),
+    ];
+    for owner in owners.into_iter().flatten() {
+        let mut cursor = owner.walk();
+        decorators.extend(
+            owner
+                .children(&mut cursor)
+                .filter(|c| c.kind() == "decorator"),
+        );
+    }
+    decorators
+}
+
+pub(super) fn decorator_name<'b>(decorator: tree_sitter::Node, bytes: &'b [u8]) -> &'b str {
+    decorator
+        .named_child(0)
+        .map(|e| callee_name(e, bytes))
+        .unwrap_or_default()
+}
+
+pub(super) fn decorator_args(decorator: tree_sitter::Node) -> Vec<tree_sitter::Node> {
+    named_args(
+        decorator
+            .named_child(0)
+            .and_then(|e| e.child_by_field_name("arguments")),
+    )
+}
+
+fn nest_auth(decorators: &[tree_sitter::Node], bytes: &[u8]) -> Option<AuthRequirement> {
+    let mut guard = None;
+    let mut roles = Vec::new();
+    for decorator in decorators {
+        match decorator_name(*decorator, bytes) {
+            "UseGuards" => {
+                guard = guard.or_else(|| {
+                    decorator_args(*decorator)
+                        .first()
+                        .map(|g| callee_name(*g, bytes))
+                });
+            }
+            "Roles" => roles.extend(
+                decorator_args(*decorator)
+                    .iter()
+                    .filter_map(|a| string_value(*a, bytes)),
+            ),
+            _ => {}
+        }
+    }
+    if guard.is_none() && roles.is_empty() {
+        return None;
+    }
+    Some(auth_requirement(guard.unwrap_or("Roles"), roles))
+}
+
+/// Type of the `@Body()` parameter, if the handler takes one.
+fn nest_body_type(method: tree_sitter::Node, bytes: &[u8]) -> Option<String> {
+    let params = method.child_by_field_name("parameters")?;
+    let mut cursor = params.walk();
+    params.named_children(&mut cursor).find_map(|param| {
+        let mut inner = param.walk();
+        let has_body = param
+            .children(&mut inner)
+            .any(|c| c.kind() == "decorator" && decorator_name(c, bytes) == "Body");
+        let annotation = param.child_by_field_name("type")?.named_child(0)?;
+        has_body.then(|| get_node_text(annotation, bytes).to_string())
+    })
+}
+
+// =============================================================================
+// Python: FastAPI and Flask
+// =============================================================================
+
+fn python_routes<'t>(root: tree_sitter::Node<'t>, bytes: &[u8], routes: &mut Routes<'t>) {
+    let prefixes = python_router_prefixes(root, bytes);
+
+    for node in preorder(root) {
+        if node.kind() != "decorated_definition" {
+            continue;
+        }
+        let Some(def) = node
+            .child_by_field_name("definition")
+            .filter(|d| d.kind() == "function_definition")
+        else {
+            continue;
+        };
+        let mut cursor = node.walk();
+        let decorators: Vec<_> = node
+            .named_children(&mut cursor)
+            .filter(|d| d.kind() == "decorator")
+            .filter_map(|d| d.named_child(0))
+            .collect();
+        let auth = python_auth(&decorators, def, bytes);
+
+        for expr in &decorators {
+            let Some((router, verb, args)) = python_route_decorator(*expr, bytes) else {
+                continue;
+            };
+            let Some(path) = args
+                .iter()
+                .find(|a| a.kind() != "keyword_argument")
+                .and_then(|a| string_value(*a, bytes))
+            else {
+                continue;
+            };
+
+            let methods = if matches!(verb, "route" | "api_route") {
+                let listed = keyword_arg(&args, "methods", bytes)
+                    .map(|list| {
+                        named_args(Some(list))
+                            .iter()
+                            .filter_map(|m| string_value(*m, bytes))
+                            .filter_map(|m| HttpMethod::from_name(&m))
+                            .collect::<Vec<_>>()
+                    })
+                    .unwrap_or_default();
+                if listed.is_empty() {
+                    vec![HttpMethod::Get]
+                } else {
+                    listed
+                }
+            } else {
+                HttpMethod::from_name(verb).into_iter().collect()
+            };
+
+            let (prefix, group) = match prefixes.get(router) {
+                Some((prefix, decl)) => {
+                    (prefix.as_str(), Some(routes.group(prefix.clone(), *decl)))
+                }
+                None => ("", None),
+            };
+            for method in methods {
+                let mut site = RouteSite::new(method, join_route(prefix, &path), node);
+                site.handler = def
+                    .child_by_field_name("name")
+                    .map(|n| get_node_text(n, bytes).to_string());
+                site.auth = auth.clone();
+                site.group = group;
+                routes.sites.push(site);
+            }
+        }
+    }
+}
+
+/// `@router.get("/x")` or `@app.route("/x")`: the router name, the
+/// decorator method and its arguments.
+fn python_route_decorator<'t, 'b>(
+    expr: tree_sitter::Node<'t>,
+    bytes: &'b [u8],
+) -> Option<(&'b str, &'b str, Vec<tree_sitter::Node<'t>>)> {
+    if expr.kind() != "call" {
+        return None;
+    }
+    let function = expr
+        .child_by_field_name("function")
+        .filter(|f| f.kind() == "attribute")?;
+    let router = get_node_text(function.child_by_field_name("object")?, bytes);
+    let verb = get_node_text(function.child_by_field_name("attribute")?, bytes);
+    if !matches!(verb, "route" | "api_route") && HttpMethod::from_name(verb).is_none() {
+        return None;
+    }
+    Some((
+        router,
+        verb,
+        named_args(expr.child_by_field_name("arguments")),
+    ))
+}
+
+pub(super) fn keyword_arg<'t>(
+    args: &[tree_sitter::Node<'t>],
+    name: &str,
+    bytes: &[u8],
+) -> Option<tree_sitter::Node<'t>> {
+    args.iter()
+        .filter(|a| a.kind() == "keyword_argument")
+        .find(|a| {
+            a.child_by_field_name("name")
+                .is_some_and(|n| get_node_text(n, bytes) == name)
+        })
+        .and_then(|a| a.child_by_field_name("value"))
+}
+
+/// Module-level `APIRouter(prefix=...)` and `Blueprint(..., url_prefix=...)`
+/// assignments, keyed by variable name.
+fn python_router_prefixes<'t>(
+    root: tree_sitter::Node<'t>,
+    bytes: &[u8],
+) -> HashMap<String, (String, tree_sitter::Node<'t>)> {
+    let mut prefixes = HashMap::new();
+    let mut cursor = root.walk();
+    for stmt in root.named_children(&mut cursor) {
+        let Some(assignment) = stmt
+            .named_child(0)
+            .filter(|a| stmt.kind() == "expression_statement" && a.kind() == "assignment")
+        else {
+            continue;
+        };
+        let (Some(left), Some(right)) = (
+            assignment.child_by_field_name("left"),
+            assignment.child_by_field_name("right"),
+        ) else {
+            continue;
+        };
+        if right.kind() != "call" {
+            continue;
+        }
+        let keyword = match callee_name(right, bytes) {
+            "APIRouter" => "prefix",
+            "Blueprint" => "url_prefix",
+            _ => continue,
+        };
+        let args = named_args(right.child_by_field_name("arguments"));
+        if let Some(prefix) =
+            keyword_arg(&args, keyword, bytes).and_then(|v| string_value(v, bytes))
+        {
+            prefixes.insert(
+                get_node_text(left, bytes).to_string(),
+                (join_route("", &prefix), stmt),
+            );
+        }
+    }
+    prefixes
+}
+
+/// Auth decorators (`@login_required`) and FastAPI dependencies
+/// (`Depends(get_current_user)`) in the signature or decorator arguments.
+fn python_auth(
+    decorators: &[tree_sitter::Node],
+    def: tree_sitter::Node,
+    bytes: &[u8],
+) -> Option<AuthRequirement> {
+    if let Some(name) = decorators
+        .iter()
+        .map(|d| callee_name(*d, bytes))
+        .find(|name| is_auth_marker(name))
+    {
+        return Some(auth_requirement(name, Vec::new()));
+    }
+
+    let scopes = decorators
+        .iter()
+        .copied()
+        .chain(def.child_by_field_name("parameters"));
+    scopes
+        .flat_map(preorder)
+        .filter(|n| n.kind() == "call" && matches!(callee_name(*n, bytes), "Depends" | "Security"))
+        .filter_map(|n| {
+            named_args(n.child_by_field_name("arguments"))
+                .first()
+                .copied()
+        })
+        .map(|dep| get_node_text(dep, bytes))
+        .find(|dep| is_auth_marker(dep))
+        .map(|dep| auth_requirement(dep, Vec::new()))
+}
+
+// =============================================================================
+// Java: Spring MVC
+// =============================================================================
+
+fn java_routes<'t>(root: tree_sitter::Node<'t>, bytes: &[u8], routes: &mut Routes<'t>) {
+    for class in preorder(root) {
+        if class.kind() != "class_declaration" {
+            continue;
+        }
+        let annotations = java_annotations(class);
+        let names: Vec<&str> = annotations
+            .iter()
+            .map(|a| annotation_name(*a, bytes))
+            .collect();
+        if !names
+            .iter()
+            .any(|n| matches!(*n, "RestController" | "Controller" | "RequestMapping"))
+        {
+            continue;
+        }
+        let (Some(name), Some(body)) = (
+            class.child_by_field_name("name"),
+            class.child_by_field_name("body"),
+        ) else {
+            continue;
+        };
+
+        let owner = get_node_text(name, bytes).to_string();
+        let prefix = annotations
+            .iter()
+            .find(|a| annotation_name(**a, bytes) == "RequestMapping")
+            .and_then(|a| java_mapping_paths(*a, bytes).into_iter().next())
+            .unwrap_or_default();
+        let class_auth = java_auth(&annotations, bytes);
+        let group = (!prefix.is_empty()).then(|| routes.group(join_route("", &prefix), class));
+
+        let mut cursor = body.walk();
+        for method in body.named_children(&mut cursor) {
+            if method.kind() != "method_declaration" {
+                continue;
+            }
+            let annotations = java_annotations(method);
+            for annotation in &annotations {
+                let name = annotation_name(*annotation, bytes);
+                let methods = if name == "RequestMapping" {
+                    java_request_methods(*annotation, bytes)
+                } else {
+                    name.strip_suffix("Mapping")
+                        .and_then(HttpMethod::from_name)
+                        .into_iter()
+                        .collect()
+                };
+                if methods.is_empty() {
+                    continue;
+                }
+                let mut paths = java_mapping_paths(*annotation, bytes);
+                if paths.is_empty() {
+                    paths.push(String::new());
+                }
+
+                for path in &paths {
+                    for method_kind in &methods {
+                        let mut site =
+                            RouteSite::new(*method_kind, join_route(&prefix, path), method);
+                        site.handler = method
+                            .child_by_field_name("name")
+                            .map(|n| get_node_text(n, bytes).to_string());
+                        site.owner = Some(owner.clone());
+                        site.auth = java_auth(&annotations, bytes).or_else(|| class_auth.clone());
+                        site.request_schema = java_request_body(method, bytes);
+                        site.group = group;
+                        routes.sites.push(site);
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub(super) fn java_annotations(node: tree_sitter::Node) -> Vec<tree_sitter::Node> {
+    let mut cursor = node.walk();
+    let Some(modifiers) = node.children(&mut cursor).find(|c| c.kind() == "modifiers") else {
+        return Vec::new();
+    };
+    let mut cursor = modifiers.walk();
+    modifiers
+        .named_children(&mut cursor)
+        .filter(|c| matches!(c.kind(), "annotation" | "marker_annotation"))
+        .collect()
+}
+
+pub(super) fn annotation_name<'b>(annotation: tree_sitter::Node, bytes: &'b [u8]) -> &'b str {
+    annotation
+        .child_by_field_name("name")
+        .map(|n| last_segment(get_node_text(n, bytes)))
+        .unwrap_or_default()
+}
+
+/// Strings in an annotation value: a literal or a `{...}` array of literals.
+fn annotation_strings(value: tree_sitter::Node, bytes: &[u8]) -> Vec<String> {
+    if value.kind() == "element_value_array_initializer" {
+        return named_args(Some(value))
+            .iter()
+            .filter_map(|v| string_value(*v, bytes))
+            .collect();
+    }
+    string_value(value, bytes).into_iter().collect()
+}
+
+/// Paths of a mapping annotation: the positional value or `value`/`path`.
+fn java_mapping_paths(annotation: tree_sitter::Node, bytes: &[u8]) -> Vec<String> {
+    let mut paths = Vec::new();
+    for arg in named_args(annotation.child_by_field_name("arguments")) {
+        if arg.kind() == "element_value_pair" {
+            let key = arg
+                .child_by_field_name("key")
+                .map(|k| get_node_text(k, bytes));
+            if matches!(key, Some("value" | "path"))
+                && let Some(value) = arg.child_by_field_name("value")
+            {
+                paths.extend(annotation_strings(value, bytes));
+            }
+        } else {
+            paths.extend(annotation_strings(arg, bytes));
+        }
+    }
+    paths
+}
+
+/// `method = RequestMethod.POST` (or an array of them); any method otherwise.
+fn java_request_methods(annotation: tree_sitter::Node, bytes: &[u8]) -> Vec<HttpMethod> {
+    let value = named_args(annotation.child_by_field_name("arguments"))
+        .into_iter()
+        .filter(|a| a.kind() == "element_value_pair")
+        .find(|a| {
+            a.child_by_field_name("key")
+                .is_some_and(|k| get_node_text(k, bytes) == "method")
+        })
+        .and_then(|a| a.child_by_field_name("value"));
+    let Some(value) = value else {
+        return vec![HttpMethod::Any];
+    };
+    let values = if value.kind() == "element_value_array_initializer" {
+        named_args(Some(value))
+    } else {
+        vec![value]
+    };
+    values
+        .iter()
+        .filter_map(|v| HttpMethod::from_name(last_segment(get_node_text(*v, bytes))))
+        .collect()
+}
+
+/// `@PreAuthorize("hasRole('ADMIN')")`, `@Secured`, `@RolesAllowed`.
+fn java_auth(annotations: &[tree_sitter::Node], bytes: &[u8]) -> Option<AuthRequirement> {
+    annotations.iter().find_map(|annotation| {
+        let name = annotation_name(*annotation, bytes);
+        let strings: Vec<String> = named_args(annotation.child_by_field_name("arguments"))
+            .iter()
+            .flat_map(|a| annotation_strings(*a, bytes))
+            .collect();
+        let roles = match name {
+            "PreAuthorize" => strings.iter().flat_map(|s| quoted_roles(s)).collect(),
+            "Secured" | "RolesAllowed" => strings,
+            _ => return None,
+        };
+        Some(auth_requirement(name, roles))
+    })
+}
+
+/// Single-quoted arguments of `hasRole('A')` / `hasAnyRole('A', 'B')`.
+fn quoted_roles(expression: &str) -> Vec<String> {
+    if !expression.contains("Role") {
+        return Vec::new();
+    }
+    expression
+        .split('\'')
+        .skip(1)
+        .step_by(2)
+        .map(str::to_string)
+        .collect()
+}
+
+fn java_request_body(method: tree_sitter::Node, bytes: &[u8]) -> Option<String> {
+    let params = method.child_by_field_name("parameters")?;
+    let mut cursor = params.walk();
+    params
+        .named_children(&mut cursor)
+        .filter(|p| p.kind() == "formal_parameter")
+        .find(|p| {
+            java_annotations(*p)
+                .iter()
+                .any(|a| annotation_name(*a, bytes) == "RequestBody")
+        })
+        .and_then(|p| p.child_by_field_name("type"))
+        .map(|t| get_node_text(t, bytes).to_string())
+}
+
+// =============================================================================
+// Rust: axum and actix-web
+// =============================================================================
+
+fn rust_routes<'t>(root: tree_sitter::Node<'t>, bytes: &[u8], routes: &mut Routes<'t>) {
+    for node in preorder(root) {
+        match node.kind() {
+            "call_expression" => rust_router_route(node, bytes, routes),
+            "attribute_item" => rust_attribute_route(node, bytes, routes),
+            _ => {}
+        }
+    }
+}
+
+/// `.route("/users", get(list).post(create))` (axum),
+/// `.route("/x", web::get().to(h))` and `web::resource("/x").route(...)`
+/// (actix-web), under any enclosing `.nest("/api", ..)` or
+/// `web::scope("/api")` prefixes.
+fn rust_router_route<'t>(call: tree_sitter::Node<'t>, bytes: &[u8], routes: &mut Routes<'t>) {
+    let Some(function) = call
+        .child_by_field_name("function")
+        .filter(|f| f.kind() == "field_expression")
+    else {
+        return;
+    };
+    if function
+        .child_by_field_name("field")
+        .is_none_or(|f| get_node_text(f, bytes) != "route")
+    {
+        return;
+    }
+
+    let args = named_args(call.child_by_field_name("arguments"));
+    let (path, router) = match args.as_slice() {
+        [path, router] => (string_value(*path, bytes), *router),
+        [router] => (
+            function
+                .child_by_field_name("value")
+                .and_then(|v| scoped_call_path(v, "resource", bytes)),
+            *router,
+        ),
+        _ => return,
+    };
+    let Some(path) = path else {
+        return;
+    };
+
+    let prefix = rust_route_prefix(call, bytes);
+    for (method, handler) in method_router(router, bytes) {
+        let mut site = RouteSite::new(method, join_route(&prefix, &path), call);
+        site.handler = handler;
+        routes.sites.push(site);
+    }
+}
+
+/// Path argument of a call to `name` (`web::resource("/x")`, `web::scope("/api")`).
+fn scoped_call_path(node: tree_sitter::Node, name: &str, bytes: &[u8]) -> Option<String> {
+    if node.kind() != "call_expression" || callee_name(node, bytes) != name {
+        return None;
+    }
+    named_args(node.child_by_field_name("arguments"))
+        .first()
+        .and_then(|a| string_value(*a, bytes))
+}
+
+/// Prefixes contributed by `web::scope(..)` receivers and enclosing
+/// `.nest(..)` calls, outermost first.
+fn rust_route_prefix(call: tree_sitter::Node, bytes: &[u8]) -> String {
+    let mut prefixes = Vec::new();
+    let mut current = call;
+    loop {
+        prefixes.extend(receiver_scope(current, bytes));
+
+        // Climb to the next call that receives `current` as an argument
+        let mut node = current;
+        let outer = loop {
+            let Some(parent) = node.parent() else {
+                break None;
+            };
+            if parent.kind() == "function_item" {
+                break None;
+            }
+            if parent.kind() == "arguments"
+                && let Some(outer) = parent.parent().filter(|p| p.kind() == "call_expression")
+            {
+                break Some(outer);
+            }
+            node = parent;
+        };
+        let Some(outer) = outer else {
+            break;
+        };
+
+        let is_nest = outer
+            .child_by_field_name("function")
+            .and_then(|f| f.child_by_field_name("field"))
+            .is_some_and(|f| get_node_text(f, bytes) == "nest");
+        if is_nest
+            && let Some(prefix) = named_args(outer.child_by_field_name("arguments"))
+                .first()
+                .and_then(|a| string_value(*a, bytes))
+        {
+            prefixes.push(prefix);
+        }
+        current = outer;
+    }
+
+    prefixes
+        .iter()
+        .rev()
+        .fold(String::new(), |acc, p| join_route(&acc, p))
+}
+
+/// `web::scope("/api")` anywhere in a method call's receiver chain.
+fn receiver_scope(call: tree_sitter::Node, bytes: &[u8]) -> Option<String> {
+    let mut function = call.child_by_field_name("function")?;
+    while function.kind() == "field_expression" {
+        let receiver = function.child_by_field_name("value")?;
+        if receiver.kind() != "call_expression" {
+            return None;
+        }
+        if let Some(prefix) = scoped_call_path(receiver, "scope", bytes) {
+            return Some(prefix);
+        }
+        function = receiver.child_by_field_name("function")?;
+    }
+    None
+}
+
+/// Methods and handlers of a method router: `get(a).post(b)`,
+/// `axum::routing::get(a)` and `web::post().to(b)`, in source order.
+fn method_router(expr: tree_sitter::Node, bytes: &[u8]) -> Vec<(HttpMethod, Option<String>)> {
+    let mut found = Vec::new();
+    for call in preorder(expr) {
+        if call.kind() != "call_expression" {
+            continue;
+        }
+        let args = named_args(call.child_by_field_name("arguments"));
+        let (Some(function), [handler]) = (call.child_by_field_name("function"), args.as_slice())
+        else {
+            continue;
+        };
+
+        let method = match function.kind() {
+            "field_expression" => {
+                let field = function
+                    .child_by_field_name("field")
+                    .map(|f| get_node_text(f, bytes))
+                    .unwrap_or_default();
+                if field == "to" {
+                    function
+                        .child_by_field_name("value")
+                        .filter(|v| v.kind() == "call_expression")
+                        .filter(|v| named_args(v.child_by_field_name("arguments")).is_empty())
+                        .and_then(|v| HttpMethod::from_name(callee_name(v, bytes)))
+                } else {
+                    HttpMethod::from_name(field)
+                }
+            }
+            "identifier" | "scoped_identifier" => {
+                HttpMethod::from_name(last_segment(get_node_text(function, bytes)))
+            }
+            _ => None,
+        };
+        if let Some(method) = method {
+            found.push((handler.start_byte(), method, handler_name(*handler, bytes)));
+        }
+    }
+
+    found.sort_by_key(|(start, _, _)| *start);
+    found.into_iter().map(|(_, m, h)| (m, h)).collect()
+}
+
+/// `#[get("/items/{id}")]` on a handler function (actix-web, Rocket).
+fn rust_attribute_route<'t>(item: tree_sitter::Node<'t>, bytes: &[u8], routes: &mut Routes<'t>) {
+    let Some(attribute) = item.named_child(0).filter(|a| a.kind() == "attribute") else {
+        return;
+    };
+    let Some(method) = attribute
+        .named_child(0)
+        .filter(|n| n.kind() == "identifier")
+        .and_then(|n| HttpMethod::from_name(get_node_text(n, bytes)))
+    else {
+        return;
+    };
+    let Some(path) = attribute
+        .child_by_field_name("arguments")
+        .and_then(|args| args.named_child(0))
+        .and_then(|s| string_value(s, bytes))
+    else {
+        return;
+    };
+
+    let mut next = item.next_named_sibling();
+    while let Some(sibling) = next
+        && matches!(
+            sibling.kind(),
+            "attribute_item" | "line_comment" | "block_comment"
+        )
+    {
+        next = sibling.next_named_sibling();
+    }
+    let Some(func) = next.filter(|n| n.kind() == "function_item") else {
+        return;
+    };
+
+    let mut site = RouteSite::new(method, join_route("", &path), func);
+    site.handler = func
+        .child_by_field_name("name")
+        .map(|n| get_node_text(n, bytes).to_string());
+    routes.sites.push(site);
+}
+
+// =============================================================================
+// Go: net/http, gin, echo, chi
+// =============================================================================
+
+fn go_routes<'t>(root: tree_sitter::Node<'t>, bytes: &[u8], routes: &mut Routes<'t>) {
+    // Router group variables (`v1 := r.Group("/v1")`) → (prefix, group index)
+    let mut groups: HashMap<String, (String, usize)> = HashMap::new();
+
+    for node in preorder(root) {
+        match node.kind() {
+            "short_var_declaration" | "assignment_statement" => {
+                go_route_group(node, bytes, routes, &mut groups)
+            }
+            "call_expression" => {
+                if let Some(site) = go_route(node, bytes, &groups) {
+                    routes.sites.push(site);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn go_route_group<'t>(
+    decl: tree_sitter::Node<'t>,
+    bytes: &[u8],
+    routes: &mut Routes<'t>,
+    groups: &mut HashMap<String, (String, usize)>,
+) {
+    let (Some(left), Some(right)) = (
+        decl.child_by_field_name("left")
+            .and_then(|l| l.named_child(0)),
+        decl.child_by_field_name("right")
+            .and_then(|r| r.named_child(0)),
+    ) else {
+        return;
+    };
+    let Some(function) = right
+        .child_by_field_name("function")
+        .filter(|f| right.kind() == "call_expression" && f.kind() == "selector_expression")
+    else {
+        return;
+    };
+    if function
+        .child_by_field_name("field")
+        .is_none_or(|f| get_node_text(f, bytes) != "Group")
+    {
+        return;
+    }
+    let Some(path) = named_args(right.child_by_field_name("arguments"))
+        .first()
+        .and_then(|a| string_value(*a, bytes))
+    else {
+        return;
+    };
+
+    let parent = function
+        .child_by_field_name("operand")
+        .and_then(|o| groups.get(get_node_text(o, bytes)))
+        .map(|(p, _)| p.clone())
+        .unwrap_or_default();
+    let prefix = join_route(&parent, &path);
+    let group = routes.group(prefix.clone(), decl);
+    groups.insert(get_node_text(left, bytes).to_string(), (prefix, group));
+}
+
+/// `http.HandleFunc("/x", h)` (optionally `"GET /x"`), gin/echo `r.GET("/x", h)`
+/// and chi `r.Get("/x", h)`, with middleware between path and handler.
+fn go_route<'t>(
+    call: tree_sitter::Node<'t>,
+    bytes: &[u8],
+    groups: &HashMap<String, (String, usize)>,
+) -> Option<RouteSite<'t>> {
+    let function = call
+        .child_by_field_name("function")
+        .filter(|f| f.kind() == "selector_expression")?;
+    let field = get_node_text(function.child_by_field_name("field")?, bytes);
+
+    let args = named_args(call.child_by_field_name("arguments"));
+    let (first, rest) = args.split_first()?;
+    let pattern = string_value(*first, bytes)?;
+
+    let (method, path) = if matches!(field, "HandleFunc" | "Handle") {
+        match pattern.split_once(' ') {
+            Some((verb, path)) => (HttpMethod::from_name(verb)?, path.trim().to_string()),
+            None => (HttpMethod::Any, pattern),
+        }
+    } else if field.starts_with(|c: char| c.is_ascii_uppercase()) {
+        (HttpMethod::from_name(field)?, pattern)
+    } else {
+        return None;
+    };
+    if !path.starts_with('/') {
+        return None;
+    }
+    let (handler, middleware) = rest.split_last()?;
+
+    let group = function
+        .child_by_field_name("operand")
+        .and_then(|o| groups.get(get_node_text(o, bytes)));
+    let prefix = group.map(|(p, _)| p.as_str()).unwrap_or_default();
+
+    let mut site = RouteSite::new(method, join_route(prefix, &path), call);
+    site.handler = handler_name(*handler, bytes);
+    site.auth = middleware_auth(middleware, bytes);
+    site.group = group.map(|(_, g)| *g);
+    Some(site)
+}
+
+// =============================================================================
+// Ruby: Rails routing DSL
+// =============================================================================
+
+/// Actions generated by `resources`: name, method and path suffix.
+const RESOURCES_ACTIONS: &[(&str, HttpMethod, &str)] = &[
+    ("index", HttpMethod::Get, ""),
+    ("create", HttpMethod::Post, ""),
+    ("new", HttpMethod::Get, "new"),
+    ("show", HttpMethod::Get, ":id"),
+    ("edit", HttpMethod::Get, ":id/edit"),
+    ("update", HttpMethod::Patch, ":id"),
+    ("destroy", HttpMethod::Delete, ":id"),
+];
+
+/// Actions generated by singular `resource` (no `index`, no `:id`).
+const RESOURCE_ACTIONS: &[(&str, HttpMethod, &str)] = &[
+    ("create", HttpMethod::Post, ""),
+    ("new", HttpMethod::Get, "new"),
+    ("show", HttpMethod::Get, ""),
+    ("edit", HttpMethod::Get, "edit"),
+    ("update", HttpMethod::Patch, ""),
+    ("destroy", HttpMethod::Delete, ""),
+];
+
+fn is_rails_routes_file(path: &str) -> bool {
+    path.ends_with("config/routes.rb") || path.contains("config/routes/")
+}
+
+#[derive(Clone, Default)]
+struct RailsScope {
+    path: String,
+    /// Controller namespace from `namespace`/`scope module:` (`admin/`)
+    module: String,
+    resource: Option<RailsResource>,
+    auth: Option<AuthRequirement>,
+}
+
+#[derive(Clone)]
+struct RailsResource {
+    controller: String,
+    group: usize,
+    member: String,
+    collection: String,
+}
+
+impl RailsScope {
+    fn controller(&self, name: &str) -> String {
+        format!("{}{}", self.module, name)
+    }
+}
+
+fn rails_routes<'t>(root: tree_sitter::Node<'t>, bytes: &[u8], routes: &mut Routes<'t>) {
+    for node in preorder(root) {
+        if node.kind() == "call"
+            && node
+                .child_by_field_name("method")
+                .is_some_and(|m| get_node_text(m, bytes) == "draw")
+        {
+            rails_block(node, bytes, &RailsScope::default(), routes);
+        }
+    }
+}
+
+/// Statements of the block attached to `call`.
+fn rails_block<'t>(
+    call: tree_sitter::Node<'t>,
+    bytes: &[u8],
+    scope: &RailsScope,
+    routes: &mut Routes<'t>,
+) {
+    let Some(body) = call
+        .child_by_field_name("block")
+        .and_then(|b| b.child_by_field_name("body"))
+    else {
+        return;
+    };
+    let mut cursor = body.walk();
+    for stmt in body.named_children(&mut cursor) {
+        if stmt.kind() == "call" && stmt.child_by_field_name("receiver").is_none() {
+            rails_statement(stmt, bytes, scope, routes);
+        }
+    }
+}
+
+fn rails_statement<'t>(
+    call: tree_sitter::Node<'t>,
+    bytes: &[u8],
+    scope: &RailsScope,
+    routes: &mut Routes<'t>,
+) {
+    let Some(name) = call
+        .child_by_field_name("method")
+        .map(|m| get_node_text(m, bytes))
+    else {
+        return;
+    };
+    let args = named_args(call.child_by_field_name("arguments"));
+    let positional = || args.iter().filter_map(|a| ruby_name(*a, bytes));
+
+    match name {
+        "get" | "post" | "put" | "patch" | "delete" | "match" => {
+            rails_verb(call, name, &args, bytes, scope, routes)
+        }
+        "root" => {
+            let to = positional()
+                .next()
+                .or_else(|| rails_option(&args, "to", bytes));
+            let mut site = RouteSite::new(HttpMethod::Get, join_route(&scope.path, ""), call);
+            site.handler = to.map(|t| scope.controller(&t));
+            site.auth = scope.auth.clone();
+            routes.sites.push(site);
+        }
+        "resources" | "resource" => {
+            for resource in positional().collect::<Vec<_>>() {
+                rails_resources(
+                    call,
+                    name == "resources",
+                    &resource,
+                    &args,
+                    bytes,
+                    scope,
+                    routes,
+                );
+            }
+        }
+        "namespace" => {
+            let Some(ns) = positional().next() else {
+                return;
+            };
+            let nested = RailsScope {
+                path: join_route(&scope.path, &ns),
+                module: format!("{}{}/", scope.module, ns),
+                ..scope.clone()
+            };
+            rails_block(call, bytes, &nested, routes);
+        }
+        "scope" => {
+            let path = positional()
+                .next()
+                .or_else(|| rails_option(&args, "path", bytes))
+                .unwrap_or_default();
+            let module = rails_option(&args, "module", bytes)
+                .map(|m| format!("{}{}/", scope.module, m))
+                .unwrap_or_else(|| scope.module.clone());
+            let nested = RailsScope {
+                path: join_route(&scope.path, &path),
+                module,
+                ..scope.clone()
+            };
+            rails_block(call, bytes, &nested, routes);
+        }
+        "member" | "collection" => {
+            let Some(resource) = &scope.resource else {
+                return;
+            };
+            let path = if name == "member" {
+                &resource.member
+            } else {
+                &resource.collection
+            };
+            let nested = RailsScope {
+                path: path.clone(),
+                ..scope.clone()
+            };
+            rails_block(call, bytes, &nested, routes);
+        }
+        "authenticate" | "authenticated" => {
+            let nested = RailsScope {
+                auth: Some(auth_requirement(name, Vec::new())),
+                ..scope.clone()
+            };
+            rails_block(call, bytes, &nested, routes);
+        }
+        _ => {}
+    }
+}
+
+/// `get 'photos/:id', to: 'photos#show'`, `post '/login' => 'sessions#create'`,
+/// `match 'x', via: [:get, :post]`, and `get :preview` inside a resource.
+fn rails_verb<'t>(
+    call: tree_sitter::Node<'t>,
+    verb: &str,
+    args: &[tree_sitter::Node],
+    bytes: &[u8],
+    scope: &RailsScope,
+    routes: &mut Routes<'t>,
+) {
+    // Hash-rocket form: the first pair's key is the path
+    let rocket = args.iter().filter(|a| a.kind() == "pair").find_map(|pair| {
+        let key = pair.child_by_field_name("key")?;
+        let value = pair.child_by_field_name("value")?;
+        (key.kind() == "string")
+            .then(|| Some((string_value(key, bytes)?, string_value(value, bytes)?)))
+            .flatten()
+    });
+    let first = args.first().and_then(|a| ruby_name(*a, bytes));
+    let Some((path, mut to)) = rocket
+        .map(|(path, to)| (path, Some(to)))
+        .or_else(|| first.clone().map(|p| (p, rails_option(args, "to", bytes))))
+    else {
+        return;
+    };
+
+    if to.is_none() {
+        let action = rails_option(args, "action", bytes).or_else(|| {
+            args.first()
+                .filter(|a| a.kind() == "simple_symbol")
+                .and(first.clone())
+        });
+        let controller = rails_option(args, "controller", bytes)
+            .map(|c| scope.controller(&c))
+            .or_else(|| scope.resource.as_ref().map(|r| r.controller.clone()));
+        if let (Some(controller), Some(action)) = (controller, action) {
+            to = Some(format!("{}#{}", controller, action));
+        }
+    } else {
+        to = to.map(|t| scope.controller(&t));
+    }
+
+    let base = match (rails_option(args, "on", bytes).as_deref(), &scope.resource) {
+        (Some("member"), Some(resource)) => resource.member.clone(),
+        (Some("collection"), Some(resource)) => resource.collection.clone(),
+        _ => scope.path.clone(),
+    };
+    let methods: Vec<HttpMethod> = if verb == "match" {
+        let via = rails_list(args, "via", bytes);
+        if via.is_empty() {
+            vec![HttpMethod::Any]
+        } else {
+            via.iter()
+                .filter_map(|v| HttpMethod::from_name(v))
+                .collect()
+        }
+    } else {
+        HttpMethod::from_name(verb).into_iter().collect()
+    };
+
+    for method in methods {
+        let mut site = RouteSite::new(method, join_route(&base, &path), call);
+        site.handler = to.clone();
+        site.auth = scope.auth.clone();
+        site.group = scope.resource.as_ref().map(|r| r.group);
+        routes.sites.push(site);
+    }
+}
+
+/// `resources :photos, only: [:index, :show]` and singular `resource :profile`,
+/// expanded into Rails' conventional actions.
+fn rails_resources<'t>(
+    call: tree_sitter::Node<'t>,
+    plural: bool,
+    name: &str,
+    args: &[tree_sitter::Node],
+    bytes: &[u8],
+    scope: &RailsScope,
+    routes: &mut Routes<'t>,
+) {
+    let segment = rails_option(args, "path", bytes).unwrap_or_else(|| name.to_string());
+    let base = join_route(&scope.path, &segment);
+    let controller =
+        scope.controller(&rails_option(args, "controller", bytes).unwrap_or_else(|| {
+            if plural || name.ends_with('s') {
+                name.to_string()
+            } else {
+                format!("{}s", name)
+            }
+        }));
+    let only = rails_list(args, "only", bytes);
+    let except = rails_list(args, "except", bytes);
+    let group = routes.group(base.clone(), call);
+
+    let actions = if plural {
+        RESOURCES_ACTIONS
+    } else {
+        RESOURCE_ACTIONS
+    };
+    for (action, method, suffix) in actions {
+        if (!only.is_empty() && !only.iter().any(|a| a == action))
+            || except.iter().any(|a| a == action)
+        {
+            continue;
+        }
+        let mut site = RouteSite::new(*method, join_route(&base, suffix), call);
+        site.handler = Some(format!("{}#{}", controller, action));
+        site.auth = scope.auth.clone();
+        site.group = Some(group);
+        routes.sites.push(site);
+    }
+
+    let (member, nested) = if plural {
+        let singular = name.strip_suffix('s').unwrap_or(name);
+        (
+            join_route(&base, ":id"),
+            join_route(&base, &format!(":{}_id", singular)),
+        )
+    } else {
+        (base.clone(), base.clone())
+    };
+    let inner = RailsScope {
+        path: nested,
+        resource: Some(RailsResource {
+            controller,
+            group,
+            member,
+            collection: base,
+        }),
+        ..scope.clone()
+    };
+    rails_block(call, bytes, &inner, routes);
+}
+
+/// Symbol or string argument as a plain name (`:photos` → `photos`).
+pub(super) fn ruby_name(node: tree_sitter::Node, bytes: &[u8]) -> Option<String> {
+    match node.kind() {
+        "simple_symbol" | "hash_key_symbol" => Some(
+            get_node_text(node, bytes)
+                .trim_start_matches(':')
+                .to_string(),
+        ),
+        "string" => string_value(node, bytes),
+        _ => None,
+    }
+}
+
+pub(super) fn rails_pair<'t>(
+    args: &[tree_sitter::Node<'t>],
+    key: &str,
+    bytes: &[u8],
+) -> Option<tree_sitter::Node<'t>> {
+    args.iter()
+        .filter(|a| a.kind() == "pair")
+        .find(|pair| {
+            pair.child_by_field_name("key")
+                .and_then(|k| ruby_name(k, bytes))
+                .is_some_and(|k| k.trim_end_matches(':') == key)
+        })
+        .and_then(|pair| pair.child_by_field_name("value"))
+}
+
+/// Scalar option (`to: 'photos#show'`, `on: :member`).
+pub(super) fn rails_option(args: &[tree_sitter::Node], key: &str, bytes: &[u8]) -> Option<String> {
+    rails_pair(args, key, bytes).and_then(|v| ruby_name(v, bytes))
+}
+
+/// List option (`only: [:index, :show]` or `only: :index`).
+fn rails_list(args: &[tree_sitter::Node], key: &str, bytes: &[u8]) -> Vec<String> {
+    let Some(value) = rails_pair(args, key, bytes) else {
+        return Vec::new();
+    };
+    if value.kind() == "array" {
+        let mut cursor = value.walk();
+        return value
+            .named_children(&mut cursor)
+            .filter_map(|v| ruby_name(v, bytes))
+            .collect();
+    }
+    ruby_name(value, bytes).into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{
+        GoParser, JavaParser, Parser, PythonParser, RubyParser, RustParser, TypeScriptParser,
+    };
+    use super::*;
+
+    fn api<'a>(result: &'a ParseResult, id: &str) -> &'a ApiMetadata {
+        result
+            .nodes
+            .iter()
+            .find(|n| n.id == id)
+            .and_then(|n| n.metadata.api_metadata.as_ref())
+            .unwrap_or_else(|| panic!("missing {}", id))
+    }
+
+    fn routes_to<'a>(result: &'a ParseResult, api_id: &str) -> Option<&'a str> {
+        result
+            .edges
+            .iter()
+            .find(|e| e.edge_type == EdgeType::RoutesTo && e.source_id == api_id)
+            .map(|e| e.target_id.as_str())
+    }
+
+    #[test]
+    fn test_join_route() {
+        assert_eq!(join_route("", ""), "/");
+        assert_eq!(join_route("users", ":id"), "/users/:id");
+        assert_eq!(join_route("/api/", "/v1/items/"), "/api/v1/items");
+    }
+
+    #[test]
+    fn test_express_and_nest_routes() {
+        let code = r#"
+function getUser(req, res) {}
+router.get('/users/:id', requireAuth, getUser);
+app.post("/login", (req, res) => {});
+cache.get('key', fallback);
+axios.post('/signup', payload);
+http.get('/health', opts);
+api.delete('/sessions/current', { headers });
+$.get('/items', function (data) {});
+
+@Controller('users')
+export class UsersController {
+  @Get(':id')
+  @UseGuards(AuthGuard('jwt'))
+  findOne(@Param('id') id: string) {}
+
+  @Post()
+  @Roles('admin')
+  create(@Body() dto: CreateUserDto) {}
+}
+"#;
+        let result = TypeScriptParser::new()
+            .unwrap()
+            .parse("app.ts", code)
+            .unwrap();
+
+        let get = api(&result, "api:app.ts:GET:/users/:id");
+        assert_eq!(
+            get.auth.as_ref().unwrap().auth_type.as_deref(),
+            Some("requireAuth")
+        );
+        assert_eq!(
+            routes_to(&result, "api:app.ts:GET:/users/:id"),
+            Some("function:app.ts:getUser")
+        );
+        assert!(api(&result, "api:app.ts:POST:/login").auth.is_none());
+        assert!(!result.nodes.iter().any(|n| n.name.contains("key")));
+        // HTTP client calls are not routes
+        for id in [
+            "api:app.ts:POST:/signup",
+            "api:app.ts:GET:/health",
+            "api:app.ts:DELETE:/sessions/current",
+            "api:app.ts:GET:/items",
+        ] {
+            assert!(!result.nodes.iter().any(|n| n.id == id), "{}", id);
+        }
+
+        // The Nest `GET /users/:id` route collides with Express's and is kept once
+        let create = api(&result, "api:app.ts:POST:/users");
+        assert_eq!(
+            create.auth.as_ref().unwrap().roles,
+            Some(vec!["admin".to_string()])
+        );
+        assert!(matches!(
+            create.request_schema,
+            Some(SchemaReference::Ref { ref ref_path }) if ref_path == "CreateUserDto"
+        ));
+        assert_eq!(
+            routes_to(&result, "api:app.ts:POST:/users"),
+            Some("class:app.ts:UsersController")
+        );
+        assert!(result.edges.iter().any(|e| e.edge_type == EdgeType::Owns
+            && e.source_id == "route:app.ts:/users"
+            && e.target_id == "api:app.ts:POST:/users"));
+    }
+
+    #[test]
+    fn test_fastapi_and_flask_routes() {
+        let code = r#"
+router = APIRouter(prefix="/items")
+bp = Blueprint("auth", __name__, url_prefix="/auth")
+
+@router.get("/{item_id}")
+async def read_item(item_id: int, user=Depends(get_current_user)):
+    pass
+
+@bp.route("/login", methods=["GET", "POST"])
+def login():
+    pass
+
+@app.route("/admin")
+@login_required
+def admin():
+    pass
+"#;
+        let result = PythonParser::new()
+            .unwrap()
+            .parse("views.py", code)
+            .unwrap();
+
+        let read = api(&result, "api:views.py:GET:/items/{item_id}");
+        assert_eq!(
+            read.auth.as_ref().unwrap().auth_type.as_deref(),
+            Some("get_current_user")
+        );
+        assert_eq!(
+            routes_to(&result, "api:views.py:GET:/items/{item_id}"),
+            Some("function:views.py:read_item")
+        );
+        assert!(api(&result, "api:views.py:GET:/auth/login").auth.is_none());
+        api(&result, "api:views.py:POST:/auth/login");
+        assert!(api(&result, "api:views.py:GET:/admin").auth.is_some());
+        assert!(result.nodes.iter().any(|n| n.id == "route:views.py:/items"));
+    }
+
+    #[test]
+    fn test_spring_routes() {
+        let code = r#"
+@RestController
+@RequestMapping("/api/users")
+public class UserController {
+    @GetMapping("/{id}")
+    @PreAuthorize("hasAnyRole('ADMIN', 'OPS')")
+    public User get(@PathVariable Long id) { return null; }
+
+    @RequestMapping(value = "/search", method = RequestMethod.POST)
+    public List<User> search(@RequestBody Query query) { return null; }
+
+    @DeleteMapping(path = {"/{id}", "/by-id/{id}"})
+    public void remove() {}
+}
+"#;
+        let result = JavaParser::new()
+            .unwrap()
+            .parse("UserController.java", code)
+            .unwrap();
+
+        let get = api(&result, "api:UserController.java:GET:/api/users/{id}");
+        assert_eq!(
+            get.auth.as_ref().unwrap().roles,
+            Some(vec!["ADMIN".to_string(), "OPS".to_string()])
+        );
+        assert_eq!(
+            routes_to(&result, "api:UserController.java:GET:/api/users/{id}"),
+            Some("method:UserController.java:get")
+        );
+        let search = api(&result, "api:UserController.java:POST:/api/users/search");
+        assert!(search.request_schema.is_some());
+        api(
+            &result,
+            "api:UserController.java:DELETE:/api/users/by-id/{id}",
+        );
+    }
+
+    #[test]
+    fn test_axum_and_actix_routes() {
+        let code = r#"
+async fn list_users() {}
+async fn create_user() {}
+
+fn app() -> Router {
+    Router::new()
+        .route("/users", get(list_users).post(create_user))
+        .nest("/admin", Router::new().route("/health", axum::routing::get(health)))
+}
+
+#[get("/items/{id}")]
+async fn item() -> impl Responder { "" }
+
+fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::scope("/api").service(web::resource("/x").route(web::post().to(make_x))));
+}
+"#;
+        let result = RustParser::new()
+            .unwrap()
+            .parse("src/web.rs", code)
+            .unwrap();
+
+        assert_eq!(
+            routes_to(&result, "api:src/web.rs:GET:/users"),
+            Some("function:src/web.rs:list_users")
+        );
+        assert_eq!(
+            routes_to(&result, "api:src/web.rs:POST:/users"),
+            Some("function:src/web.rs:create_user")
+        );
+        api(&result, "api:src/web.rs:GET:/admin/health");
+        assert_eq!(
+            routes_to(&result, "api:src/web.rs:GET:/items/{id}"),
+            Some("function:src/web.rs:item")
+        );
+        api(&result, "api:src/web.rs:POST:/api/x");
+    }
+
+    #[test]
+    fn test_go_routes() {
+        let code = r#"
+package main
+
+func health(w http.ResponseWriter, r *http.Request) {}
+
+func main() {
+	http.HandleFunc("/health", health)
+	mux.HandleFunc("POST /users/{id}", s.updateUser)
+	r := gin.Default()
+	v1 := r.Group("/v1")
+	v1.GET("/ping", AuthRequired(), ping)
+}
+"#;
+        let result = GoParser::new().unwrap().parse("main.go", code).unwrap();
+
+        assert_eq!(
+            routes_to(&result, "api:main.go:ANY:/health"),
+            Some("function:main.go:health")
+        );
+        api(&result, "api:main.go:POST:/users/{id}");
+        let ping = api(&result, "api:main.go:GET:/v1/ping");
+        assert_eq!(
+            ping.auth.as_ref().unwrap().auth_type.as_deref(),
+            Some("AuthRequired")
+        );
+        assert!(result.edges.iter().any(
+            |e| e.source_id == "route:main.go:/v1" && e.target_id == "api:main.go:GET:/v1/ping"
+        ));
+    }
+
+    #[test]
+    fn test_rails_routes() {
+        let code = r#"
+Rails.application.routes.draw do
+  root 'home#index'
+  get 'photos/:id/download', to: 'photos#download'
+  post '/login' => 'sessions#create'
+  resources :photos, only: [:index, :show] do
+    get :preview, on: :member
+    resources :comments, except: :destroy
+  end
+  namespace :admin do
+    resource :profile
+  end
+  authenticate :user do
+    match 'reports', to: 'reports#run', via: [:get, :post]
+  end
+end
+"#;
+        let result = RubyParser::new()
+            .unwrap()
+            .parse("config/routes.rb", code)
+            .unwrap();
+        let handler = |id: &str| {
+            result
+                .nodes
+                .iter()
+                .find(|n| n.id == id)
+                .and_then(|n| n.metadata.extra.get("handler"))
+                .and_then(|h| h.as_str())
+                .unwrap_or_else(|| panic!("missing {}", id))
+                .to_string()
+        };
+
+        assert_eq!(handler("api:config/routes.rb:GET:/"), "home#index");
+        assert_eq!(
+            handler("api:config/routes.rb:GET:/photos/:id/download"),
+            "photos#download"
+        );
+        assert_eq!(
+            handler("api:config/routes.rb:POST:/login"),
+            "sessions#create"
+        );
+        assert_eq!(
+            handler("api:config/routes.rb:GET:/photos/:id"),
+            "photos#show"
+        );
+        assert_eq!(
+            handler("api:config/routes.rb:GET:/photos/:id/preview"),
+            "photos#preview"
+        );
+        assert_eq!(
+            handler("api:config/routes.rb:POST:/photos/:photo_id/comments"),
+            "comments#create"
+        );
+        assert!(
+            !result
+                .nodes
+                .iter()
+                .any(|n| n.id == "api:config/routes.rb:DELETE:/photos/:photo_id/comments/:id")
+        );
+        assert_eq!(
+            handler("api:config/routes.rb:PATCH:/admin/profile"),
+            "admin/profiles#update"
+        );
+        assert!(
+            api(&result, "api:config/routes.rb:POST:/reports")
+                .auth
+                .is_some()
+        );
+
+        // Other Ruby files are not routing files
+        let other = RubyParser::new()
+            .unwrap()
+            .parse("lib/tasks.rb", code)
+            .unwrap();
+        assert!(!other.nodes.iter().any(|n| n.node_type == NodeType::Api));
+    }
+}
@@ -1,12 +1,62 @@
 use chrono::Utc;
 use tree_sitter::{Parser as TsParser, Query, QueryCursor, StreamingIterator};
 
-use super::{Language, ParseResult, Parser, create_file_node};
+use super::{
+    CallSyntax, DocSyntax, EntityDialect, Language, MetricsSyntax, ParseResult, Parser,
+    RouteDialect, TestSyntax, create_file_node, extract_calls, extract_docs, extract_entities,
+    extract_metrics, extract_routes, extract_settings, extract_tests, fingerprint_nodes,
+};
 use crate::types::{
     Edge, EdgeMetadata, EdgeType, EvidenceLocation, FunctionSignature, ImportType, InformationTier,
     Node, NodeMetadata, NodeStatus, NodeType, Parameter, Result, Visibility, WeaveError,
 };
 
+const CALL_SYNTAX: CallSyntax = CallSyntax {
+    scope_kinds: &["method", "singleton_method", "class", "module"],
+    call_kinds: &[("call", Some("method"))],
+    async_kinds: &[],
+};
+
+const DOC_SYNTAX: DocSyntax = DocSyntax {
+    item_kinds: &["method", "singleton_method", "class", "module"],
+    file_item_kinds: &[],
+    comment_kinds: &["comment"],
+    doc_prefixes: &["#"],
+    inner_prefixes: &[],
+    skip_kinds: &[],
+    wrapper_kinds: &[],
+    docstrings: false,
+};
+
+const TEST_SYNTAX: TestSyntax = TestSyntax {
+    item_kinds: &["method"],
+    marker_kinds: &[],
+    markers: &[],
+    name_prefixes: &["test_"],
+    calls: &CALL_SYNTAX,
+};
+
+const METRICS_SYNTAX: MetricsSyntax = MetricsSyntax {
+    branch_kinds: &[
+        "if",
+        "elsif",
+        "unless",
+        "while",
+        "until",
+        "for",
+        "when",
+        "rescue",
+        "conditional",
+        "if_modifier",
+        "unless_modifier",
+        "while_modifier",
+        "until_modifier",
+    ],
+    logical_kinds: &["binary"],
+    nesting_kinds: &["if", "unless", "while", "until", "for", "case", "begin"],
+    return_kinds: &["return"],
+};
+
 pub struct RubyParser;
 
 impl RubyParser {
@@ -50,6 +100,14 @@ impl Parser for RubyParser {
         extract_classes(root, content, path, &mut result);
         extract_modules(root, content, path, &mut result);
         extract_methods(root, content, path, &mut result);
+        extract_calls(root, content, path, &mut result, &CALL_SYNTAX);
+        extract_docs(root, content, &mut result, &DOC_SYNTAX);
+        extract_tests(root, content, path, &mut result, &TEST_SYNTAX);
+        extract_routes(root, content, path, &mut result, RouteDialect::Rails);
+        extract_entities(root, content, path, &mut result, EntityDialect::Ruby);
+        extract_settings(root, content, path, &mut result, &CALL_SYNTAX);
+        extract_metrics(root, content, &mut result, &METRICS_SYNTAX);
+        fingerprint_nodes(root, content, &mut result);
 
         Ok(result)
     }
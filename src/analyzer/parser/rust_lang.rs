@@ -1,12 +1,72 @@
 use chrono::Utc;
 use tree_sitter::{Query, QueryCursor, StreamingIterator};
 
-use super::{Language, ParseResult, Parser, create_file_node, create_ts_parser, get_node_text};
+use super::{
+    CallSyntax, DocSyntax, EntityDialect, Language, MetricsSyntax, ParseResult, Parser,
+    RouteDialect, TestSyntax, create_file_node, create_ts_parser, extract_calls, extract_docs,
+    extract_entities, extract_fields, extract_metrics, extract_return_types, extract_routes,
+    extract_settings, extract_tests, fingerprint_nodes, get_node_text,
+};
 use crate::types::{
     Edge, EdgeMetadata, EdgeType, EvidenceLocation, FunctionSignature, ImportType, InformationTier,
     Node, NodeId, NodeMetadata, NodeStatus, NodeType, Parameter, Result, Visibility, WeaveError,
 };
 
+const CALL_SYNTAX: CallSyntax = CallSyntax {
+    scope_kinds: &["function_item"],
+    call_kinds: &[("call_expression", Some("function"))],
+    async_kinds: &["await_expression"],
+};
+
+const DOC_SYNTAX: DocSyntax = DocSyntax {
+    item_kinds: &[
+        "function_item",
+        "struct_item",
+        "enum_item",
+        "union_item",
+        "trait_item",
+        "impl_item",
+        "mod_item",
+        "type_item",
+        "const_item",
+        "static_item",
+        "macro_definition",
+    ],
+    file_item_kinds: &[],
+    comment_kinds: &["line_comment", "block_comment"],
+    doc_prefixes: &["///", "/**"],
+    inner_prefixes: &["//!", "/*!"],
+    skip_kinds: &["attribute_item"],
+    wrapper_kinds: &[],
+    docstrings: false,
+};
+
+const TEST_SYNTAX: TestSyntax = TestSyntax {
+    item_kinds: &["function_item", "mod_item"],
+    marker_kinds: &["attribute_item"],
+    markers: &["test", "cfg(test)", "rstest", "test_case", "bench"],
+    name_prefixes: &[],
+    calls: &CALL_SYNTAX,
+};
+
+const METRICS_SYNTAX: MetricsSyntax = MetricsSyntax {
+    branch_kinds: &[
+        "if_expression",
+        "while_expression",
+        "for_expression",
+        "match_arm",
+    ],
+    logical_kinds: &["binary_expression"],
+    nesting_kinds: &[
+        "if_expression",
+        "while_expression",
+        "for_expression",
+        "loop_expression",
+        "match_expression",
+    ],
+    return_kinds: &["return_expression"],
+};
+
 pub struct RustParser;
 
 impl RustParser {
@@ -51,6 +111,16 @@ impl Parser for RustParser {
         extract_traits(root, content, path, &mut result);
         extract_functions(root, content, path, &mut result);
         extract_impl_blocks(root, content, path, &mut result);
+        extract_calls(root, content, path, &mut result, &CALL_SYNTAX);
+        extract_docs(root, content, &mut result, &DOC_SYNTAX);
+        extract_tests(root, content, path, &mut result, &TEST_SYNTAX);
+        extract_routes(root, content, path, &mut result, RouteDialect::Rust);
+        extract_entities(root, content, path, &mut result, EntityDialect::Rust);
+        extract_return_types(root, content, &mut result, "return_type");
+        extract_fields(root, content, &mut result);
+        extract_settings(root, content, path, &mut result, &CALL_SYNTAX);
+        extract_metrics(root, content, &mut result, &METRICS_SYNTAX);
+        fingerprint_nodes(root, content, &mut result);
 
         Ok(result)
     }
@@ -68,8 +138,8 @@ fn extract_use_statements(
 ) {
     let query_str = r#"
         (use_declaration
-            argument: (scoped_identifier) @path
-        )
+            argument: (_) @tree
+        ) @decl
     "#;
 
     if let Ok(query) = Query::new(&tree_sitter_rust::LANGUAGE.into(), query_str) {
@@ -77,38 +147,133 @@ fn extract_use_statements(
         let mut matches = cursor.matches(&query, root, content.as_bytes());
 
         while let Some(m) = matches.next() {
+            let mut decl = None;
+            let mut tree = None;
             for cap in m.captures.iter() {
-                let node = cap.node;
-                let use_path = get_node_text(node, content.as_bytes());
-
-                if use_path.starts_with("crate::") || use_path.starts_with("super::") {
-                    let edge = Edge {
-                        id: format!("use:{}:{}", path, use_path),
-                        edge_type: EdgeType::DependsOn,
-                        source_id: NodeId::file(path).into_inner(),
-                        target_id: NodeId::module(use_path).into_inner(),
-                        metadata: EdgeMetadata {
-                            import_type: Some(ImportType::Static),
-                            ..Default::default()
-                        },
-                        evidence: EvidenceLocation {
-                            file: path.to_string(),
-                            start_line: node.start_position().row as u32 + 1,
-                            end_line: node.end_position().row as u32 + 1,
-                            start_column: Some(node.start_position().column as u32),
-                            end_column: Some(node.end_position().column as u32),
-                        },
-                        tier: InformationTier::Fact,
-                        confidence: 1.0,
-                        last_verified: Utc::now(),
-                    };
-                    result.edges.push(edge);
+                if cap.index == 0 {
+                    tree = Some(cap.node);
+                } else {
+                    decl = Some(cap.node);
                 }
             }
+            let (Some(decl), Some(tree)) = (decl, tree) else {
+                continue;
+            };
+
+            let mut walker = decl.walk();
+            let reexport = decl
+                .children(&mut walker)
+                .any(|c| c.kind() == "visibility_modifier");
+
+            let mut entries = Vec::new();
+            flatten_use_tree(tree, "", content, &mut entries);
+
+            for (use_path, alias) in entries {
+                // Standard library paths never resolve to project files; other
+                // crate names may be workspace members, so the resolver decides.
+                let first = use_path.split("::").next().unwrap_or("");
+                if matches!(first, "std" | "core" | "alloc") {
+                    continue;
+                }
+
+                let last = use_path.rsplit("::").next().unwrap_or("");
+                let imported_symbols = match last {
+                    "*" | "self" | "crate" | "super" => None,
+                    _ => Some(vec![last.to_string()]),
+                };
+
+                let mut metadata = EdgeMetadata {
+                    import_type: Some(ImportType::Static),
+                    imported_symbols,
+                    exposed_as: if reexport { alias } else { None },
+                    ..Default::default()
+                };
+                if reexport {
+                    metadata
+                        .extra
+                        .insert("reexport".to_string(), serde_json::Value::Bool(true));
+                }
+
+                let edge = Edge {
+                    id: format!("use:{}:{}", path, use_path),
+                    edge_type: EdgeType::DependsOn,
+                    source_id: NodeId::file(path).into_inner(),
+                    target_id: NodeId::module(&use_path).into_inner(),
+                    metadata,
+                    evidence: EvidenceLocation {
+                        file: path.to_string(),
+                        start_line: decl.start_position().row as u32 + 1,
+                        end_line: decl.end_position().row as u32 + 1,
+                        start_column: Some(decl.start_position().column as u32),
+                        end_column: Some(decl.end_position().column as u32),
+                    },
+                    tier: InformationTier::Fact,
+                    confidence: 1.0,
+                    last_verified: Utc::now(),
+                };
+                result.edges.push(edge);
+            }
         }
     }
 }
 
+/// Flatten a use tree into `(path, alias)` pairs.
+///
+/// `use crate::a::{b, c::D as E, f::*}` yields `crate::a::b`,
+/// `crate::a::c::D` (alias `E`) and `crate::a::f::*`.
+fn flatten_use_tree(
+    node: tree_sitter::Node,
+    prefix: &str,
+    content: &str,
+    out: &mut Vec<(String, Option<String>)>,
+) {
+    let text_of = |n: tree_sitter::Node| -> String {
+        get_node_text(n, content.as_bytes())
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect()
+    };
+    let join = |prefix: &str, segment: &str| {
+        if prefix.is_empty() {
+            segment.to_string()
+        } else if segment.is_empty() {
+            prefix.to_string()
+        } else {
+            format!("{}::{}", prefix, segment)
+        }
+    };
+
+    match node.kind() {
+        "scoped_use_list" => {
+            let scope = node
+                .child_by_field_name("path")
+                .map(text_of)
+                .unwrap_or_default();
+            if let Some(list) = node.child_by_field_name("list") {
+                flatten_use_tree(list, &join(prefix, &scope), content, out);
+            }
+        }
+        "use_list" => {
+            let mut cursor = node.walk();
+            for child in node.named_children(&mut cursor) {
+                flatten_use_tree(child, prefix, content, out);
+            }
+        }
+        "use_as_clause" => {
+            if let Some(target) = node.child_by_field_name("path") {
+                let alias = node.child_by_field_name("alias").map(text_of);
+                out.push((join(prefix, &text_of(target)), alias));
+            }
+        }
+        "use_wildcard" => {
+            let scope = node.named_child(0).map(text_of).unwrap_or_default();
+            out.push((join(&join(prefix, &scope), "*"), None));
+        }
+        "line_comment" | "block_comment" => {}
+        _ => out.push((join(prefix, &text_of(node)), None)),
+    }
+}
+
 fn extract_mod_declarations(
     root: tree_sitter::Node,
     content: &str,
@@ -130,12 +295,32 @@ fn extract_mod_declarations(
                 let node = cap.node;
                 let name = get_node_text(node, content.as_bytes()).to_string();
 
+                let mut metadata = NodeMetadata::default();
+                if let Some(item) = node.parent() {
+                    let (inline, path_attr, parents) = mod_item_layout(item, content);
+                    metadata
+                        .extra
+                        .insert("inline".to_string(), serde_json::Value::Bool(inline));
+                    if let Some(path_attr) = path_attr {
+                        metadata.extra.insert(
+                            "path_attr".to_string(),
+                            serde_json::Value::String(path_attr),
+                        );
+                    }
+                    if !parents.is_empty() {
+                        metadata.extra.insert(
+                            "parent_modules".to_string(),
+                            serde_json::Value::String(parents.join("::")),
+                        );
+                    }
+                }
+
                 let mod_node = Node {
                     id: format!("module:{}:{}", path, name),
                     node_type: NodeType::Module,
                     path: path.to_string(),
                     name: name.clone(),
-                    metadata: NodeMetadata::default(),
+                    metadata,
                     evidence: EvidenceLocation {
                         file: path.to_string(),
                         start_line: node.start_position().row as u32 + 1,
@@ -154,6 +339,42 @@ fn extract_mod_declarations(
     }
 }
 
+/// Describe how a `mod_item` maps onto files: whether it has an inline body,
+/// its `#[path = "..."]` override, and the enclosing inline modules.
+fn mod_item_layout(item: tree_sitter::Node, content: &str) -> (bool, Option<String>, Vec<String>) {
+    let inline = item.child_by_field_name("body").is_some();
+
+    let mut path_attr = None;
+    let mut sibling = item.prev_named_sibling();
+    while let Some(attr) = sibling.filter(|s| s.kind() == "attribute_item") {
+        let text = get_node_text(attr, content.as_bytes());
+        if let Some(rest) = text
+            .trim_start_matches("#[")
+            .trim_end_matches(']')
+            .trim()
+            .strip_prefix("path")
+            && let Some(value) = rest.trim_start().strip_prefix('=')
+        {
+            path_attr = Some(value.trim().trim_matches('"').to_string());
+        }
+        sibling = attr.prev_named_sibling();
+    }
+
+    let mut parents = Vec::new();
+    let mut current = item.parent();
+    while let Some(node) = current {
+        if node.kind() == "mod_item"
+            && let Some(name) = node.child_by_field_name("name")
+        {
+            parents.push(get_node_text(name, content.as_bytes()).to_string());
+        }
+        current = node.parent();
+    }
+    parents.reverse();
+
+    (inline, path_attr, parents)
+}
+
 fn extract_structs(root: tree_sitter::Node, content: &str, path: &str, result: &mut ParseResult) {
     let query_str = r#"
         (struct_item
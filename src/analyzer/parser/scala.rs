@@ -0,0 +1,515 @@
+use tree_sitter::{Query, QueryCursor, StreamingIterator};
+
+use super::{
+    CallSyntax, DocSyntax, Language, MetricsSyntax, ParseResult, Parser, TestSyntax,
+    create_code_edge, create_code_node, create_file_node, create_ts_parser, extract_calls,
+    extract_docs, extract_metrics, extract_settings, extract_tests, fingerprint_nodes,
+    get_node_text,
+};
+use crate::types::{
+    EdgeType, FunctionSignature, ImportType, NodeMetadata, NodeType, Parameter, Result, Visibility,
+    WeaveError,
+};
+
+const CALL_SYNTAX: CallSyntax = CallSyntax {
+    scope_kinds: &[
+        "function_definition",
+        "class_definition",
+        "object_definition",
+        "trait_definition",
+    ],
+    call_kinds: &[
+        ("call_expression", Some("function")),
+        ("instance_expression", None),
+    ],
+    async_kinds: &[],
+};
+
+const DOC_SYNTAX: DocSyntax = DocSyntax {
+    item_kinds: &[
+        "class_definition",
+        "object_definition",
+        "trait_definition",
+        "enum_definition",
+        "function_definition",
+        "function_declaration",
+    ],
+    file_item_kinds: &["package_clause"],
+    comment_kinds: &["block_comment", "comment"],
+    doc_prefixes: &["/**"],
+    inner_prefixes: &[],
+    skip_kinds: &["annotation"],
+    wrapper_kinds: &[],
+    docstrings: false,
+};
+
+const TEST_SYNTAX: TestSyntax = TestSyntax {
+    item_kinds: &["function_definition"],
+    marker_kinds: &["annotation"],
+    markers: &["Test"],
+    name_prefixes: &[],
+    calls: &CALL_SYNTAX,
+};
+
+const METRICS_SYNTAX: MetricsSyntax = MetricsSyntax {
+    branch_kinds: &[
+        "if_expression",
+        "for_expression",
+        "while_expression",
+        "do_while_expression",
+        "case_clause",
+        "catch_clause",
+    ],
+    logical_kinds: &["infix_expression"],
+    nesting_kinds: &[
+        "if_expression",
+        "for_expression",
+        "while_expression",
+        "do_while_expression",
+        "match_expression",
+        "try_expression",
+    ],
+    return_kinds: &["return_expression"],
+};
+
+/// Definitions that own members, with the ID prefix and node type they map to.
+const TYPE_KINDS: &[(&str, &str, NodeType)] = &[
+    ("class_definition", "class", NodeType::Class),
+    ("object_definition", "object", NodeType::Class),
+    ("trait_definition", "interface", NodeType::Interface),
+    ("enum_definition", "enum", NodeType::Enum),
+];
+
+pub struct ScalaParser;
+
+impl ScalaParser {
+    pub fn new() -> Result<Self> {
+        let _ = create_ts_parser(tree_sitter_scala::LANGUAGE, "Scala")?;
+        Ok(Self)
+    }
+}
+
+impl Parser for ScalaParser {
+    fn parse(&self, path: &str, content: &str) -> Result<ParseResult> {
+        let mut parser =
+            create_ts_parser(tree_sitter_scala::LANGUAGE, "Scala").map_err(|mut e| {
+                if let WeaveError::Parse {
+                    path: ref mut p, ..
+                } = e
+                {
+                    *p = path.to_string();
+                }
+                e
+            })?;
+
+        let tree = parser
+            .parse(content, None)
+            .ok_or_else(|| WeaveError::Parse {
+                message: "Failed to parse Scala file".to_string(),
+                path: path.to_string(),
+            })?;
+
+        let mut result = ParseResult::new();
+        let root = tree.root_node();
+
+        result.nodes.push(create_file_node(path));
+
+        extract_package(root, content, path, &mut result);
+        extract_imports(root, content, path, &mut result);
+        extract_types(root, content, path, &mut result);
+        extract_functions(root, content, path, &mut result);
+        extract_calls(root, content, path, &mut result, &CALL_SYNTAX);
+        extract_docs(root, content, &mut result, &DOC_SYNTAX);
+        extract_tests(root, content, path, &mut result, &TEST_SYNTAX);
+        extract_settings(root, content, path, &mut result, &CALL_SYNTAX);
+        extract_metrics(root, content, &mut result, &METRICS_SYNTAX);
+        fingerprint_nodes(root, content, &mut result);
+
+        Ok(result)
+    }
+
+    fn language(&self) -> Language {
+        Language::Scala
+    }
+}
+
+fn extract_package(root: tree_sitter::Node, content: &str, path: &str, result: &mut ParseResult) {
+    let query_str = r#"
+        (package_clause
+            name: (package_identifier) @package
+        )
+    "#;
+
+    if let Ok(query) = Query::new(&tree_sitter_scala::LANGUAGE.into(), query_str) {
+        let mut cursor = QueryCursor::new();
+        let mut matches = cursor.matches(&query, root, content.as_bytes());
+
+        while let Some(m) = matches.next() {
+            for cap in m.captures.iter() {
+                let node = cap.node;
+                let name = get_node_text(node, content.as_bytes()).to_string();
+                result.nodes.push(create_code_node(
+                    format!("package:{}:{}", path, name),
+                    NodeType::Module,
+                    path,
+                    name,
+                    node,
+                ));
+            }
+        }
+    }
+}
+
+/// `import a.b.C`, `import a.b.{C, D => E}` and `import a.b._`
+fn extract_imports(root: tree_sitter::Node, content: &str, path: &str, result: &mut ParseResult) {
+    let query_str = r#"
+        (import_declaration) @import
+    "#;
+
+    if let Ok(query) = Query::new(&tree_sitter_scala::LANGUAGE.into(), query_str) {
+        let mut cursor = QueryCursor::new();
+        let mut matches = cursor.matches(&query, root, content.as_bytes());
+
+        while let Some(m) = matches.next() {
+            for cap in m.captures.iter() {
+                let node = cap.node;
+                let bytes = content.as_bytes();
+
+                let mut cursor = node.walk();
+                let segments: Vec<&str> = node
+                    .children_by_field_name("path", &mut cursor)
+                    .filter(|s| s.is_named())
+                    .map(|s| get_node_text(s, bytes))
+                    .collect();
+                if segments.is_empty() {
+                    continue;
+                }
+                let module = segments.join(".");
+
+                let mut cursor = node.walk();
+                let symbols: Vec<String> = node
+                    .named_children(&mut cursor)
+                    .flat_map(|c| match c.kind() {
+                        "wildcard" => vec!["*".to_string()],
+                        "namespace_selectors" => {
+                            let mut inner = c.walk();
+                            c.named_children(&mut inner)
+                                .map(|s| {
+                                    let name = s.child_by_field_name("name").unwrap_or(s);
+                                    get_node_text(name, bytes).to_string()
+                                })
+                                .collect()
+                        }
+                        _ => Vec::new(),
+                    })
+                    .collect();
+
+                let mut edge = create_code_edge(
+                    format!("import:{}:{}", path, module),
+                    EdgeType::DependsOn,
+                    format!("file:{}", path),
+                    format!("module:{}", module),
+                    node,
+                    path,
+                );
+                edge.metadata.import_type = Some(ImportType::Static);
+                if !symbols.is_empty() {
+                    edge.metadata.imported_symbols = Some(symbols);
+                }
+                result.edges.push(edge);
+            }
+        }
+    }
+}
+
+fn extract_types(root: tree_sitter::Node, content: &str, path: &str, result: &mut ParseResult) {
+    let query_str = r#"
+        [
+            (class_definition)
+            (object_definition)
+            (trait_definition)
+            (enum_definition)
+        ] @decl
+    "#;
+
+    if let Ok(query) = Query::new(&tree_sitter_scala::LANGUAGE.into(), query_str) {
+        let mut cursor = QueryCursor::new();
+        let mut matches = cursor.matches(&query, root, content.as_bytes());
+
+        while let Some(m) = matches.next() {
+            for cap in m.captures.iter() {
+                let decl = cap.node;
+                let bytes = content.as_bytes();
+                let Some((_, prefix, node_type)) =
+                    TYPE_KINDS.iter().find(|(k, _, _)| *k == decl.kind())
+                else {
+                    continue;
+                };
+                let Some(name_node) = decl.child_by_field_name("name") else {
+                    continue;
+                };
+                let name = get_node_text(name_node, bytes).to_string();
+                let id = format!("{}:{}:{}", prefix, path, name);
+
+                let mut type_node =
+                    create_code_node(id.clone(), *node_type, path, name.clone(), decl);
+                type_node.metadata.visibility = Some(scala_visibility(decl, content));
+
+                // `extends A with B with C`: traits can only extend traits, and
+                // everything after the first parent is a mixin
+                let mut implements = Vec::new();
+                if let Some(clause) = decl.child_by_field_name("extend") {
+                    let mut inner = clause.walk();
+                    let parents = clause
+                        .children_by_field_name("type", &mut inner)
+                        .filter(|t| t.is_named());
+                    for (i, parent) in parents.enumerate() {
+                        let parent_name = get_node_text(parent, bytes);
+                        let (edge_type, label, target) = if i > 0 {
+                            implements.push(parent_name.to_string());
+                            (EdgeType::Implements, "implements", "interface")
+                        } else if *node_type == NodeType::Interface {
+                            (EdgeType::Extends, "extends", "interface")
+                        } else {
+                            type_node.metadata.extends = Some(parent_name.to_string());
+                            (EdgeType::Extends, "extends", "class")
+                        };
+                        result.edges.push(create_code_edge(
+                            format!("{}:{}:{}:{}", label, path, name, parent_name),
+                            edge_type,
+                            id.clone(),
+                            format!("{}:{}", target, parent_name),
+                            parent,
+                            path,
+                        ));
+                    }
+                }
+                if !implements.is_empty() {
+                    type_node.metadata.implements = Some(implements);
+                }
+                result.nodes.push(type_node);
+            }
+        }
+    }
+}
+
+fn extract_functions(root: tree_sitter::Node, content: &str, path: &str, result: &mut ParseResult) {
+    let query_str = r#"
+        [
+            (function_definition)
+            (function_declaration)
+        ] @func
+    "#;
+
+    if let Ok(query) = Query::new(&tree_sitter_scala::LANGUAGE.into(), query_str) {
+        let mut cursor = QueryCursor::new();
+        let mut matches = cursor.matches(&query, root, content.as_bytes());
+
+        while let Some(m) = matches.next() {
+            for cap in m.captures.iter() {
+                let func = cap.node;
+                let bytes = content.as_bytes();
+                let Some(name_node) = func.child_by_field_name("name") else {
+                    continue;
+                };
+                let name = get_node_text(name_node, bytes).to_string();
+
+                let owner = match enclosing_type(func, content, path) {
+                    Scope::Local => continue,
+                    Scope::TopLevel => None,
+                    Scope::Type(owner) => Some(owner),
+                };
+
+                let (id, node_type) = match owner {
+                    Some((ref owner_name, _)) => (
+                        format!("method:{}:{}:{}", path, owner_name, name),
+                        NodeType::Method,
+                    ),
+                    None => (format!("function:{}:{}", path, name), NodeType::Function),
+                };
+
+                let mut func_node =
+                    create_code_node(id.clone(), node_type, path, name.clone(), func);
+                func_node.metadata = NodeMetadata {
+                    visibility: Some(scala_visibility(func, content)),
+                    signature: Some(FunctionSignature {
+                        parameters: scala_parameters(func, content),
+                        return_type: func
+                            .child_by_field_name("return_type")
+                            .map(|t| get_node_text(t, bytes).to_string()),
+                        is_async: false,
+                        generator: false,
+                    }),
+                    ..Default::default()
+                };
+                result.nodes.push(func_node);
+
+                if let Some((owner_name, owner_id)) = owner {
+                    result.edges.push(create_code_edge(
+                        format!("member:{}:{}:{}", path, owner_name, name),
+                        EdgeType::Owns,
+                        owner_id,
+                        id,
+                        func,
+                        path,
+                    ));
+                }
+            }
+        }
+    }
+}
+
+enum Scope {
+    TopLevel,
+    /// Owning class/object/trait as (name, node ID)
+    Type((String, String)),
+    /// Nested inside another function body
+    Local,
+}
+
+fn enclosing_type(node: tree_sitter::Node, content: &str, path: &str) -> Scope {
+    let mut current = node.parent();
+    while let Some(parent) = current {
+        if matches!(parent.kind(), "function_definition" | "lambda_expression") {
+            return Scope::Local;
+        }
+        if let Some((_, prefix, _)) = TYPE_KINDS.iter().find(|(k, _, _)| *k == parent.kind()) {
+            let Some(name) = parent.child_by_field_name("name") else {
+                return Scope::TopLevel;
+            };
+            let name = get_node_text(name, content.as_bytes()).to_string();
+            let id = format!("{}:{}:{}", prefix, path, name);
+            return Scope::Type((name, id));
+        }
+        current = parent.parent();
+    }
+    Scope::TopLevel
+}
+
+/// Scala members are public unless marked; `private[pkg]` is package-scoped.
+fn scala_visibility(decl: tree_sitter::Node, content: &str) -> Visibility {
+    let mut cursor = decl.walk();
+    let modifier = decl
+        .named_children(&mut cursor)
+        .filter(|c| c.kind() == "modifiers")
+        .flat_map(|m| {
+            let mut inner = m.walk();
+            m.named_children(&mut inner).collect::<Vec<_>>()
+        })
+        .find(|m| m.kind() == "access_modifier");
+
+    match modifier.map(|m| get_node_text(m, content.as_bytes())) {
+        Some(text) if text.contains('[') => Visibility::Internal,
+        Some(text) if text.starts_with("private") => Visibility::Private,
+        Some(text) if text.starts_with("protected") => Visibility::Protected,
+        _ => Visibility::Public,
+    }
+}
+
+/// Parameters from every parameter list, so curried and `using` lists are included.
+fn scala_parameters(func: tree_sitter::Node, content: &str) -> Vec<Parameter> {
+    let bytes = content.as_bytes();
+    let mut cursor = func.walk();
+    func.children_by_field_name("parameters", &mut cursor)
+        .flat_map(|list| {
+            let mut inner = list.walk();
+            list.named_children(&mut inner)
+                .filter(|p| p.kind() == "parameter")
+                .collect::<Vec<_>>()
+        })
+        .filter_map(|param| {
+            let name = param.child_by_field_name("name")?;
+            let default_value = param
+                .child_by_field_name("default_value")
+                .map(|v| get_node_text(v, bytes).to_string());
+            Some(Parameter {
+                name: get_node_text(name, bytes).to_string(),
+                param_type: param
+                    .child_by_field_name("type")
+                    .map(|t| get_node_text(t, bytes).to_string()),
+                optional: default_value.is_some(),
+                default_value,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scala_definitions() {
+        let code = r#"
+package com.shop
+
+import com.shop.model.{Order, Item => I}
+
+/** Places orders. */
+class OrderService(repo: Repo) extends BaseService with Logging {
+  def place(id: Int, name: String = "x"): Order = {
+    def local(): Unit = ()
+    new Order(id)
+  }
+  protected def log(): Unit = ()
+}
+trait Logging { def log(): Unit }
+private[shop] def helper(x: Int): Int = x
+"#;
+        let result = ScalaParser::new()
+            .unwrap()
+            .parse("OrderService.scala", code)
+            .unwrap();
+
+        let import = result
+            .edges
+            .iter()
+            .find(|e| e.id.starts_with("import:"))
+            .unwrap();
+        assert_eq!(import.target_id, "module:com.shop.model");
+        assert_eq!(
+            import.metadata.imported_symbols,
+            Some(vec!["Order".to_string(), "Item".to_string()])
+        );
+
+        let service = result
+            .nodes
+            .iter()
+            .find(|n| n.id == "class:OrderService.scala:OrderService")
+            .unwrap();
+        assert_eq!(service.metadata.extends.as_deref(), Some("BaseService"));
+        assert_eq!(
+            service.metadata.implements,
+            Some(vec!["Logging".to_string()])
+        );
+        assert_eq!(
+            service.metadata.description.as_deref(),
+            Some("Places orders.")
+        );
+
+        let place = result
+            .nodes
+            .iter()
+            .find(|n| n.id == "method:OrderService.scala:OrderService:place")
+            .unwrap();
+        let sig = place.metadata.signature.as_ref().unwrap();
+        assert_eq!(sig.return_type.as_deref(), Some("Order"));
+        assert_eq!(sig.parameters[1].default_value.as_deref(), Some("\"x\""));
+
+        let log = result
+            .nodes
+            .iter()
+            .find(|n| n.id == "method:OrderService.scala:OrderService:log")
+            .unwrap();
+        assert_eq!(log.metadata.visibility, Some(Visibility::Protected));
+
+        let helper = result
+            .nodes
+            .iter()
+            .find(|n| n.id == "function:OrderService.scala:helper")
+            .unwrap();
+        assert_eq!(helper.metadata.visibility, Some(Visibility::Internal));
+
+        assert!(!result.nodes.iter().any(|n| n.name == "local"));
+    }
+}
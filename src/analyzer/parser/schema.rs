@@ -0,0 +1,584 @@
+//! Database Schema Parser
+//!
+//! SQL migrations (`CREATE TABLE`) and Prisma schemas (`model X { ... }`)
+//! declare entities directly. Neither has a tree-sitter grammar here, so both
+//! are scanned by hand: comments are masked, then each declaration is split
+//! into column definitions. Each table or model becomes an `entity` node
+//! owned by the file.
+
+use super::entities::{EntityDecl, emit_entities};
+use super::{Language, ParseResult, Parser, create_file_node};
+use crate::types::{EvidenceLocation, Result, WeaveError};
+
+/// Words that end a column's type in a SQL column definition.
+const SQL_CONSTRAINTS: &[&str] = &[
+    "not",
+    "null",
+    "primary",
+    "references",
+    "default",
+    "unique",
+    "check",
+    "constraint",
+    "generated",
+    "collate",
+    "auto_increment",
+    "autoincrement",
+    "identity",
+    "comment",
+    "on",
+];
+
+/// Prisma scalar types; any other field type names a model or an enum.
+const PRISMA_SCALARS: &[&str] = &[
+    "String", "Boolean", "Int", "BigInt", "Float", "Decimal", "DateTime", "Json", "Bytes",
+];
+
+pub struct SchemaParser {
+    language: Language,
+}
+
+impl SchemaParser {
+    pub fn new(language: Language) -> Result<Self> {
+        match language {
+            Language::Sql | Language::Prisma => Ok(Self { language }),
+            _ => Err(WeaveError::Config(format!(
+                "Not a database schema language: {}",
+                language
+            ))),
+        }
+    }
+}
+
+impl Parser for SchemaParser {
+    fn parse(&self, path: &str, content: &str) -> Result<ParseResult> {
+        let mut result = ParseResult::new();
+        result.nodes.push(create_file_node(path));
+
+        let decls = match self.language {
+            Language::Prisma => prisma_models(path, content),
+            _ => sql_tables(path, content),
+        };
+        emit_entities(decls, path, &mut result);
+
+        Ok(result)
+    }
+
+    fn language(&self) -> Language {
+        self.language
+    }
+}
+
+fn evidence(path: &str, content: &str, start: usize, end: usize) -> EvidenceLocation {
+    EvidenceLocation {
+        file: path.to_string(),
+        start_line: line_at(content, start),
+        end_line: line_at(content, end),
+        start_column: None,
+        end_column: None,
+    }
+}
+
+fn line_at(content: &str, offset: usize) -> u32 {
+    content[..offset].matches('\n').count() as u32 + 1
+}
+
+/// Replace comments with spaces, keeping byte offsets and line numbers intact.
+fn mask_comments(content: &str, line_comment: &str, block_comments: bool) -> String {
+    let mut masked = String::with_capacity(content.len());
+    let mut quote = None;
+    let mut i = 0;
+
+    while let Some(c) = content[i..].chars().next() {
+        let rest = &content[i..];
+        let comment_end = match quote {
+            Some(_) => None,
+            None if rest.starts_with(line_comment) => {
+                Some(rest.find('\n').map_or(content.len(), |n| i + n))
+            }
+            None if block_comments && rest.starts_with("/*") => {
+                Some(rest.find("*/").map_or(content.len(), |n| i + n + 2))
+            }
+            None => None,
+        };
+        if let Some(end) = comment_end {
+            for c in content[i..end].chars() {
+                match c {
+                    '\n' => masked.push('\n'),
+                    _ => masked.extend(std::iter::repeat_n(' ', c.len_utf8())),
+                }
+            }
+            i = end;
+            continue;
+        }
+
+        match quote {
+            Some(q) if c == q => quote = None,
+            None if matches!(c, '\'' | '"' | '`') => quote = Some(c),
+            _ => {}
+        }
+        masked.push(c);
+        i += c.len_utf8();
+    }
+    masked
+}
+
+/// Split on `,` outside parentheses and quotes.
+fn split_top_level(text: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut quote = None;
+    let mut start = 0;
+    for (i, c) in text.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"' | '`') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => depth -= 1,
+            (None, ',') if depth == 0 => {
+                parts.push(&text[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&text[start..]);
+    parts
+}
+
+/// Byte offset of the `)` matching the `(` at `open`.
+fn matching_paren(text: &str, open: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in text[open..].char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(open + i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+// =============================================================================
+// SQL
+// =============================================================================
+
+fn sql_identifier(word: &str) -> String {
+    let word = word.rsplit('.').next().unwrap_or(word);
+    word.trim_matches(|c| matches!(c, '"' | '`' | '[' | ']'))
+        .to_string()
+}
+
+/// Identifiers in a parenthesized list (`(tenant_id, id)`).
+fn sql_identifier_list(text: &str) -> Vec<String> {
+    let inner = text
+        .split_once('(')
+        .and_then(|(_, rest)| rest.split_once(')'))
+        .map_or("", |(inner, _)| inner);
+    inner
+        .split(',')
+        .map(|c| sql_identifier(c.trim()))
+        .filter(|c| !c.is_empty())
+        .collect()
+}
+
+/// Table named after `REFERENCES` in a constraint.
+fn sql_reference(lower: &str, original: &str) -> Option<String> {
+    let start = lower.find("references")? + "references".len();
+    let target = original[start..]
+        .split(|c: char| c.is_whitespace() || c == '(')
+        .find(|w| !w.is_empty())?;
+    Some(sql_identifier(target))
+}
+
+fn sql_tables(path: &str, content: &str) -> Vec<EntityDecl> {
+    let masked = mask_comments(content, "--", true);
+    let mut decls: Vec<EntityDecl> = Vec::new();
+    let mut start = 0;
+
+    for statement in masked.split(';') {
+        let offset = start + (statement.len() - statement.trim_start().len());
+        start += statement.len() + 1;
+        let statement = statement.trim();
+        let lower = statement.to_ascii_lowercase();
+        let words: Vec<&str> = lower.split_whitespace().take(8).collect();
+
+        if words.first() == Some(&"create")
+            && let Some(table_at) = words.iter().position(|w| *w == "table")
+            && words[1..table_at]
+                .iter()
+                .all(|w| matches!(*w, "or" | "replace" | "temp" | "temporary" | "unlogged"))
+        {
+            let ev = evidence(path, content, offset, offset + statement.len());
+            decls.extend(sql_create_table(statement, ev));
+        } else if words.first() == Some(&"alter") && words.get(1) == Some(&"table") {
+            sql_alter_foreign_key(statement, &lower, &mut decls);
+        }
+    }
+    decls
+}
+
+fn sql_create_table(statement: &str, evidence: EvidenceLocation) -> Option<EntityDecl> {
+    let open = statement.find('(')?;
+    let close = matching_paren(statement, open)?;
+    let header = statement[..open].split_whitespace().last()?;
+    let name = sql_identifier(header);
+    let mut decl = EntityDecl::new(name.clone(), evidence);
+    decl.table = Some(name);
+
+    let body = &statement[open + 1..close];
+    let mut primary_keys = Vec::new();
+    for item in split_top_level(body) {
+        let item = item.trim();
+        let lower = item.to_ascii_lowercase();
+        // `CONSTRAINT name PRIMARY KEY (...)` reads like the bare constraint
+        let item = match lower.starts_with("constraint ") {
+            true => item
+                .split_whitespace()
+                .skip(2)
+                .collect::<Vec<_>>()
+                .join(" "),
+            false => item.to_string(),
+        };
+        let lower = item.to_ascii_lowercase();
+
+        if lower.starts_with("primary key") {
+            primary_keys.extend(sql_identifier_list(&item));
+        } else if lower.starts_with("foreign key") {
+            if let (Some(column), Some(target)) = (
+                sql_identifier_list(&item).first(),
+                sql_reference(&lower, &item),
+            ) {
+                decl.relation(column, "many_to_one", &target);
+            }
+        } else if !["unique", "check", "index", "key", "exclude", "fulltext"]
+            .iter()
+            .any(|k| lower.starts_with(k))
+        {
+            sql_column(&item, &lower, &mut decl);
+        }
+    }
+    for key in primary_keys {
+        decl.mark_primary_key(&key);
+    }
+    Some(decl)
+}
+
+/// `email VARCHAR(255) NOT NULL UNIQUE`
+fn sql_column(item: &str, lower: &str, decl: &mut EntityDecl) {
+    let mut words = item.split_whitespace();
+    let Some(name) = words.next() else {
+        return;
+    };
+    let type_words: Vec<&str> = words
+        .take_while(|w| {
+            let w = w.to_ascii_lowercase();
+            !SQL_CONSTRAINTS.contains(&w.as_str())
+        })
+        .collect();
+    if type_words.is_empty() {
+        return;
+    }
+
+    let name = sql_identifier(name);
+    let primary_key = lower.contains("primary key");
+    let nullable = !lower.contains("not null");
+    decl.field(&name, &type_words.join(" "), nullable, primary_key);
+    if let Some(target) = sql_reference(lower, item) {
+        decl.relation(&name, "many_to_one", &target);
+    }
+}
+
+/// `ALTER TABLE posts ADD [CONSTRAINT fk] FOREIGN KEY (user_id) REFERENCES users (id)`
+/// for a table created earlier in the same file.
+fn sql_alter_foreign_key(statement: &str, lower: &str, decls: &mut [EntityDecl]) {
+    let Some(fk) = lower.find("foreign key") else {
+        return;
+    };
+    let Some(table) = statement.split_whitespace().nth(2).map(sql_identifier) else {
+        return;
+    };
+    let (Some(column), Some(target)) = (
+        sql_identifier_list(&statement[fk..]).into_iter().next(),
+        sql_reference(&lower[fk..], &statement[fk..]),
+    ) else {
+        return;
+    };
+    if let Some(decl) = decls.iter_mut().find(|d| d.name == table) {
+        decl.relation(&column, "many_to_one", &target);
+    }
+}
+
+// =============================================================================
+// Prisma
+// =============================================================================
+
+/// `model User { ... }` blocks as (name, body, start offset, end offset).
+fn prisma_blocks(masked: &str) -> Vec<(&str, &str, usize, usize)> {
+    let mut blocks = Vec::new();
+    let mut offset = 0;
+    for line in masked.split_inclusive('\n') {
+        let line_start = offset;
+        offset += line.len();
+        let mut words = line.split_whitespace();
+        if words.next() != Some("model") {
+            continue;
+        }
+        let Some(name) = words.next() else {
+            continue;
+        };
+        let name = name.trim_end_matches('{');
+        let Some(open) = masked[line_start..].find('{').map(|i| line_start + i) else {
+            continue;
+        };
+        let Some(close) = masked[open..].find('}').map(|i| open + i) else {
+            continue;
+        };
+        blocks.push((name, &masked[open + 1..close], line_start, close));
+    }
+    blocks
+}
+
+fn prisma_models(path: &str, content: &str) -> Vec<EntityDecl> {
+    let masked = mask_comments(content, "//", false);
+    let blocks = prisma_blocks(&masked);
+    let models: Vec<&str> = blocks.iter().map(|(name, ..)| *name).collect();
+
+    let mut decls = Vec::new();
+    for (name, body, start, end) in blocks {
+        let mut decl = EntityDecl::new(name, evidence(path, content, start, end));
+        let mut primary_keys = Vec::new();
+
+        for line in body.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            if let Some(attribute) = line.strip_prefix("@@") {
+                if let Some(args) = attribute.strip_prefix("map(") {
+                    decl.table = args.split('"').nth(1).map(str::to_string);
+                } else if attribute.starts_with("id(") {
+                    primary_keys.extend(
+                        sql_identifier_list(&attribute.replace(['[', ']'], ""))
+                            .into_iter()
+                            .map(|k| k.trim().to_string()),
+                    );
+                }
+                continue;
+            }
+
+            let mut words = line.split_whitespace();
+            let (Some(field), Some(field_type)) = (words.next(), words.next()) else {
+                continue;
+            };
+            let attributes = &line[line.find(field_type).unwrap_or(0) + field_type.len()..];
+            let list = field_type.ends_with("[]");
+            let nullable = field_type.ends_with('?');
+            let base = field_type.trim_end_matches("[]").trim_end_matches('?');
+
+            if models.contains(&base) && !PRISMA_SCALARS.contains(&base) {
+                let relation = if list {
+                    "one_to_many"
+                } else if attributes.contains("@relation(") && attributes.contains("fields:") {
+                    "many_to_one"
+                } else {
+                    "one_to_one"
+                };
+                decl.relation(field, relation, base);
+            } else {
+                decl.field(field, base, nullable, attributes.contains("@id"));
+            }
+        }
+        for key in primary_keys {
+            decl.mark_primary_key(&key);
+        }
+        decls.push(decl);
+    }
+    decls
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{EdgeType, EntityMetadata, NodeType};
+
+    fn entities(result: &ParseResult) -> Vec<(&str, &EntityMetadata)> {
+        result
+            .nodes
+            .iter()
+            .filter(|n| n.node_type == NodeType::Entity)
+            .filter_map(|n| Some((n.name.as_str(), n.metadata.entity_metadata.as_ref()?)))
+            .collect()
+    }
+
+    fn columns(entity: &EntityMetadata) -> Vec<(&str, &str, bool, bool)> {
+        entity
+            .fields
+            .iter()
+            .flatten()
+            .map(|f| {
+                (
+                    f.name.as_str(),
+                    f.field_type.as_str(),
+                    f.nullable,
+                    f.primary_key == Some(true),
+                )
+            })
+            .collect()
+    }
+
+    fn relations(entity: &EntityMetadata) -> Vec<(&str, &str, &str)> {
+        entity
+            .relations
+            .iter()
+            .flatten()
+            .map(|r| (r.name.as_str(), r.relation_type.as_str(), r.target.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_sql_create_table() {
+        let sql = r#"
+-- users; not a statement
+CREATE TABLE IF NOT EXISTS public.users (
+    id BIGSERIAL PRIMARY KEY,
+    email VARCHAR(255) NOT NULL UNIQUE,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT now()
+);
+
+CREATE TABLE "memberships" (
+    user_id BIGINT NOT NULL REFERENCES users(id),
+    team_id BIGINT NOT NULL,
+    role TEXT, /* owner, member */
+    CONSTRAINT memberships_pk PRIMARY KEY (user_id, team_id)
+);
+
+ALTER TABLE memberships ADD CONSTRAINT fk_team FOREIGN KEY (team_id) REFERENCES teams (id);
+CREATE INDEX idx_users_email ON users (email);
+"#;
+        let result = SchemaParser::new(Language::Sql)
+            .unwrap()
+            .parse("db/001_init.sql", sql)
+            .unwrap();
+        let entities = entities(&result);
+        assert_eq!(entities.len(), 2);
+
+        let (name, users) = entities[0];
+        assert_eq!(name, "users");
+        assert_eq!(
+            columns(users),
+            vec![
+                ("id", "BIGSERIAL", false, true),
+                ("email", "VARCHAR(255)", false, false),
+                ("created_at", "TIMESTAMP WITH TIME ZONE", true, false),
+            ]
+        );
+
+        let (name, memberships) = entities[1];
+        assert_eq!(name, "memberships");
+        assert_eq!(
+            columns(memberships),
+            vec![
+                ("user_id", "BIGINT", false, true),
+                ("team_id", "BIGINT", false, true),
+                ("role", "TEXT", true, false),
+            ]
+        );
+        assert_eq!(
+            relations(memberships),
+            vec![
+                ("user_id", "many_to_one", "users"),
+                ("team_id", "many_to_one", "teams")
+            ]
+        );
+        assert_eq!(
+            result.nodes[2].evidence.start_line, 9,
+            "evidence points at the CREATE TABLE line"
+        );
+        assert!(
+            result
+                .edges
+                .iter()
+                .all(|e| e.edge_type == EdgeType::Owns && e.source_id == "file:db/001_init.sql")
+        );
+    }
+
+    #[test]
+    fn test_prisma_models() {
+        let prisma = r#"
+datasource db {
+  provider = "postgresql"
+}
+
+// A registered account
+model User {
+  id      Int      @id @default(autoincrement())
+  email   String   @unique
+  name    String?
+  role    Role     @default(USER)
+  posts   Post[]
+  profile Profile?
+
+  @@map("users")
+}
+
+model Post {
+  id       Int  @id
+  author   User @relation(fields: [authorId], references: [id])
+  authorId Int
+}
+
+model Profile {
+  userId Int
+  kind   String
+  user   User @relation(fields: [userId], references: [id])
+
+  @@id([userId, kind])
+}
+
+enum Role {
+  USER
+  ADMIN
+}
+"#;
+        let result = SchemaParser::new(Language::Prisma)
+            .unwrap()
+            .parse("prisma/schema.prisma", prisma)
+            .unwrap();
+        let entities = entities(&result);
+        let names: Vec<&str> = entities.iter().map(|(n, _)| *n).collect();
+        assert_eq!(names, vec!["User", "Post", "Profile"]);
+
+        let user = entities[0].1;
+        assert_eq!(user.table_name.as_deref(), Some("users"));
+        assert_eq!(
+            columns(user),
+            vec![
+                ("id", "Int", false, true),
+                ("email", "String", false, false),
+                ("name", "String", true, false),
+                ("role", "Role", false, false),
+            ]
+        );
+        assert_eq!(
+            relations(user),
+            vec![
+                ("posts", "one_to_many", "Post"),
+                ("profile", "one_to_one", "Profile")
+            ]
+        );
+        assert_eq!(
+            relations(entities[1].1),
+            vec![("author", "many_to_one", "User")]
+        );
+        assert_eq!(
+            columns(entities[2].1),
+            vec![
+                ("userId", "Int", false, true),
+                ("kind", "String", false, true)
+            ]
+        );
+    }
+}
@@ -0,0 +1,574 @@
+//! Setting Read Extraction
+//!
+//! Finds where code reads environment variables (`std::env::var`,
+//! `process.env.X`, `os.getenv`, `System.getenv`, ...), configuration keys
+//! (`viper.GetString`, `@Value("${...}")`, figment `extract_inner`) and
+//! feature flags (`cfg(feature = "...")`, `flags.isEnabled("...")`).
+//!
+//! Each distinct name becomes a `setting` node of the file, linked by a
+//! `reads` edge from the innermost function that reads it (or the file for
+//! top-level reads). Only literal names are recorded: `env::var(key)` with a
+//! computed key is skipped rather than guessed.
+//!
+//! Detection works on source text rather than grammar kinds, since the same
+//! read looks alike across languages while its node shapes do not.
+
+use std::collections::{HashMap, HashSet};
+
+use super::calls::{CallSyntax, enclosing_scope, scope_symbols};
+use super::traits::{ParseResult, create_code_edge, create_code_node, get_node_text};
+use crate::types::{Edge, EdgeMetadata, EdgeType, Node, NodeType};
+
+/// Nodes longer than this cannot be a single read and are not inspected.
+const MAX_READ_LEN: usize = 300;
+
+/// Functions whose first argument names an environment variable
+const ENV_CALLS: &[&str] = &[
+    "std::env::var",
+    "std::env::var_os",
+    "env::var",
+    "env::var_os",
+    "env!",
+    "option_env!",
+    "dotenvy::var",
+    "dotenv::var",
+    "os.getenv",
+    "os.environ.get",
+    "environ.get",
+    "os.Getenv",
+    "os.LookupEnv",
+    "System.getenv",
+    "Environment.GetEnvironmentVariable",
+    "getenv",
+    "std::getenv",
+    "ENV.fetch",
+    "Deno.env.get",
+    "sys.env",
+    "sys.env.get",
+];
+
+/// Functions whose first argument is a prefix every environment variable
+/// of the configuration shares
+const ENV_PREFIX_CALLS: &[(&str, &str)] = &[
+    ("Env::prefixed", ""),
+    ("figment::providers::Env::prefixed", ""),
+    ("viper.SetEnvPrefix", "_"),
+];
+
+/// Maps that hold the environment, read as `MAP.NAME` or `MAP["NAME"]`
+const ENV_MAPS: &[&str] = &[
+    "process.env",
+    "import.meta.env",
+    "Bun.env",
+    "os.environ",
+    "environ",
+    "ENV",
+    "$_ENV",
+    "ProcessInfo.processInfo.environment",
+];
+
+/// Methods of the environment maps, which `MAP.NAME` must not mistake for
+/// a variable (`os.environ.get`, `ENV.fetch`)
+const MAP_METHODS: &[&str] = &[
+    "get",
+    "fetch",
+    "getenv",
+    "setdefault",
+    "pop",
+    "keys",
+    "values",
+    "items",
+    "copy",
+    "update",
+    "has_key",
+    "key",
+    "include",
+    "each",
+    "to_h",
+    "delete",
+];
+
+/// Maps that hold configuration keys, read as `MAP["key"]`
+const CONFIG_MAPS: &[&str] = &[
+    "Configuration",
+    "configuration",
+    "_configuration",
+    "app.config",
+];
+
+/// Methods whose first argument names a feature flag when called on a client
+const FLAG_METHODS: &[&str] = &[
+    "isEnabled",
+    "is_enabled",
+    "isFeatureEnabled",
+    "variation",
+    "boolVariation",
+    "stringVariation",
+    "getFeatureValue",
+    "isOn",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SettingKind {
+    Env,
+    ConfigKey,
+    FeatureFlag,
+}
+
+impl SettingKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            SettingKind::Env => "env",
+            SettingKind::ConfigKey => "config_key",
+            SettingKind::FeatureFlag => "feature_flag",
+        }
+    }
+}
+
+struct SettingRead<'t> {
+    name: String,
+    kind: SettingKind,
+    node: tree_sitter::Node<'t>,
+}
+
+/// Extract `setting` nodes and `reads` edges from every literal read under
+/// `root`.
+///
+/// Must run after the parser has pushed its declaration nodes, since reads
+/// are attributed to the enclosing function by name. Repeated reads of one
+/// name by the same function collapse into one edge whose `call_count`
+/// records the number of sites.
+pub fn extract_settings(
+    root: tree_sitter::Node,
+    content: &str,
+    path: &str,
+    result: &mut ParseResult,
+    syntax: &CallSyntax,
+) {
+    let bytes = content.as_bytes();
+    let mut reads = Vec::new();
+    let mut seen: HashSet<(usize, String)> = HashSet::new();
+    let mut cursor = root.walk();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        stack.extend(node.named_children(&mut cursor));
+        if node.end_byte() - node.start_byte() > MAX_READ_LEN {
+            continue;
+        }
+        // Statements wrapping a bare read share its text and start
+        for (name, kind) in setting_reads(get_node_text(node, bytes)) {
+            if seen.insert((node.start_byte(), name.clone())) {
+                reads.push(SettingRead { name, kind, node });
+            }
+        }
+    }
+    if reads.is_empty() {
+        return;
+    }
+    reads.sort_by_key(|r| r.node.start_byte());
+
+    let file_id = format!("file:{}", path);
+    let symbols = scope_symbols(&result.nodes);
+    let mut settings: Vec<Node> = Vec::new();
+    let mut edges: Vec<Edge> = Vec::new();
+    let mut index: HashMap<(String, String), usize> = HashMap::new();
+
+    for read in reads {
+        let setting_id = format!("setting:{}:{}", path, read.name);
+        if !settings.iter().any(|s| s.id == setting_id) {
+            let mut setting = create_code_node(
+                setting_id.clone(),
+                NodeType::Setting,
+                path,
+                read.name.clone(),
+                read.node,
+            );
+            setting.metadata.extra.insert(
+                "setting_kind".to_string(),
+                serde_json::Value::String(read.kind.as_str().to_string()),
+            );
+            settings.push(setting);
+        }
+
+        let reader =
+            enclosing_scope(read.node, bytes, syntax, &symbols).unwrap_or_else(|| file_id.clone());
+        let key = (reader.clone(), setting_id.clone());
+        if let Some(&i) = index.get(&key) {
+            let edge = &mut edges[i];
+            edge.metadata.call_count = edge.metadata.call_count.map(|c| c + 1);
+            continue;
+        }
+        let mut edge = create_code_edge(
+            format!("reads:{}->{}", reader, setting_id),
+            EdgeType::Reads,
+            reader,
+            setting_id,
+            read.node,
+            path,
+        );
+        edge.metadata = EdgeMetadata {
+            call_count: Some(1),
+            ..Default::default()
+        };
+        index.insert(key, edges.len());
+        edges.push(edge);
+    }
+
+    result.nodes.extend(settings);
+    result.edges.extend(edges);
+}
+
+/// Settings read by `text` when it is exactly one read expression.
+fn setting_reads(text: &str) -> Vec<(String, SettingKind)> {
+    if let Some(read) = map_read(text) {
+        return vec![read];
+    }
+    let Some((callee, args)) = split_call(text) else {
+        return Vec::new();
+    };
+
+    if matches!(callee, "cfg" | "cfg!" | "cfg_attr") {
+        return feature_names(args)
+            .into_iter()
+            .map(|name| (name, SettingKind::FeatureFlag))
+            .collect();
+    }
+
+    let first = string_literal(args);
+    let read = if ENV_CALLS.contains(&callee) {
+        first.map(|name| (name.to_string(), SettingKind::Env))
+    } else if let Some((_, separator)) = ENV_PREFIX_CALLS.iter().find(|(c, _)| *c == callee) {
+        first.map(|prefix| (format!("{}{}*", prefix, separator), SettingKind::Env))
+    } else if callee == "@Value" {
+        first
+            .and_then(placeholder_key)
+            .map(|key| (key.to_string(), SettingKind::ConfigKey))
+    } else if callee.starts_with("viper.Get") || callee == "viper.IsSet" {
+        first.map(|key| (key.to_string(), SettingKind::ConfigKey))
+    } else {
+        let (qualifier, method) = callee.rsplit_once('.').unwrap_or(("", callee));
+        if qualifier.is_empty() {
+            None
+        } else if method == "extract_inner" {
+            first.map(|key| (key.to_string(), SettingKind::ConfigKey))
+        } else if FLAG_METHODS.contains(&method) {
+            first.map(|flag| (flag.to_string(), SettingKind::FeatureFlag))
+        } else {
+            None
+        }
+    };
+
+    read.filter(|(name, _)| is_setting_name(name))
+        .into_iter()
+        .collect()
+}
+
+/// `process.env.NAME`, `os.environ["NAME"]`, `Configuration["Key"]`.
+fn map_read(text: &str) -> Option<(String, SettingKind)> {
+    let maps = ENV_MAPS
+        .iter()
+        .map(|m| (m, SettingKind::Env))
+        .chain(CONFIG_MAPS.iter().map(|m| (m, SettingKind::ConfigKey)));
+    for (map, kind) in maps {
+        let Some(rest) = text.strip_prefix(map) else {
+            continue;
+        };
+        let name = if let Some(ident) = rest.strip_prefix('.') {
+            (kind == SettingKind::Env
+                && !ident.is_empty()
+                && ident.chars().all(|c| c.is_alphanumeric() || c == '_')
+                && !MAP_METHODS.contains(&ident))
+            .then_some(ident)
+        } else if let Some(index) = rest.strip_prefix('[').and_then(|r| r.strip_suffix(']')) {
+            // The index must be the literal alone, give or take a prefix
+            string_literal(index).filter(|s| index.trim().len() <= s.len() + 3)
+        } else {
+            None
+        };
+        if let Some(name) = name.filter(|n| is_setting_name(n)) {
+            return Some((name.to_string(), kind));
+        }
+    }
+    None
+}
+
+/// Split `callee(args)` when the whole text is one call on a plain or
+/// chained callee (`Figment::new().extract_inner("db")`).
+fn split_call(text: &str) -> Option<(&str, &str)> {
+    let body = text.strip_suffix(')')?;
+
+    // Walk back to the parenthesis opening the final argument list
+    let mut depth = 0usize;
+    let mut open = None;
+    for (i, c) in body.char_indices().rev() {
+        match c {
+            ')' => depth += 1,
+            '(' if depth == 0 => {
+                open = Some(i);
+                break;
+            }
+            '(' => depth -= 1,
+            _ => {}
+        }
+    }
+    let open = open?;
+    let callee = &body[..open];
+
+    // Outside nested parentheses the callee is a dotted or scoped path
+    let mut depth = 0usize;
+    for c in callee.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.checked_sub(1)?,
+            c if depth == 0
+                && !(c.is_alphanumeric() || matches!(c, '_' | '$' | '.' | ':' | '!' | '@')) =>
+            {
+                return None;
+            }
+            _ => {}
+        }
+    }
+    if callee.is_empty() || depth != 0 {
+        return None;
+    }
+
+    Some((callee, &body[open + 1..]))
+}
+
+/// Every `feature = "name"` inside a `cfg` predicate.
+fn feature_names(args: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut rest = args;
+    while let Some(at) = rest.find("feature") {
+        rest = &rest[at + "feature".len()..];
+        let Some(value) = rest.trim_start().strip_prefix('=') else {
+            continue;
+        };
+        if let Some(name) = string_literal(value).filter(|n| is_setting_name(n))
+            && !names.iter().any(|n| n == name)
+        {
+            names.push(name.to_string());
+        }
+    }
+    names
+}
+
+/// Contents of the string literal `text` starts with, after any raw,
+/// byte, format or verbatim prefix (`r#"..."#`, `f"..."`, `@"..."`).
+fn string_literal(text: &str) -> Option<&str> {
+    let text = text
+        .trim_start()
+        .trim_start_matches(['r', 'b', 'f', 'u', 'R', 'B', 'F', 'U', '@', '$'])
+        .trim_start_matches('#');
+    let quote = text
+        .chars()
+        .next()
+        .filter(|c| matches!(c, '"' | '\'' | '`'))?;
+    let inner = &text[1..];
+    let end = inner.find(quote)?;
+    Some(&inner[..end])
+}
+
+/// The key of a Spring `${key:default}` placeholder.
+fn placeholder_key(text: &str) -> Option<&str> {
+    let inner = text
+        .trim_start_matches('\\')
+        .strip_prefix("${")?
+        .strip_suffix('}')?;
+    Some(inner.split(':').next().unwrap_or(inner))
+}
+
+/// Names a setting can have, with a trailing `*` for prefixes.
+fn is_setting_name(name: &str) -> bool {
+    let name = name.strip_suffix('*').unwrap_or(name);
+    name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{
+        GoParser, JavaParser, Parser, PythonParser, RubyParser, RustParser, TypeScriptParser,
+    };
+    use crate::analyzer::parser::ParseResult;
+    use crate::types::{EdgeType, NodeType};
+
+    /// `(name, setting_kind, readers)` for each setting in the result
+    fn settings(result: &ParseResult) -> Vec<(String, String, Vec<String>)> {
+        result
+            .nodes
+            .iter()
+            .filter(|n| n.node_type == NodeType::Setting)
+            .map(|n| {
+                let kind = n.metadata.extra["setting_kind"]
+                    .as_str()
+                    .unwrap()
+                    .to_string();
+                let readers = result
+                    .edges
+                    .iter()
+                    .filter(|e| e.edge_type == EdgeType::Reads && e.target_id == n.id)
+                    .map(|e| e.source_id.clone())
+                    .collect();
+                (n.name.clone(), kind, readers)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_rust_env_config_and_features() {
+        let code = r#"
+fn load() -> Config {
+    let url = std::env::var("DATABASE_URL").unwrap();
+    let port = env::var("PORT").or_else(|_| env::var("HTTP_PORT"));
+    let again = std::env::var("DATABASE_URL");
+    let key = std::env::var(name);
+    Figment::new().merge(Env::prefixed("APP_")).extract_inner("server")
+}
+
+#[cfg(feature = "metrics")]
+fn record() {
+    if cfg!(any(feature = "tracing", feature = "metrics")) {}
+}
+
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+"#;
+        let result = RustParser::new()
+            .unwrap()
+            .parse("src/config.rs", code)
+            .unwrap();
+        let found = settings(&result);
+        let load = vec!["function:src/config.rs:load".to_string()];
+
+        assert!(found.contains(&("DATABASE_URL".into(), "env".into(), load.clone())));
+        assert!(found.contains(&("PORT".into(), "env".into(), load.clone())));
+        assert!(found.contains(&("HTTP_PORT".into(), "env".into(), load.clone())));
+        assert!(found.contains(&("APP_*".into(), "env".into(), load.clone())));
+        assert!(found.contains(&("server".into(), "config_key".into(), load)));
+        assert!(found.contains(&(
+            "CARGO_PKG_VERSION".into(),
+            "env".into(),
+            vec!["file:src/config.rs".into()]
+        )));
+        assert!(found.iter().any(|(n, k, r)| n == "metrics"
+            && k == "feature_flag"
+            && r.contains(&"function:src/config.rs:record".to_string())));
+        assert!(found.iter().any(|(n, _, _)| n == "tracing"));
+        assert!(!found.iter().any(|(n, _, _)| n == "name"));
+
+        let repeated = result
+            .edges
+            .iter()
+            .find(|e| e.target_id == "setting:src/config.rs:DATABASE_URL")
+            .unwrap();
+        assert_eq!(repeated.metadata.call_count, Some(2));
+    }
+
+    #[test]
+    fn test_typescript_python_go_java_reads() {
+        let ts = TypeScriptParser::new()
+            .unwrap()
+            .parse(
+                "src/app.ts",
+                "function start() {\n  const port = process.env.PORT ?? 3000;\n  \
+                 const key = process.env['API_KEY'];\n  \
+                 if (flags.isEnabled('new-checkout')) {}\n  return port;\n}\n",
+            )
+            .unwrap();
+        let names: Vec<(String, String)> =
+            settings(&ts).into_iter().map(|(n, k, _)| (n, k)).collect();
+        assert_eq!(
+            names,
+            vec![
+                ("PORT".into(), "env".into()),
+                ("API_KEY".into(), "env".into()),
+                ("new-checkout".into(), "feature_flag".into()),
+            ]
+        );
+
+        let py = PythonParser::new()
+            .unwrap()
+            .parse(
+                "settings.py",
+                "import os\n\nDEBUG = os.getenv(\"DEBUG\", \"0\")\n\n\
+                 def secret():\n    return os.environ[\"SECRET_KEY\"]\n",
+            )
+            .unwrap();
+        let found = settings(&py);
+        assert!(found.contains(&(
+            "DEBUG".into(),
+            "env".into(),
+            vec!["file:settings.py".into()]
+        )));
+        assert!(found.contains(&(
+            "SECRET_KEY".into(),
+            "env".into(),
+            vec!["function:settings.py:secret".into()]
+        )));
+
+        let go = GoParser::new()
+            .unwrap()
+            .parse(
+                "main.go",
+                "package main\n\nfunc run() {\n\tos.Getenv(\"HOME\")\n\
+                 \tviper.GetString(\"db.host\")\n}\n",
+            )
+            .unwrap();
+        let found = settings(&go);
+        assert!(found.iter().any(|(n, k, _)| n == "HOME" && k == "env"));
+        assert!(
+            found
+                .iter()
+                .any(|(n, k, _)| n == "db.host" && k == "config_key")
+        );
+
+        let java = JavaParser::new()
+            .unwrap()
+            .parse(
+                "App.java",
+                "class App {\n  @Value(\"${server.port:8080}\")\n  int port;\n\n  \
+                 String home() { return System.getenv(\"JAVA_HOME\"); }\n}\n",
+            )
+            .unwrap();
+        let found = settings(&java);
+        assert!(
+            found
+                .iter()
+                .any(|(n, k, _)| n == "server.port" && k == "config_key")
+        );
+        assert!(found.iter().any(|(n, k, r)| n == "JAVA_HOME"
+            && k == "env"
+            && r == &vec!["method:App.java:home".to_string()]));
+    }
+
+    #[test]
+    fn test_map_methods_are_not_settings() {
+        let py = PythonParser::new()
+            .unwrap()
+            .parse(
+                "settings.py",
+                "import os
+
+HOST = os.environ.get(\"HOST\")
+PORT = os.getenv(\"PORT\")
+",
+            )
+            .unwrap();
+        let names: Vec<String> = settings(&py).into_iter().map(|(n, _, _)| n).collect();
+        assert_eq!(names, vec!["HOST".to_string(), "PORT".to_string()]);
+
+        let rb = RubyParser::new()
+            .unwrap()
+            .parse(
+                "config.rb",
+                "TOKEN = ENV.fetch(\"TOKEN\")
+",
+            )
+            .unwrap();
+        let names: Vec<String> = settings(&rb).into_iter().map(|(n, _, _)| n).collect();
+        assert_eq!(names, vec!["TOKEN".to_string()]);
+    }
+}
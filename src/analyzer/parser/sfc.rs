@@ -0,0 +1,1081 @@
+//! Single-File Component Parser
+//!
+//! Vue (`.vue`) and Svelte (`.svelte`) files bundle a script, markup and
+//! styles in one file. The script blocks are parsed with the TypeScript
+//! parser at their original positions, and the file itself becomes a
+//! `Component` node carrying props, state, lifecycle hooks and emitted
+//! events, with `Renders` edges to the child components used in the markup.
+
+use std::collections::HashMap;
+use std::ops::Range;
+use std::path::Path;
+
+use chrono::Utc;
+
+use super::{
+    Language, ParseResult, Parser, TypeScriptParser, create_code_edge, create_code_node,
+    create_ts_parser, get_node_text,
+};
+use crate::analyzer::resolver::resolve_relative;
+use crate::types::{
+    ComponentMetadata, Edge, EdgeMetadata, EdgeType, EvidenceLocation, FieldDefinition,
+    InformationTier, NodeType, PropDefinition, Result, StateDefinition, Visibility, WeaveError,
+};
+
+/// Top-level blocks of a component file.
+const BLOCK_TAGS: &[&str] = &["script", "template", "style"];
+
+/// Calls whose result is reactive component state.
+const STATE_CALLS: &[&str] = &[
+    "ref",
+    "reactive",
+    "shallowRef",
+    "shallowReactive",
+    "$state",
+    "writable",
+];
+
+/// Lifecycle and effect hooks that don't follow the `onX` naming pattern.
+const EXTRA_HOOKS: &[&str] = &[
+    "watch",
+    "watchEffect",
+    "beforeUpdate",
+    "afterUpdate",
+    "$effect",
+];
+
+/// Vue built-ins that look like components but never resolve to a file.
+const VUE_BUILTINS: &[&str] = &[
+    "Component",
+    "KeepAlive",
+    "Slot",
+    "Suspense",
+    "Teleport",
+    "Template",
+    "Transition",
+    "TransitionGroup",
+];
+
+/// A top-level `<script>`, `<template>` or `<style>` block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SfcBlock {
+    pub tag: &'static str,
+    /// Raw attribute text of the opening tag (`setup lang="ts"`)
+    pub attrs: String,
+    /// Byte range between the opening and closing tags
+    pub inner: Range<usize>,
+    /// Byte range including both tags
+    pub outer: Range<usize>,
+}
+
+/// Split a component file into its top-level blocks.
+///
+/// Nested `<template>` elements (Vue slots) are matched so the outer
+/// template ends at its own closing tag. HTML comments are skipped.
+pub fn split_blocks(content: &str) -> Vec<SfcBlock> {
+    let mut blocks = Vec::new();
+    let mut pos = 0;
+
+    while let Some(offset) = content[pos..].find('<') {
+        let start = pos + offset;
+        let rest = &content[start..];
+        if rest.starts_with("<!--") {
+            pos = rest.find("-->").map_or(content.len(), |e| start + e + 3);
+            continue;
+        }
+        let Some(tag) = BLOCK_TAGS.iter().find(|t| opens_tag(&rest[1..], t)) else {
+            pos = start + 1;
+            continue;
+        };
+        let Some(open_end) = rest.find('>').map(|e| start + e + 1) else {
+            break;
+        };
+        let attrs = content[start + 1 + tag.len()..open_end - 1]
+            .trim()
+            .trim_end_matches('/')
+            .trim()
+            .to_string();
+
+        // `<script src="..." />` has no body
+        if content[..open_end].ends_with("/>") {
+            blocks.push(SfcBlock {
+                tag,
+                attrs,
+                inner: open_end..open_end,
+                outer: start..open_end,
+            });
+            pos = open_end;
+            continue;
+        }
+
+        let close = if *tag == "template" {
+            template_close(content, open_end)
+        } else {
+            content[open_end..]
+                .find(&format!("</{}", tag))
+                .map(|e| open_end + e)
+        };
+        let Some(close) = close else {
+            break;
+        };
+        let outer_end = content[close..]
+            .find('>')
+            .map_or(content.len(), |e| close + e + 1);
+
+        blocks.push(SfcBlock {
+            tag,
+            attrs,
+            inner: open_end..close,
+            outer: start..outer_end,
+        });
+        pos = outer_end;
+    }
+
+    blocks
+}
+
+fn opens_tag(rest: &str, tag: &str) -> bool {
+    rest.strip_prefix(tag)
+        .and_then(|after| after.chars().next())
+        .is_some_and(|c| c.is_whitespace() || c == '>' || c == '/')
+}
+
+/// Position of the `</template` closing the template opened before `from`.
+fn template_close(content: &str, from: usize) -> Option<usize> {
+    let mut depth = 1;
+    let mut pos = from;
+    while let Some(offset) = content[pos..].find("<") {
+        let at = pos + offset;
+        let rest = &content[at + 1..];
+        if let Some(closing) = rest.strip_prefix('/') {
+            if opens_tag(closing, "template") {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(at);
+                }
+            }
+        } else if opens_tag(rest, "template") {
+            depth += 1;
+        }
+        pos = at + 1;
+    }
+    None
+}
+
+/// Blank out everything outside `keep`, preserving byte offsets and line breaks.
+fn mask(content: &str, keep: &[Range<usize>]) -> String {
+    let mut out = String::with_capacity(content.len());
+    for (i, c) in content.char_indices() {
+        if c == '\n' || keep.iter().any(|r| r.contains(&i)) {
+            out.push(c);
+        } else {
+            out.extend(std::iter::repeat_n(' ', c.len_utf8()));
+        }
+    }
+    out
+}
+
+pub struct SfcParser {
+    language: Language,
+}
+
+impl SfcParser {
+    pub fn new(language: Language) -> Result<Self> {
+        match language {
+            Language::Vue | Language::Svelte => Ok(Self { language }),
+            _ => Err(WeaveError::Config(format!(
+                "Not a single-file component language: {}",
+                language
+            ))),
+        }
+    }
+}
+
+impl Parser for SfcParser {
+    fn parse(&self, path: &str, content: &str) -> Result<ParseResult> {
+        let blocks = split_blocks(content);
+        let scripts: Vec<Range<usize>> = blocks
+            .iter()
+            .filter(|b| b.tag == "script")
+            .map(|b| b.inner.clone())
+            .collect();
+        let script = mask(content, &scripts);
+
+        let mut result = TypeScriptParser::new()?.parse(path, &script)?;
+
+        let mut parser =
+            create_ts_parser(tree_sitter_typescript::LANGUAGE_TYPESCRIPT, "TypeScript").map_err(
+                |mut e| {
+                    if let WeaveError::Parse {
+                        path: ref mut p, ..
+                    } = e
+                    {
+                        *p = path.to_string();
+                    }
+                    e
+                },
+            )?;
+        let tree = parser
+            .parse(&script, None)
+            .ok_or_else(|| WeaveError::Parse {
+                message: "Failed to parse component script".to_string(),
+                path: path.to_string(),
+            })?;
+        let root = tree.root_node();
+
+        let mut info = ComponentInfo::default();
+        collect_script(root, &script, &mut info);
+        if self.language == Language::Svelte {
+            collect_svelte_props(root, &script, &mut info);
+        }
+
+        let markup = match self.language {
+            Language::Vue => {
+                let templates: Vec<Range<usize>> = blocks
+                    .iter()
+                    .filter(|b| b.tag == "template")
+                    .map(|b| b.inner.clone())
+                    .collect();
+                mask(content, &templates)
+            }
+            // Svelte markup is everything outside the script and style blocks
+            _ => {
+                let mut keep = Vec::new();
+                let mut start = 0;
+                for block in blocks.iter().filter(|b| b.tag != "template") {
+                    keep.push(start..block.outer.start);
+                    start = block.outer.end;
+                }
+                keep.push(start..content.len());
+                mask(content, &keep)
+            }
+        };
+        for event in template_emits(&markup, self.language) {
+            info.add_emit(event);
+        }
+
+        let name = info.name.clone().unwrap_or_else(|| component_name(path));
+        let id = format!("component:{}:{}", path, name);
+
+        let mut component = create_code_node(id.clone(), NodeType::Component, path, name, root);
+        component.evidence.start_line = 1;
+        component.evidence.end_line = content.lines().count().max(1) as u32;
+        component.metadata.visibility = Some(Visibility::Public);
+        component.metadata.component_metadata = Some(info.metadata());
+        result.nodes.push(component);
+
+        for (tag, offset) in child_components(&markup, self.language) {
+            let target = info
+                .imports
+                .get(&tag)
+                .filter(|source| is_component_file(source) && source.starts_with('.'))
+                .map(|source| {
+                    let file = resolve_relative(path, source);
+                    let child = component_name(&file);
+                    format!("component:{}:{}", file, child)
+                })
+                .unwrap_or_else(|| format!("component:{}", tag));
+            result.edges.push(renders_edge(
+                path,
+                &id,
+                &tag,
+                target,
+                line_at(content, offset),
+            ));
+        }
+
+        // The file exposes its component the way a module exposes its exports
+        if let Some(file) = result.nodes.iter().find(|n| n.node_type == NodeType::File) {
+            let file_id = file.id.clone();
+            result.edges.push(create_code_edge(
+                format!("exposes:{}:{}", path, id),
+                EdgeType::Exposes,
+                file_id,
+                id,
+                root,
+                path,
+            ));
+        }
+
+        Ok(result)
+    }
+
+    fn language(&self) -> Language {
+        self.language
+    }
+}
+
+/// Facts gathered from a component's script and markup.
+#[derive(Debug, Default)]
+pub(super) struct ComponentInfo {
+    /// Name set explicitly with the Options API `name:` key
+    name: Option<String>,
+    props: Vec<PropDefinition>,
+    emits: Vec<String>,
+    pub(super) state: Vec<FieldDefinition>,
+    pub(super) hooks: Vec<String>,
+    /// Local binding → import source
+    imports: HashMap<String, String>,
+}
+
+impl ComponentInfo {
+    pub(super) fn add_prop(&mut self, name: &str, prop_type: &str, required: bool) {
+        if !self.props.iter().any(|p| p.name == name) {
+            self.props.push(PropDefinition {
+                name: name.to_string(),
+                prop_type: prop_type.to_string(),
+                required,
+                description: None,
+            });
+        }
+    }
+
+    fn add_emit(&mut self, event: String) {
+        if !self.emits.contains(&event) {
+            self.emits.push(event);
+        }
+    }
+
+    pub(super) fn metadata(&self) -> ComponentMetadata {
+        ComponentMetadata {
+            props: (!self.props.is_empty()).then(|| self.props.clone()),
+            state: (!self.state.is_empty()).then(|| StateDefinition {
+                fields: self.state.clone(),
+            }),
+            hooks: (!self.hooks.is_empty()).then(|| self.hooks.clone()),
+            emits: (!self.emits.is_empty()).then(|| self.emits.clone()),
+        }
+    }
+}
+
+fn collect_script(root: tree_sitter::Node, script: &str, info: &mut ComponentInfo) {
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        match node.kind() {
+            "call_expression" => collect_call(node, script, info),
+            "import_statement" => collect_import(node, script, info),
+            "variable_declarator" => collect_state(node, script, info),
+            // `export default { props: ..., emits: ... }`
+            "export_statement" => {
+                if let Some(value) = node.child_by_field_name("value")
+                    && value.kind() == "object"
+                {
+                    collect_options(value, script, info);
+                }
+            }
+            _ => {}
+        }
+
+        let mut cursor = node.walk();
+        let children: Vec<_> = node.named_children(&mut cursor).collect();
+        stack.extend(children.into_iter().rev());
+    }
+}
+
+fn collect_call(call: tree_sitter::Node, script: &str, info: &mut ComponentInfo) {
+    let bytes = script.as_bytes();
+    let Some(function) = call.child_by_field_name("function") else {
+        return;
+    };
+    let callee = get_node_text(function, bytes);
+    let method = callee.rsplit('.').next().unwrap_or(callee);
+    let first_arg = call
+        .child_by_field_name("arguments")
+        .and_then(|a| a.named_child(0));
+
+    match method {
+        "defineProps" => {
+            if let Some(members) = type_argument_members(call, script) {
+                for member in members {
+                    props_from_signature(member, script, info);
+                }
+            } else if let Some(arg) = first_arg {
+                props_from_runtime(arg, script, info);
+            }
+        }
+        "defineEmits" => {
+            if let Some(members) = type_argument_members(call, script) {
+                for member in members {
+                    if let Some(event) = emit_from_signature(member, script) {
+                        info.add_emit(event);
+                    }
+                }
+            } else if let Some(arg) = first_arg {
+                for event in keys_or_strings(arg, script) {
+                    info.add_emit(event);
+                }
+            }
+        }
+        "defineModel" => {
+            let model = first_arg
+                .and_then(|a| string_value(a, script))
+                .unwrap_or_else(|| "modelValue".to_string());
+            info.add_prop(&model, "unknown", false);
+            info.add_emit(format!("update:{}", model));
+        }
+        "defineComponent" => {
+            if let Some(arg) = first_arg.filter(|a| a.kind() == "object") {
+                collect_options(arg, script, info);
+            }
+        }
+        "emit" | "$emit" | "dispatch" => {
+            if let Some(event) = first_arg.and_then(|a| string_value(a, script)) {
+                info.add_emit(event);
+            }
+        }
+        _ if is_hook(method) => {
+            if !info.hooks.iter().any(|h| h == method) {
+                info.hooks.push(method.to_string());
+            }
+        }
+        _ => {}
+    }
+}
+
+fn is_hook(name: &str) -> bool {
+    let lifecycle = name
+        .strip_prefix("on")
+        .and_then(|rest| rest.chars().next())
+        .is_some_and(|c| c.is_ascii_uppercase());
+    lifecycle || EXTRA_HOOKS.contains(&name)
+}
+
+fn collect_import(import: tree_sitter::Node, script: &str, info: &mut ComponentInfo) {
+    let bytes = script.as_bytes();
+    let Some(source) = import
+        .child_by_field_name("source")
+        .and_then(|s| string_value(s, script))
+    else {
+        return;
+    };
+
+    let mut cursor = import.walk();
+    let Some(clause) = import
+        .named_children(&mut cursor)
+        .find(|c| c.kind() == "import_clause")
+    else {
+        return;
+    };
+
+    let mut cursor = clause.walk();
+    for child in clause.named_children(&mut cursor) {
+        match child.kind() {
+            "identifier" => {
+                info.imports
+                    .insert(get_node_text(child, bytes).to_string(), source.clone());
+            }
+            "named_imports" => {
+                let mut inner = child.walk();
+                for spec in child.named_children(&mut inner) {
+                    let local = spec
+                        .child_by_field_name("alias")
+                        .or_else(|| spec.child_by_field_name("name"));
+                    if let Some(local) = local {
+                        info.imports
+                            .insert(get_node_text(local, bytes).to_string(), source.clone());
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// `const count = ref(0)`, `let items = $state([])` and Svelte 5 `$props()` destructuring.
+fn collect_state(declarator: tree_sitter::Node, script: &str, info: &mut ComponentInfo) {
+    let bytes = script.as_bytes();
+    let (Some(name), Some(value)) = (
+        declarator.child_by_field_name("name"),
+        declarator.child_by_field_name("value"),
+    ) else {
+        return;
+    };
+    if value.kind() != "call_expression" {
+        return;
+    }
+    let Some(callee) = value.child_by_field_name("function") else {
+        return;
+    };
+    let callee = get_node_text(callee, bytes);
+
+    if callee == "$props" && name.kind() == "object_pattern" {
+        let prop_type = declarator
+            .child_by_field_name("type")
+            .map(|t| annotation_text(t, script))
+            .unwrap_or_else(|| "unknown".to_string());
+        let mut cursor = name.walk();
+        for field in name.named_children(&mut cursor) {
+            let (field_name, required) = match field.kind() {
+                "shorthand_property_identifier_pattern" => (field, true),
+                "object_assignment_pattern" => match field.child_by_field_name("left") {
+                    Some(left) => (left, false),
+                    None => continue,
+                },
+                _ => continue,
+            };
+            info.add_prop(get_node_text(field_name, bytes), &prop_type, required);
+        }
+        return;
+    }
+
+    if STATE_CALLS.contains(&callee) && name.kind() == "identifier" {
+        let field_type = value
+            .child_by_field_name("type_arguments")
+            .map(|t| {
+                get_node_text(t, bytes)
+                    .trim_start_matches('<')
+                    .trim_end_matches('>')
+                    .to_string()
+            })
+            .unwrap_or_else(|| callee.to_string());
+        info.state.push(FieldDefinition {
+            name: get_node_text(name, bytes).to_string(),
+            field_type,
+            nullable: false,
+            primary_key: None,
+        });
+    }
+}
+
+/// Svelte 4 props are `export let` declarations; those without a default are required.
+fn collect_svelte_props(root: tree_sitter::Node, script: &str, info: &mut ComponentInfo) {
+    let bytes = script.as_bytes();
+    let mut cursor = root.walk();
+    for stmt in root.named_children(&mut cursor) {
+        let (declaration, exported) = match stmt.kind() {
+            "export_statement" => (stmt.child_by_field_name("declaration"), true),
+            "lexical_declaration" => (Some(stmt), false),
+            _ => continue,
+        };
+        let Some(declaration) = declaration.filter(|d| d.kind() == "lexical_declaration") else {
+            continue;
+        };
+        let is_let = declaration
+            .child_by_field_name("kind")
+            .is_some_and(|k| get_node_text(k, bytes) == "let");
+        if !is_let {
+            continue;
+        }
+
+        let mut inner = declaration.walk();
+        for declarator in declaration
+            .named_children(&mut inner)
+            .filter(|d| d.kind() == "variable_declarator")
+        {
+            let Some(name) = declarator
+                .child_by_field_name("name")
+                .filter(|n| n.kind() == "identifier")
+            else {
+                continue;
+            };
+            let name = get_node_text(name, bytes);
+            let declared_type = declarator
+                .child_by_field_name("type")
+                .map(|t| annotation_text(t, script));
+            let value = declarator.child_by_field_name("value");
+
+            if exported {
+                let prop_type = declared_type.unwrap_or_else(|| "unknown".to_string());
+                info.add_prop(name, &prop_type, value.is_none());
+            } else if value.is_none_or(|v| v.kind() != "call_expression")
+                && !info.state.iter().any(|f| f.name == name)
+            {
+                // Top-level `let` is reactive state in Svelte 4
+                info.state.push(FieldDefinition {
+                    name: name.to_string(),
+                    field_type: declared_type.unwrap_or_else(|| "unknown".to_string()),
+                    nullable: false,
+                    primary_key: None,
+                });
+            }
+        }
+    }
+}
+
+/// Options API: `{ name: 'X', props: {...}, emits: [...] }`
+fn collect_options(object: tree_sitter::Node, script: &str, info: &mut ComponentInfo) {
+    let bytes = script.as_bytes();
+    let mut cursor = object.walk();
+    for pair in object
+        .named_children(&mut cursor)
+        .filter(|c| c.kind() == "pair")
+    {
+        let (Some(key), Some(value)) = (
+            pair.child_by_field_name("key"),
+            pair.child_by_field_name("value"),
+        ) else {
+            continue;
+        };
+        match key_text(key, bytes).as_str() {
+            "name" => info.name = string_value(value, script),
+            "props" => props_from_runtime(value, script, info),
+            "emits" => {
+                for event in keys_or_strings(value, script) {
+                    info.add_emit(event);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Members of the type literal passed as `defineX<{ ... }>()`, following a
+/// local `interface`/`type` when the argument is a name.
+fn type_argument_members<'a>(
+    call: tree_sitter::Node<'a>,
+    script: &str,
+) -> Option<Vec<tree_sitter::Node<'a>>> {
+    let args = call.child_by_field_name("type_arguments")?;
+    let arg = args.named_child(0)?;
+    let body = match arg.kind() {
+        "object_type" => arg,
+        "type_identifier" => find_type_body(call, get_node_text(arg, script.as_bytes()), script)?,
+        _ => return None,
+    };
+    let mut cursor = body.walk();
+    Some(body.named_children(&mut cursor).collect())
+}
+
+pub(super) fn find_type_body<'a>(
+    from: tree_sitter::Node<'a>,
+    name: &str,
+    script: &str,
+) -> Option<tree_sitter::Node<'a>> {
+    let mut root = from;
+    while let Some(parent) = root.parent() {
+        root = parent;
+    }
+
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        let declared = node
+            .child_by_field_name("name")
+            .is_some_and(|n| get_node_text(n, script.as_bytes()) == name);
+        match node.kind() {
+            "interface_declaration" if declared => return node.child_by_field_name("body"),
+            "type_alias_declaration" if declared => {
+                return node
+                    .child_by_field_name("value")
+                    .filter(|v| v.kind() == "object_type");
+            }
+            _ => {}
+        }
+        let mut cursor = node.walk();
+        stack.extend(node.named_children(&mut cursor));
+    }
+    None
+}
+
+pub(super) fn props_from_signature(
+    member: tree_sitter::Node,
+    script: &str,
+    info: &mut ComponentInfo,
+) {
+    if member.kind() != "property_signature" {
+        return;
+    }
+    let Some(name) = member.child_by_field_name("name") else {
+        return;
+    };
+    let mut cursor = member.walk();
+    let optional = member.children(&mut cursor).any(|c| c.kind() == "?");
+    let prop_type = member
+        .child_by_field_name("type")
+        .map(|t| annotation_text(t, script))
+        .unwrap_or_else(|| "unknown".to_string());
+    info.add_prop(&key_text(name, script.as_bytes()), &prop_type, !optional);
+}
+
+/// `(e: 'change', id: number): void` or `change: [id: number]`
+fn emit_from_signature(member: tree_sitter::Node, script: &str) -> Option<String> {
+    match member.kind() {
+        "property_signature" => member
+            .child_by_field_name("name")
+            .map(|n| key_text(n, script.as_bytes())),
+        "call_signature" => {
+            let params = member.child_by_field_name("parameters")?;
+            let event = params.named_child(0)?.child_by_field_name("type")?;
+            let literal = event.named_child(0)?;
+            string_value(literal.named_child(0).unwrap_or(literal), script)
+        }
+        _ => None,
+    }
+}
+
+/// Runtime prop declarations: `['a', 'b']`, `{ a: String, b: { type: Number, required: true } }`.
+fn props_from_runtime(value: tree_sitter::Node, script: &str, info: &mut ComponentInfo) {
+    let bytes = script.as_bytes();
+    match value.kind() {
+        "array" => {
+            for name in keys_or_strings(value, script) {
+                info.add_prop(&name, "unknown", false);
+            }
+        }
+        "object" => {
+            let mut cursor = value.walk();
+            for pair in value
+                .named_children(&mut cursor)
+                .filter(|c| c.kind() == "pair")
+            {
+                let (Some(key), Some(spec)) = (
+                    pair.child_by_field_name("key"),
+                    pair.child_by_field_name("value"),
+                ) else {
+                    continue;
+                };
+                let (prop_type, required) = if spec.kind() == "object" {
+                    let option = |wanted: &str| {
+                        let mut inner = spec.walk();
+                        spec.named_children(&mut inner)
+                            .filter(|p| p.kind() == "pair")
+                            .find(|p| {
+                                p.child_by_field_name("key")
+                                    .is_some_and(|k| key_text(k, bytes) == wanted)
+                            })
+                            .and_then(|p| p.child_by_field_name("value"))
+                    };
+                    (
+                        option("type").map(|t| get_node_text(t, bytes).to_string()),
+                        option("required").is_some_and(|r| get_node_text(r, bytes) == "true"),
+                    )
+                } else {
+                    (Some(get_node_text(spec, bytes).to_string()), false)
+                };
+                info.add_prop(
+                    &key_text(key, bytes),
+                    prop_type.as_deref().unwrap_or("unknown"),
+                    required,
+                );
+            }
+        }
+        _ => {}
+    }
+}
+
+/// String elements of an array, or the keys of an object.
+fn keys_or_strings(value: tree_sitter::Node, script: &str) -> Vec<String> {
+    let mut cursor = value.walk();
+    let children = value.named_children(&mut cursor);
+    match value.kind() {
+        "array" => children.filter_map(|c| string_value(c, script)).collect(),
+        "object" => children
+            .filter_map(|c| match c.kind() {
+                "pair" => c.child_by_field_name("key"),
+                "shorthand_property_identifier" | "method_definition" => Some(c),
+                _ => None,
+            })
+            .map(|k| {
+                let k = k.child_by_field_name("name").unwrap_or(k);
+                key_text(k, script.as_bytes())
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn string_value(node: tree_sitter::Node, script: &str) -> Option<String> {
+    matches!(node.kind(), "string" | "template_string").then(|| {
+        get_node_text(node, script.as_bytes())
+            .trim_matches(|c| c == '"' || c == '\'' || c == '`')
+            .to_string()
+    })
+}
+
+fn key_text(key: tree_sitter::Node, bytes: &[u8]) -> String {
+    get_node_text(key, bytes)
+        .trim_matches(|c| c == '"' || c == '\'')
+        .to_string()
+}
+
+pub(super) fn annotation_text(annotation: tree_sitter::Node, script: &str) -> String {
+    get_node_text(annotation, script.as_bytes())
+        .trim_start_matches(':')
+        .trim()
+        .to_string()
+}
+
+/// Events emitted directly from markup expressions: Vue `$emit('name')`,
+/// Svelte `dispatch('name')`.
+fn template_emits(markup: &str, language: Language) -> Vec<String> {
+    let call = match language {
+        Language::Vue => "$emit(",
+        _ => "dispatch(",
+    };
+    markup
+        .match_indices(call)
+        .filter_map(|(i, m)| {
+            let rest = markup[i + m.len()..].trim_start();
+            let quote = rest
+                .chars()
+                .next()
+                .filter(|c| matches!(c, '\'' | '"' | '`'))?;
+            let end = rest[1..].find(quote)?;
+            Some(rest[1..1 + end].to_string())
+        })
+        .collect()
+}
+
+/// Child component tags in the markup, with the offset of their first use.
+///
+/// Capitalized tags are components in both frameworks; Vue also allows
+/// kebab-case (`<user-card>` for `UserCard`).
+fn child_components(markup: &str, language: Language) -> Vec<(String, usize)> {
+    let mut found: Vec<(String, usize)> = Vec::new();
+
+    for (i, _) in markup.match_indices('<') {
+        let tag: String = markup[i + 1..]
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | ':'))
+            .collect();
+        let Some(first) = tag.chars().next() else {
+            continue;
+        };
+        let name = if first.is_ascii_uppercase() && !tag.contains(':') {
+            tag
+        } else if language == Language::Vue && tag.contains('-') && !tag.contains(':') {
+            pascal_case(&tag)
+        } else {
+            continue;
+        };
+        if language == Language::Vue && VUE_BUILTINS.contains(&name.as_str()) {
+            continue;
+        }
+        if !found.iter().any(|(n, _)| *n == name) {
+            found.push((name, i));
+        }
+    }
+
+    found
+}
+
+fn renders_edge(path: &str, source: &str, tag: &str, target: String, line: u32) -> Edge {
+    Edge {
+        id: format!("renders:{}:{}", path, tag),
+        edge_type: EdgeType::Renders,
+        source_id: source.to_string(),
+        target_id: target,
+        metadata: EdgeMetadata::default(),
+        evidence: EvidenceLocation {
+            file: path.to_string(),
+            start_line: line,
+            end_line: line,
+            start_column: None,
+            end_column: None,
+        },
+        tier: InformationTier::Fact,
+        confidence: 1.0,
+        last_verified: Utc::now(),
+    }
+}
+
+fn line_at(content: &str, offset: usize) -> u32 {
+    content[..offset].matches('\n').count() as u32 + 1
+}
+
+fn is_component_file(source: &str) -> bool {
+    source.ends_with(".vue") || source.ends_with(".svelte")
+}
+
+/// Component name from the file stem: `user-card.vue` → `UserCard`.
+pub(super) fn component_name(path: &str) -> String {
+    let stem = Path::new(path)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or(path);
+    pascal_case(stem)
+}
+
+fn pascal_case(name: &str) -> String {
+    name.split(['-', '_', '.'])
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|c| c.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn component(result: &ParseResult) -> &crate::types::Node {
+        result
+            .nodes
+            .iter()
+            .find(|n| n.node_type == NodeType::Component)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_split_blocks() {
+        let content = r#"<!-- <script>not this</script> -->
+<template>
+  <List><template #item="{ x }"><Row :x="x" /></template></List>
+</template>
+<script setup lang="ts">
+const a = 1
+</script>
+<style scoped>.a {}</style>
+"#;
+        let blocks = split_blocks(content);
+        let tags: Vec<&str> = blocks.iter().map(|b| b.tag).collect();
+        assert_eq!(tags, vec!["template", "script", "style"]);
+        assert!(content[blocks[0].inner.clone()].contains("<Row"));
+        assert!(
+            content[blocks[0].inner.clone()]
+                .trim_end()
+                .ends_with("</List>")
+        );
+        assert_eq!(blocks[1].attrs, r#"setup lang="ts""#);
+        assert_eq!(content[blocks[1].inner.clone()].trim(), "const a = 1");
+    }
+
+    #[test]
+    fn test_vue_script_setup_component() {
+        let content = r#"<template>
+  <div>
+    <UserCard :user="user" @click="$emit('select', user)" />
+    <base-button />
+    <Transition />
+  </div>
+</template>
+
+<script setup lang="ts">
+import UserCard from './UserCard.vue'
+import BaseButton from '@/components/BaseButton.vue'
+
+const props = defineProps<{ title: string; count?: number }>()
+const emit = defineEmits<{ (e: 'change', id: number): void }>()
+const user = ref<User | null>(null)
+
+onMounted(() => emit('change', 1))
+
+function load() {}
+</script>
+"#;
+        let result = SfcParser::new(Language::Vue)
+            .unwrap()
+            .parse("./src/components/user-list.vue", content)
+            .unwrap();
+
+        let node = component(&result);
+        assert_eq!(node.id, "component:./src/components/user-list.vue:UserList");
+        let meta = node.metadata.component_metadata.as_ref().unwrap();
+
+        let props = meta.props.as_ref().unwrap();
+        assert_eq!(props.len(), 2);
+        assert_eq!(props[0].name, "title");
+        assert!(props[0].required);
+        assert_eq!(props[1].prop_type, "number");
+        assert!(!props[1].required);
+
+        assert_eq!(
+            meta.emits,
+            Some(vec!["change".to_string(), "select".to_string()])
+        );
+        assert_eq!(meta.hooks, Some(vec!["onMounted".to_string()]));
+        let state = &meta.state.as_ref().unwrap().fields;
+        assert_eq!(state[0].name, "user");
+        assert_eq!(state[0].field_type, "User | null");
+
+        let renders: Vec<&str> = result
+            .edges
+            .iter()
+            .filter(|e| e.edge_type == EdgeType::Renders)
+            .map(|e| e.target_id.as_str())
+            .collect();
+        assert_eq!(
+            renders,
+            vec![
+                "component:./src/components/UserCard.vue:UserCard",
+                "component:BaseButton"
+            ]
+        );
+
+        // The script is still parsed like any TypeScript file, at its real position
+        let load = result
+            .nodes
+            .iter()
+            .find(|n| n.name == "load" && n.node_type == NodeType::Function)
+            .unwrap();
+        assert_eq!(load.evidence.start_line, 19);
+    }
+
+    #[test]
+    fn test_vue_options_api() {
+        let content = r#"<script>
+export default defineComponent({
+  name: 'TodoItem',
+  props: { label: String, done: { type: Boolean, required: true } },
+  emits: ['toggle'],
+})
+</script>
+"#;
+        let result = SfcParser::new(Language::Vue)
+            .unwrap()
+            .parse("TodoItem.vue", content)
+            .unwrap();
+        let node = component(&result);
+        assert_eq!(node.name, "TodoItem");
+        let meta = node.metadata.component_metadata.as_ref().unwrap();
+        let props = meta.props.as_ref().unwrap();
+        assert_eq!(props[0].prop_type, "String");
+        assert!(props[1].required);
+        assert_eq!(meta.emits, Some(vec!["toggle".to_string()]));
+    }
+
+    #[test]
+    fn test_svelte_component() {
+        let content = r#"<script lang="ts">
+  import Row from './Row.svelte';
+  import { createEventDispatcher, onMount } from 'svelte';
+
+  export let items: string[];
+  export let title = 'List';
+  let selected = 0;
+
+  const dispatch = createEventDispatcher();
+  onMount(() => dispatch('ready'));
+</script>
+
+<h1>{title}</h1>
+{#each items as item}
+  <Row {item} on:click={() => dispatch('pick', item)} />
+{/each}
+<svelte:window />
+
+<style>h1 { color: red; }</style>
+"#;
+        let result = SfcParser::new(Language::Svelte)
+            .unwrap()
+            .parse("src/List.svelte", content)
+            .unwrap();
+
+        let node = component(&result);
+        assert_eq!(node.name, "List");
+        let meta = node.metadata.component_metadata.as_ref().unwrap();
+        let props = meta.props.as_ref().unwrap();
+        assert_eq!(props[0].name, "items");
+        assert_eq!(props[0].prop_type, "string[]");
+        assert!(props[0].required);
+        assert!(!props[1].required);
+        assert_eq!(meta.state.as_ref().unwrap().fields[0].name, "selected");
+        assert_eq!(
+            meta.emits,
+            Some(vec!["ready".to_string(), "pick".to_string()])
+        );
+
+        let renders: Vec<&str> = result
+            .edges
+            .iter()
+            .filter(|e| e.edge_type == EdgeType::Renders)
+            .map(|e| e.target_id.as_str())
+            .collect();
+        assert_eq!(renders, vec!["component:src/Row.svelte:Row"]);
+    }
+}
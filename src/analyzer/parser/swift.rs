@@ -0,0 +1,481 @@
+use tree_sitter::{Query, QueryCursor, StreamingIterator};
+
+use super::{
+    CallSyntax, DocSyntax, Language, MetricsSyntax, ParseResult, Parser, TestSyntax,
+    create_code_edge, create_code_node, create_file_node, create_ts_parser, extract_calls,
+    extract_docs, extract_metrics, extract_settings, extract_tests, fingerprint_nodes,
+    get_node_text,
+};
+use crate::types::{
+    EdgeType, FunctionSignature, ImportType, NodeMetadata, NodeType, Parameter, Result, Visibility,
+    WeaveError,
+};
+
+const CALL_SYNTAX: CallSyntax = CallSyntax {
+    scope_kinds: &[
+        "function_declaration",
+        "init_declaration",
+        "class_declaration",
+        "protocol_declaration",
+    ],
+    call_kinds: &[("call_expression", None)],
+    async_kinds: &["await_expression"],
+};
+
+const DOC_SYNTAX: DocSyntax = DocSyntax {
+    item_kinds: &[
+        "class_declaration",
+        "protocol_declaration",
+        "function_declaration",
+        "init_declaration",
+        "protocol_function_declaration",
+    ],
+    file_item_kinds: &[],
+    comment_kinds: &["comment", "multiline_comment"],
+    doc_prefixes: &["///", "/**"],
+    inner_prefixes: &[],
+    skip_kinds: &[],
+    wrapper_kinds: &[],
+    docstrings: false,
+};
+
+const TEST_SYNTAX: TestSyntax = TestSyntax {
+    item_kinds: &["function_declaration"],
+    marker_kinds: &[],
+    markers: &[],
+    name_prefixes: &["test"],
+    calls: &CALL_SYNTAX,
+};
+
+const METRICS_SYNTAX: MetricsSyntax = MetricsSyntax {
+    branch_kinds: &[
+        "if_statement",
+        "guard_statement",
+        "for_statement",
+        "while_statement",
+        "repeat_while_statement",
+        "switch_entry",
+        "catch_block",
+        "ternary_expression",
+        "conjunction_expression",
+        "disjunction_expression",
+    ],
+    logical_kinds: &[],
+    nesting_kinds: &[
+        "if_statement",
+        "for_statement",
+        "while_statement",
+        "repeat_while_statement",
+        "switch_statement",
+        "do_statement",
+    ],
+    return_kinds: &["control_transfer_statement"],
+};
+
+/// Keywords that open a `class_declaration` (the grammar shares one node kind).
+const TYPE_KEYWORDS: &[&str] = &["class", "struct", "enum", "extension", "actor"];
+
+pub struct SwiftParser;
+
+impl SwiftParser {
+    pub fn new() -> Result<Self> {
+        let _ = create_ts_parser(tree_sitter_swift::LANGUAGE, "Swift")?;
+        Ok(Self)
+    }
+}
+
+impl Parser for SwiftParser {
+    fn parse(&self, path: &str, content: &str) -> Result<ParseResult> {
+        let mut parser =
+            create_ts_parser(tree_sitter_swift::LANGUAGE, "Swift").map_err(|mut e| {
+                if let WeaveError::Parse {
+                    path: ref mut p, ..
+                } = e
+                {
+                    *p = path.to_string();
+                }
+                e
+            })?;
+
+        let tree = parser
+            .parse(content, None)
+            .ok_or_else(|| WeaveError::Parse {
+                message: "Failed to parse Swift file".to_string(),
+                path: path.to_string(),
+            })?;
+
+        let mut result = ParseResult::new();
+        let root = tree.root_node();
+
+        result.nodes.push(create_file_node(path));
+
+        extract_imports(root, content, path, &mut result);
+        extract_types(root, content, path, &mut result);
+        extract_functions(root, content, path, &mut result);
+        extract_calls(root, content, path, &mut result, &CALL_SYNTAX);
+        extract_docs(root, content, &mut result, &DOC_SYNTAX);
+        extract_tests(root, content, path, &mut result, &TEST_SYNTAX);
+        extract_settings(root, content, path, &mut result, &CALL_SYNTAX);
+        extract_metrics(root, content, &mut result, &METRICS_SYNTAX);
+        fingerprint_nodes(root, content, &mut result);
+
+        Ok(result)
+    }
+
+    fn language(&self) -> Language {
+        Language::Swift
+    }
+}
+
+fn extract_imports(root: tree_sitter::Node, content: &str, path: &str, result: &mut ParseResult) {
+    let query_str = r#"
+        (import_declaration
+            (identifier) @module
+        )
+    "#;
+
+    if let Ok(query) = Query::new(&tree_sitter_swift::LANGUAGE.into(), query_str) {
+        let mut cursor = QueryCursor::new();
+        let mut matches = cursor.matches(&query, root, content.as_bytes());
+
+        while let Some(m) = matches.next() {
+            for cap in m.captures.iter() {
+                let node = cap.node;
+                let module = get_node_text(node, content.as_bytes());
+
+                let mut edge = create_code_edge(
+                    format!("import:{}:{}", path, module),
+                    EdgeType::DependsOn,
+                    format!("file:{}", path),
+                    format!("module:{}", module),
+                    node,
+                    path,
+                );
+                edge.metadata.import_type = Some(ImportType::Static);
+                result.edges.push(edge);
+            }
+        }
+    }
+}
+
+fn extract_types(root: tree_sitter::Node, content: &str, path: &str, result: &mut ParseResult) {
+    let query_str = r#"
+        [
+            (class_declaration)
+            (protocol_declaration)
+        ] @decl
+    "#;
+
+    if let Ok(query) = Query::new(&tree_sitter_swift::LANGUAGE.into(), query_str) {
+        let mut cursor = QueryCursor::new();
+        let mut matches = cursor.matches(&query, root, content.as_bytes());
+
+        while let Some(m) = matches.next() {
+            for cap in m.captures.iter() {
+                let decl = cap.node;
+                let Some(name_node) = decl.child_by_field_name("name") else {
+                    continue;
+                };
+                let name = get_node_text(name_node, content.as_bytes()).to_string();
+                let keyword = type_keyword(decl);
+
+                // Extensions add conformances to a type declared elsewhere
+                let (prefix, node_type) = match keyword {
+                    "protocol" => ("interface", NodeType::Interface),
+                    "enum" => ("enum", NodeType::Enum),
+                    _ => ("class", NodeType::Class),
+                };
+                let type_id = format!("{}:{}:{}", prefix, path, name);
+
+                // Only a class can have a superclass, and it must come first
+                let mut extends = None;
+                let mut implements = Vec::new();
+                let mut cursor = decl.walk();
+                let inherited = decl
+                    .named_children(&mut cursor)
+                    .filter(|c| c.kind() == "inheritance_specifier")
+                    .filter_map(|c| c.child_by_field_name("inherits_from"));
+                for (i, parent) in inherited.enumerate() {
+                    let parent_name = get_node_text(parent, content.as_bytes());
+                    let (edge_type, label, target) = if i == 0 && keyword == "class" {
+                        extends = Some(parent_name.to_string());
+                        (EdgeType::Extends, "extends", "class")
+                    } else {
+                        implements.push(parent_name.to_string());
+                        (EdgeType::Implements, "implements", "interface")
+                    };
+                    result.edges.push(create_code_edge(
+                        format!("{}:{}:{}:{}", label, path, name, parent_name),
+                        edge_type,
+                        type_id.clone(),
+                        format!("{}:{}", target, parent_name),
+                        parent,
+                        path,
+                    ));
+                }
+
+                if keyword != "extension" {
+                    let mut type_node =
+                        create_code_node(type_id, node_type, path, name.clone(), decl);
+                    type_node.metadata.visibility = Some(swift_visibility(decl, content));
+                    type_node.metadata.extends = extends;
+                    if !implements.is_empty() {
+                        type_node.metadata.implements = Some(implements);
+                    }
+                    result.nodes.push(type_node);
+                }
+            }
+        }
+    }
+}
+
+fn extract_functions(root: tree_sitter::Node, content: &str, path: &str, result: &mut ParseResult) {
+    let query_str = r#"
+        [
+            (function_declaration)
+            (init_declaration)
+            (protocol_function_declaration)
+        ] @func
+    "#;
+
+    if let Ok(query) = Query::new(&tree_sitter_swift::LANGUAGE.into(), query_str) {
+        let mut cursor = QueryCursor::new();
+        let mut matches = cursor.matches(&query, root, content.as_bytes());
+
+        while let Some(m) = matches.next() {
+            for cap in m.captures.iter() {
+                let func = cap.node;
+                let name = if func.kind() == "init_declaration" {
+                    "init".to_string()
+                } else {
+                    match func.child_by_field_name("name") {
+                        Some(n) => get_node_text(n, content.as_bytes()).to_string(),
+                        None => continue,
+                    }
+                };
+
+                let owner = match enclosing_type(func, content, path) {
+                    Scope::Local => continue,
+                    Scope::TopLevel => None,
+                    Scope::Type(owner) => Some(owner),
+                };
+
+                let (id, node_type) = match owner {
+                    Some((ref owner_name, _)) => (
+                        format!("method:{}:{}:{}", path, owner_name, name),
+                        NodeType::Method,
+                    ),
+                    None => (format!("function:{}:{}", path, name), NodeType::Function),
+                };
+
+                let mut func_node =
+                    create_code_node(id.clone(), node_type, path, name.clone(), func);
+                func_node.metadata = NodeMetadata {
+                    visibility: Some(swift_visibility(func, content)),
+                    signature: Some(FunctionSignature {
+                        parameters: swift_parameters(func, content),
+                        return_type: return_type(func, content),
+                        is_async: has_keyword(func, "async"),
+                        generator: false,
+                    }),
+                    ..Default::default()
+                };
+                result.nodes.push(func_node);
+
+                if let Some((owner_name, owner_id)) = owner {
+                    result.edges.push(create_code_edge(
+                        format!("member:{}:{}:{}", path, owner_name, name),
+                        EdgeType::Owns,
+                        owner_id,
+                        id,
+                        func,
+                        path,
+                    ));
+                }
+            }
+        }
+    }
+}
+
+enum Scope {
+    TopLevel,
+    /// Owning type as (name, node ID)
+    Type((String, String)),
+    /// Nested inside a function body
+    Local,
+}
+
+fn enclosing_type(node: tree_sitter::Node, content: &str, path: &str) -> Scope {
+    let mut current = node.parent();
+    while let Some(parent) = current {
+        match parent.kind() {
+            "function_declaration" | "init_declaration" | "lambda_literal" => return Scope::Local,
+            "class_declaration" | "protocol_declaration" => {
+                let Some(name) = parent.child_by_field_name("name") else {
+                    return Scope::TopLevel;
+                };
+                let name = get_node_text(name, content.as_bytes()).to_string();
+                let prefix = match type_keyword(parent) {
+                    "protocol" => "interface",
+                    "enum" => "enum",
+                    _ => "class",
+                };
+                let id = format!("{}:{}:{}", prefix, path, name);
+                return Scope::Type((name, id));
+            }
+            _ => {}
+        }
+        current = parent.parent();
+    }
+    Scope::TopLevel
+}
+
+/// `class`, `struct`, `enum`, `extension`, `actor` or `protocol`.
+fn type_keyword(decl: tree_sitter::Node) -> &'static str {
+    if decl.kind() == "protocol_declaration" {
+        return "protocol";
+    }
+    let mut cursor = decl.walk();
+    decl.children(&mut cursor)
+        .find_map(|c| TYPE_KEYWORDS.iter().find(|k| **k == c.kind()).copied())
+        .unwrap_or("class")
+}
+
+fn has_keyword(node: tree_sitter::Node, keyword: &str) -> bool {
+    let mut cursor = node.walk();
+    node.children(&mut cursor).any(|c| c.kind() == keyword)
+}
+
+/// Swift access level; declarations without a modifier are `internal`.
+fn swift_visibility(decl: tree_sitter::Node, content: &str) -> Visibility {
+    let mut cursor = decl.walk();
+    let modifier = decl
+        .named_children(&mut cursor)
+        .filter(|c| c.kind() == "modifiers")
+        .flat_map(|m| {
+            let mut inner = m.walk();
+            m.named_children(&mut inner).collect::<Vec<_>>()
+        })
+        .find(|m| m.kind() == "visibility_modifier");
+
+    match modifier.map(|m| get_node_text(m, content.as_bytes())) {
+        Some(text) if text.starts_with("public") || text.starts_with("open") => Visibility::Public,
+        Some(text) if text.starts_with("private") || text.starts_with("fileprivate") => {
+            Visibility::Private
+        }
+        _ => Visibility::Internal,
+    }
+}
+
+fn swift_parameters(func: tree_sitter::Node, content: &str) -> Vec<Parameter> {
+    let bytes = content.as_bytes();
+    let mut cursor = func.walk();
+    func.children(&mut cursor)
+        .filter(|c| c.kind() == "parameter")
+        .filter_map(|param| {
+            let name = param.child_by_field_name("name")?;
+            let mut inner = param.walk();
+            let param_type = param
+                .named_children(&mut inner)
+                .last()
+                .filter(|t| t.id() != name.id())
+                .map(|t| get_node_text(t, bytes).to_string());
+
+            // Default values are siblings of the parameter: `name: T = value`
+            let default_value = param
+                .next_sibling()
+                .filter(|s| s.kind() == "=")
+                .and_then(|s| s.next_named_sibling())
+                .map(|v| get_node_text(v, bytes).to_string());
+
+            Some(Parameter {
+                name: get_node_text(name, bytes).to_string(),
+                param_type,
+                optional: default_value.is_some(),
+                default_value,
+            })
+        })
+        .collect()
+}
+
+fn return_type(func: tree_sitter::Node, content: &str) -> Option<String> {
+    let mut cursor = func.walk();
+    let arrow = func.children(&mut cursor).find(|c| c.kind() == "->")?;
+    arrow
+        .next_named_sibling()
+        .map(|t| get_node_text(t, content.as_bytes()).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swift_types_and_methods() {
+        let code = r#"
+import Foundation
+
+/// A user.
+public class User: Base, Codable {
+    public init(name: String) {}
+    func greet(to other: User, times: Int = 1) async -> String { return "hi" }
+    private func reset() {}
+}
+extension User: Greeter {}
+fileprivate func helper() {}
+"#;
+        let result = SwiftParser::new()
+            .unwrap()
+            .parse("User.swift", code)
+            .unwrap();
+
+        let user = result
+            .nodes
+            .iter()
+            .find(|n| n.id == "class:User.swift:User")
+            .unwrap();
+        assert_eq!(user.metadata.visibility, Some(Visibility::Public));
+        assert_eq!(user.metadata.extends.as_deref(), Some("Base"));
+        assert_eq!(user.metadata.description.as_deref(), Some("A user."));
+
+        let greet = result
+            .nodes
+            .iter()
+            .find(|n| n.id == "method:User.swift:User:greet")
+            .unwrap();
+        assert_eq!(greet.metadata.visibility, Some(Visibility::Internal));
+        let sig = greet.metadata.signature.as_ref().unwrap();
+        assert!(sig.is_async);
+        assert_eq!(sig.return_type.as_deref(), Some("String"));
+        assert_eq!(sig.parameters[1].default_value.as_deref(), Some("1"));
+
+        let helper = result
+            .nodes
+            .iter()
+            .find(|n| n.id == "function:User.swift:helper")
+            .unwrap();
+        assert_eq!(helper.metadata.visibility, Some(Visibility::Private));
+
+        // Extensions contribute conformances but no node of their own
+        assert_eq!(result.nodes.iter().filter(|n| n.name == "User").count(), 1);
+        assert!(
+            result
+                .edges
+                .iter()
+                .any(|e| e.edge_type == EdgeType::Implements && e.target_id == "interface:Greeter")
+        );
+        assert!(
+            result
+                .edges
+                .iter()
+                .any(|e| e.edge_type == EdgeType::Owns
+                    && e.target_id == "method:User.swift:User:init")
+        );
+        assert!(
+            result
+                .edges
+                .iter()
+                .any(|e| e.target_id == "module:Foundation")
+        );
+    }
+}
@@ -0,0 +1,346 @@
+//! Test Code Detection
+//!
+//! Classifies test files by path convention (`*_test.go`, `test_*.py`,
+//! `*.spec.ts`, `FooTest.java`, `tests/` directories) and test items by the
+//! markers each language uses: `#[test]` and `#[cfg(test)] mod tests` in
+//! Rust, JUnit `@Test`, xUnit `[Fact]`, or a `test` name prefix inside a test
+//! file. Each parser describes its grammar with a [`TestSyntax`] table.
+//!
+//! Test files and items are flagged with `extra.test = true`. Test functions
+//! also record the names they call in `extra.callees`, which the resolver
+//! uses to link them to the code under test once every file is parsed.
+
+use std::collections::HashMap;
+
+use super::calls::{CallSyntax, callee_names, scope_name};
+use super::traits::{ParseResult, get_node_text};
+use crate::types::NodeType;
+
+/// Directories whose files are all test code.
+const TEST_DIRS: &[&str] = &["test", "tests", "__tests__", "spec", "specs"];
+
+/// Grammar description for test detection.
+pub struct TestSyntax {
+    /// Node kinds that can be tests or test containers.
+    pub item_kinds: &'static [&'static str],
+    /// Attribute, annotation or decorator node kinds.
+    pub marker_kinds: &'static [&'static str],
+    /// Markers that make an item test code, matched against the marker's
+    /// last path segment (`test` for `#[tokio::test]`) or all of it
+    /// (`cfg(test)`).
+    pub markers: &'static [&'static str],
+    /// Name prefixes that make an item in a test file a test.
+    pub name_prefixes: &'static [&'static str],
+    /// Call syntax used to record what each test calls.
+    pub calls: &'static CallSyntax,
+}
+
+/// Whether a path follows a test file convention.
+pub fn is_test_path(path: &str) -> bool {
+    let path = path.replace('\\', "/");
+    let mut parts: Vec<&str> = path.split('/').collect();
+    let file_name = parts.pop().unwrap_or_default();
+    if parts.iter().any(|dir| TEST_DIRS.contains(dir)) {
+        return true;
+    }
+    file_name == "conftest.py" || subject_stem(file_name).is_some()
+}
+
+/// Stem of the file a test file name is testing: `parser` for
+/// `parser_test.go`, `test_parser.py` and `parser.spec.ts`, `Parser` for
+/// `ParserTest.java`. `None` when the name follows no test convention.
+pub fn subject_stem(file_name: &str) -> Option<&str> {
+    let stem = file_name.split('.').next().unwrap_or(file_name);
+    let mut inner = file_name.split('.').skip(1);
+    if inner.any(|part| part == "test" || part == "spec") && file_name.matches('.').count() > 1 {
+        return Some(stem);
+    }
+
+    let subject = stem
+        .strip_suffix("_test")
+        .or_else(|| stem.strip_suffix("_spec"))
+        .or_else(|| stem.strip_prefix("test_"))
+        .or_else(|| stem.strip_suffix("Tests"))
+        .or_else(|| stem.strip_suffix("Test"))
+        .or_else(|| stem.strip_suffix("Spec"))?;
+    (!subject.is_empty()).then_some(subject)
+}
+
+/// Flag test files and test items in `result`.
+///
+/// Must run after the parser has pushed its declaration nodes, which are
+/// matched to test items by start line.
+pub fn extract_tests(
+    root: tree_sitter::Node,
+    content: &str,
+    path: &str,
+    result: &mut ParseResult,
+    syntax: &TestSyntax,
+) {
+    let bytes = content.as_bytes();
+    let test_file = is_test_path(path);
+    if test_file
+        && let Some(file) = result
+            .nodes
+            .iter_mut()
+            .find(|n| n.node_type == NodeType::File)
+    {
+        file.metadata
+            .extra
+            .insert("test".to_string(), serde_json::Value::Bool(true));
+    }
+
+    // Start line -> names the test calls
+    let mut tests: HashMap<u32, Vec<String>> = HashMap::new();
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if syntax.item_kinds.contains(&node.kind()) && is_test_item(node, bytes, syntax, test_file)
+        {
+            let mut callees = callee_names(node, bytes, syntax.calls);
+            callees.extend(macro_callees(node, bytes));
+            callees.sort();
+            callees.dedup();
+            let mut rows = vec![node.start_position().row];
+            rows.extend(
+                node.child_by_field_name("name")
+                    .map(|n| n.start_position().row),
+            );
+            rows.extend(
+                leading_markers(node, syntax)
+                    .first()
+                    .map(|m| m.start_position().row),
+            );
+            for row in rows {
+                tests.insert(row as u32 + 1, callees.clone());
+            }
+        }
+
+        let mut cursor = node.walk();
+        stack.extend(node.named_children(&mut cursor));
+    }
+
+    for node in &mut result.nodes {
+        if !matches!(
+            node.node_type,
+            NodeType::Function | NodeType::Method | NodeType::Class | NodeType::Module
+        ) {
+            continue;
+        }
+        let Some(callees) = tests.get(&node.evidence.start_line) else {
+            continue;
+        };
+        let extra = &mut node.metadata.extra;
+        extra.insert("test".to_string(), serde_json::Value::Bool(true));
+        let callees: Vec<&String> = callees.iter().filter(|c| **c != node.name).collect();
+        if matches!(node.node_type, NodeType::Function | NodeType::Method) && !callees.is_empty() {
+            extra.insert("callees".to_string(), serde_json::json!(callees));
+        }
+    }
+}
+
+fn is_test_item(
+    node: tree_sitter::Node,
+    content: &[u8],
+    syntax: &TestSyntax,
+    test_file: bool,
+) -> bool {
+    let marked = leading_markers(node, syntax)
+        .into_iter()
+        .chain(inner_markers(node, syntax))
+        .any(|marker| matches_marker(get_node_text(marker, content), syntax.markers));
+    if marked {
+        return true;
+    }
+
+    test_file
+        && scope_name(node, content).is_some_and(|name| {
+            syntax
+                .name_prefixes
+                .iter()
+                .any(|prefix| name.starts_with(prefix))
+        })
+}
+
+/// Markers written as siblings before the item (`#[test]`, decorators).
+fn leading_markers<'t>(
+    node: tree_sitter::Node<'t>,
+    syntax: &TestSyntax,
+) -> Vec<tree_sitter::Node<'t>> {
+    let mut markers = Vec::new();
+    let mut sibling = node.prev_named_sibling();
+    while let Some(prev) = sibling {
+        if syntax.marker_kinds.contains(&prev.kind()) {
+            markers.push(prev);
+        } else if !prev.kind().contains("comment") {
+            break;
+        }
+        sibling = prev.prev_named_sibling();
+    }
+    markers.reverse();
+    markers
+}
+
+/// Markers inside the item's header (`modifiers`, `attribute_list`), not
+/// its body or parameters.
+fn inner_markers<'t>(
+    node: tree_sitter::Node<'t>,
+    syntax: &TestSyntax,
+) -> Vec<tree_sitter::Node<'t>> {
+    let mut markers = Vec::new();
+    let mut stack = Vec::new();
+    let mut cursor = node.walk();
+    for (i, child) in node.named_children(&mut cursor).enumerate() {
+        let field = node.field_name_for_named_child(i as u32);
+        if !matches!(field, Some("body" | "parameters")) {
+            stack.push(child);
+        }
+    }
+
+    while let Some(child) = stack.pop() {
+        if syntax.marker_kinds.contains(&child.kind()) {
+            markers.push(child);
+            continue;
+        }
+        let mut cursor = child.walk();
+        stack.extend(child.named_children(&mut cursor));
+    }
+    markers
+}
+
+/// Calls inside Rust macro arguments (`assert_eq!(add(1), 2)`), which the
+/// grammar leaves as token trees: an identifier followed by a parenthesized
+/// tree.
+fn macro_callees(node: tree_sitter::Node, content: &[u8]) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut stack = vec![node];
+    while let Some(node) = stack.pop() {
+        let mut cursor = node.walk();
+        let children: Vec<_> = node.named_children(&mut cursor).collect();
+        if node.kind() == "token_tree" {
+            for (i, pair) in children.windows(2).enumerate() {
+                if pair[0].kind() != "identifier"
+                    || pair[1].kind() != "token_tree"
+                    || !get_node_text(pair[1], content).starts_with('(')
+                {
+                    continue;
+                }
+                names.push(get_node_text(pair[0], content).to_string());
+                if let Some(qualifier) = i.checked_sub(1).map(|q| children[q])
+                    && qualifier.kind() == "identifier"
+                    && get_node_text(qualifier, content).starts_with(|c: char| c.is_uppercase())
+                {
+                    names.push(get_node_text(qualifier, content).to_string());
+                }
+            }
+        }
+        stack.extend(children);
+    }
+    names
+}
+
+/// Match `#[tokio::test]`, `@org.junit.Test`, `[Fact]` or `#[cfg(test)]`
+/// against marker names.
+fn matches_marker(text: &str, markers: &[&str]) -> bool {
+    let full: String = text
+        .trim_start_matches(['#', '@', '['])
+        .trim_end_matches(']')
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    let path = full.split('(').next().unwrap_or(&full);
+    let name = path.rsplit([':', '.']).next().unwrap_or(path);
+    markers.iter().any(|m| *m == name || *m == full)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::parser::{JavaParser, Parser, PythonParser, RustParser};
+
+    fn tests_of(result: &ParseResult) -> Vec<(&str, Option<&serde_json::Value>)> {
+        result
+            .nodes
+            .iter()
+            .filter(|n| n.metadata.extra.contains_key("test") && n.node_type != NodeType::File)
+            .map(|n| (n.name.as_str(), n.metadata.extra.get("callees")))
+            .collect()
+    }
+
+    #[test]
+    fn test_test_paths() {
+        for path in [
+            "pkg/parser_test.go",
+            "tests/test_parser.py",
+            "app/parser_test.py",
+            "src/Parser.spec.ts",
+            "src/__tests__/Parser.tsx",
+            "src/test/java/com/x/ParserTest.java",
+            "Tests/ParserTests.cs",
+            "spec/parser_spec.rb",
+            "tests/integration.rs",
+            "conftest.py",
+        ] {
+            assert!(is_test_path(path), "{}", path);
+        }
+        for path in [
+            "src/latest.rs",
+            "src/Contest.java",
+            "src/testing.rs",
+            "src/parser.ts",
+        ] {
+            assert!(!is_test_path(path), "{}", path);
+        }
+
+        assert_eq!(subject_stem("parser_test.go"), Some("parser"));
+        assert_eq!(subject_stem("Parser.test.tsx"), Some("Parser"));
+        assert_eq!(subject_stem("ParserTest.java"), Some("Parser"));
+        assert_eq!(subject_stem("Test.java"), None);
+    }
+
+    #[test]
+    fn test_rust_python_and_junit_tests() {
+        let rust = RustParser::new()
+            .unwrap()
+            .parse(
+                "src/lib.rs",
+                "pub fn parse() {}\n#[cfg(test)]\nmod tests {\n    use super::*;\n\n    \
+                 #[test]\n    fn parses() { parse(); }\n\n    #[tokio::test]\n    \
+                 async fn parses_async() { crate::parse(); }\n\n    fn helper() {}\n}\n",
+            )
+            .unwrap();
+        let found = tests_of(&rust);
+        assert!(found.contains(&("parses", Some(&serde_json::json!(["parse"])))));
+        assert!(found.iter().any(|(name, _)| *name == "parses_async"));
+        assert!(
+            !found
+                .iter()
+                .any(|(name, _)| *name == "helper" || *name == "parse")
+        );
+
+        let python = PythonParser::new()
+            .unwrap()
+            .parse(
+                "tests/test_config.py",
+                "import pytest\nfrom app.config import load\n\n@pytest.mark.slow\n\
+                 def test_load():\n    assert load('x')\n\ndef make_config():\n    pass\n",
+            )
+            .unwrap();
+        assert!(python.nodes[0].metadata.extra.contains_key("test"));
+        assert_eq!(
+            tests_of(&python),
+            vec![("test_load", Some(&serde_json::json!(["load"])))]
+        );
+
+        let java = JavaParser::new()
+            .unwrap()
+            .parse(
+                "src/test/java/ParserTest.java",
+                "class ParserTest {\n    @Test\n    void parses() { new Parser().parse(); }\n\n    \
+                 private void setUp() {}\n}\n",
+            )
+            .unwrap();
+        let found = tests_of(&java);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].0, "parses");
+    }
+}
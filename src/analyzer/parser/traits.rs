@@ -11,6 +11,21 @@ use crate::types::{
 pub struct ParseResult {
     pub nodes: Vec<Node>,
     pub edges: Vec<Edge>,
+    /// Calls to names this file does not declare, left for
+    /// [`resolve_calls`](crate::analyzer::resolver::resolve_calls)
+    pub unresolved_calls: Vec<UnresolvedCall>,
+}
+
+/// A call the parser could not resolve within its file.
+#[derive(Debug, Clone)]
+pub struct UnresolvedCall {
+    /// Called name: the final segment of the callee (`load` in `api.load()`)
+    pub name: String,
+    /// Receiver or path segment before the name (`api`, `util` in `util::pad()`)
+    pub qualifier: Option<String>,
+    /// The `calls` edge from the caller, aggregated over its call sites,
+    /// whose target is set once the callee is found
+    pub edge: Edge,
 }
 
 impl ParseResult {
@@ -18,12 +33,14 @@ impl ParseResult {
         Self {
             nodes: Vec::new(),
             edges: Vec::new(),
+            unresolved_calls: Vec::new(),
         }
     }
 
     pub fn merge(&mut self, other: ParseResult) {
         self.nodes.extend(other.nodes);
         self.edges.extend(other.edges);
+        self.unresolved_calls.extend(other.unresolved_calls);
     }
 }
 
@@ -1,37 +1,136 @@
-use chrono::Utc;
-use tree_sitter::{Parser as TsParser, Query, QueryCursor, StreamingIterator};
+use std::collections::BTreeMap;
 
-use super::{Language, ParseResult, Parser, create_file_node};
+use chrono::Utc;
+use tree_sitter::{Query, QueryCursor, StreamingIterator};
+
+use super::routes::{named_args, preorder, string_value};
+use super::{
+    CallSyntax, DocSyntax, EntityDialect, Language, MetricsSyntax, ParseResult, Parser,
+    RouteDialect, TestSyntax, create_file_node, create_ts_parser, evidence_from_node,
+    extract_calls, extract_components, extract_docs, extract_entities, extract_fields,
+    extract_metrics, extract_return_types, extract_routes, extract_settings, extract_tests,
+    fingerprint_nodes, get_node_text,
+};
+use crate::analyzer::resolver::resolve_relative;
 use crate::types::{
     Edge, EdgeMetadata, EdgeType, EvidenceLocation, FunctionSignature, ImportType, InformationTier,
     Node, NodeMetadata, NodeStatus, NodeType, Parameter, Result, Visibility, WeaveError,
 };
 
-pub struct TypeScriptParser;
+const CALL_SYNTAX: CallSyntax = CallSyntax {
+    scope_kinds: &[
+        "function_declaration",
+        "method_definition",
+        "variable_declarator",
+        "class_declaration",
+    ],
+    call_kinds: &[
+        ("call_expression", Some("function")),
+        ("new_expression", Some("constructor")),
+    ],
+    async_kinds: &["await_expression"],
+};
+
+const DOC_SYNTAX: DocSyntax = DocSyntax {
+    item_kinds: &[
+        "function_declaration",
+        "generator_function_declaration",
+        "class_declaration",
+        "abstract_class_declaration",
+        "interface_declaration",
+        "type_alias_declaration",
+        "enum_declaration",
+        "method_definition",
+        "lexical_declaration",
+    ],
+    file_item_kinds: &[],
+    comment_kinds: &["comment"],
+    doc_prefixes: &["/**"],
+    inner_prefixes: &[],
+    skip_kinds: &["decorator"],
+    wrapper_kinds: &["export_statement"],
+    docstrings: false,
+};
+
+// Tests here are found by file name only
+const TEST_SYNTAX: TestSyntax = TestSyntax {
+    item_kinds: &[],
+    marker_kinds: &[],
+    markers: &[],
+    name_prefixes: &[],
+    calls: &CALL_SYNTAX,
+};
+
+const METRICS_SYNTAX: MetricsSyntax = MetricsSyntax {
+    branch_kinds: &[
+        "if_statement",
+        "for_statement",
+        "for_in_statement",
+        "while_statement",
+        "do_statement",
+        "switch_case",
+        "catch_clause",
+        "ternary_expression",
+    ],
+    logical_kinds: &["binary_expression"],
+    nesting_kinds: &[
+        "if_statement",
+        "for_statement",
+        "for_in_statement",
+        "while_statement",
+        "do_statement",
+        "switch_statement",
+        "try_statement",
+    ],
+    return_kinds: &["return_statement"],
+};
+
+/// Parser for TypeScript and JavaScript sources.
+///
+/// `.ts` files use the TypeScript grammar. `.tsx`, `.jsx` and `.js` files use
+/// the TSX grammar, which also accepts JSX (JavaScript has no `<T>x` casts for
+/// it to misread), and their React components are extracted.
+pub struct TypeScriptParser {
+    language: Language,
+}
 
 impl TypeScriptParser {
     pub fn new() -> Result<Self> {
-        let mut parser = TsParser::new();
-        parser
-            .set_language(&tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into())
-            .map_err(|e| WeaveError::Parse {
-                message: format!("Failed to set TypeScript language: {}", e),
-                path: String::new(),
-            })?;
-        Ok(Self)
+        Self::for_language(Language::TypeScript)
+    }
+
+    pub fn for_language(language: Language) -> Result<Self> {
+        match language {
+            Language::TypeScript | Language::JavaScript | Language::Tsx | Language::Jsx => {
+                let parser = Self { language };
+                create_ts_parser(parser.grammar(), "TypeScript")?;
+                Ok(parser)
+            }
+            _ => Err(WeaveError::Config(format!(
+                "Not a TypeScript or JavaScript language: {}",
+                language
+            ))),
+        }
+    }
+
+    fn grammar(&self) -> tree_sitter::Language {
+        match self.language {
+            Language::TypeScript => tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
+            _ => tree_sitter_typescript::LANGUAGE_TSX.into(),
+        }
     }
 }
 
 impl Parser for TypeScriptParser {
     fn parse(&self, path: &str, content: &str) -> Result<ParseResult> {
-        let mut parser = TsParser::new();
-        let language = tree_sitter_typescript::LANGUAGE_TYPESCRIPT;
-        parser
-            .set_language(&language.into())
-            .map_err(|e| WeaveError::Parse {
-                message: format!("Failed to set TypeScript language: {}", e),
+        let grammar = self.grammar();
+        let mut parser = create_ts_parser(grammar.clone(), "TypeScript").map_err(|e| match e {
+            WeaveError::Parse { message, .. } => WeaveError::Parse {
+                message,
                 path: path.to_string(),
-            })?;
+            },
+            e => e,
+        })?;
 
         let tree = parser
             .parse(content, None)
@@ -47,75 +146,300 @@ impl Parser for TypeScriptParser {
         result.nodes.push(file_node);
 
         extract_imports(root, content, path, &mut result);
-        extract_exports(root, content, path, &mut result);
-        extract_classes(root, content, path, &mut result);
-        extract_functions(root, content, path, &mut result);
-        extract_interfaces(root, content, path, &mut result);
+        extract_exports(root, content, path, &mut result, &grammar);
+        extract_classes(root, content, path, &mut result, &grammar);
+        extract_functions(root, content, path, &mut result, &grammar);
+        extract_interfaces(root, content, path, &mut result, &grammar);
+        extract_calls(root, content, path, &mut result, &CALL_SYNTAX);
+        extract_docs(root, content, &mut result, &DOC_SYNTAX);
+        extract_tests(root, content, path, &mut result, &TEST_SYNTAX);
+        extract_routes(root, content, path, &mut result, RouteDialect::JavaScript);
+        extract_entities(root, content, path, &mut result, EntityDialect::JavaScript);
+        if self.language != Language::TypeScript {
+            extract_components(root, content, path, &mut result);
+        }
+        extract_return_types(root, content, &mut result, "return_type");
+        extract_fields(root, content, &mut result);
+        extract_settings(root, content, path, &mut result, &CALL_SYNTAX);
+        extract_metrics(root, content, &mut result, &METRICS_SYNTAX);
+        fingerprint_nodes(root, content, &mut result);
 
         Ok(result)
     }
 
     fn language(&self) -> Language {
-        Language::TypeScript
+        self.language
     }
 }
 
+/// A module specifier loaded by the file, merged across every statement
+/// that loads it.
+struct ModuleImport<'tree> {
+    source: String,
+    import_type: ImportType,
+    symbols: Vec<String>,
+    /// Local names bound to something else: local name -> imported name
+    aliases: BTreeMap<String, String>,
+    node: tree_sitter::Node<'tree>,
+}
+
+/// Record `import`/`export ... from` statements, `require()` and dynamic
+/// `import()` as `depends_on` edges, one per module specifier.
+///
+/// `imported_symbols` holds the names as the target module exports them:
+/// `default` for default imports and `*` for namespace imports and
+/// `export *`. Local names that differ from those (`import * as api`,
+/// `{ load as fetch }`) are kept in `extra.aliases`. Relative specifiers get a best-guess `file:` target that the
+/// import resolver replaces once it can probe the real files.
 fn extract_imports(root: tree_sitter::Node, content: &str, path: &str, result: &mut ParseResult) {
-    let query_str = r#"
-        (import_statement
-            source: (string) @source
-        )
-    "#;
+    let bytes = content.as_bytes();
+    let mut imports: Vec<ModuleImport> = Vec::new();
+
+    for node in preorder(root) {
+        let found = match node.kind() {
+            "import_statement" | "export_statement" => static_import(node, bytes),
+            "call_expression" => call_import(node, bytes),
+            _ => None,
+        };
+        let Some((source, import_type, symbols)) = found else {
+            continue;
+        };
+        let aliases = import_aliases(node, bytes);
+
+        match imports.iter_mut().find(|i| i.source == source) {
+            Some(existing) => {
+                // A runtime import outweighs a dynamic one, which outweighs types
+                if import_rank(import_type) < import_rank(existing.import_type) {
+                    existing.import_type = import_type;
+                }
+                for symbol in symbols {
+                    if !existing.symbols.contains(&symbol) {
+                        existing.symbols.push(symbol);
+                    }
+                }
+                existing.aliases.extend(aliases);
+            }
+            None => imports.push(ModuleImport {
+                source,
+                import_type,
+                symbols,
+                aliases,
+                node,
+            }),
+        }
+    }
 
-    if let Ok(query) = Query::new(
-        &tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
-        query_str,
-    ) {
-        let mut cursor = QueryCursor::new();
-        let mut matches = cursor.matches(&query, root, content.as_bytes());
+    for import in imports {
+        // Bare specifiers may name a workspace package or path alias;
+        // the package resolver rewrites them once all files are known
+        let source = import.source;
+        let target_id = if source.starts_with('.') || source.starts_with('/') {
+            format!("file:{}", resolve_import(path, &source))
+        } else {
+            format!("module:{}", source)
+        };
+
+        let mut extra = std::collections::HashMap::new();
+        extra.insert("specifier".to_string(), serde_json::json!(source));
+        if !import.aliases.is_empty() {
+            extra.insert("aliases".to_string(), serde_json::json!(import.aliases));
+        }
+        result.edges.push(Edge {
+            id: format!("dep:{}:{}", path, source),
+            edge_type: EdgeType::DependsOn,
+            source_id: format!("file:{}", path),
+            target_id,
+            metadata: EdgeMetadata {
+                import_type: Some(import.import_type),
+                imported_symbols: (!import.symbols.is_empty()).then_some(import.symbols),
+                extra,
+                ..Default::default()
+            },
+            evidence: evidence_from_node(import.node, path),
+            tier: InformationTier::Fact,
+            confidence: 1.0,
+            last_verified: Utc::now(),
+        });
+    }
+}
 
-        while let Some(m) = matches.next() {
-            for cap in m.captures.iter() {
-                let node = cap.node;
-                let source_text = node.utf8_text(content.as_bytes()).unwrap_or("");
-                let source = source_text.trim_matches(|c| c == '"' || c == '\'');
+fn import_rank(import_type: ImportType) -> u8 {
+    match import_type {
+        ImportType::Static => 0,
+        ImportType::Dynamic => 1,
+        ImportType::TypeOnly => 2,
+    }
+}
 
-                if !source.starts_with('.') && !source.starts_with('/') {
-                    continue;
+/// `import ... from 'x'`, `export ... from 'x'` and `export * from 'x'`.
+fn static_import(
+    node: tree_sitter::Node,
+    bytes: &[u8],
+) -> Option<(String, ImportType, Vec<String>)> {
+    let source = string_value(node.child_by_field_name("source")?, bytes)?;
+    let mut type_only = has_type_keyword(node);
+    let mut symbols = Vec::new();
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        match child.kind() {
+            "import_clause" => {
+                let mut all_types = true;
+                let mut inner = child.walk();
+                for part in child.named_children(&mut inner) {
+                    match part.kind() {
+                        "identifier" => {
+                            all_types = false;
+                            symbols.push("default".to_string());
+                        }
+                        "namespace_import" => {
+                            all_types = false;
+                            symbols.push("*".to_string());
+                        }
+                        "named_imports" => {
+                            let mut specs = part.walk();
+                            for spec in part.named_children(&mut specs) {
+                                all_types &= has_type_keyword(spec);
+                                if let Some(name) = spec.child_by_field_name("name") {
+                                    symbols.push(get_node_text(name, bytes).to_string());
+                                }
+                            }
+                        }
+                        _ => {}
+                    }
                 }
+                type_only |= all_types && !symbols.is_empty();
+            }
+            "export_clause" => {
+                let mut specs = child.walk();
+                for spec in child.named_children(&mut specs) {
+                    if let Some(name) = spec.child_by_field_name("name") {
+                        symbols.push(get_node_text(name, bytes).to_string());
+                    }
+                }
+            }
+            "namespace_export" => symbols.push("*".to_string()),
+            _ => {}
+        }
+    }
+    // `export * from 'x'` has no named child besides its source
+    if node.kind() == "export_statement" && symbols.is_empty() {
+        symbols.push("*".to_string());
+    }
 
-                let edge = Edge {
-                    id: format!("dep:{}:{}", path, source),
-                    edge_type: EdgeType::DependsOn,
-                    source_id: format!("file:{}", path),
-                    target_id: format!("file:{}", resolve_import(path, source)),
-                    metadata: EdgeMetadata {
-                        import_type: Some(ImportType::Static),
-                        imported_symbols: None,
-                        call_count: None,
-                        is_async: None,
-                        exposed_as: None,
-                        extra: Default::default(),
-                    },
-                    evidence: EvidenceLocation {
-                        file: path.to_string(),
-                        start_line: node.start_position().row as u32 + 1,
-                        end_line: node.end_position().row as u32 + 1,
-                        start_column: Some(node.start_position().column as u32),
-                        end_column: Some(node.end_position().column as u32),
-                    },
-                    tier: InformationTier::Fact,
-                    confidence: 1.0,
-                    last_verified: Utc::now(),
-                };
+    let import_type = if type_only {
+        ImportType::TypeOnly
+    } else {
+        ImportType::Static
+    };
+    Some((source, import_type, symbols))
+}
 
-                result.edges.push(edge);
+/// Names an `import` statement binds to something other than themselves:
+/// namespaces (`* as api`), defaults and renamed specifiers.
+fn import_aliases(node: tree_sitter::Node, bytes: &[u8]) -> BTreeMap<String, String> {
+    let mut aliases = BTreeMap::new();
+    if node.kind() != "import_statement" {
+        return aliases;
+    }
+    let mut cursor = node.walk();
+    let clauses: Vec<_> = node
+        .named_children(&mut cursor)
+        .filter(|c| c.kind() == "import_clause")
+        .collect();
+    for clause in clauses {
+        let mut inner = clause.walk();
+        for part in clause.named_children(&mut inner) {
+            match part.kind() {
+                "identifier" => {
+                    aliases.insert(
+                        get_node_text(part, bytes).to_string(),
+                        "default".to_string(),
+                    );
+                }
+                "namespace_import" => {
+                    let mut ids = part.walk();
+                    if let Some(local) = part
+                        .named_children(&mut ids)
+                        .find(|c| c.kind() == "identifier")
+                    {
+                        aliases.insert(get_node_text(local, bytes).to_string(), "*".to_string());
+                    }
+                }
+                "named_imports" => {
+                    let mut specs = part.walk();
+                    for spec in part.named_children(&mut specs) {
+                        if let (Some(name), Some(alias)) = (
+                            spec.child_by_field_name("name"),
+                            spec.child_by_field_name("alias"),
+                        ) {
+                            aliases.insert(
+                                get_node_text(alias, bytes).to_string(),
+                                get_node_text(name, bytes).to_string(),
+                            );
+                        }
+                    }
+                }
+                _ => {}
             }
         }
     }
+    aliases
 }
 
-fn extract_exports(root: tree_sitter::Node, content: &str, path: &str, result: &mut ParseResult) {
+/// `require('x')` and `import('x')`, with the names destructured from them.
+fn call_import(node: tree_sitter::Node, bytes: &[u8]) -> Option<(String, ImportType, Vec<String>)> {
+    let function = node.child_by_field_name("function")?;
+    let import_type = match function.kind() {
+        "import" => ImportType::Dynamic,
+        "identifier" if get_node_text(function, bytes) == "require" => ImportType::Static,
+        _ => return None,
+    };
+    let args = named_args(node.child_by_field_name("arguments"));
+    let source = string_value(*args.first()?, bytes)?;
+
+    // `const { a, b } = require('x')` / `= await import('x')`
+    let mut symbols = Vec::new();
+    let mut parent = node.parent();
+    while let Some(p) = parent.filter(|p| p.kind() == "await_expression") {
+        parent = p.parent();
+    }
+    if let Some(pattern) = parent
+        .filter(|p| p.kind() == "variable_declarator")
+        .and_then(|p| p.child_by_field_name("name"))
+        .filter(|n| n.kind() == "object_pattern")
+    {
+        let mut cursor = pattern.walk();
+        for prop in pattern.named_children(&mut cursor) {
+            let name = match prop.kind() {
+                "shorthand_property_identifier_pattern" => Some(prop),
+                "pair_pattern" => prop.child_by_field_name("key"),
+                _ => None,
+            };
+            if let Some(name) = name {
+                symbols.push(get_node_text(name, bytes).to_string());
+            }
+        }
+    }
+
+    Some((source, import_type, symbols))
+}
+
+/// Whether a statement or specifier carries the `type` keyword
+/// (`import type ...`, `import { type T }`).
+fn has_type_keyword(node: tree_sitter::Node) -> bool {
+    let mut cursor = node.walk();
+    node.children(&mut cursor)
+        .any(|c| !c.is_named() && c.kind() == "type")
+}
+
+fn extract_exports(
+    root: tree_sitter::Node,
+    content: &str,
+    path: &str,
+    result: &mut ParseResult,
+    grammar: &tree_sitter::Language,
+) {
     let query_str = r#"
         (export_statement
             declaration: [
@@ -126,10 +450,7 @@ fn extract_exports(root: tree_sitter::Node, content: &str, path: &str, result: &
         )
     "#;
 
-    if let Ok(query) = Query::new(
-        &tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
-        query_str,
-    ) {
+    if let Ok(query) = Query::new(grammar, query_str) {
         let mut cursor = QueryCursor::new();
         let mut matches = cursor.matches(&query, root, content.as_bytes());
 
@@ -165,17 +486,20 @@ fn extract_exports(root: tree_sitter::Node, content: &str, path: &str, result: &
     }
 }
 
-fn extract_classes(root: tree_sitter::Node, content: &str, path: &str, result: &mut ParseResult) {
+fn extract_classes(
+    root: tree_sitter::Node,
+    content: &str,
+    path: &str,
+    result: &mut ParseResult,
+    grammar: &tree_sitter::Language,
+) {
     let query_str = r#"
         (class_declaration
             name: (type_identifier) @name
         )
     "#;
 
-    if let Ok(query) = Query::new(
-        &tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
-        query_str,
-    ) {
+    if let Ok(query) = Query::new(grammar, query_str) {
         let mut cursor = QueryCursor::new();
         let mut matches = cursor.matches(&query, root, content.as_bytes());
 
@@ -232,7 +556,13 @@ fn extract_classes(root: tree_sitter::Node, content: &str, path: &str, result: &
     }
 }
 
-fn extract_functions(root: tree_sitter::Node, content: &str, path: &str, result: &mut ParseResult) {
+fn extract_functions(
+    root: tree_sitter::Node,
+    content: &str,
+    path: &str,
+    result: &mut ParseResult,
+    grammar: &tree_sitter::Language,
+) {
     let query_str = r#"
         (function_declaration
             name: (identifier) @name
@@ -240,10 +570,7 @@ fn extract_functions(root: tree_sitter::Node, content: &str, path: &str, result:
         )
     "#;
 
-    if let Ok(query) = Query::new(
-        &tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
-        query_str,
-    ) {
+    if let Ok(query) = Query::new(grammar, query_str) {
         let mut cursor = QueryCursor::new();
         let mut matches = cursor.matches(&query, root, content.as_bytes());
 
@@ -323,6 +650,7 @@ fn extract_interfaces(
     content: &str,
     path: &str,
     result: &mut ParseResult,
+    grammar: &tree_sitter::Language,
 ) {
     let query_str = r#"
         (interface_declaration
@@ -330,10 +658,7 @@ fn extract_interfaces(
         )
     "#;
 
-    if let Ok(query) = Query::new(
-        &tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
-        query_str,
-    ) {
+    if let Ok(query) = Query::new(grammar, query_str) {
         let mut cursor = QueryCursor::new();
         let mut matches = cursor.matches(&query, root, content.as_bytes());
 
@@ -396,18 +721,106 @@ fn parse_parameters(params_text: &str) -> Vec<Parameter> {
         .collect()
 }
 
+/// Join a relative specifier onto the importing file. Specifiers without
+/// a known extension are assumed to name a `.ts` file until the resolver
+/// probes the real candidates.
 fn resolve_import(current_path: &str, import_path: &str) -> String {
-    let current_dir = std::path::Path::new(current_path)
-        .parent()
-        .unwrap_or(std::path::Path::new(""));
-
-    let resolved = current_dir.join(import_path);
-
-    let path_str = resolved.to_string_lossy().to_string();
-
-    if !path_str.ends_with(".ts") && !path_str.ends_with(".tsx") {
-        format!("{}.ts", path_str)
+    let resolved = resolve_relative(current_path, import_path);
+
+    // Imports that already name a file (`./App.vue`, `./util.js`) keep it
+    let has_extension = resolved
+        .rsplit_once('.')
+        .filter(|(_, ext)| !ext.contains('/'))
+        .is_some_and(|(_, ext)| {
+            matches!(
+                ext,
+                "ts" | "tsx" | "js" | "jsx" | "mjs" | "cjs" | "json" | "vue" | "svelte"
+            )
+        });
+
+    if !has_extension {
+        format!("{}.ts", resolved)
     } else {
-        path_str
+        resolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_import_forms() {
+        let code = r#"
+import type { User } from './types';
+import def, { a, b as c, type T } from '../lib/util';
+import * as ns from 'lodash';
+import './side-effect';
+export * from './all';
+export { x, y as z } from './xy';
+const { p, q: renamed } = require('./cjs');
+const lazy = await import('./lazy');
+import { a as again } from '../lib/util';
+"#;
+        let result = TypeScriptParser::new()
+            .unwrap()
+            .parse("./src/app/main.ts", code)
+            .unwrap();
+        let import = |source: &str| {
+            result
+                .edges
+                .iter()
+                .find(|e| e.id == format!("dep:./src/app/main.ts:{}", source))
+                .unwrap_or_else(|| panic!("no import of {}", source))
+        };
+        let symbols = |source: &str| import(source).metadata.imported_symbols.clone();
+
+        assert_eq!(
+            import("./types").metadata.import_type,
+            Some(ImportType::TypeOnly)
+        );
+        assert_eq!(import("./types").target_id, "file:./src/app/types.ts");
+        assert_eq!(symbols("./types"), Some(vec!["User".to_string()]));
+
+        let util = import("../lib/util");
+        assert_eq!(util.target_id, "file:./src/lib/util.ts");
+        assert_eq!(util.metadata.import_type, Some(ImportType::Static));
+        assert_eq!(
+            util.metadata.imported_symbols,
+            Some(vec!["default".into(), "a".into(), "b".into(), "T".into()])
+        );
+        assert_eq!(
+            result
+                .edges
+                .iter()
+                .filter(|e| e.id.ends_with("../lib/util"))
+                .count(),
+            1,
+            "repeated imports of one module are merged"
+        );
+
+        assert_eq!(
+            util.metadata.extra["aliases"],
+            serde_json::json!({"again": "a", "c": "b", "def": "default"})
+        );
+
+        assert_eq!(import("lodash").target_id, "module:lodash");
+        assert_eq!(
+            import("lodash").metadata.extra["aliases"],
+            serde_json::json!({"ns": "*"})
+        );
+        assert_eq!(symbols("lodash"), Some(vec!["*".to_string()]));
+        assert_eq!(symbols("./side-effect"), None);
+        assert_eq!(symbols("./all"), Some(vec!["*".to_string()]));
+        assert_eq!(symbols("./xy"), Some(vec!["x".into(), "y".into()]));
+        assert_eq!(symbols("./cjs"), Some(vec!["p".into(), "q".into()]));
+        assert_eq!(
+            import("./cjs").metadata.import_type,
+            Some(ImportType::Static)
+        );
+        assert_eq!(
+            import("./lazy").metadata.import_type,
+            Some(ImportType::Dynamic)
+        );
     }
 }
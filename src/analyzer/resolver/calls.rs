@@ -0,0 +1,304 @@
+//! Cross-File Calls
+//!
+//! Parsers link calls to what their own file declares and keep the rest as
+//! [`UnresolvedCall`]s. Once imports point at files, each of those calls is
+//! looked up in the project files its file imports, or owns through a Rust
+//! `mod` declaration:
+//!
+//! - a bare name the import lists (`import { load }`, `use crate::util::pad`)
+//!   resolves to that file's function or class of the name;
+//! - a qualifier naming the imported module (`api.load()` after
+//!   `import * as api from './api'`, `util::pad()`), or the alias it is
+//!   bound to (`client.load()` after `import * as client`), resolves to the
+//!   module's function or class; renamed names (`{ load as fetch }`) are
+//!   looked up by the name the module exports;
+//! - a qualifier naming an imported class resolves to its member, or the
+//!   class itself (`Repo.create()`, `Store::new()`).
+//!
+//! A name found in several imported files is ambiguous and, like names found
+//! in none, is dropped. Calls reaching the same target merge into one edge.
+//!
+//! [`UnresolvedCall`]: crate::analyzer::parser::UnresolvedCall
+
+use std::collections::HashMap;
+
+use crate::analyzer::parser::{ParseResult, UnresolvedCall};
+use crate::types::{Edge, EdgeType, NodeType};
+
+/// A project file as imported by one file.
+struct Import<'a> {
+    file: &'a FileSymbols<'a>,
+    /// Names the import lists
+    names: &'a [String],
+    /// Local name -> imported name (`*` for the whole module)
+    aliases: HashMap<&'a str, &'a str>,
+}
+
+/// A project file's module name and the symbols it declares.
+struct FileSymbols<'a> {
+    module: &'a str,
+    /// Functions, methods and classes by name, the first declaration winning
+    symbols: HashMap<&'a str, (&'a str, NodeType)>,
+}
+
+impl FileSymbols<'_> {
+    /// A function or class declared at the top of the file.
+    fn top_level(&self, name: &str) -> Option<String> {
+        self.symbols
+            .get(name)
+            .filter(|(_, t)| *t != NodeType::Method)
+            .map(|(id, _)| id.to_string())
+    }
+}
+
+/// Link the unresolved calls of every result to the files it imports, and
+/// drop the calls that stay unresolved.
+pub fn resolve_calls(results: &mut [ParseResult]) {
+    let mut links: Vec<(usize, Vec<Edge>)> = Vec::new();
+    {
+        let mut files: HashMap<&str, FileSymbols> = HashMap::new();
+        for result in results.iter() {
+            let Some(file) = result.nodes.iter().find(|n| n.node_type == NodeType::File) else {
+                continue;
+            };
+            let mut symbols = HashMap::new();
+            for node in &result.nodes {
+                if matches!(
+                    node.node_type,
+                    NodeType::Function | NodeType::Method | NodeType::Class
+                ) {
+                    symbols
+                        .entry(node.name.as_str())
+                        .or_insert((node.id.as_str(), node.node_type));
+                }
+            }
+            files.insert(
+                &file.id,
+                FileSymbols {
+                    module: module_name(&file.path),
+                    symbols,
+                },
+            );
+        }
+
+        for (index, result) in results.iter().enumerate() {
+            if result.unresolved_calls.is_empty() {
+                continue;
+            }
+            let imports: Vec<Import> = result
+                .edges
+                .iter()
+                .filter(|e| matches!(e.edge_type, EdgeType::DependsOn | EdgeType::Owns))
+                .filter_map(|e| {
+                    let aliases = e
+                        .metadata
+                        .extra
+                        .get("aliases")
+                        .and_then(|a| a.as_object())
+                        .into_iter()
+                        .flatten()
+                        .filter_map(|(local, name)| Some((local.as_str(), name.as_str()?)))
+                        .collect();
+                    Some(Import {
+                        file: files.get(e.target_id.as_str())?,
+                        names: e.metadata.imported_symbols.as_deref().unwrap_or_default(),
+                        aliases,
+                    })
+                })
+                .collect();
+
+            let edges: Vec<Edge> = result
+                .unresolved_calls
+                .iter()
+                .filter_map(|call| {
+                    let target = lookup(call, &imports)?;
+                    let mut edge = call.edge.clone();
+                    edge.id = format!("call:{}->{}", edge.source_id, target);
+                    edge.target_id = target;
+                    Some(edge)
+                })
+                .collect();
+            if !edges.is_empty() {
+                links.push((index, edges));
+            }
+        }
+    }
+
+    for result in results.iter_mut() {
+        result.unresolved_calls.clear();
+    }
+    for (index, edges) in links {
+        let result = &mut results[index];
+        for edge in edges {
+            let existing = result.edges.iter_mut().find(|e| {
+                e.edge_type == EdgeType::Calls
+                    && e.source_id == edge.source_id
+                    && e.target_id == edge.target_id
+            });
+            match existing {
+                Some(existing) => {
+                    let count = edge.metadata.call_count.unwrap_or(1);
+                    existing.metadata.call_count =
+                        Some(existing.metadata.call_count.unwrap_or(1) + count);
+                    if edge.metadata.is_async == Some(true) {
+                        existing.metadata.is_async = Some(true);
+                    }
+                }
+                None => result.edges.push(edge),
+            }
+        }
+    }
+}
+
+/// The one declaration among the imported files that `call` names.
+fn lookup(call: &UnresolvedCall, imports: &[Import]) -> Option<String> {
+    let mut targets = imports.iter().filter_map(|import| {
+        let file = import.file;
+        let lists = |name: &str| import.names.iter().any(|n| n == name);
+        let imported = |local: &str| import.aliases.get(local).copied();
+        match call.qualifier.as_deref() {
+            None => {
+                let name = imported(&call.name).unwrap_or(&call.name);
+                lists(name).then(|| file.top_level(name)).flatten()
+            }
+            Some(q) if q == file.module || imported(q) == Some("*") => file.top_level(&call.name),
+            Some(q) => {
+                let q = imported(q).unwrap_or(q);
+                if !lists(q) {
+                    return None;
+                }
+                match file.symbols.get(q) {
+                    Some((class, NodeType::Class)) => Some(
+                        file.symbols
+                            .get(call.name.as_str())
+                            .filter(|(_, t)| *t != NodeType::Class)
+                            .map_or(*class, |(id, _)| *id)
+                            .to_string(),
+                    ),
+                    _ => None,
+                }
+            }
+        }
+    });
+    let first = targets.next()?;
+    targets.all(|t| t == first).then_some(first)
+}
+
+/// Name a file is imported by: its stem, or its directory's name for
+/// `mod.rs`, `index.*` and `__init__.py`.
+fn module_name(path: &str) -> &str {
+    let path = path.trim_start_matches("./");
+    let (dir, name) = path.rsplit_once('/').unwrap_or(("", path));
+    let stem = name.split('.').next().unwrap_or(name);
+    match stem {
+        "mod" | "index" | "__init__" => dir.rsplit('/').next().unwrap_or(stem),
+        _ => stem,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::Workspace;
+    use crate::analyzer::parser::{Language, create_parser};
+    use crate::analyzer::resolver::resolve_imports;
+
+    #[test]
+    fn test_resolves_calls_through_imports() {
+        let files = [
+            (
+                "./src/api.ts",
+                "export function load() {}\nexport function save() {}\nexport class Repo {}\n",
+            ),
+            (
+                "./src/index.ts",
+                "import { load, Repo } from './api';\nimport * as api from './api';\n\
+                 function main() { load(); load(); api.save(); Repo.open(); client.load(); }\n",
+            ),
+            ("./src/util/mod.rs", "pub fn pad() {}\n"),
+            (
+                "./src/main.rs",
+                "mod util;\nuse crate::util::pad;\n\nfn main() { pad(); util::pad(); missing(); }\n",
+            ),
+        ];
+        let mut results: Vec<ParseResult> = files
+            .iter()
+            .map(|(path, code)| {
+                create_parser(Language::from_path(path))
+                    .unwrap()
+                    .parse(path, code)
+                    .unwrap()
+            })
+            .collect();
+        resolve_imports(&mut results, &Workspace::default());
+        resolve_calls(&mut results);
+
+        let calls = |index: usize| -> Vec<(String, Option<u32>)> {
+            let mut calls: Vec<(String, Option<u32>)> = results[index]
+                .edges
+                .iter()
+                .filter(|e| e.edge_type == EdgeType::Calls)
+                .map(|e| (e.target_id.clone(), e.metadata.call_count))
+                .collect();
+            calls.sort();
+            calls
+        };
+        // `client.load()` names no import, so it stays unlinked
+        assert_eq!(
+            calls(1),
+            vec![
+                ("class:./src/api.ts:Repo".to_string(), Some(1)),
+                ("function:./src/api.ts:load".to_string(), Some(2)),
+                ("function:./src/api.ts:save".to_string(), Some(1)),
+            ]
+        );
+        assert_eq!(
+            calls(3),
+            vec![("function:./src/util/mod.rs:pad".to_string(), Some(2))]
+        );
+        assert!(results.iter().all(|r| r.unresolved_calls.is_empty()));
+    }
+
+    #[test]
+    fn test_resolves_calls_through_aliases() {
+        let files = [
+            (
+                "./src/api.ts",
+                "export function load() {}\nexport function save() {}\nexport class Repo {}\n",
+            ),
+            (
+                "./src/index.ts",
+                "import * as client from './api';\n\
+                 import { save as persist, Repo as Store } from './api';\n\
+                 function main() { client.load(); persist(); Store.open(); }\n",
+            ),
+        ];
+        let mut results: Vec<ParseResult> = files
+            .iter()
+            .map(|(path, code)| {
+                create_parser(Language::from_path(path))
+                    .unwrap()
+                    .parse(path, code)
+                    .unwrap()
+            })
+            .collect();
+        resolve_imports(&mut results, &Workspace::default());
+        resolve_calls(&mut results);
+
+        let mut targets: Vec<&str> = results[1]
+            .edges
+            .iter()
+            .filter(|e| e.edge_type == EdgeType::Calls)
+            .map(|e| e.target_id.as_str())
+            .collect();
+        targets.sort();
+        assert_eq!(
+            targets,
+            vec![
+                "class:./src/api.ts:Repo",
+                "function:./src/api.ts:load",
+                "function:./src/api.ts:save",
+            ]
+        );
+    }
+}
@@ -0,0 +1,132 @@
+//! Rendered Components
+//!
+//! A React component rendering `<Button>` imported from `./Button` cannot
+//! know which file that specifier names, so its `Renders` edge points at a
+//! `component:Button` placeholder and records the import's specifier and
+//! exported name. Once imports are resolved, the file's own `depends_on` edge
+//! for that specifier names the file, and the edge is pointed at the
+//! component declared there.
+
+use std::collections::HashMap;
+
+use super::normalize_path;
+use crate::analyzer::parser::ParseResult;
+use crate::types::{EdgeType, NodeType};
+
+/// A component declared in a file.
+struct Declared {
+    name: String,
+    id: String,
+    default_export: bool,
+}
+
+/// Retarget `Renders` edges to components imported from resolved files.
+pub fn resolve_rendered_components(results: &mut [ParseResult]) {
+    let mut by_file: HashMap<String, Vec<Declared>> = HashMap::new();
+    for node in results.iter().flat_map(|r| &r.nodes) {
+        if node.node_type == NodeType::Component {
+            by_file
+                .entry(normalize_path(&node.path))
+                .or_default()
+                .push(Declared {
+                    name: node.name.clone(),
+                    id: node.id.clone(),
+                    default_export: node.metadata.extra.contains_key("default_export"),
+                });
+        }
+    }
+    if by_file.is_empty() {
+        return;
+    }
+
+    for result in results.iter_mut() {
+        // Specifier -> file, from the imports the resolvers already rewrote
+        let files: HashMap<String, String> = result
+            .edges
+            .iter()
+            .filter(|e| e.edge_type == EdgeType::DependsOn)
+            .filter_map(|e| {
+                let specifier = e.metadata.extra.get("specifier")?.as_str()?;
+                let file = e.target_id.strip_prefix("file:")?;
+                Some((specifier.to_string(), normalize_path(file)))
+            })
+            .collect();
+
+        for edge in &mut result.edges {
+            if edge.edge_type != EdgeType::Renders {
+                continue;
+            }
+            let extra = &edge.metadata.extra;
+            let (Some(specifier), Some(symbol)) = (
+                extra.get("specifier").and_then(|v| v.as_str()),
+                extra.get("symbol").and_then(|v| v.as_str()),
+            ) else {
+                continue;
+            };
+            let Some(declared) = files.get(specifier).and_then(|f| by_file.get(f)) else {
+                continue;
+            };
+
+            let target = if symbol == "default" {
+                declared
+                    .iter()
+                    .find(|c| c.default_export)
+                    .or_else(|| (declared.len() == 1).then(|| &declared[0]))
+            } else {
+                declared.iter().find(|c| c.name == symbol)
+            };
+            if let Some(target) = target {
+                edge.target_id = target.id.clone();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::parser::{Language, Parser, TypeScriptParser};
+    use crate::analyzer::resolver::resolve_imports;
+    use crate::analyzer::workspace::Workspace;
+
+    #[test]
+    fn test_renders_edges_follow_imports() {
+        let files = [
+            (
+                "./src/App.tsx",
+                "import Button from './Button';\nimport { Card } from './ui/Card';\n\
+                 export default function App() { return <Card><Button /></Card>; }\n",
+            ),
+            (
+                "./src/Button.tsx",
+                "export default function Button() { return <button />; }\n",
+            ),
+            (
+                "./src/ui/Card.jsx",
+                "export const Card = ({ children }) => <div>{children}</div>;\n",
+            ),
+        ];
+        let mut results: Vec<ParseResult> = files
+            .iter()
+            .map(|(path, code)| {
+                TypeScriptParser::for_language(Language::from_path(path))
+                    .unwrap()
+                    .parse(path, code)
+                    .unwrap()
+            })
+            .collect();
+
+        resolve_imports(&mut results, &Workspace::default());
+
+        let target = |tag: &str| {
+            results[0]
+                .edges
+                .iter()
+                .find(|e| e.edge_type == EdgeType::Renders && e.id.ends_with(tag))
+                .map(|e| e.target_id.clone())
+                .unwrap()
+        };
+        assert_eq!(target(":Button"), "component:./src/Button.tsx:Button");
+        assert_eq!(target(":Card"), "component:./src/ui/Card.jsx:Card");
+    }
+}
@@ -0,0 +1,376 @@
+//! Test Subjects
+//!
+//! Links test code to the code it exercises with `tests` edges:
+//!
+//! - a test file to the file it is named after (`parser_test.go` →
+//!   `parser.go`, `ParserTest.java` → `Parser.java`), or failing that to the
+//!   project files it imports;
+//! - a test function to the functions and classes it calls, looked up in
+//!   its own file (inline `mod tests`), then in those files and the names
+//!   it imports, then in its directory for Go (tests share a package with
+//!   the code under test). A name declared more than once at the nearest
+//!   level is ambiguous and left unlinked.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use chrono::Utc;
+
+use super::{normalize_path, parent_dir, result_path};
+use crate::analyzer::parser::ParseResult;
+use crate::analyzer::parser::testing::subject_stem;
+use crate::types::{Edge, EdgeMetadata, EdgeType, InformationTier, Node, NodeType};
+
+/// A file of the project, as seen by test resolution.
+struct SourceFile<'a> {
+    id: &'a str,
+    test: bool,
+    /// Declared non-test functions and classes: name -> node IDs
+    symbols: HashMap<&'a str, Vec<&'a str>>,
+}
+
+/// Add `tests` edges from test files and functions to their subjects.
+pub fn resolve_test_subjects(results: &mut [ParseResult]) {
+    let mut files: HashMap<String, SourceFile> = HashMap::new();
+    for result in results.iter() {
+        let Some(file) = result.nodes.iter().find(|n| n.node_type == NodeType::File) else {
+            continue;
+        };
+        let mut symbols: HashMap<&str, Vec<&str>> = HashMap::new();
+        for node in &result.nodes {
+            if matches!(
+                node.node_type,
+                NodeType::Function | NodeType::Method | NodeType::Class
+            ) && !is_test(node)
+            {
+                symbols.entry(&node.name).or_default().push(&node.id);
+            }
+        }
+        files.insert(
+            normalize_path(&file.path),
+            SourceFile {
+                id: &file.id,
+                test: is_test(file),
+                symbols,
+            },
+        );
+    }
+    if !files.values().any(|f| f.test) && !has_test_functions(results) {
+        return;
+    }
+
+    let mut by_stem: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (path, file) in &files {
+        if !file.test {
+            by_stem.entry(file_stem(path)).or_default().push(path);
+        }
+    }
+
+    let mut links: Vec<(usize, Vec<Edge>)> = Vec::new();
+    for (index, result) in results.iter().enumerate() {
+        let Some(path) = result_path(result).map(normalize_path) else {
+            continue;
+        };
+        let Some(file) = files.get(&path) else {
+            continue;
+        };
+
+        let mut edges = Vec::new();
+        let mut subjects = Vec::new();
+        if file.test {
+            subjects = named_subjects(&path, &by_stem);
+            if subjects.is_empty() {
+                subjects = imported_subjects(result, &files);
+            }
+            for subject in &subjects {
+                let file_node = result.nodes.iter().find(|n| n.node_type == NodeType::File);
+                if let (Some(node), Some(target)) = (file_node, files.get(subject.as_str())) {
+                    edges.push(tests_edge(node, target.id));
+                }
+            }
+        }
+
+        // Where a test function's callees may be declared, nearest first:
+        // its own file, then its subjects and imports, then (Go only) the
+        // other files of its package
+        let mut imported = imported_names(result, &files);
+        for subject in &subjects {
+            imported.entry(subject.as_str()).or_insert(None);
+        }
+        let package: Vec<&str> = if path.ends_with(".go") {
+            let dir = parent_dir(&path);
+            files
+                .iter()
+                .filter(|(p, f)| !f.test && parent_dir(p) == dir && **p != path)
+                .map(|(p, _)| p.as_str())
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        for node in result.nodes.iter().filter(|n| is_test(n)) {
+            let callees = node
+                .metadata
+                .extra
+                .get("callees")
+                .and_then(|c| c.as_array());
+            let mut targets: BTreeSet<&str> = BTreeSet::new();
+            for name in callees.into_iter().flatten().filter_map(|c| c.as_str()) {
+                let own = [path.as_str()];
+                let imports: Vec<&str> = imported
+                    .iter()
+                    .filter(|(_, names)| names.as_ref().is_none_or(|n| n.iter().any(|n| n == name)))
+                    .map(|(p, _)| *p)
+                    .collect();
+                let tiers =
+                    [&own[..], &imports, &package].map(|paths| declared(&files, paths, name));
+                // The nearest declarations win; a name declared twice there
+                // is ambiguous and left unlinked
+                if let Some(ids) = tiers.into_iter().find(|ids| !ids.is_empty())
+                    && ids.len() == 1
+                {
+                    targets.extend(ids);
+                }
+            }
+            edges.extend(targets.into_iter().map(|target| tests_edge(node, target)));
+        }
+
+        if !edges.is_empty() {
+            links.push((index, edges));
+        }
+    }
+
+    for (index, edges) in links {
+        results[index].edges.extend(edges);
+    }
+}
+
+/// IDs of the symbols named `name` declared in `paths`.
+fn declared<'a>(
+    files: &HashMap<String, SourceFile<'a>>,
+    paths: &[&str],
+    name: &str,
+) -> BTreeSet<&'a str> {
+    paths
+        .iter()
+        .filter_map(|p| files.get(*p))
+        .flat_map(|f| f.symbols.get(name).into_iter().flatten().copied())
+        .collect()
+}
+
+fn is_test(node: &Node) -> bool {
+    node.metadata.extra.contains_key("test")
+}
+
+fn has_test_functions(results: &[ParseResult]) -> bool {
+    results
+        .iter()
+        .flat_map(|r| &r.nodes)
+        .any(|n| n.node_type != NodeType::File && is_test(n))
+}
+
+/// File name up to its first `.`.
+fn file_stem(path: &str) -> &str {
+    let name = path.rsplit('/').next().unwrap_or(path);
+    name.split('.').next().unwrap_or(name)
+}
+
+/// Files named like the test file minus its test affix. Among several,
+/// those sharing the most directories with the test file win.
+fn named_subjects(path: &str, by_stem: &HashMap<&str, Vec<&str>>) -> Vec<String> {
+    let name = path.rsplit('/').next().unwrap_or(path);
+    let stem = subject_stem(name).unwrap_or_else(|| file_stem(path));
+    let Some(candidates) = by_stem.get(stem) else {
+        return Vec::new();
+    };
+
+    let dirs: Vec<&str> = parent_dir(path).split('/').collect();
+    let shared = |candidate: &str| {
+        parent_dir(candidate)
+            .split('/')
+            .filter(|d| dirs.contains(d))
+            .count()
+    };
+    let best = candidates.iter().map(|c| shared(c)).max().unwrap_or(0);
+    let mut subjects: Vec<String> = candidates
+        .iter()
+        .filter(|c| shared(c) == best)
+        .map(|c| c.to_string())
+        .collect();
+    subjects.sort();
+    subjects
+}
+
+/// Non-test project files the test file imports.
+fn imported_subjects(result: &ParseResult, files: &HashMap<String, SourceFile>) -> Vec<String> {
+    imported_names(result, files)
+        .into_keys()
+        .map(str::to_string)
+        .collect()
+}
+
+/// Non-test project files `result` imports, with the names it takes from
+/// each, or `None` when it takes the whole module (`*`, or no list).
+fn imported_names<'a>(
+    result: &ParseResult,
+    files: &'a HashMap<String, SourceFile>,
+) -> BTreeMap<&'a str, Option<Vec<String>>> {
+    let mut imports: BTreeMap<&str, Option<Vec<String>>> = BTreeMap::new();
+    for edge in result
+        .edges
+        .iter()
+        .filter(|e| e.edge_type == EdgeType::DependsOn)
+    {
+        let Some(target) = edge.target_id.strip_prefix("file:") else {
+            continue;
+        };
+        let Some((path, _)) = files
+            .get_key_value(&normalize_path(target))
+            .filter(|(_, f)| !f.test)
+        else {
+            continue;
+        };
+        let names = edge
+            .metadata
+            .imported_symbols
+            .clone()
+            .filter(|names| !names.iter().any(|n| n == "*"));
+        match (
+            imports.entry(path.as_str()).or_insert(Some(Vec::new())),
+            names,
+        ) {
+            (Some(listed), Some(names)) => listed.extend(names),
+            (entry, None) => *entry = None,
+            (None, Some(_)) => {}
+        }
+    }
+    imports
+}
+
+fn tests_edge(source: &Node, target: &str) -> Edge {
+    Edge {
+        id: format!("tests:{}->{}", source.id, target),
+        edge_type: EdgeType::Tests,
+        source_id: source.id.clone(),
+        target_id: target.to_string(),
+        metadata: EdgeMetadata::default(),
+        evidence: source.evidence.clone(),
+        tier: InformationTier::Fact,
+        confidence: 1.0,
+        last_verified: Utc::now(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::parser::{Language, create_parser};
+
+    fn parse(files: &[(&str, &str)]) -> Vec<ParseResult> {
+        files
+            .iter()
+            .map(|(path, code)| {
+                create_parser(Language::from_path(path))
+                    .unwrap()
+                    .parse(path, code)
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    fn tests_edges(results: &[ParseResult]) -> Vec<(String, String)> {
+        let mut edges: Vec<(String, String)> = results
+            .iter()
+            .flat_map(|r| &r.edges)
+            .filter(|e| e.edge_type == EdgeType::Tests)
+            .map(|e| (e.source_id.clone(), e.target_id.clone()))
+            .collect();
+        edges.sort();
+        edges
+    }
+
+    #[test]
+    fn test_links_tests_to_subjects() {
+        let mut results = parse(&[
+            (
+                "pkg/parser.go",
+                "package pkg\n\nfunc Parse(s string) int { return 0 }\n",
+            ),
+            (
+                "pkg/lexer.go",
+                "package pkg\n\nfunc Lex(s string) []string { return nil }\n",
+            ),
+            (
+                "pkg/parser_test.go",
+                "package pkg\n\nimport \"testing\"\n\n\
+                 func TestParse(t *testing.T) { Lex(\"x\"); Parse(\"x\") }\n\n\
+                 func helper() {}\n",
+            ),
+            (
+                "src/lib.rs",
+                "pub fn add(a: i32) -> i32 { a }\n\n#[cfg(test)]\nmod tests {\n    \
+                 use super::*;\n\n    #[test]\n    fn adds() { assert_eq!(add(1), 1); }\n}\n",
+            ),
+        ]);
+        resolve_test_subjects(&mut results);
+
+        let edges = tests_edges(&results);
+        let function = |path: &str, name: &str| {
+            results
+                .iter()
+                .flat_map(|r| &r.nodes)
+                .find(|n| n.path == path && n.name == name)
+                .map(|n| n.id.clone())
+                .unwrap()
+        };
+        let test_parse = function("pkg/parser_test.go", "TestParse");
+        let mut expected = vec![
+            (
+                "file:pkg/parser_test.go".to_string(),
+                "file:pkg/parser.go".to_string(),
+            ),
+            (test_parse.clone(), function("pkg/lexer.go", "Lex")),
+            (test_parse, function("pkg/parser.go", "Parse")),
+            (
+                function("src/lib.rs", "adds"),
+                function("src/lib.rs", "add"),
+            ),
+        ];
+        expected.sort();
+        assert_eq!(edges, expected);
+    }
+
+    #[test]
+    fn test_skips_unimported_and_ambiguous_callees() {
+        let mut results = parse(&[
+            (
+                "src/budget.rs",
+                "pub fn total() -> u32 { 0 }\n\n#[cfg(test)]\nmod tests {\n    \
+                 use super::*;\n\n    #[test]\n    fn sums() { let m = Metrics::new(); total(); }\n}\n",
+            ),
+            (
+                "src/metrics.rs",
+                "pub struct Metrics;\n\nimpl Metrics {\n    pub fn new() -> Self { Metrics }\n}\n",
+            ),
+            ("pkg/a.go", "package pkg\n\nfunc Load() {}\n"),
+            ("pkg/b.go", "package pkg\n\nfunc Load() {}\n"),
+            (
+                "pkg/load_test.go",
+                "package pkg\n\nimport \"testing\"\n\nfunc TestLoad(t *testing.T) { Load() }\n",
+            ),
+        ]);
+        resolve_test_subjects(&mut results);
+
+        let edges = tests_edges(&results);
+        assert!(
+            !edges
+                .iter()
+                .any(|(_, target)| target.contains("metrics.rs"))
+        );
+        assert!(!edges.iter().any(|(_, target)| target.contains("Load")));
+        assert!(
+            edges
+                .iter()
+                .any(|(source, target)| source.ends_with(":sums") && target.ends_with(":total"))
+        );
+    }
+}
@@ -0,0 +1,281 @@
+//! External Package Resolution
+//!
+//! Third-party packages come from the manifests and lockfiles the config
+//! parser read: a manifest declares a package's requirement and scope, a
+//! lockfile pins its version. Every declared package, and every locked one
+//! that code imports, becomes a `package` node owned by the file naming it,
+//! and imports of it are pointed at that node:
+//!
+//! - Rust `use` paths are linked by [`super::RustModuleResolver`], which
+//!   receives [`ExternalPackages::crates`].
+//! - TypeScript/JavaScript bare specifiers, Python absolute imports and Go
+//!   import paths the workspace resolvers left alone are rewritten here.
+//!   Python imports naming no known package (the standard library, the
+//!   project's own modules) are dropped.
+//!
+//! Workspace members are never external, even when another member declares
+//! them as a dependency.
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use chrono::Utc;
+
+use super::result_path;
+use crate::analyzer::parser::ParseResult;
+use crate::analyzer::workspace::{PackageKind, Workspace};
+use crate::types::{
+    ConfigKind, Edge, EdgeType, EvidenceLocation, InformationTier, Node, NodeMetadata, NodeStatus,
+    NodeType,
+};
+
+/// Dependency scopes that name a package code can import.
+const PACKAGE_SCOPES: &[&str] = &["runtime", "dev", "build", "peer", "optional", "locked"];
+
+/// A third-party package with what its manifests and lockfiles say about it.
+#[derive(Debug, Clone)]
+struct ExternalPackage {
+    ecosystem: &'static str,
+    name: String,
+    /// Version a lockfile pins
+    version: Option<String>,
+    /// Requirement as the manifest writes it (`^1.0`, `>=2`)
+    requirement: Option<String>,
+    /// Scope in the declaring manifest; `None` for locked-only packages
+    scope: Option<String>,
+    /// Manifest declaring it, or the lockfile for locked-only packages
+    owner: String,
+}
+
+/// Every third-party package known from the parsed config files, by node ID.
+#[derive(Debug, Default)]
+pub struct ExternalPackages {
+    packages: BTreeMap<String, ExternalPackage>,
+}
+
+impl ExternalPackages {
+    /// Read the packages declared and pinned by the config nodes in `results`.
+    pub fn collect(results: &[ParseResult], workspace: &Workspace) -> Self {
+        let local: HashSet<&str> = workspace
+            .packages
+            .iter()
+            .flat_map(|p| [p.name.as_str(), p.import_name.as_str()])
+            .collect();
+
+        let mut configs: Vec<(&str, &crate::types::ConfigMetadata)> = results
+            .iter()
+            .flat_map(|r| &r.nodes)
+            .filter(|n| n.node_type == NodeType::Config)
+            .filter_map(|n| Some((n.path.as_str(), n.metadata.config_metadata.as_ref()?)))
+            .collect();
+        // Manifests first so that a lockfile only adds versions to them
+        configs.sort_by_key(|(path, config)| (config.kind == ConfigKind::Lockfile, *path));
+
+        let mut packages = Self::default();
+        for (path, config) in configs {
+            let Some(ecosystem) = ecosystem(config.kind, path) else {
+                continue;
+            };
+            for dep in &config.dependencies {
+                if !PACKAGE_SCOPES.contains(&dep.scope.as_str())
+                    || local.contains(dep.name.as_str())
+                    || (ecosystem == "pypi" && dep.name == "python")
+                {
+                    continue;
+                }
+                let locked = dep.scope == "locked";
+                let entry = packages
+                    .packages
+                    .entry(package_id(ecosystem, &dep.name))
+                    .or_insert_with(|| ExternalPackage {
+                        ecosystem,
+                        name: dep.name.clone(),
+                        version: None,
+                        requirement: None,
+                        scope: None,
+                        owner: path.to_string(),
+                    });
+                if locked {
+                    entry.version = entry.version.take().or_else(|| dep.version.clone());
+                } else if entry.scope.is_none() {
+                    entry.requirement = dep.version.clone();
+                    entry.scope = Some(dep.scope.clone());
+                }
+            }
+        }
+        packages
+    }
+
+    /// Crates by the name `use` paths write them (`serde_json`), with their
+    /// package node IDs.
+    pub fn crates(&self) -> impl Iterator<Item = (String, String)> + '_ {
+        self.packages
+            .iter()
+            .filter(|(_, p)| p.ecosystem == "cargo")
+            .map(|(id, p)| (p.name.replace('-', "_"), id.clone()))
+    }
+
+    /// Point unresolved TypeScript/JavaScript, Python and Go imports at the
+    /// packages they name. Imports of the same package from one file merge
+    /// into one edge.
+    pub fn link_imports(&self, results: &mut [ParseResult]) {
+        for result in results.iter_mut() {
+            let Some(path) = result_path(result).map(String::from) else {
+                continue;
+            };
+            let python = path.ends_with(".py") || path.ends_with(".pyi");
+            let ecosystem = match PackageKind::for_path(&path) {
+                Some(PackageKind::Npm) => "npm",
+                Some(PackageKind::Go) => "go",
+                _ if python => "pypi",
+                _ => continue,
+            };
+
+            let mut edges: Vec<Edge> = Vec::with_capacity(result.edges.len());
+            for mut edge in result.edges.drain(..) {
+                if edge.edge_type != EdgeType::DependsOn {
+                    edges.push(edge);
+                    continue;
+                }
+                let Some(id) = self.package_for(ecosystem, &edge.target_id) else {
+                    // Python has no other resolver for absolute imports
+                    if !(python && edge.target_id.starts_with("module:")) {
+                        edges.push(edge);
+                    }
+                    continue;
+                };
+
+                if let Some(existing) = edges.iter_mut().find(|e| e.target_id == id) {
+                    if let Some(symbols) = edge.metadata.imported_symbols {
+                        let merged = existing.metadata.imported_symbols.get_or_insert_default();
+                        for symbol in symbols {
+                            if !merged.contains(&symbol) {
+                                merged.push(symbol);
+                            }
+                        }
+                    }
+                    continue;
+                }
+                let prefix = edge.id.split(':').next().unwrap_or("dep");
+                edge.id = format!("{}:{}:{}", prefix, path, id);
+                edge.target_id = id;
+                edges.push(edge);
+            }
+            result.edges = edges;
+        }
+    }
+
+    /// Package node ID an import target names, if it is a known package.
+    fn package_for(&self, ecosystem: &str, target: &str) -> Option<String> {
+        let id = match ecosystem {
+            "npm" => {
+                let specifier = target.strip_prefix("module:")?;
+                let mut segments = specifier.split('/');
+                let name = match segments.next()? {
+                    scope if scope.starts_with('@') => format!("{}/{}", scope, segments.next()?),
+                    name => name.to_string(),
+                };
+                package_id("npm", &name)
+            }
+            "pypi" => {
+                let module = target.strip_prefix("module:")?;
+                package_id("pypi", module.split('.').next()?)
+            }
+            "go" => {
+                let import = target.strip_prefix("package:")?;
+                // The longest module path the import lies under
+                return self
+                    .packages
+                    .iter()
+                    .filter(|(_, p)| p.ecosystem == "go")
+                    .filter(|(_, p)| {
+                        import == p.name || import.starts_with(&format!("{}/", p.name))
+                    })
+                    .max_by_key(|(_, p)| p.name.len())
+                    .map(|(id, _)| id.clone());
+            }
+            _ => return None,
+        };
+        self.packages.contains_key(&id).then_some(id)
+    }
+
+    /// Nodes for every declared package and every locked package an edge in
+    /// `results` points at.
+    pub fn graph(&self, results: &[ParseResult]) -> ParseResult {
+        let imported: BTreeSet<&str> = results
+            .iter()
+            .flat_map(|r| &r.edges)
+            .map(|e| e.target_id.as_str())
+            .filter(|t| self.packages.contains_key(*t))
+            .collect();
+
+        let mut result = ParseResult::new();
+        for (id, package) in &self.packages {
+            if package.scope.is_none() && !imported.contains(id.as_str()) {
+                continue;
+            }
+
+            let mut metadata = NodeMetadata::default();
+            let facts = [
+                ("ecosystem", Some(package.ecosystem.to_string())),
+                ("version", package.version.clone()),
+                ("requirement", package.requirement.clone()),
+                ("scope", package.scope.clone()),
+            ];
+            for (key, value) in facts {
+                if let Some(value) = value {
+                    metadata
+                        .extra
+                        .insert(key.to_string(), serde_json::Value::String(value));
+                }
+            }
+
+            result.nodes.push(Node {
+                id: id.clone(),
+                node_type: NodeType::Package,
+                path: package.owner.clone(),
+                name: package.name.clone(),
+                metadata,
+                evidence: EvidenceLocation {
+                    file: package.owner.clone(),
+                    start_line: 1,
+                    end_line: 1,
+                    start_column: None,
+                    end_column: None,
+                },
+                tier: InformationTier::Fact,
+                confidence: 1.0,
+                last_verified: Utc::now(),
+                status: NodeStatus::Verified,
+            });
+        }
+        result
+    }
+}
+
+/// Package node ID. PyPI names compare case-insensitively with `-`, `_` and
+/// `.` interchangeable, so they are normalized.
+fn package_id(ecosystem: &str, name: &str) -> String {
+    if ecosystem == "pypi" {
+        let name = name.to_lowercase().replace(['_', '.'], "-");
+        format!("external:{}:{}", ecosystem, name)
+    } else {
+        format!("external:{}:{}", ecosystem, name)
+    }
+}
+
+/// Ecosystem whose packages a config file declares or pins.
+fn ecosystem(kind: ConfigKind, path: &str) -> Option<&'static str> {
+    match kind {
+        ConfigKind::Cargo => Some("cargo"),
+        ConfigKind::Npm => Some("npm"),
+        ConfigKind::Pyproject => Some("pypi"),
+        ConfigKind::Lockfile => match path.rsplit('/').next()?.to_lowercase().as_str() {
+            "cargo.lock" => Some("cargo"),
+            "package-lock.json" => Some("npm"),
+            "poetry.lock" => Some("pypi"),
+            "go.sum" => Some("go"),
+            _ => None,
+        },
+        _ => None,
+    }
+}
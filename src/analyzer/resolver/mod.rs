@@ -0,0 +1,128 @@
+//! Import Resolution
+//!
+//! Parsers see one file at a time, so import edges initially point at
+//! opaque `module:` targets. Resolvers run after all files are parsed and
+//! rewrite those edges to the concrete `file:` nodes they refer to, then
+//! point edges that name an imported symbol at its declaration, link calls
+//! to the imported functions they reach and link signatures to the types
+//! they mention. Imports of third-party packages land on `package` nodes
+//! built from manifests and lockfiles.
+
+pub mod calls;
+pub mod components;
+pub mod coverage;
+pub mod external;
+pub mod packages;
+pub mod routes;
+pub mod rust;
+pub mod type_refs;
+
+pub use calls::resolve_calls;
+pub use components::resolve_rendered_components;
+pub use coverage::resolve_test_subjects;
+pub use external::ExternalPackages;
+pub use packages::resolve_package_imports;
+pub use routes::resolve_route_handlers;
+pub use rust::RustModuleResolver;
+pub use type_refs::resolve_type_references;
+
+use crate::analyzer::parser::ParseResult;
+use crate::analyzer::workspace::{PackageKind, Workspace};
+
+/// Resolve cross-file and cross-package imports for every supported
+/// language in place.
+pub fn resolve_imports(results: &mut [ParseResult], workspace: &Workspace) {
+    let external = ExternalPackages::collect(results, workspace);
+    let crates = workspace
+        .packages
+        .iter()
+        .filter(|p| p.kind == PackageKind::Cargo)
+        .filter_map(|p| Some((p.import_name.clone(), p.entry.clone()?)));
+    RustModuleResolver::new(results)
+        .with_crates(crates)
+        .with_external_crates(external.crates())
+        .apply(results);
+
+    resolve_package_imports(results, workspace);
+    external.link_imports(results);
+    resolve_rendered_components(results);
+}
+
+/// Path of the file a parse result describes (its `File` node).
+pub(crate) fn result_path(result: &ParseResult) -> Option<&str> {
+    result
+        .nodes
+        .iter()
+        .find(|n| n.node_type == crate::types::NodeType::File)
+        .map(|n| n.path.as_str())
+}
+
+/// Lexically normalize a `/`-separated path: drop `.` and leading `./`,
+/// collapse `..` against the preceding component.
+pub(crate) fn normalize_path(path: &str) -> String {
+    let mut parts: Vec<&str> = Vec::new();
+    for part in path.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                if parts.last().is_some_and(|p| *p != "..") {
+                    parts.pop();
+                } else {
+                    parts.push("..");
+                }
+            }
+            _ => parts.push(part),
+        }
+    }
+    parts.join("/")
+}
+
+/// Parent directory of a normalized path (`""` for top-level files).
+pub(crate) fn parent_dir(path: &str) -> &str {
+    path.rsplit_once('/').map(|(d, _)| d).unwrap_or("")
+}
+
+/// Join a directory and a relative path, then normalize.
+pub(crate) fn join_path(dir: &str, rel: &str) -> String {
+    if dir.is_empty() {
+        normalize_path(rel)
+    } else {
+        normalize_path(&format!("{}/{}", dir, rel))
+    }
+}
+
+/// Resolve a relative specifier (`./Child.vue`) against the importing
+/// file, keeping its path style (`./`, absolute or bare).
+pub(crate) fn resolve_relative(path: &str, source: &str) -> String {
+    if source.starts_with('/') {
+        return format!("/{}", normalize_path(source));
+    }
+    let joined = join_path(parent_dir(path), source);
+    if path.starts_with("./") {
+        format!("./{}", joined)
+    } else if path.starts_with('/') {
+        format!("/{}", joined)
+    } else {
+        joined
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_path() {
+        assert_eq!(normalize_path("./src/lib.rs"), "src/lib.rs");
+        assert_eq!(normalize_path("src/a/../b.rs"), "src/b.rs");
+        assert_eq!(normalize_path("../x/./y.rs"), "../x/y.rs");
+    }
+
+    #[test]
+    fn test_join_path() {
+        assert_eq!(join_path("src/a", "../b/c.rs"), "src/b/c.rs");
+        assert_eq!(join_path("", "main.rs"), "main.rs");
+        assert_eq!(parent_dir("src/a/b.rs"), "src/a");
+        assert_eq!(parent_dir("b.rs"), "");
+    }
+}
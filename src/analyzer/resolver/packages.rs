@@ -0,0 +1,384 @@
+//! Cross-Package Import Resolution
+//!
+//! Rewrites imports that name a workspace package instead of a relative
+//! path:
+//!
+//! - TypeScript/JavaScript bare specifiers (`@org/ui`, `@org/ui/button`)
+//!   resolve to the package entry or a file inside the package, and
+//!   `tsconfig.json` path aliases are expanded and probed.
+//! - Relative TypeScript/JavaScript specifiers are probed the same way
+//!   (extensions, `index` files, `.js` written for a `.ts` source), since
+//!   the parser can only guess the file they name.
+//! - Go import paths under a module from `go.mod`/`go.work` resolve to every
+//!   non-test `.go` file of the imported package directory.
+//!
+//! Rust crate names are handled by [`super::RustModuleResolver`], which
+//! needs the module tree anyway.
+
+use std::collections::{HashMap, HashSet};
+
+use super::{join_path as join, normalize_path, parent_dir, result_path};
+use crate::analyzer::parser::ParseResult;
+use crate::analyzer::workspace::{PackageKind, Workspace};
+use crate::types::{Edge, EdgeType};
+
+/// Extensions tried, in order, when an import omits one.
+const SCRIPT_EXTENSIONS: &[&str] = &[
+    ".ts", ".tsx", ".d.ts", ".js", ".jsx", ".mjs", ".cjs", ".mts", ".cts",
+];
+
+/// Resolve package-level imports for TypeScript/JavaScript and Go files.
+pub fn resolve_package_imports(results: &mut [ParseResult], workspace: &Workspace) {
+    let files: HashMap<String, String> = results
+        .iter()
+        .filter_map(result_path)
+        .map(|p| (normalize_path(p), p.to_string()))
+        .collect();
+
+    let mut go_dirs: HashMap<String, Vec<String>> = HashMap::new();
+    for (norm, original) in &files {
+        if norm.ends_with(".go") && !norm.ends_with("_test.go") {
+            go_dirs
+                .entry(parent_dir(norm).to_string())
+                .or_default()
+                .push(original.clone());
+        }
+    }
+    for dir_files in go_dirs.values_mut() {
+        dir_files.sort();
+    }
+
+    let resolver = PackageResolver {
+        workspace,
+        files: &files,
+        go_dirs: &go_dirs,
+    };
+
+    for result in results.iter_mut() {
+        let Some(path) = result_path(result).map(String::from) else {
+            continue;
+        };
+        let kind = PackageKind::for_path(&path);
+        if !matches!(kind, Some(PackageKind::Npm | PackageKind::Go)) {
+            continue;
+        }
+
+        let mut edges = Vec::with_capacity(result.edges.len());
+        for edge in result.edges.drain(..) {
+            if edge.edge_type != EdgeType::DependsOn {
+                edges.push(edge);
+                continue;
+            }
+            match kind {
+                Some(PackageKind::Npm) => edges.push(resolver.resolve_script(&path, edge)),
+                Some(PackageKind::Go) => edges.extend(resolver.resolve_go(&path, edge)),
+                _ => edges.push(edge),
+            }
+        }
+        result.edges = edges;
+    }
+}
+
+struct PackageResolver<'a> {
+    workspace: &'a Workspace,
+    /// Normalized path -> path as stored on graph nodes
+    files: &'a HashMap<String, String>,
+    /// Normalized directory -> Go files in that package
+    go_dirs: &'a HashMap<String, Vec<String>>,
+}
+
+impl PackageResolver<'_> {
+    fn resolve_script(&self, path: &str, mut edge: Edge) -> Edge {
+        let relative = edge
+            .metadata
+            .extra
+            .get("specifier")
+            .and_then(|s| s.as_str())
+            .filter(|s| s.starts_with('.'));
+        let target = match (relative, edge.target_id.strip_prefix("module:")) {
+            (Some(specifier), _) => self.resolve_relative(path, specifier),
+            (None, Some(specifier)) => self.resolve_specifier(specifier),
+            (None, None) => None,
+        };
+
+        if let Some(target) = target {
+            edge.id = format!("dep:{}:{}", path, target);
+            edge.target_id = format!("file:{}", target);
+        }
+        edge
+    }
+
+    /// Resolve `./x` against the importing file's directory.
+    fn resolve_relative(&self, path: &str, specifier: &str) -> Option<String> {
+        let base = join(parent_dir(&normalize_path(path)), specifier);
+        probe_module(self.files, &base).or_else(|| {
+            // ESM TypeScript imports name the emitted `.js` file
+            let stem = base
+                .strip_suffix(".js")
+                .or_else(|| base.strip_suffix(".jsx"))?;
+            [".ts", ".tsx"]
+                .iter()
+                .find_map(|ext| self.files.get(&format!("{}{}", stem, ext)).cloned())
+        })
+    }
+
+    /// Resolve a bare specifier through path aliases, then workspace packages.
+    fn resolve_specifier(&self, specifier: &str) -> Option<String> {
+        for alias in &self.workspace.path_aliases {
+            let Some(capture) = match_alias(&alias.pattern, specifier) else {
+                continue;
+            };
+            for target in &alias.targets {
+                let candidate = target.replacen('*', capture, 1);
+                if let Some(found) = probe_module(self.files, &normalize_path(&candidate)) {
+                    return Some(found);
+                }
+            }
+        }
+
+        for package in &self.workspace.packages {
+            if package.kind != PackageKind::Npm {
+                continue;
+            }
+            let dir = normalize_path(&package.dir);
+
+            if specifier == package.import_name {
+                let entry = package
+                    .entry
+                    .as_deref()
+                    .map(normalize_path)
+                    .and_then(|e| self.files.get(&e).cloned());
+                return entry.or_else(|| {
+                    probe_module(self.files, &join(&dir, "src/index"))
+                        .or_else(|| probe_module(self.files, &join(&dir, "index")))
+                });
+            }
+
+            if let Some(subpath) = specifier
+                .strip_prefix(package.import_name.as_str())
+                .and_then(|rest| rest.strip_prefix('/'))
+            {
+                return probe_module(self.files, &join(&dir, subpath)).or_else(|| {
+                    probe_module(self.files, &join(&dir, &format!("src/{}", subpath)))
+                });
+            }
+        }
+
+        None
+    }
+
+    fn resolve_go(&self, path: &str, edge: Edge) -> Vec<Edge> {
+        let Some(import) = edge.target_id.strip_prefix("package:") else {
+            return vec![edge];
+        };
+
+        let package_dir = self
+            .workspace
+            .packages
+            .iter()
+            .filter(|p| p.kind == PackageKind::Go)
+            .find_map(|module| {
+                let rest = import.strip_prefix(module.import_name.as_str())?;
+                if !rest.is_empty() && !rest.starts_with('/') {
+                    return None;
+                }
+                Some(join(
+                    &normalize_path(&module.dir),
+                    rest.trim_start_matches('/'),
+                ))
+            });
+
+        let Some(targets) = package_dir.and_then(|dir| self.go_dirs.get(&dir)) else {
+            return vec![edge];
+        };
+
+        targets
+            .iter()
+            .filter(|target| *target != path)
+            .map(|target| {
+                let mut resolved = edge.clone();
+                resolved.id = format!("import:{}:{}", path, target);
+                resolved.target_id = format!("file:{}", target);
+                resolved
+            })
+            .collect()
+    }
+}
+
+/// Match a tsconfig `paths` pattern, returning the text matched by `*`.
+fn match_alias<'s>(pattern: &str, specifier: &'s str) -> Option<&'s str> {
+    match pattern.split_once('*') {
+        Some((prefix, suffix)) => specifier
+            .strip_prefix(prefix)?
+            .strip_suffix(suffix)
+            .filter(|capture| !capture.is_empty() || prefix.is_empty() && suffix.is_empty()),
+        None => (pattern == specifier).then_some(""),
+    }
+}
+
+/// Find the file an extensionless module path refers to, trying the path
+/// itself, known script extensions, and `index` files.
+pub(crate) fn probe_module(files: &HashMap<String, String>, base: &str) -> Option<String> {
+    if let Some(found) = files.get(base) {
+        return Some(found.clone());
+    }
+
+    let stems = [base.to_string(), join(base, "index")];
+    let mut seen = HashSet::new();
+    for stem in &stems {
+        for ext in SCRIPT_EXTENSIONS {
+            let candidate = format!("{}{}", stem, ext);
+            if seen.insert(candidate.clone())
+                && let Some(found) = files.get(&candidate)
+            {
+                return Some(found.clone());
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::parser::{GoParser, Parser, TypeScriptParser};
+    use crate::analyzer::workspace::{Package, PathAlias};
+
+    fn package(name: &str, kind: PackageKind, dir: &str, entry: Option<&str>) -> Package {
+        Package {
+            name: name.to_string(),
+            import_name: name.to_string(),
+            kind,
+            dir: dir.to_string(),
+            manifest: format!("{}/package.json", dir),
+            entry: entry.map(String::from),
+            member: true,
+        }
+    }
+
+    fn deps(result: &ParseResult) -> Vec<&str> {
+        let mut targets: Vec<&str> = result
+            .edges
+            .iter()
+            .filter(|e| e.edge_type == EdgeType::DependsOn)
+            .map(|e| e.target_id.as_str())
+            .collect();
+        targets.sort();
+        targets
+    }
+
+    #[test]
+    fn test_npm_workspace_imports() {
+        let ts = TypeScriptParser::new().unwrap();
+        let mut results = vec![
+            ts.parse(
+                "apps/web/src/main.ts",
+                "import { Button } from '@org/ui';\nimport { theme } from '@org/ui/theme';\nimport React from 'react';\nimport { db } from '@app/db';\n",
+            )
+            .unwrap(),
+            ts.parse("packages/ui/src/index.ts", "export const Button = 1;")
+                .unwrap(),
+            ts.parse("packages/ui/src/theme/index.ts", "export const theme = 1;")
+                .unwrap(),
+            ts.parse("apps/web/src/app/db.ts", "export const db = 1;")
+                .unwrap(),
+        ];
+        let workspace = Workspace {
+            packages: vec![package(
+                "@org/ui",
+                PackageKind::Npm,
+                "packages/ui",
+                Some("packages/ui/src/index.ts"),
+            )],
+            path_aliases: vec![PathAlias {
+                pattern: "@app/*".to_string(),
+                targets: vec!["apps/web/src/app/*".to_string()],
+            }],
+        };
+
+        resolve_package_imports(&mut results, &workspace);
+
+        assert_eq!(
+            deps(&results[0]),
+            vec![
+                "file:apps/web/src/app/db.ts",
+                "file:packages/ui/src/index.ts",
+                "file:packages/ui/src/theme/index.ts",
+                "module:react",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_relative_imports_probe_files() {
+        let ts = TypeScriptParser::new().unwrap();
+        let mut results = vec![
+            ts.parse(
+                "./src/main.ts",
+                "import { pad } from './util';
+import { Button } from './components';
+import { h } from './helpers.js';
+const cfg = require('../config.json');
+import './missing';
+",
+            )
+            .unwrap(),
+            ts.parse("./src/util.tsx", "export const pad = 1;").unwrap(),
+            ts.parse("./src/components/index.ts", "export const Button = 1;")
+                .unwrap(),
+            ts.parse("./src/helpers.ts", "export const h = 1;").unwrap(),
+            ts.parse("./config.json", "{}").unwrap(),
+        ];
+
+        resolve_package_imports(&mut results, &Workspace::default());
+
+        assert_eq!(
+            deps(&results[0]),
+            vec![
+                "file:./config.json",
+                "file:./src/components/index.ts",
+                "file:./src/helpers.ts",
+                "file:./src/missing.ts",
+                "file:./src/util.tsx",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_go_module_imports_fan_out_to_package_files() {
+        let go = GoParser::new().unwrap();
+        let mut results = vec![
+            go.parse(
+                "svc/main.go",
+                "package main\n\nimport (\n\t\"fmt\"\n\t\"example.com/svc/store\"\n)\n",
+            )
+            .unwrap(),
+            go.parse("svc/store/db.go", "package store\n").unwrap(),
+            go.parse("svc/store/cache.go", "package store\n").unwrap(),
+            go.parse("svc/store/db_test.go", "package store\n").unwrap(),
+        ];
+        let workspace = Workspace {
+            packages: vec![package("example.com/svc", PackageKind::Go, "svc", None)],
+            path_aliases: Vec::new(),
+        };
+
+        resolve_package_imports(&mut results, &workspace);
+
+        assert_eq!(
+            deps(&results[0]),
+            vec![
+                "file:svc/store/cache.go",
+                "file:svc/store/db.go",
+                "package:fmt"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_match_alias() {
+        assert_eq!(match_alias("@app/*", "@app/db"), Some("db"));
+        assert_eq!(match_alias("@app/*", "@other/db"), None);
+        assert_eq!(match_alias("config", "config"), Some(""));
+        assert_eq!(match_alias("*", "lodash"), Some("lodash"));
+    }
+}
@@ -0,0 +1,120 @@
+//! Rails Route Handlers
+//!
+//! `config/routes.rb` names handlers as `controller#action` strings
+//! (`admin/photos#show`), and by convention the action is a method of
+//! `app/controllers/admin/photos_controller.rb`. The routes file alone cannot
+//! tell whether that method exists, so the `routes_to` edge is added here,
+//! once the controllers have been parsed.
+
+use std::collections::HashMap;
+
+use chrono::Utc;
+
+use super::{join_path, normalize_path};
+use crate::analyzer::parser::ParseResult;
+use crate::types::{Edge, EdgeMetadata, EdgeType, InformationTier, NodeType};
+
+/// Link Rails `api` nodes to the controller methods that handle them.
+pub fn resolve_route_handlers(results: &mut [ParseResult]) {
+    // (normalized controller path, action) -> method node ID
+    let mut actions: HashMap<(String, &str), String> = HashMap::new();
+    for node in results.iter().flat_map(|r| &r.nodes) {
+        if node.node_type == NodeType::Method && node.path.ends_with("_controller.rb") {
+            actions.insert(
+                (normalize_path(&node.path), node.name.as_str()),
+                node.id.clone(),
+            );
+        }
+    }
+    if actions.is_empty() {
+        return;
+    }
+
+    let mut links: Vec<(usize, Edge)> = Vec::new();
+    for (index, result) in results.iter().enumerate() {
+        for node in &result.nodes {
+            if node.node_type != NodeType::Api {
+                continue;
+            }
+            let routes_file = normalize_path(&node.path);
+            let Some((app_root, _)) = routes_file.split_once("config/routes") else {
+                continue;
+            };
+            let Some((controller, action)) = node
+                .metadata
+                .extra
+                .get("handler")
+                .and_then(|h| h.as_str())
+                .and_then(|h| h.split_once('#'))
+            else {
+                continue;
+            };
+
+            let file = join_path(
+                app_root.trim_end_matches('/'),
+                &format!("app/controllers/{}_controller.rb", controller),
+            );
+            if let Some(target) = actions.get(&(file, action)) {
+                links.push((
+                    index,
+                    Edge {
+                        id: format!("handler:{}", node.id.trim_start_matches("api:")),
+                        edge_type: EdgeType::RoutesTo,
+                        source_id: node.id.clone(),
+                        target_id: target.clone(),
+                        metadata: EdgeMetadata::default(),
+                        evidence: node.evidence.clone(),
+                        tier: InformationTier::Fact,
+                        confidence: 1.0,
+                        last_verified: Utc::now(),
+                    },
+                ));
+            }
+        }
+    }
+
+    for (index, edge) in links {
+        results[index].edges.push(edge);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::parser::{Parser, RubyParser};
+
+    #[test]
+    fn test_links_rails_routes_to_controller_actions() {
+        let parser = RubyParser::new().unwrap();
+        let routes = parser
+            .parse(
+                "./config/routes.rb",
+                "Rails.application.routes.draw do\n  namespace :admin do\n    resources :photos, only: [:show, :index]\n  end\nend\n",
+            )
+            .unwrap();
+        let controller = parser
+            .parse(
+                "./app/controllers/admin/photos_controller.rb",
+                "class Admin::PhotosController < ApplicationController\n  def show\n  end\nend\n",
+            )
+            .unwrap();
+        let mut results = vec![routes, controller];
+
+        resolve_route_handlers(&mut results);
+
+        let linked: Vec<(&str, &str)> = results[0]
+            .edges
+            .iter()
+            .filter(|e| e.edge_type == EdgeType::RoutesTo)
+            .map(|e| (e.source_id.as_str(), e.target_id.as_str()))
+            .collect();
+        // `index` has no method in the controller, so only `show` is linked
+        assert_eq!(
+            linked,
+            vec![(
+                "api:./config/routes.rb:GET:/admin/photos/:id",
+                "method:./app/controllers/admin/photos_controller.rb:show"
+            )]
+        );
+    }
+}
@@ -0,0 +1,669 @@
+//! Rust Module Resolver
+//!
+//! Rebuilds each crate's module tree from `mod` declarations and maps `use`
+//! paths onto the files that define them:
+//!
+//! - `mod foo;` resolves to `foo.rs` or `foo/mod.rs` (next to `lib.rs`,
+//!   `main.rs` and `mod.rs`, or under `<stem>/` for other files), honouring
+//!   `#[path = "..."]` and inline `mod foo { ... }` bodies.
+//! - Files that no other file declares are treated as crate roots, which
+//!   covers `lib.rs`, `main.rs`, `src/bin/*`, integration tests and examples.
+//! - `crate::`, `self::`, `super::` and paths through local modules resolve
+//!   to the longest matching module; the next segment is the imported symbol.
+//! - `pub use` re-exports (named and glob) are followed so that imports land
+//!   on the file that actually declares the symbol.
+//! - Paths starting with the name of a workspace crate resolve from that
+//!   crate's library root; paths into a declared third-party crate point at
+//!   its package node, and other external crates are dropped.
+
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+use chrono::Utc;
+
+use super::{join_path, normalize_path, parent_dir, result_path};
+use crate::analyzer::parser::ParseResult;
+use crate::types::{Edge, EdgeMetadata, EdgeType, InformationTier, NodeType};
+
+/// Maximum number of re-export hops followed for a single symbol.
+const MAX_REEXPORT_DEPTH: usize = 8;
+
+/// A `mod` item as recorded by the Rust parser.
+struct ModDecl {
+    name: String,
+    inline: bool,
+    path_attr: Option<String>,
+    parents: Vec<String>,
+    line: u32,
+}
+
+/// A file-backed `mod` declaration resolved to its child file.
+struct ModLink {
+    parent: String,
+    child: String,
+    line: u32,
+}
+
+/// Module tree and re-export index for all Rust files in a build.
+pub struct RustModuleResolver {
+    /// Normalized path -> path as stored on graph nodes
+    files: HashMap<String, String>,
+    /// Normalized path -> (crate root, module path)
+    file_modules: HashMap<String, (String, Vec<String>)>,
+    /// (crate root, `a::b`) -> normalized file declaring that module
+    modules: HashMap<(String, String), String>,
+    /// Normalized path -> names of items declared in the file
+    definitions: HashMap<String, HashSet<String>>,
+    /// Normalized path -> `pub use` entries as (exported name, path segments)
+    reexports: HashMap<String, Vec<(String, Vec<String>)>>,
+    /// Crate name as written in paths -> normalized library root
+    crates: HashMap<String, String>,
+    /// Third-party crate name as written in paths -> package node ID
+    external: HashMap<String, String>,
+    mod_links: Vec<ModLink>,
+}
+
+impl RustModuleResolver {
+    /// Build the module tree from parsed Rust files.
+    pub fn new(results: &[ParseResult]) -> Self {
+        let mut resolver = Self {
+            files: HashMap::new(),
+            file_modules: HashMap::new(),
+            modules: HashMap::new(),
+            definitions: HashMap::new(),
+            reexports: HashMap::new(),
+            crates: HashMap::new(),
+            external: HashMap::new(),
+            mod_links: Vec::new(),
+        };
+
+        let mut decls: HashMap<String, Vec<ModDecl>> = HashMap::new();
+        for result in results {
+            let Some(path) = result_path(result).filter(|p| p.ends_with(".rs")) else {
+                continue;
+            };
+            let norm = normalize_path(path);
+            resolver.files.insert(norm.clone(), path.to_string());
+
+            let defined = result
+                .nodes
+                .iter()
+                .filter(|n| n.node_type != NodeType::File)
+                .map(|n| n.name.clone())
+                .collect();
+            resolver.definitions.insert(norm.clone(), defined);
+
+            let file_decls = result
+                .nodes
+                .iter()
+                .filter(|n| n.node_type == NodeType::Module)
+                .map(|n| ModDecl {
+                    name: n.name.clone(),
+                    inline: n
+                        .metadata
+                        .extra
+                        .get("inline")
+                        .and_then(|v| v.as_bool())
+                        .unwrap_or(false),
+                    path_attr: n
+                        .metadata
+                        .extra
+                        .get("path_attr")
+                        .and_then(|v| v.as_str())
+                        .map(String::from),
+                    parents: n
+                        .metadata
+                        .extra
+                        .get("parent_modules")
+                        .and_then(|v| v.as_str())
+                        .map(|s| s.split("::").map(String::from).collect())
+                        .unwrap_or_default(),
+                    line: n.evidence.start_line,
+                })
+                .collect();
+            decls.insert(norm.clone(), file_decls);
+
+            let exports = result
+                .edges
+                .iter()
+                .filter(|e| is_reexport(e))
+                .filter_map(|e| {
+                    let segments = use_segments(e)?;
+                    let last = segments.last()?.clone();
+                    let name = e.metadata.exposed_as.clone().unwrap_or(last);
+                    Some((name, segments))
+                })
+                .collect();
+            resolver.reexports.insert(norm, exports);
+        }
+
+        resolver.build_module_tree(&decls);
+        resolver
+    }
+
+    /// Register workspace crates by import name and library root so that
+    /// `use other_crate::...` resolves across packages.
+    pub fn with_crates<I>(mut self, crates: I) -> Self
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        self.crates.extend(
+            crates
+                .into_iter()
+                .map(|(name, entry)| (name, normalize_path(&entry)))
+                .filter(|(_, entry)| self.file_modules.contains_key(entry)),
+        );
+        self
+    }
+
+    /// Register third-party crates by import name and package node ID so
+    /// that `use serde::Deserialize` depends on the `serde` package.
+    pub fn with_external_crates<I>(mut self, crates: I) -> Self
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        self.external.extend(crates);
+        self
+    }
+
+    /// Assign module paths by walking `mod` declarations from every crate root.
+    fn build_module_tree(&mut self, decls: &HashMap<String, Vec<ModDecl>>) {
+        let mut children: HashMap<&str, Vec<(&ModDecl, Option<String>)>> = HashMap::new();
+        let mut declared: HashSet<String> = HashSet::new();
+
+        for (file, file_decls) in decls {
+            let entries = file_decls
+                .iter()
+                .map(|decl| {
+                    let child = if decl.inline {
+                        None
+                    } else {
+                        self.child_file(file, decl)
+                    };
+                    if let Some(ref c) = child {
+                        declared.insert(c.clone());
+                    }
+                    (decl, child)
+                })
+                .collect();
+            children.insert(file.as_str(), entries);
+        }
+
+        let mut pending: Vec<&String> = self.files.keys().collect();
+        pending.sort();
+
+        // Undeclared files first, then anything left over from cycles
+        let (roots, rest): (Vec<_>, Vec<_>) = pending
+            .into_iter()
+            .partition(|f| !declared.contains(f.as_str()));
+
+        for root in roots.into_iter().chain(rest) {
+            if self.file_modules.contains_key(root.as_str()) {
+                continue;
+            }
+
+            let mut queue = VecDeque::from([(root.clone(), Vec::<String>::new())]);
+            self.file_modules
+                .insert(root.clone(), (root.clone(), Vec::new()));
+            self.modules
+                .insert((root.clone(), String::new()), root.clone());
+
+            while let Some((file, module)) = queue.pop_front() {
+                let Some(entries) = children.get(file.as_str()) else {
+                    continue;
+                };
+                for (decl, child) in entries {
+                    let mut child_module = module.clone();
+                    child_module.extend(decl.parents.iter().cloned());
+                    child_module.push(decl.name.clone());
+                    let key = (root.clone(), child_module.join("::"));
+
+                    let Some(child) = child else {
+                        self.modules.entry(key).or_insert_with(|| file.clone());
+                        continue;
+                    };
+
+                    self.mod_links.push(ModLink {
+                        parent: file.clone(),
+                        child: child.clone(),
+                        line: decl.line,
+                    });
+                    if self.file_modules.contains_key(child) {
+                        continue;
+                    }
+                    self.modules.insert(key, child.clone());
+                    self.file_modules
+                        .insert(child.clone(), (root.clone(), child_module.clone()));
+                    queue.push_back((child.clone(), child_module));
+                }
+            }
+        }
+    }
+
+    /// Locate the file backing `mod name;` declared in `file`.
+    fn child_file(&self, file: &str, decl: &ModDecl) -> Option<String> {
+        let dir = parent_dir(file);
+        let file_name = file.rsplit('/').next().unwrap_or(file);
+        let owns_dir = matches!(file_name, "lib.rs" | "main.rs" | "mod.rs");
+        let stem = file_name.trim_end_matches(".rs");
+        let nested = decl.parents.join("/");
+
+        let base = |with_stem: bool| {
+            let mut base = dir.to_string();
+            for part in [if with_stem { stem } else { "" }, nested.as_str()] {
+                if !part.is_empty() {
+                    base = join_path(&base, part);
+                }
+            }
+            base
+        };
+
+        if let Some(ref attr) = decl.path_attr {
+            let candidate = join_path(&base(!owns_dir && !decl.parents.is_empty()), attr);
+            return self.files.contains_key(&candidate).then_some(candidate);
+        }
+
+        // Non-root files own `<stem>/`; crate roots with other names own their dir
+        let mut bases = vec![base(!owns_dir)];
+        if !owns_dir {
+            bases.push(base(false));
+        }
+
+        bases
+            .iter()
+            .flat_map(|b| {
+                [
+                    join_path(b, &format!("{}.rs", decl.name)),
+                    join_path(b, &format!("{}/mod.rs", decl.name)),
+                ]
+            })
+            .find(|candidate| self.files.contains_key(candidate))
+    }
+
+    /// Resolve a `use` path written in `from` to (file, imported symbol).
+    fn resolve_path(
+        &self,
+        from: &str,
+        segments: &[String],
+        depth: usize,
+    ) -> Option<(String, Option<String>)> {
+        let (from_root, module) = self.file_modules.get(from)?;
+        let mut root = from_root;
+        let first = segments.first()?;
+
+        let (mut abs, rest) = match first.as_str() {
+            "crate" => (Vec::new(), &segments[1..]),
+            "self" => (module.clone(), &segments[1..]),
+            "super" => {
+                let supers = segments.iter().take_while(|s| *s == "super").count();
+                if supers > module.len() {
+                    return None;
+                }
+                (
+                    module[..module.len() - supers].to_vec(),
+                    &segments[supers..],
+                )
+            }
+            _ => {
+                let mut local = module.clone();
+                local.push(first.clone());
+                if self.modules.contains_key(&(root.clone(), local.join("::"))) {
+                    (module.clone(), segments)
+                } else {
+                    let entry = self.crates.get(first)?;
+                    root = &self.file_modules.get(entry)?.0;
+                    (Vec::new(), &segments[1..])
+                }
+            }
+        };
+        abs.extend(rest.iter().cloned());
+
+        for k in (0..=abs.len()).rev() {
+            let key = (root.clone(), abs[..k].join("::"));
+            let Some(file) = self.modules.get(&key) else {
+                continue;
+            };
+            return match abs.get(k).map(String::as_str) {
+                None | Some("self") => Some((file.clone(), None)),
+                Some("*") => Some((file.clone(), Some("*".to_string()))),
+                Some(symbol) => Some(self.follow_reexports(file, symbol, depth)),
+            };
+        }
+        None
+    }
+
+    /// Follow `pub use` chains until reaching the file that declares `symbol`.
+    fn follow_reexports(&self, file: &str, symbol: &str, depth: usize) -> (String, Option<String>) {
+        let here = (file.to_string(), Some(symbol.to_string()));
+        if depth == 0 || self.defines(file, symbol) {
+            return here;
+        }
+
+        let Some(exports) = self.reexports.get(file) else {
+            return here;
+        };
+
+        for (name, segments) in exports {
+            if name == symbol
+                && let Some((target, Some(target_symbol))) =
+                    self.resolve_path(file, segments, depth - 1)
+            {
+                return (target, Some(target_symbol));
+            }
+        }
+
+        for (name, segments) in exports {
+            if name != "*" {
+                continue;
+            }
+            let Some((glob_file, _)) = self.resolve_path(file, segments, depth - 1) else {
+                continue;
+            };
+            let reexported = self
+                .reexports
+                .get(&glob_file)
+                .is_some_and(|e| e.iter().any(|(n, _)| n == symbol));
+            if glob_file != file && (self.defines(&glob_file, symbol) || reexported) {
+                return self.follow_reexports(&glob_file, symbol, depth - 1);
+            }
+        }
+
+        here
+    }
+
+    fn defines(&self, file: &str, symbol: &str) -> bool {
+        self.definitions
+            .get(file)
+            .is_some_and(|names| names.contains(symbol))
+    }
+
+    /// Rewrite `use` edges to `file:` targets and add `mod` ownership edges.
+    ///
+    /// Imports of the same file are merged into one edge whose
+    /// `imported_symbols` lists every symbol taken from it. Crate-relative
+    /// paths that do not resolve (e.g. excluded files) keep their original
+    /// `module:` target; unresolved external crate paths are dropped.
+    pub fn apply(&self, results: &mut [ParseResult]) {
+        for result in results.iter_mut() {
+            let Some(path) = result_path(result).map(String::from) else {
+                continue;
+            };
+            let norm = normalize_path(&path);
+            if !self.file_modules.contains_key(&norm) {
+                continue;
+            }
+
+            let mut merged: Vec<(Edge, BTreeSet<String>)> = Vec::new();
+            let mut kept = Vec::with_capacity(result.edges.len());
+
+            for edge in result.edges.drain(..) {
+                let Some(segments) = use_segments(&edge) else {
+                    kept.push(edge);
+                    continue;
+                };
+                let (target_id, target_name, symbol) =
+                    match self.resolve_path(&norm, &segments, MAX_REEXPORT_DEPTH) {
+                        Some((target, _)) if target == norm => continue,
+                        Some((target, symbol)) => (
+                            format!("file:{}", self.files[&target]),
+                            self.files[&target].clone(),
+                            symbol,
+                        ),
+                        None => match self.external.get(&segments[0]) {
+                            Some(package) => (
+                                package.clone(),
+                                package.clone(),
+                                edge.metadata
+                                    .imported_symbols
+                                    .as_ref()
+                                    .and_then(|s| s.first().cloned()),
+                            ),
+                            _ => {
+                                if matches!(segments[0].as_str(), "crate" | "self" | "super") {
+                                    kept.push(edge);
+                                }
+                                continue;
+                            }
+                        },
+                    };
+
+                let slot = match merged.iter().position(|(e, _)| e.target_id == target_id) {
+                    Some(i) => i,
+                    None => {
+                        let mut first = edge.clone();
+                        first.id = format!("use:{}:{}", path, target_name);
+                        first.target_id = target_id;
+                        merged.push((first, BTreeSet::new()));
+                        merged.len() - 1
+                    }
+                };
+
+                let (merged_edge, symbols) = &mut merged[slot];
+                symbols.extend(symbol);
+                if is_reexport(&edge) {
+                    merged_edge
+                        .metadata
+                        .extra
+                        .insert("reexport".to_string(), serde_json::Value::Bool(true));
+                }
+            }
+
+            for (mut edge, symbols) in merged {
+                edge.metadata.imported_symbols = if symbols.is_empty() {
+                    None
+                } else {
+                    Some(symbols.into_iter().collect())
+                };
+                kept.push(edge);
+            }
+
+            for link in self.mod_links.iter().filter(|l| l.parent == norm) {
+                let child = &self.files[&link.child];
+                kept.push(Edge {
+                    id: format!("mod:{}:{}", path, child),
+                    edge_type: EdgeType::Owns,
+                    source_id: format!("file:{}", path),
+                    target_id: format!("file:{}", child),
+                    metadata: EdgeMetadata::default(),
+                    evidence: crate::types::EvidenceLocation {
+                        file: path.clone(),
+                        start_line: link.line,
+                        end_line: link.line,
+                        start_column: None,
+                        end_column: None,
+                    },
+                    tier: InformationTier::Fact,
+                    confidence: 1.0,
+                    last_verified: Utc::now(),
+                });
+            }
+
+            result.edges = kept;
+        }
+    }
+}
+
+/// Segments of a Rust `use` edge target (`module:crate::a::B`).
+fn use_segments(edge: &Edge) -> Option<Vec<String>> {
+    if edge.edge_type != EdgeType::DependsOn || !edge.id.starts_with("use:") {
+        return None;
+    }
+    let use_path = edge.target_id.strip_prefix("module:")?;
+    Some(use_path.split("::").map(String::from).collect())
+}
+
+fn is_reexport(edge: &Edge) -> bool {
+    edge.metadata
+        .extra
+        .get("reexport")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::parser::{Parser, RustParser};
+
+    fn parse_tree(files: &[(&str, &str)]) -> Vec<ParseResult> {
+        let parser = RustParser::new().unwrap();
+        files
+            .iter()
+            .map(|(path, code)| parser.parse(path, code).unwrap())
+            .collect()
+    }
+
+    fn edges_from<'a>(results: &'a [ParseResult], path: &str) -> Vec<&'a Edge> {
+        results
+            .iter()
+            .find(|r| result_path(r) == Some(path))
+            .map(|r| r.edges.iter().collect())
+            .unwrap_or_default()
+    }
+
+    fn sample_crate() -> Vec<ParseResult> {
+        parse_tree(&[
+            (
+                "./src/lib.rs",
+                "mod types;\nmod util;\n#[path = \"gen/out.rs\"]\nmod generated;\npub use types::Node;\n",
+            ),
+            (
+                "./src/types/mod.rs",
+                "mod node;\npub use node::*;\npub use self::node::Edge as Link;\n",
+            ),
+            (
+                "./src/types/node.rs",
+                "pub struct Node;\npub struct Edge;\n",
+            ),
+            (
+                "./src/util.rs",
+                "mod fmt;\nuse crate::types::{Node, Link};\nuse super::generated;\nuse std::fmt;\n",
+            ),
+            (
+                "./src/util/fmt.rs",
+                "use crate::Node;\nuse super::super::types::*;\n",
+            ),
+            ("./src/gen/out.rs", "pub fn generated() {}\n"),
+        ])
+    }
+
+    #[test]
+    fn test_mod_declarations_become_ownership_edges() {
+        let mut results = sample_crate();
+        RustModuleResolver::new(&results).apply(&mut results);
+
+        let mut owned: Vec<&str> = edges_from(&results, "./src/lib.rs")
+            .into_iter()
+            .filter(|e| e.edge_type == EdgeType::Owns)
+            .map(|e| e.target_id.as_str())
+            .collect();
+        owned.sort();
+        assert_eq!(
+            owned,
+            vec![
+                "file:./src/gen/out.rs",
+                "file:./src/types/mod.rs",
+                "file:./src/util.rs"
+            ]
+        );
+
+        let util_children: Vec<&str> = edges_from(&results, "./src/util.rs")
+            .into_iter()
+            .filter(|e| e.edge_type == EdgeType::Owns)
+            .map(|e| e.target_id.as_str())
+            .collect();
+        assert_eq!(util_children, vec!["file:./src/util/fmt.rs"]);
+    }
+
+    #[test]
+    fn test_use_lists_follow_reexports_to_defining_file() {
+        let mut results = sample_crate();
+        RustModuleResolver::new(&results).apply(&mut results);
+
+        let deps: Vec<&Edge> = edges_from(&results, "./src/util.rs")
+            .into_iter()
+            .filter(|e| e.edge_type == EdgeType::DependsOn)
+            .collect();
+        assert_eq!(deps.len(), 2, "std imports are not tracked: {:?}", deps);
+
+        let node = deps
+            .iter()
+            .find(|e| e.target_id == "file:./src/types/node.rs")
+            .expect("re-exported symbols resolve to node.rs");
+        assert_eq!(
+            node.metadata.imported_symbols,
+            Some(vec!["Edge".to_string(), "Node".to_string()])
+        );
+
+        let generated = deps
+            .iter()
+            .find(|e| e.target_id == "file:./src/gen/out.rs")
+            .expect("#[path] module resolves");
+        assert_eq!(generated.metadata.imported_symbols, None);
+    }
+
+    #[test]
+    fn test_super_paths_and_globs() {
+        let mut results = sample_crate();
+        RustModuleResolver::new(&results).apply(&mut results);
+
+        let mut deps: Vec<(&str, Option<Vec<String>>)> = edges_from(&results, "./src/util/fmt.rs")
+            .into_iter()
+            .map(|e| (e.target_id.as_str(), e.metadata.imported_symbols.clone()))
+            .collect();
+        deps.sort();
+        assert_eq!(
+            deps,
+            vec![
+                ("file:./src/types/mod.rs", Some(vec!["*".to_string()])),
+                ("file:./src/types/node.rs", Some(vec!["Node".to_string()])),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_workspace_crate_paths() {
+        let mut results = parse_tree(&[
+            (
+                "crates/core/src/lib.rs",
+                "mod graph;\npub use graph::Graph;\n",
+            ),
+            ("crates/core/src/graph.rs", "pub struct Graph;\n"),
+            (
+                "crates/cli/src/main.rs",
+                "use weave_core::Graph;\nuse serde::Serialize;\n",
+            ),
+        ]);
+        RustModuleResolver::new(&results)
+            .with_crates([(
+                "weave_core".to_string(),
+                "./crates/core/src/lib.rs".to_string(),
+            )])
+            .apply(&mut results);
+
+        let deps: Vec<(&str, Option<Vec<String>>)> = edges_from(&results, "crates/cli/src/main.rs")
+            .into_iter()
+            .map(|e| (e.target_id.as_str(), e.metadata.imported_symbols.clone()))
+            .collect();
+        assert_eq!(
+            deps,
+            vec![(
+                "file:crates/core/src/graph.rs",
+                Some(vec!["Graph".to_string()])
+            )]
+        );
+    }
+
+    #[test]
+    fn test_undeclared_files_are_crate_roots() {
+        let mut results = parse_tree(&[
+            ("tests/it.rs", "mod common;\nuse common::setup;\n"),
+            ("tests/common/mod.rs", "pub fn setup() {}\n"),
+        ]);
+        RustModuleResolver::new(&results).apply(&mut results);
+
+        let deps = edges_from(&results, "tests/it.rs");
+        assert!(deps.iter().any(|e| e.edge_type == EdgeType::DependsOn
+            && e.target_id == "file:tests/common/mod.rs"
+            && e.metadata.imported_symbols == Some(vec!["setup".to_string()])));
+    }
+}
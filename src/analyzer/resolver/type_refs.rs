@@ -0,0 +1,354 @@
+//! Type References
+//!
+//! Parameter, return and field types are recorded as the strings written in
+//! the source (`&ProjectProfile`, `Option<Vec<Node>>`, `User | null`). Once
+//! imports are resolved, every identifier in those strings that names a
+//! class, interface, type alias, enum or entity of the project becomes a
+//! `uses_type` edge from the function, entity or component to that node.
+//!
+//! A name is looked up in the file itself, then in the files it imports,
+//! then in its directory or package; a name that is ambiguous there, or
+//! only declared elsewhere, is left unlinked rather than guessed.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use chrono::Utc;
+
+use super::{normalize_path, parent_dir, result_path};
+use crate::analyzer::parser::ParseResult;
+use crate::types::{Edge, EdgeMetadata, EdgeType, InformationTier, Node, NodeType};
+
+/// A type declared in the project.
+struct Declared<'a> {
+    id: &'a str,
+    path: String,
+    entity: bool,
+}
+
+/// Add `uses_type` edges from signatures and fields to the declared types
+/// they mention.
+pub fn resolve_type_references(results: &mut [ParseResult]) {
+    let mut declared: HashMap<&str, Vec<Declared>> = HashMap::new();
+    for node in results.iter().flat_map(|r| &r.nodes) {
+        if matches!(
+            node.node_type,
+            NodeType::Class
+                | NodeType::Interface
+                | NodeType::Type
+                | NodeType::Enum
+                | NodeType::Entity
+        ) && node.tier == InformationTier::Fact
+        {
+            declared.entry(&node.name).or_default().push(Declared {
+                id: &node.id,
+                path: normalize_path(&node.path),
+                entity: node.node_type == NodeType::Entity,
+            });
+        }
+    }
+    if declared.is_empty() {
+        return;
+    }
+
+    let mut links: Vec<(usize, Vec<Edge>)> = Vec::new();
+    for (index, result) in results.iter().enumerate() {
+        let Some(path) = result_path(result).map(normalize_path) else {
+            continue;
+        };
+        let imported: BTreeSet<String> = result
+            .edges
+            .iter()
+            .filter(|e| e.edge_type == EdgeType::DependsOn)
+            .filter_map(|e| e.target_id.strip_prefix("file:"))
+            .map(normalize_path)
+            .collect();
+
+        let mut edges = Vec::new();
+        for node in &result.nodes {
+            // Target ID -> roles, ordered for stable edges
+            let mut targets: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+            for (role, type_text) in referenced_types(node) {
+                for name in type_names(type_text) {
+                    let Some(candidates) = declared.get(name) else {
+                        continue;
+                    };
+                    if let Some(target) = pick(candidates, &path, &imported)
+                        && target != node.id
+                    {
+                        targets.entry(target).or_default().insert(role);
+                    }
+                }
+            }
+            edges.extend(
+                targets
+                    .into_iter()
+                    .map(|(target, roles)| uses_type_edge(node, target, roles)),
+            );
+        }
+
+        if !edges.is_empty() {
+            links.push((index, edges));
+        }
+    }
+
+    for (index, edges) in links {
+        results[index].edges.extend(edges);
+    }
+}
+
+/// The type strings a node mentions, with the role they play.
+fn referenced_types(node: &Node) -> Vec<(&'static str, &str)> {
+    let mut types = Vec::new();
+    let metadata = &node.metadata;
+    if let Some(signature) = &metadata.signature {
+        for parameter in &signature.parameters {
+            if let Some(param_type) = &parameter.param_type {
+                types.push(("parameter", param_type.as_str()));
+            }
+        }
+        if let Some(return_type) = &signature.return_type {
+            types.push(("return", return_type.as_str()));
+        }
+    }
+    for field in metadata.fields.iter().flatten() {
+        types.push(("field", field.field_type.as_str()));
+    }
+    if let Some(entity) = &metadata.entity_metadata {
+        for field in entity.fields.iter().flatten() {
+            types.push(("field", field.field_type.as_str()));
+        }
+    }
+    if let Some(component) = &metadata.component_metadata {
+        for prop in component.props.iter().flatten() {
+            types.push(("field", prop.prop_type.as_str()));
+        }
+        for field in component.state.iter().flat_map(|s| &s.fields) {
+            types.push(("field", field.field_type.as_str()));
+        }
+    }
+    types
+}
+
+/// Identifiers in a type string (`Result<Vec<Node>, Error>` → `Result`,
+/// `Vec`, `Node`, `Error`), skipping lifetimes.
+fn type_names(text: &str) -> impl Iterator<Item = &str> {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_' || c == '$';
+    let mut names = Vec::new();
+    let mut start = None;
+    for (i, c) in text.char_indices().chain([(text.len(), ' ')]) {
+        match (start, is_ident(c)) {
+            (None, true) => start = Some(i),
+            (Some(s), false) => {
+                let name = &text[s..i];
+                let lifetime = text[..s].ends_with('\'');
+                if !lifetime && !name.starts_with(|c: char| c.is_ascii_digit()) {
+                    names.push(name);
+                }
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    names.into_iter()
+}
+
+/// The declaration a name refers to from `path`: in the file itself, in an
+/// imported file or in the same directory, whichever scope first holds
+/// exactly one. A model class wins over the entity derived from it.
+fn pick<'a>(
+    candidates: &[Declared<'a>],
+    path: &str,
+    imported: &BTreeSet<String>,
+) -> Option<&'a str> {
+    let dir = parent_dir(path);
+    let in_file = |d: &Declared| d.path == path;
+    let in_imports = |d: &Declared| imported.contains(&d.path);
+    let in_dir = |d: &Declared| parent_dir(&d.path) == dir;
+    let scopes: [&dyn Fn(&Declared) -> bool; 3] = [&in_file, &in_imports, &in_dir];
+    for in_scope in scopes {
+        let found: Vec<&Declared> = candidates.iter().filter(|d| in_scope(d)).collect();
+        let types: Vec<&Declared> = found.iter().copied().filter(|d| !d.entity).collect();
+        let preferred = if types.is_empty() { found } else { types };
+        match preferred.as_slice() {
+            [] => continue,
+            [only] => return Some(only.id),
+            _ => return None,
+        }
+    }
+    None
+}
+
+fn uses_type_edge(source: &Node, target: &str, roles: BTreeSet<&str>) -> Edge {
+    let mut metadata = EdgeMetadata::default();
+    metadata
+        .extra
+        .insert("roles".to_string(), serde_json::json!(roles));
+    Edge {
+        id: format!("uses_type:{}->{}", source.id, target),
+        edge_type: EdgeType::UsesType,
+        source_id: source.id.clone(),
+        target_id: target.to_string(),
+        metadata,
+        evidence: source.evidence.clone(),
+        tier: InformationTier::Fact,
+        confidence: 1.0,
+        last_verified: Utc::now(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::parser::{Language, create_parser};
+    use crate::analyzer::resolver::resolve_imports;
+    use crate::analyzer::workspace::Workspace;
+
+    fn parse(files: &[(&str, &str)]) -> Vec<ParseResult> {
+        files
+            .iter()
+            .map(|(path, code)| {
+                create_parser(Language::from_path(path))
+                    .unwrap()
+                    .parse(path, code)
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    fn uses_type_edges(results: &[ParseResult]) -> Vec<(String, String, serde_json::Value)> {
+        let mut edges: Vec<_> = results
+            .iter()
+            .flat_map(|r| &r.edges)
+            .filter(|e| e.edge_type == EdgeType::UsesType)
+            .map(|e| {
+                (
+                    e.source_id.clone(),
+                    e.target_id.clone(),
+                    e.metadata.extra["roles"].clone(),
+                )
+            })
+            .collect();
+        edges.sort_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
+        edges
+    }
+
+    #[test]
+    fn test_type_names() {
+        let names: Vec<&str> = type_names("Result<Vec<&'a Node>, crate::Error>").collect();
+        assert_eq!(names, ["Result", "Vec", "Node", "crate", "Error"]);
+    }
+
+    #[test]
+    fn test_links_signatures_to_types() {
+        let mut results = parse(&[
+            (
+                "./src/profile.rs",
+                "pub struct ProjectProfile { name: String }\npub struct Config;\n",
+            ),
+            ("./src/other.rs", "pub struct Config;\n"),
+            (
+                "./src/wiki.rs",
+                "use crate::profile::ProjectProfile;\n\n\
+                 pub fn describe(profile: &ProjectProfile) -> Option<ProjectProfile> { None }\n\n\
+                 pub fn load(path: &str) -> Config { todo!() }\n",
+            ),
+        ]);
+        resolve_imports(&mut results, &Workspace::default());
+        resolve_type_references(&mut results);
+
+        let profile = results[0]
+            .nodes
+            .iter()
+            .find(|n| n.name == "ProjectProfile")
+            .map(|n| n.id.clone())
+            .unwrap();
+        let describe = results[2]
+            .nodes
+            .iter()
+            .find(|n| n.name == "describe")
+            .map(|n| n.id.clone())
+            .unwrap();
+        // `Config` is declared twice and imported from neither file
+        assert_eq!(
+            uses_type_edges(&results),
+            vec![(
+                describe,
+                profile,
+                serde_json::json!(["parameter", "return"])
+            )]
+        );
+    }
+
+    #[test]
+    fn test_links_fields_to_types() {
+        let mut results = parse(&[
+            (
+                "./src/profile.rs",
+                "pub struct Config;
+pub struct Profile { pub config: Config, name: String }
+",
+            ),
+            (
+                "./src/user.ts",
+                "export class Account {}
+export interface User { account?: Account; }
+",
+            ),
+        ]);
+        resolve_imports(&mut results, &Workspace::default());
+        resolve_type_references(&mut results);
+
+        let id = |index: usize, name: &str| {
+            results[index]
+                .nodes
+                .iter()
+                .find(|n| n.name == name)
+                .map(|n| n.id.clone())
+                .unwrap()
+        };
+        let field = serde_json::json!(["field"]);
+        assert_eq!(
+            uses_type_edges(&results),
+            vec![
+                (id(0, "Profile"), id(0, "Config"), field.clone()),
+                (id(1, "User"), id(1, "Account"), field),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_skips_unimported_types() {
+        let mut results = parse(&[
+            ("./src/types.ts", "export interface Props { id: string; }\n"),
+            (
+                "./src/components/Card.tsx",
+                "import { Props } from '../types';\n\n\
+                 export function card(props: Props) {}\n",
+            ),
+            (
+                "./src/pages/Home.tsx",
+                "export function home(props: Props) {}\n",
+            ),
+        ]);
+        resolve_imports(&mut results, &Workspace::default());
+        resolve_type_references(&mut results);
+
+        let id = |index: usize, name: &str| {
+            results[index]
+                .nodes
+                .iter()
+                .find(|n| n.name == name)
+                .map(|n| n.id.clone())
+                .unwrap()
+        };
+        // `Home.tsx` neither declares nor imports a `Props`
+        assert_eq!(
+            uses_type_edges(&results),
+            vec![(
+                id(1, "card"),
+                id(0, "Props"),
+                serde_json::json!(["parameter"])
+            )]
+        );
+    }
+}
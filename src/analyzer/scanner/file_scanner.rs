@@ -1,16 +1,18 @@
 use ignore::WalkBuilder;
+use ignore::gitignore::GitignoreBuilder;
+use std::collections::HashSet;
+use std::fmt;
 use std::path::{Path, PathBuf};
 
+use crate::analyzer::parser::Language;
+use crate::config::AnalysisConfig;
 use crate::types::Result;
 
 /// Default maximum file size for analysis (1MB)
 const DEFAULT_MAX_FILE_SIZE: usize = 1_048_576;
 
-/// Common source code extensions
-const SOURCE_EXTENSIONS: &[&str] = &[
-    "rs", "ts", "tsx", "js", "jsx", "py", "go", "java", "kt", "rb", "c", "cpp", "h", "hpp", "cs",
-    "swift", "scala", "php", "lua", "sh", "bash", "zsh",
-];
+/// Project-specific ignore file, read with gitignore syntax in every directory
+pub const IGNORE_FILENAME: &str = ".weavewikiignore";
 
 /// Default directories to skip
 const DEFAULT_SKIP_DIRS: &[&str] = &[
@@ -24,12 +26,68 @@ const DEFAULT_SKIP_DIRS: &[&str] = &[
     ".venv",
 ];
 
+/// Selects the files a project is analyzed from.
+///
+/// `build` and `generate` both scan through [`FileScanner::for_project`], so
+/// include/exclude globs (matched against root-relative paths), ignore files
+/// (`.gitignore` and `.weavewikiignore`), the size limit and per-language
+/// toggles apply identically to both.
 pub struct FileScanner {
     root: PathBuf,
     include: Vec<String>,
     exclude: Vec<String>,
     max_file_size: u64,
     source_only: bool,
+    disabled_languages: HashSet<Language>,
+}
+
+/// Include and exclude globs, compiled once per scan
+struct Rules {
+    include: Vec<(glob::Pattern, String)>,
+    exclude: Vec<(glob::Pattern, String)>,
+}
+
+/// Why a file was or was not selected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Selection {
+    Included(Language),
+    NotAFile,
+    Ignored { file: PathBuf, pattern: String },
+    Excluded(String),
+    NotIncluded,
+    LanguageDisabled(Language),
+    TooLarge { size: u64, max: u64 },
+    NotSource,
+}
+
+impl Selection {
+    pub fn is_included(&self) -> bool {
+        matches!(self, Selection::Included(_))
+    }
+}
+
+impl fmt::Display for Selection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Selection::Included(lang) if lang.is_known() => write!(f, "included ({})", lang),
+            Selection::Included(_) => write!(f, "included"),
+            Selection::NotAFile => write!(f, "skipped: not a file"),
+            Selection::Ignored { file, pattern } => {
+                write!(f, "skipped: ignored by `{}` in {}", pattern, file.display())
+            }
+            Selection::Excluded(pattern) => {
+                write!(f, "skipped: matches exclude pattern `{}`", pattern)
+            }
+            Selection::NotIncluded => write!(f, "skipped: matches no include pattern"),
+            Selection::LanguageDisabled(lang) => {
+                write!(f, "skipped: {} is disabled in analysis.languages", lang)
+            }
+            Selection::TooLarge { size, max } => {
+                write!(f, "skipped: {} bytes exceeds max_file_size ({})", size, max)
+            }
+            Selection::NotSource => write!(f, "skipped: not source in a parsed language"),
+        }
+    }
 }
 
 impl FileScanner {
@@ -40,6 +98,7 @@ impl FileScanner {
             exclude: vec![],
             max_file_size: DEFAULT_MAX_FILE_SIZE as u64,
             source_only: false,
+            disabled_languages: HashSet::new(),
         }
     }
 
@@ -50,14 +109,21 @@ impl FileScanner {
             .map(|d| format!("{}/**", d))
             .collect();
         Self {
-            root: root.as_ref().to_path_buf(),
-            include: vec!["**/*".to_string()],
             exclude,
-            max_file_size: DEFAULT_MAX_FILE_SIZE as u64,
             source_only: true,
+            ..Self::new(root)
         }
     }
 
+    /// Create a scanner applying the project's `analysis` settings.
+    pub fn for_project<P: AsRef<Path>>(root: P, config: &AnalysisConfig) -> Self {
+        Self::new(root)
+            .with_include(config.include.clone())
+            .with_exclude(config.exclude.clone())
+            .with_max_file_size(config.max_file_size as u64)
+            .with_disabled_languages(config.disabled_languages())
+    }
+
     pub fn with_include(mut self, patterns: Vec<String>) -> Self {
         self.include = patterns;
         self
@@ -73,7 +139,13 @@ impl FileScanner {
         self
     }
 
-    /// Enable source file extension filtering
+    pub fn with_disabled_languages(mut self, languages: HashSet<Language>) -> Self {
+        self.disabled_languages = languages;
+        self
+    }
+
+    /// Only select source files in a language `build` has a parser for, so
+    /// `generate` documents exactly the source the graph is built from.
     pub fn source_only(mut self) -> Self {
         self.source_only = true;
         self
@@ -81,22 +153,12 @@ impl FileScanner {
 
     /// Count files without collecting them (more efficient for scale detection)
     pub fn count(&self) -> usize {
-        let walker = WalkBuilder::new(&self.root)
-            .hidden(false)
-            .git_ignore(true)
-            .git_global(true)
-            .git_exclude(true)
-            .follow_links(false) // Security: prevent symlink traversal attacks
-            .build();
-
-        walker
+        let rules = self.rules();
+        self.walker(&rules)
             .filter_map(|e| e.ok())
             .filter(|entry| {
-                let path = entry.path();
-                path.is_file()
-                    && !self.should_exclude(path)
-                    && self.check_size(path)
-                    && self.check_source_extension(path)
+                let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+                entry.path().is_file() && self.select(&rules, entry.path(), size).is_included()
             })
             .count()
     }
@@ -117,32 +179,17 @@ impl FileScanner {
 
     pub fn scan(&self) -> Result<Vec<ScannedFile>> {
         let mut files = Vec::new();
+        let rules = self.rules();
 
-        let walker = WalkBuilder::new(&self.root)
-            .hidden(false)
-            .git_ignore(true)
-            .git_global(true)
-            .git_exclude(true)
-            .follow_links(false) // Security: prevent symlink traversal attacks
-            .build();
-
-        for entry in walker.filter_map(|e| e.ok()) {
+        for entry in self.walker(&rules).filter_map(|e| e.ok()) {
             let path = entry.path();
 
             if !path.is_file() {
                 continue;
             }
 
-            if self.should_exclude(path) {
-                continue;
-            }
-
-            if !self.check_source_extension(path) {
-                continue;
-            }
-
             if let Ok(metadata) = path.metadata() {
-                if metadata.len() > self.max_file_size {
+                if !self.select(&rules, path, metadata.len()).is_included() {
                     continue;
                 }
 
@@ -157,41 +204,244 @@ impl FileScanner {
         Ok(files)
     }
 
-    fn should_exclude(&self, path: &Path) -> bool {
-        let path_str = path.to_string_lossy();
+    /// Explain whether `path` (relative to the scan root, or absolute) is
+    /// selected, checking ignore files first and then the same rules
+    /// [`scan`](Self::scan) applies.
+    pub fn explain<P: AsRef<Path>>(&self, path: P) -> Selection {
+        let joined = self.root.join(path.as_ref());
+        let path = joined.as_path();
+        let metadata = match path.metadata() {
+            Ok(metadata) if metadata.is_file() => metadata,
+            _ => return Selection::NotAFile,
+        };
+        if let Some(ignored) = self.ignored_by(path) {
+            return ignored;
+        }
+        self.select(&self.rules(), path, metadata.len())
+    }
+
+    fn rules(&self) -> Rules {
+        Rules {
+            include: compile(&self.include),
+            exclude: compile(&self.exclude),
+        }
+    }
 
-        for pattern in &self.exclude {
-            if glob::Pattern::new(pattern)
-                .map(|p| p.matches(&path_str))
-                .unwrap_or(false)
-            {
+    fn walker(&self, rules: &Rules) -> ignore::Walk {
+        let mut builder = WalkBuilder::new(&self.root);
+        builder
+            .hidden(false)
+            .git_ignore(true)
+            .git_global(true)
+            .git_exclude(true)
+            .add_custom_ignore_filename(IGNORE_FILENAME)
+            .follow_links(false); // Security: prevent symlink traversal attacks
+
+        // Skip excluded directories instead of filtering every file below them
+        let root = self.root.clone();
+        let exclude = rules.exclude.clone();
+        builder.filter_entry(move |entry| {
+            let is_dir = entry.file_type().is_some_and(|t| t.is_dir());
+            if !is_dir || entry.depth() == 0 {
                 return true;
             }
+            let dir = format!("{}/", relative(&root, entry.path()));
+            !exclude.iter().any(|(p, _)| p.matches(&dir))
+        });
+        builder.build()
+    }
+
+    /// Apply the configured rules to a file that no ignore file excludes.
+    fn select(&self, rules: &Rules, path: &Path, size: u64) -> Selection {
+        let rel = relative(&self.root, path);
+
+        if let Some((_, pattern)) = rules.exclude.iter().find(|(p, _)| p.matches(&rel)) {
+            return Selection::Excluded(pattern.clone());
+        }
+        if !rules.include.iter().any(|(p, _)| p.matches(&rel)) {
+            return Selection::NotIncluded;
         }
 
-        false
-    }
+        let lang = Language::from_path(path);
+        if self.disabled_languages.contains(&lang) {
+            return Selection::LanguageDisabled(lang);
+        }
+        if size > self.max_file_size {
+            return Selection::TooLarge {
+                size,
+                max: self.max_file_size,
+            };
+        }
+        if self.source_only && !lang.has_parser_support() {
+            return Selection::NotSource;
+        }
 
-    fn check_size(&self, path: &Path) -> bool {
-        path.metadata()
-            .map(|m| m.len() <= self.max_file_size)
-            .unwrap_or(false)
+        Selection::Included(lang)
     }
 
-    fn check_source_extension(&self, path: &Path) -> bool {
-        if !self.source_only {
-            return true;
+    /// Match `path` against `.weavewikiignore` files and, inside a git
+    /// repository, `.gitignore` files from its directory up to the root.
+    /// The deepest file with a matching rule decides, as in the walker.
+    fn ignored_by(&self, path: &Path) -> Option<Selection> {
+        let in_repo = self.root.ancestors().any(|d| d.join(".git").exists());
+        let names: &[&str] = if in_repo {
+            &[IGNORE_FILENAME, ".gitignore"]
+        } else {
+            &[IGNORE_FILENAME]
+        };
+
+        let dirs = path
+            .ancestors()
+            .skip(1)
+            .take_while(|d| d.starts_with(&self.root));
+        for dir in dirs {
+            for name in names {
+                let file = dir.join(name);
+                if !file.is_file() {
+                    continue;
+                }
+                let mut builder = GitignoreBuilder::new(dir);
+                builder.add(&file);
+                let Ok(matcher) = builder.build() else {
+                    continue;
+                };
+                let matched = matcher.matched_path_or_any_parents(path, false);
+                if matched.is_whitelist() {
+                    return None;
+                }
+                if let Some(glob) = matched.inner().filter(|_| matched.is_ignore()) {
+                    return Some(Selection::Ignored {
+                        file: file.strip_prefix(&self.root).unwrap_or(&file).to_path_buf(),
+                        pattern: glob.original().to_string(),
+                    });
+                }
+            }
         }
-        path.extension()
-            .and_then(|e| e.to_str())
-            .map(|ext| SOURCE_EXTENSIONS.contains(&ext))
-            .unwrap_or(false)
+        None
     }
 }
 
+/// `path` relative to `root` with `/` separators, as globs are written.
+fn relative(root: &Path, path: &Path) -> String {
+    let rel = path.strip_prefix(root).unwrap_or(path);
+    rel.components()
+        .filter_map(|c| match c {
+            std::path::Component::Normal(part) => Some(part.to_string_lossy()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn compile(patterns: &[String]) -> Vec<(glob::Pattern, String)> {
+    patterns
+        .iter()
+        .filter_map(|p| Some((glob::Pattern::new(p).ok()?, p.clone())))
+        .collect()
+}
+
 #[derive(Debug, Clone)]
 pub struct ScannedFile {
     pub path: PathBuf,
     pub size: u64,
     pub extension: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_selection_shared_by_scan_and_explain() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        for file in [
+            "src/main.rs",
+            "src/gen/schema.rs",
+            "scripts/run.py",
+            "node_modules/pkg/index.js",
+            "docs/guide.md",
+        ] {
+            fs::create_dir_all(root.join(file).parent().unwrap()).unwrap();
+            fs::write(root.join(file), "x").unwrap();
+        }
+        fs::write(root.join(IGNORE_FILENAME), "src/gen/\n").unwrap();
+
+        let config = AnalysisConfig {
+            include: vec!["src/**".to_string(), "scripts/**".to_string()],
+            languages: [("python".to_string(), false)].into_iter().collect(),
+            ..AnalysisConfig::default()
+        };
+        let scanner = FileScanner::for_project(root, &config);
+
+        let paths = scanner.paths().unwrap();
+        assert_eq!(paths, vec!["src/main.rs"]);
+        assert_eq!(scanner.count(), 1);
+
+        assert_eq!(
+            scanner.explain(root.join("src/main.rs")),
+            Selection::Included(Language::Rust)
+        );
+        assert_eq!(
+            scanner.explain(root.join("src/gen/schema.rs")),
+            Selection::Ignored {
+                file: PathBuf::from(IGNORE_FILENAME),
+                pattern: "src/gen/".to_string()
+            }
+        );
+        assert_eq!(
+            scanner.explain(root.join("node_modules/pkg/index.js")),
+            Selection::Excluded("node_modules/**".to_string())
+        );
+        assert_eq!(
+            scanner.explain(root.join("scripts/run.py")),
+            Selection::LanguageDisabled(Language::Python)
+        );
+        assert_eq!(
+            scanner.explain(root.join("docs/guide.md")),
+            Selection::NotIncluded
+        );
+        assert_eq!(
+            scanner.explain(root.join("missing.rs")),
+            Selection::NotAFile
+        );
+    }
+
+    #[test]
+    fn test_source_only_follows_parsed_languages() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let files = [
+            "src/App.vue",
+            "src/Card.svelte",
+            "db/schema.prisma",
+            "db/init.sql",
+            "src/util.h",
+            "README.md",
+            "Cargo.toml",
+        ];
+        for file in files {
+            fs::create_dir_all(root.join(file).parent().unwrap()).unwrap();
+            fs::write(root.join(file), "x").unwrap();
+        }
+
+        let scanner = FileScanner::for_project(root, &AnalysisConfig::default()).source_only();
+        let mut paths = scanner.paths().unwrap();
+        paths.sort();
+        assert_eq!(
+            paths,
+            vec![
+                "db/init.sql",
+                "db/schema.prisma",
+                "src/App.vue",
+                "src/Card.svelte",
+                "src/util.h"
+            ]
+        );
+        assert_eq!(
+            scanner.explain(root.join("README.md")),
+            Selection::NotSource
+        );
+    }
+}
@@ -8,9 +8,15 @@
 //! - No framework-specific paths (no "/adapter/", "/domain/" detection)
 //! - Focus on universal structural information that AI can interpret
 
-use crate::storage::Database;
+use std::collections::HashMap;
+
+use super::graph::DependencyGraph;
+use crate::storage::{Database, GraphStore};
 use crate::types::{Result, log_filter_error};
 
+const MAX_HOTSPOTS: usize = 50;
+const MAX_CLUSTERS: usize = 30;
+
 /// Universal structure analysis results
 #[derive(Debug, Clone)]
 pub struct StructureAnalysis {
@@ -22,6 +28,8 @@ pub struct StructureAnalysis {
     pub hotspots: Vec<Hotspot>,
     /// Dependency clusters (groups of tightly coupled code)
     pub clusters: Vec<CodeCluster>,
+    /// Files that depend on each other in a cycle
+    pub cycles: Vec<Vec<String>>,
     /// Build/config files found (language-agnostic detection)
     pub build_markers: Vec<BuildMarker>,
 }
@@ -80,15 +88,18 @@ pub struct Hotspot {
     pub reference_count: i64,
     /// How many other nodes this references
     pub dependency_count: i64,
-    /// Centrality score (importance in the graph)
+    /// PageRank over dependency edges, scaled so the most central node is 1.0
     pub centrality: f64,
 }
 
-/// Code cluster - tightly coupled group
+/// Code cluster - a community of files linked more to each other than to
+/// the rest of the project
 #[derive(Debug, Clone)]
 pub struct CodeCluster {
     pub id: String,
+    /// Longest directory prefix shared by the cluster's files
     pub root_directory: String,
+    pub files: Vec<String>,
     pub node_count: i64,
     pub internal_edges: i64,
     pub external_edges: i64,
@@ -125,11 +136,13 @@ impl<'a> StructureAnalyzer<'a> {
 
     /// Perform complete structure analysis
     pub fn analyze(&self) -> Result<StructureAnalysis> {
+        let files = DependencyGraph::files(&GraphStore::new(self.db))?;
         Ok(StructureAnalysis {
             directories: self.analyze_directories()?,
             entry_points: self.find_entry_points()?,
             hotspots: self.find_hotspots()?,
-            clusters: self.find_clusters()?,
+            clusters: find_clusters(&files),
+            cycles: files.cycles(),
             build_markers: self.find_build_markers()?,
         })
     }
@@ -140,6 +153,7 @@ impl<'a> StructureAnalyzer<'a> {
 
         // Get top-level directories (first 2 levels)
         // SQLite doesn't have reverse(), so we use a simpler approach
+        // Test files, flagged by the parsers, are left out
         let mut stmt = conn.prepare(
             "WITH top_dirs AS (
                 SELECT DISTINCT
@@ -150,11 +164,12 @@ impl<'a> StructureAnalyzer<'a> {
                     END as dir_path
                 FROM nodes
                 WHERE node_type = 'file'
-                AND path NOT LIKE '%/test%'
+                AND json_extract(metadata, '$.test') IS NULL
             )
             SELECT
                 d.dir_path,
-                (SELECT COUNT(*) FROM nodes WHERE node_type = 'file' AND path LIKE d.dir_path || '%') as file_count,
+                (SELECT COUNT(*) FROM nodes WHERE node_type = 'file' AND path LIKE d.dir_path || '%'
+                    AND json_extract(metadata, '$.test') IS NULL) as file_count,
                 (SELECT COUNT(*) FROM nodes WHERE node_type = 'class' AND path LIKE d.dir_path || '%') as class_count,
                 (SELECT COUNT(*) FROM nodes WHERE node_type IN ('function', 'method') AND path LIKE d.dir_path || '%') as func_count
             FROM top_dirs d
@@ -190,16 +205,19 @@ impl<'a> StructureAnalyzer<'a> {
     fn find_entry_points(&self) -> Result<Vec<EntryPoint>> {
         let conn = self.db.connection()?;
 
-        // Find nodes that are not targets of any edge (no one calls them internally)
+        // Find nodes that nothing imports or calls internally
         let mut stmt = conn.prepare(
             "SELECT n.id, n.name, n.path, n.node_type,
-                (SELECT COUNT(*) FROM edges WHERE target_id = n.id) as internal_callers,
-                (SELECT COUNT(*) FROM edges WHERE source_id = n.id) as outgoing_calls
+                (SELECT COUNT(DISTINCT source_id) FROM edges
+                    WHERE target_id = n.id AND edge_type = 'calls') as internal_callers,
+                (SELECT COUNT(*) FROM edges
+                    WHERE source_id = n.id AND edge_type = 'calls') as outgoing_calls
              FROM nodes n
-             WHERE n.node_type IN ('class', 'function', 'interface')
-             AND NOT EXISTS (SELECT 1 FROM edges WHERE target_id = n.id AND edge_type = 'depends_on')
+             WHERE n.node_type IN ('class', 'function', 'method', 'interface')
+             AND NOT EXISTS (SELECT 1 FROM edges WHERE target_id = n.id
+                    AND edge_type IN ('depends_on', 'calls'))
              ORDER BY outgoing_calls DESC
-             LIMIT 50"
+             LIMIT 50",
         )?;
 
         let entries: Vec<EntryPoint> = stmt
@@ -233,8 +251,9 @@ impl<'a> StructureAnalyzer<'a> {
         Ok(entries)
     }
 
-    /// Find hotspots (most referenced code)
+    /// Find hotspots: the most central code by PageRank
     fn find_hotspots(&self) -> Result<Vec<Hotspot>> {
+        let ranks = DependencyGraph::symbols(&GraphStore::new(self.db))?.pagerank();
         let conn = self.db.connection()?;
 
         let mut stmt = conn.prepare(
@@ -243,94 +262,39 @@ impl<'a> StructureAnalyzer<'a> {
                 (SELECT COUNT(*) FROM edges WHERE target_id = n.id) as ref_count,
                 (SELECT COUNT(*) FROM edges WHERE source_id = n.id) as dep_count
              FROM nodes n
-             WHERE n.node_type IN ('class', 'function', 'interface', 'module')
-             ORDER BY ref_count DESC
-             LIMIT 50",
+             WHERE n.node_type IN ('class', 'function', 'method', 'interface', 'module')",
         )?;
 
-        let hotspots: Vec<Hotspot> = stmt
+        let mut hotspots: Vec<Hotspot> = stmt
             .query_map([], |row| {
-                let ref_count: i64 = row.get(4)?;
-                let dep_count: i64 = row.get(5)?;
-                let total = ref_count + dep_count;
-                let centrality = if total > 0 {
-                    ref_count as f64 / total as f64
-                } else {
-                    0.0
-                };
-
+                let node_id: String = row.get(0)?;
                 Ok(Hotspot {
-                    node_id: row.get(0)?,
+                    centrality: ranks.get(&node_id).copied().unwrap_or(0.0),
+                    node_id,
                     name: row.get(1)?,
                     path: row.get(2)?,
                     node_type: row.get(3)?,
-                    reference_count: ref_count,
-                    dependency_count: dep_count,
-                    centrality,
+                    reference_count: row.get(4)?,
+                    dependency_count: row.get(5)?,
                 })
             })?
             .filter_map(|r| log_filter_error(r, "reading hotspot"))
+            .filter(|h: &Hotspot| h.centrality > 0.0)
             .collect();
 
-        Ok(hotspots)
-    }
-
-    /// Find code clusters (tightly coupled groups)
-    fn find_clusters(&self) -> Result<Vec<CodeCluster>> {
-        let conn = self.db.connection()?;
-
-        // Simple clustering by top-level directory
-        let mut stmt = conn.prepare(
-            "WITH dir_edges AS (
-                SELECT
-                    CASE WHEN instr(n1.path, '/') > 0
-                         THEN substr(n1.path, 1, instr(substr(n1.path, 3), '/') + 1)
-                         ELSE '.' END as src_dir,
-                    CASE WHEN instr(n2.path, '/') > 0
-                         THEN substr(n2.path, 1, instr(substr(n2.path, 3), '/') + 1)
-                         ELSE '.' END as tgt_dir
-                FROM edges e
-                JOIN nodes n1 ON e.source_id = n1.id
-                JOIN nodes n2 ON e.target_id = n2.id
-            )
-            SELECT
-                src_dir,
-                COUNT(*) as total_edges,
-                SUM(CASE WHEN src_dir = tgt_dir THEN 1 ELSE 0 END) as internal_edges,
-                SUM(CASE WHEN src_dir != tgt_dir THEN 1 ELSE 0 END) as external_edges
-            FROM dir_edges
-            WHERE src_dir != ''
-            GROUP BY src_dir
-            HAVING total_edges > 10
-            ORDER BY internal_edges DESC
-            LIMIT 30",
-        )?;
-
-        let clusters: Vec<CodeCluster> = stmt
-            .query_map([], |row| {
-                let root: String = row.get(0)?;
-                let internal: i64 = row.get(2)?;
-                let external: i64 = row.get(3)?;
-                let total = internal + external;
-                let cohesion = if total > 0 {
-                    internal as f64 / total as f64
-                } else {
-                    0.0
-                };
-
-                Ok(CodeCluster {
-                    id: format!("cluster:{}", root),
-                    root_directory: root,
-                    node_count: 0, // Would need separate query
-                    internal_edges: internal,
-                    external_edges: external,
-                    cohesion,
-                })
-            })?
-            .filter_map(|r| log_filter_error(r, "reading code cluster"))
-            .collect();
+        hotspots.sort_by(|a, b| {
+            b.centrality
+                .total_cmp(&a.centrality)
+                .then_with(|| b.reference_count.cmp(&a.reference_count))
+        });
+        hotspots.truncate(MAX_HOTSPOTS);
+        if let Some(max) = hotspots.first().map(|h| h.centrality) {
+            for hotspot in &mut hotspots {
+                hotspot.centrality /= max;
+            }
+        }
 
-        Ok(clusters)
+        Ok(hotspots)
     }
 
     /// Find build/config markers
@@ -380,10 +344,94 @@ impl<'a> StructureAnalyzer<'a> {
             }
         }
 
+        // Remaining files the config parser recognized (Dockerfiles, workflows, ...)
+        let mut stmt = conn.prepare("SELECT path FROM nodes WHERE node_type = 'config'")?;
+        let config_paths: Vec<String> = stmt
+            .query_map([], |row| row.get(0))?
+            .filter_map(|r| log_filter_error(r, "reading config path"))
+            .collect();
+        for path in config_paths {
+            if !markers.iter().any(|m| m.path == path) {
+                markers.push(BuildMarker {
+                    path,
+                    marker_type: BuildMarkerType::Config,
+                });
+            }
+        }
+
         Ok(markers)
     }
 }
 
+/// Clusters from the communities of the file graph, largest first.
+fn find_clusters(files: &DependencyGraph) -> Vec<CodeCluster> {
+    let communities = files.communities();
+    let community_of: HashMap<&str, usize> = communities
+        .iter()
+        .enumerate()
+        .flat_map(|(i, c)| c.iter().map(move |f| (f.as_str(), i)))
+        .collect();
+
+    // (internal, external) edge counts per community
+    let mut counts = vec![(0i64, 0i64); communities.len()];
+    for (from, to) in files.edges() {
+        let (Some(&a), b) = (community_of.get(from), community_of.get(to).copied()) else {
+            continue;
+        };
+        if b == Some(a) {
+            counts[a].0 += 1;
+        } else {
+            counts[a].1 += 1;
+            if let Some(b) = b {
+                counts[b].1 += 1;
+            }
+        }
+    }
+
+    communities
+        .into_iter()
+        .zip(counts)
+        .take(MAX_CLUSTERS)
+        .enumerate()
+        .map(|(i, (files, (internal, external)))| {
+            let root = common_directory(&files);
+            let total = internal + external;
+            CodeCluster {
+                id: format!("cluster:{}", i + 1),
+                root_directory: root,
+                node_count: files.len() as i64,
+                files,
+                internal_edges: internal,
+                external_edges: external,
+                cohesion: if total > 0 {
+                    internal as f64 / total as f64
+                } else {
+                    0.0
+                },
+            }
+        })
+        .collect()
+}
+
+/// Longest directory shared by every path (`""` when they share none).
+fn common_directory(paths: &[String]) -> String {
+    let mut common: Option<Vec<&str>> = None;
+    for path in paths {
+        let dirs: Vec<&str> = path.split('/').collect();
+        let dirs = &dirs[..dirs.len().saturating_sub(1)];
+        common = Some(match common {
+            None => dirs.to_vec(),
+            Some(prefix) => prefix
+                .iter()
+                .zip(dirs)
+                .take_while(|(a, b)| a == b)
+                .map(|(a, _)| *a)
+                .collect(),
+        });
+    }
+    common.unwrap_or_default().join("/")
+}
+
 /// Code sample extractor for AI context
 pub struct CodeSampleExtractor;
 
@@ -423,6 +471,118 @@ mod tests {
         assert!(matches!(reason, EntryPointReason::NoInternalCallers));
     }
 
+    #[test]
+    fn test_entry_points_use_call_edges() {
+        use crate::analyzer::parser::{Parser, RustParser};
+        use crate::storage::GraphStore;
+
+        let db = Database::open_in_memory().expect("Failed to open database");
+        db.initialize().expect("Failed to initialize");
+        let store = GraphStore::new(&db);
+
+        let code = "fn main() { run(); }\nfn run() { step(); step(); }\nfn step() {}\n";
+        let parsed = RustParser::new()
+            .unwrap()
+            .parse("src/main.rs", code)
+            .unwrap();
+        for node in &parsed.nodes {
+            store.insert_node(node).unwrap();
+        }
+        for edge in &parsed.edges {
+            store.insert_edge(edge).unwrap();
+        }
+
+        let entries = StructureAnalyzer::new(&db).find_entry_points().unwrap();
+        let names: Vec<&str> = entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["main"]);
+        assert_eq!(entries[0].internal_callers, 0);
+        assert_eq!(entries[0].outgoing_calls, 1);
+    }
+
+    #[test]
+    fn test_graph_backed_structure() {
+        use crate::analyzer::parser::{Parser, TypeScriptParser};
+        use crate::analyzer::resolver::resolve_imports;
+        use crate::analyzer::workspace::Workspace;
+
+        let db = Database::open_in_memory().expect("Failed to open database");
+        db.initialize().expect("Failed to initialize");
+        let store = GraphStore::new(&db);
+
+        let ts = TypeScriptParser::new().unwrap();
+        let files = [
+            (
+                "./src/a.ts",
+                "import { b } from './b';\nexport function a() { b(); }\n",
+            ),
+            (
+                "./src/b.ts",
+                "import { a } from './a';\nexport function b() { a(); }\n",
+            ),
+            ("./lib/log.ts", "export function log() {}\n"),
+            (
+                "./lib/fmt.ts",
+                "import { log } from './log';\nexport function fmt() { log(); }\n",
+            ),
+        ];
+        let mut results: Vec<_> = files
+            .iter()
+            .map(|(path, code)| ts.parse(path, code).unwrap())
+            .collect();
+        resolve_imports(&mut results, &Workspace::default());
+        for result in &results {
+            for node in &result.nodes {
+                store.insert_node(node).unwrap();
+            }
+            for edge in &result.edges {
+                store.insert_edge(edge).unwrap();
+            }
+        }
+
+        let structure = StructureAnalyzer::new(&db).analyze().unwrap();
+        assert_eq!(structure.cycles, vec![vec!["src/a.ts", "src/b.ts"]]);
+
+        let roots: Vec<(&str, i64)> = structure
+            .clusters
+            .iter()
+            .map(|c| (c.root_directory.as_str(), c.node_count))
+            .collect();
+        assert_eq!(roots, vec![("lib", 2), ("src", 2)]);
+        assert_eq!(structure.clusters[0].cohesion, 1.0);
+        assert_eq!(common_directory(&["a/b/c.rs".into(), "a/d.rs".into()]), "a");
+    }
+
+    #[test]
+    fn test_directories_skip_test_files() {
+        use crate::analyzer::parser::{Parser, TypeScriptParser};
+
+        let db = Database::open_in_memory().expect("Failed to open database");
+        db.initialize().expect("Failed to initialize");
+        let store = GraphStore::new(&db);
+
+        let ts = TypeScriptParser::new().unwrap();
+        for path in [
+            "./src/a.ts",
+            "./src/b.ts",
+            "./src/c.ts",
+            "./src/a.test.ts",
+            "./e2e/login.spec.ts",
+            "./e2e/signup.spec.ts",
+            "./e2e/home.spec.ts",
+        ] {
+            for node in ts.parse(path, "export {};\n").unwrap().nodes {
+                store.insert_node(&node).unwrap();
+            }
+        }
+
+        let directories = StructureAnalyzer::new(&db).analyze_directories().unwrap();
+        let found: Vec<(&str, i64)> = directories
+            .iter()
+            .map(|d| (d.path.as_str(), d.file_count))
+            .collect();
+        assert_eq!(found, vec![("./src/", 3)]);
+    }
+
     #[test]
     fn test_code_sample_extractor() {
         let meta = r#"{"signature": "fn main() -> Result<()>"}"#;
@@ -0,0 +1,718 @@
+//! Workspace Discovery
+//!
+//! Reads package manifests so that imports between packages of the same
+//! repository can be resolved to files:
+//!
+//! - Cargo: `[workspace] members` plus the root `[package]`
+//! - npm: `workspaces` in `package.json` or `pnpm-workspace.yaml`, plus
+//!   `compilerOptions.paths` / `baseUrl` from the root `tsconfig.json`
+//! - Go: `go.work` `use` directives, or the root `go.mod`
+//!
+//! Paths are joined onto the scan root the same way `FileScanner` does, so
+//! they can be compared directly with parsed file paths.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+use serde::Serialize;
+
+use crate::analyzer::parser::ParseResult;
+use crate::analyzer::resolver::normalize_path;
+use crate::types::{
+    Edge, EdgeMetadata, EdgeType, EvidenceLocation, InformationTier, Node, NodeMetadata,
+    NodeStatus, NodeType,
+};
+
+/// Package ecosystem a manifest belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PackageKind {
+    Cargo,
+    Npm,
+    Go,
+}
+
+impl PackageKind {
+    /// Ecosystem whose imports a source file participates in.
+    pub fn for_path(path: &str) -> Option<Self> {
+        let ext = path.rsplit_once('.').map(|(_, e)| e)?;
+        match ext {
+            "rs" => Some(Self::Cargo),
+            "go" => Some(Self::Go),
+            "ts" | "tsx" | "js" | "jsx" | "mjs" | "cjs" | "mts" | "cts" | "vue" | "svelte" => {
+                Some(Self::Npm)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A package declared by a manifest.
+#[derive(Debug, Clone)]
+pub struct Package {
+    /// Name as written in the manifest
+    pub name: String,
+    /// Name other code imports it by (crate ident, npm name, Go module path)
+    pub import_name: String,
+    pub kind: PackageKind,
+    /// Package directory
+    pub dir: String,
+    /// Manifest file
+    pub manifest: String,
+    /// Library entry file (`src/lib.rs`, `main`/`source` of package.json)
+    pub entry: Option<String>,
+    /// Listed as a member by a workspace manifest
+    pub member: bool,
+}
+
+/// A `compilerOptions.paths` mapping; `*` marks the wildcard.
+#[derive(Debug, Clone)]
+pub struct PathAlias {
+    pub pattern: String,
+    /// Target patterns, already joined onto `baseUrl`
+    pub targets: Vec<String>,
+}
+
+/// Packages and path aliases discovered under a project root.
+#[derive(Debug, Clone, Default)]
+pub struct Workspace {
+    pub packages: Vec<Package>,
+    pub path_aliases: Vec<PathAlias>,
+}
+
+impl Workspace {
+    /// Discover packages from the manifests under `root`.
+    pub fn discover(root: &Path) -> Self {
+        let mut workspace = Self::default();
+        workspace.discover_cargo(root);
+        workspace.discover_npm(root);
+        workspace.discover_go(root);
+        workspace.discover_tsconfig(root);
+        workspace
+    }
+
+    /// True when a workspace manifest lists two or more member packages.
+    pub fn is_monorepo(&self) -> bool {
+        self.packages.iter().filter(|p| p.member).count() >= 2
+    }
+
+    /// Innermost package of the matching ecosystem that contains `path`.
+    pub fn package_for(&self, path: &str) -> Option<&Package> {
+        let kind = PackageKind::for_path(path)?;
+        let path = normalize_path(path);
+        self.packages
+            .iter()
+            .filter(|p| p.kind == kind)
+            .filter(|p| {
+                let dir = normalize_path(&p.dir);
+                dir.is_empty() || path.starts_with(&format!("{}/", dir))
+            })
+            .max_by_key(|p| normalize_path(&p.dir).len())
+    }
+
+    /// Package `Module` nodes and `owns` edges to the files each one contains.
+    ///
+    /// The nodes carry `workspace_package`, since their path is a manifest
+    /// rather than a source file whose exports could be checked.
+    pub fn package_graph<'a>(&self, files: impl IntoIterator<Item = &'a str>) -> ParseResult {
+        let mut result = ParseResult::new();
+
+        for package in &self.packages {
+            let mut metadata = NodeMetadata::default();
+            metadata.extra.insert(
+                "workspace_package".to_string(),
+                serde_json::Value::Bool(true),
+            );
+            metadata.extra.insert(
+                "ecosystem".to_string(),
+                serde_json::to_value(package.kind).unwrap_or_default(),
+            );
+            metadata.extra.insert(
+                "dir".to_string(),
+                serde_json::Value::String(package.dir.clone()),
+            );
+            if let Some(ref entry) = package.entry {
+                metadata.extra.insert(
+                    "entry".to_string(),
+                    serde_json::Value::String(entry.clone()),
+                );
+            }
+
+            result.nodes.push(Node {
+                id: package_id(package),
+                node_type: NodeType::Module,
+                path: package.manifest.clone(),
+                name: package.name.clone(),
+                metadata,
+                evidence: manifest_evidence(&package.manifest),
+                tier: InformationTier::Fact,
+                confidence: 1.0,
+                last_verified: Utc::now(),
+                status: NodeStatus::Verified,
+            });
+        }
+
+        for file in files {
+            let Some(package) = self.package_for(file) else {
+                continue;
+            };
+            result.edges.push(Edge {
+                id: format!("member:{}:{}", package.import_name, file),
+                edge_type: EdgeType::Owns,
+                source_id: package_id(package),
+                target_id: format!("file:{}", file),
+                metadata: EdgeMetadata::default(),
+                evidence: manifest_evidence(&package.manifest),
+                tier: InformationTier::Fact,
+                confidence: 1.0,
+                last_verified: Utc::now(),
+            });
+        }
+
+        result
+    }
+
+    fn discover_cargo(&mut self, root: &Path) {
+        let Some(manifest) = read_toml(&root.join("Cargo.toml")) else {
+            return;
+        };
+
+        if let Some(package) = cargo_package(root, false) {
+            self.packages.push(package);
+        }
+
+        let workspace = manifest.get("workspace");
+        let excluded: Vec<PathBuf> = string_list(workspace.and_then(|w| w.get("exclude")))
+            .iter()
+            .map(|e| root.join(e))
+            .collect();
+
+        for pattern in string_list(workspace.and_then(|w| w.get("members"))) {
+            for dir in expand_dirs(root, &pattern) {
+                if dir == root || excluded.contains(&dir) {
+                    continue;
+                }
+                if let Some(package) = cargo_package(&dir, true) {
+                    self.packages.push(package);
+                }
+            }
+        }
+    }
+
+    fn discover_npm(&mut self, root: &Path) {
+        let manifest = read_json(&root.join("package.json"));
+
+        let mut patterns = Vec::new();
+        if let Some(ref manifest) = manifest {
+            match manifest.get("workspaces") {
+                Some(serde_json::Value::Array(list)) => {
+                    patterns.extend(list.iter().filter_map(|v| v.as_str()).map(String::from));
+                }
+                Some(serde_json::Value::Object(obj)) => {
+                    if let Some(serde_json::Value::Array(list)) = obj.get("packages") {
+                        patterns.extend(list.iter().filter_map(|v| v.as_str()).map(String::from));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if let Ok(text) = fs::read_to_string(root.join("pnpm-workspace.yaml"))
+            && let Ok(yaml) = serde_yaml::from_str::<serde_yaml::Value>(&text)
+            && let Some(list) = yaml.get("packages").and_then(|p| p.as_sequence())
+        {
+            patterns.extend(list.iter().filter_map(|v| v.as_str()).map(String::from));
+        }
+
+        if manifest.is_some()
+            && let Some(package) = npm_package(root, false)
+        {
+            self.packages.push(package);
+        }
+
+        for pattern in patterns {
+            if pattern.starts_with('!') {
+                continue;
+            }
+            for dir in expand_dirs(root, &pattern) {
+                if dir == root {
+                    continue;
+                }
+                if let Some(package) = npm_package(&dir, true) {
+                    self.packages.push(package);
+                }
+            }
+        }
+    }
+
+    fn discover_go(&mut self, root: &Path) {
+        let work_dirs = fs::read_to_string(root.join("go.work"))
+            .map(|text| go_work_dirs(&text))
+            .unwrap_or_default();
+
+        if work_dirs.is_empty() {
+            if let Some(package) = go_module(root, false) {
+                self.packages.push(package);
+            }
+            return;
+        }
+
+        for dir in work_dirs {
+            let dir = if dir == "." {
+                root.to_path_buf()
+            } else {
+                root.join(dir)
+            };
+            if let Some(package) = go_module(&dir, true) {
+                self.packages.push(package);
+            }
+        }
+    }
+
+    fn discover_tsconfig(&mut self, root: &Path) {
+        let Some(config) = fs::read_to_string(root.join("tsconfig.json"))
+            .ok()
+            .and_then(|text| serde_json::from_str::<serde_json::Value>(&strip_jsonc(&text)).ok())
+        else {
+            return;
+        };
+        let Some(options) = config.get("compilerOptions") else {
+            return;
+        };
+
+        let base = options
+            .get("baseUrl")
+            .and_then(|b| b.as_str())
+            .map(|b| root.join(b))
+            .unwrap_or_else(|| root.to_path_buf());
+
+        if let Some(paths) = options.get("paths").and_then(|p| p.as_object()) {
+            for (pattern, targets) in paths {
+                let targets = string_list_json(targets)
+                    .iter()
+                    .map(|t| path_string(&base.join(t)))
+                    .collect();
+                self.path_aliases.push(PathAlias {
+                    pattern: pattern.clone(),
+                    targets,
+                });
+            }
+        }
+
+        // `baseUrl` alone makes every bare specifier resolvable from it
+        if options.get("baseUrl").is_some() {
+            self.path_aliases.push(PathAlias {
+                pattern: "*".to_string(),
+                targets: vec![path_string(&base.join("*"))],
+            });
+        }
+    }
+}
+
+fn package_id(package: &Package) -> String {
+    format!("package:{}", package.import_name)
+}
+
+fn manifest_evidence(manifest: &str) -> EvidenceLocation {
+    EvidenceLocation {
+        file: manifest.to_string(),
+        start_line: 1,
+        end_line: 1,
+        start_column: None,
+        end_column: None,
+    }
+}
+
+fn cargo_package(dir: &Path, member: bool) -> Option<Package> {
+    let manifest_path = dir.join("Cargo.toml");
+    let manifest = read_toml(&manifest_path)?;
+    let name = manifest.get("package")?.get("name")?.as_str()?.to_string();
+
+    let lib = manifest.get("lib");
+    let import_name = lib
+        .and_then(|l| l.get("name"))
+        .and_then(|n| n.as_str())
+        .map(String::from)
+        .unwrap_or_else(|| name.replace('-', "_"));
+    let entry = dir.join(
+        lib.and_then(|l| l.get("path"))
+            .and_then(|p| p.as_str())
+            .unwrap_or("src/lib.rs"),
+    );
+
+    Some(Package {
+        name,
+        import_name,
+        kind: PackageKind::Cargo,
+        dir: path_string(dir),
+        manifest: path_string(&manifest_path),
+        entry: entry.is_file().then(|| path_string(&entry)),
+        member,
+    })
+}
+
+fn npm_package(dir: &Path, member: bool) -> Option<Package> {
+    let manifest_path = dir.join("package.json");
+    let manifest = read_json(&manifest_path)?;
+    let name = manifest.get("name")?.as_str()?.to_string();
+
+    // Prefer source entries; `main` often points at build output
+    let declared = ["source", "module", "main", "types"]
+        .iter()
+        .filter_map(|field| manifest.get(*field).and_then(|v| v.as_str()))
+        .map(|rel| dir.join(rel));
+    let conventional = ["src/index", "index"].iter().flat_map(|stem| {
+        ["ts", "tsx", "js", "jsx"]
+            .iter()
+            .map(move |ext| dir.join(format!("{}.{}", stem, ext)))
+    });
+    let entry = declared
+        .chain(conventional)
+        .find(|p| p.is_file() && !p.to_string_lossy().ends_with(".d.ts"));
+
+    Some(Package {
+        import_name: name.clone(),
+        name,
+        kind: PackageKind::Npm,
+        dir: path_string(dir),
+        manifest: path_string(&manifest_path),
+        entry: entry.map(|p| path_string(&p)),
+        member,
+    })
+}
+
+fn go_module(dir: &Path, member: bool) -> Option<Package> {
+    let manifest_path = dir.join("go.mod");
+    let text = fs::read_to_string(&manifest_path).ok()?;
+    let module = text.lines().find_map(|line| {
+        line.trim()
+            .strip_prefix("module")
+            .filter(|rest| rest.starts_with(char::is_whitespace))
+            .map(|rest| rest.trim().trim_matches('"').to_string())
+    })?;
+
+    Some(Package {
+        name: module.clone(),
+        import_name: module,
+        kind: PackageKind::Go,
+        dir: path_string(dir),
+        manifest: path_string(&manifest_path),
+        entry: None,
+        member,
+    })
+}
+
+/// Directories listed by `use` directives in a `go.work` file.
+fn go_work_dirs(text: &str) -> Vec<String> {
+    let mut dirs = Vec::new();
+    let mut in_block = false;
+
+    for line in text.lines() {
+        let line = line.split("//").next().unwrap_or("").trim();
+        if in_block {
+            if line == ")" {
+                in_block = false;
+            } else if !line.is_empty() {
+                dirs.push(line.trim_matches('"').to_string());
+            }
+        } else if let Some(rest) = line.strip_prefix("use") {
+            let rest = rest.trim();
+            if rest == "(" {
+                in_block = true;
+            } else if !rest.is_empty() {
+                dirs.push(rest.trim_matches('"').to_string());
+            }
+        }
+    }
+
+    dirs
+}
+
+/// Expand a workspace member glob into directories relative to `root`.
+fn expand_dirs(root: &Path, pattern: &str) -> Vec<PathBuf> {
+    let pattern = pattern.trim_end_matches('/');
+    if !pattern.contains(['*', '?', '[']) {
+        let dir = root.join(pattern);
+        return if dir.is_dir() { vec![dir] } else { Vec::new() };
+    }
+
+    let full = root.join(pattern);
+    let mut dirs: Vec<PathBuf> = glob::glob(&full.to_string_lossy())
+        .map(|paths| {
+            paths
+                .filter_map(|p| p.ok())
+                .filter(|p| p.is_dir())
+                .collect()
+        })
+        .unwrap_or_default();
+    dirs.sort();
+
+    // Keep paths in the same shape as `root.join(..)` produces
+    dirs.into_iter()
+        .filter_map(|d| d.strip_prefix(root).ok().map(|rel| root.join(rel)))
+        .collect()
+}
+
+fn read_toml(path: &Path) -> Option<toml::Table> {
+    let text = fs::read_to_string(path).ok()?;
+    toml::from_str(&text)
+        .map_err(|e| tracing::debug!("Failed to parse {}: {}", path.display(), e))
+        .ok()
+}
+
+fn read_json(path: &Path) -> Option<serde_json::Value> {
+    let text = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&text)
+        .map_err(|e| tracing::debug!("Failed to parse {}: {}", path.display(), e))
+        .ok()
+}
+
+fn string_list(value: Option<&toml::Value>) -> Vec<String> {
+    value
+        .and_then(|v| v.as_array())
+        .map(|list| {
+            list.iter()
+                .filter_map(|v| v.as_str())
+                .map(String::from)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn string_list_json(value: &serde_json::Value) -> Vec<String> {
+    match value {
+        serde_json::Value::String(s) => vec![s.clone()],
+        serde_json::Value::Array(list) => list
+            .iter()
+            .filter_map(|v| v.as_str())
+            .map(String::from)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn path_string(path: &Path) -> String {
+    path.to_string_lossy().to_string()
+}
+
+/// Strip `//` and `/* */` comments and trailing commas from JSONC
+/// (the dialect of `tsconfig.json`), in one pass over the text.
+pub(crate) fn strip_jsonc(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    let mut in_string = false;
+    // A comma is held back until the next token shows whether it trails
+    let mut pending_comma = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            out.push(c);
+            if c == '\\' {
+                if let Some(escaped) = chars.next() {
+                    out.push(escaped);
+                }
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        match (c, chars.peek()) {
+            ('/', Some('/')) => {
+                for next in chars.by_ref() {
+                    if next == '\n' {
+                        out.push('\n');
+                        break;
+                    }
+                }
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                let mut prev = ' ';
+                for next in chars.by_ref() {
+                    if prev == '*' && next == '/' {
+                        break;
+                    }
+                    prev = next;
+                }
+            }
+            _ if c.is_whitespace() => out.push(c),
+            _ => {
+                if std::mem::take(&mut pending_comma) && !matches!(c, '}' | ']') {
+                    out.push(',');
+                }
+                match c {
+                    ',' => pending_comma = true,
+                    '"' => {
+                        in_string = true;
+                        out.push(c);
+                    }
+                    _ => out.push(c),
+                }
+            }
+        }
+    }
+    if pending_comma {
+        out.push(',');
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write(root: &Path, rel: &str, content: &str) {
+        let path = root.join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_cargo_workspace_members() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+        write(
+            root,
+            "Cargo.toml",
+            "[workspace]\nmembers = [\"crates/*\"]\n",
+        );
+        write(
+            root,
+            "crates/core-lib/Cargo.toml",
+            "[package]\nname = \"core-lib\"\n",
+        );
+        write(root, "crates/core-lib/src/lib.rs", "");
+        write(
+            root,
+            "crates/cli/Cargo.toml",
+            "[package]\nname = \"cli\"\n[lib]\npath = \"lib.rs\"\n",
+        );
+
+        let workspace = Workspace::discover(root);
+        assert!(workspace.is_monorepo());
+
+        let core = workspace
+            .packages
+            .iter()
+            .find(|p| p.name == "core-lib")
+            .unwrap();
+        assert_eq!(core.import_name, "core_lib");
+        assert!(core.entry.as_ref().unwrap().ends_with("src/lib.rs"));
+
+        let cli = workspace.packages.iter().find(|p| p.name == "cli").unwrap();
+        assert_eq!(cli.entry, None, "missing [lib] path is not an entry");
+    }
+
+    #[test]
+    fn test_npm_workspaces_and_tsconfig_paths() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+        write(
+            root,
+            "package.json",
+            r#"{"name": "repo", "private": true, "workspaces": ["packages/*"]}"#,
+        );
+        write(root, "packages/ui/package.json", r#"{"name": "@org/ui"}"#);
+        write(root, "packages/ui/src/index.tsx", "");
+        write(root, "packages/api/package.json", r#"{"name": "@org/api"}"#);
+        write(
+            root,
+            "tsconfig.json",
+            "{\n  // comment\n  \"compilerOptions\": {\"paths\": {\"@app/*\": [\"src/app/*\"],},},\n}",
+        );
+
+        let workspace = Workspace::discover(root);
+        assert!(workspace.is_monorepo());
+
+        let ui = workspace
+            .packages
+            .iter()
+            .find(|p| p.name == "@org/ui")
+            .unwrap();
+        assert!(ui.entry.as_ref().unwrap().ends_with("src/index.tsx"));
+
+        assert_eq!(workspace.path_aliases.len(), 1);
+        assert_eq!(workspace.path_aliases[0].pattern, "@app/*");
+        assert!(workspace.path_aliases[0].targets[0].ends_with("src/app/*"));
+    }
+
+    #[test]
+    fn test_go_work_modules() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+        write(
+            root,
+            "go.work",
+            "go 1.22\n\nuse (\n\t./svc\n\t./lib // shared\n)\n",
+        );
+        write(root, "svc/go.mod", "module example.com/svc\n\ngo 1.22\n");
+        write(root, "lib/go.mod", "module example.com/lib\n");
+
+        let workspace = Workspace::discover(root);
+        let names: Vec<&str> = workspace
+            .packages
+            .iter()
+            .map(|p| p.import_name.as_str())
+            .collect();
+        assert_eq!(names, vec!["example.com/svc", "example.com/lib"]);
+    }
+
+    #[test]
+    fn test_package_for_picks_innermost_matching_ecosystem() {
+        let workspace = Workspace {
+            packages: vec![
+                Package {
+                    name: "root".to_string(),
+                    import_name: "root".to_string(),
+                    kind: PackageKind::Cargo,
+                    dir: ".".to_string(),
+                    manifest: "./Cargo.toml".to_string(),
+                    entry: None,
+                    member: false,
+                },
+                Package {
+                    name: "inner".to_string(),
+                    import_name: "inner".to_string(),
+                    kind: PackageKind::Cargo,
+                    dir: "./crates/inner".to_string(),
+                    manifest: "./crates/inner/Cargo.toml".to_string(),
+                    entry: None,
+                    member: true,
+                },
+            ],
+            path_aliases: Vec::new(),
+        };
+
+        assert_eq!(
+            workspace
+                .package_for("./crates/inner/src/lib.rs")
+                .unwrap()
+                .name,
+            "inner"
+        );
+        assert_eq!(workspace.package_for("./src/main.rs").unwrap().name, "root");
+        assert!(workspace.package_for("./web/index.ts").is_none());
+
+        let graph = workspace.package_graph(["./crates/inner/src/lib.rs"]);
+        assert!(graph.nodes.iter().all(|n| n.node_type == NodeType::Module
+            && n.metadata.extra.get("workspace_package") == Some(&serde_json::json!(true))));
+        assert_eq!(graph.edges.len(), 1);
+        assert_eq!(graph.edges[0].source_id, "package:inner");
+    }
+
+    #[test]
+    fn test_strip_jsonc() {
+        let text = "{\"a\": \"//not a comment\", /* block */ \"b\": [1, 2,],}";
+        let value: serde_json::Value = serde_json::from_str(&strip_jsonc(text)).unwrap();
+        assert_eq!(value["a"], "//not a comment");
+        assert_eq!(value["b"], serde_json::json!([1, 2]));
+
+        let text = "{\"a\": [1, // one\n 2 /* two */ ,\n ], \"b\": \"x,}\", }";
+        let value: serde_json::Value = serde_json::from_str(&strip_jsonc(text)).unwrap();
+        assert_eq!(value, serde_json::json!({"a": [1, 2], "b": "x,}"}));
+    }
+}
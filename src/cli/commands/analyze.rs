@@ -2,19 +2,25 @@
 //!
 //! Builds knowledge graph from source code.
 //! Extracts structural information without language-specific pattern matching.
+//! Only files whose content changed since the previous build are re-parsed;
+//! Code that moved or was renamed keeps its history through symbol
+//! fingerprints. `--full` starts from an empty graph.
 
-use std::fs;
-use std::path::{Path, PathBuf};
+use std::collections::HashMap;
+use std::path::PathBuf;
 
-use crate::analyzer::StructureAnalyzer;
-use crate::analyzer::parser::{
-    BashParser, CLangParser, CppLangParser, GoParser, JavaParser, KotlinParser, Language,
-    ParseResult, Parser, PythonParser, RubyParser, RustParser, TypeScriptParser,
+use crate::analyzer::identity::carry_forward;
+use crate::analyzer::incremental::{FileChanges, hash_files, parse_files};
+use crate::analyzer::parser::{Language, ParseResult, config_kind, is_parsed};
+use crate::analyzer::resolver::{
+    ExternalPackages, resolve_calls, resolve_imports, resolve_route_handlers,
+    resolve_test_subjects, resolve_type_references,
 };
 use crate::analyzer::scanner::FileScanner;
-use crate::config::{Config, ConfigLoader};
+use crate::analyzer::{StructureAnalyzer, Workspace};
+use crate::config::{Config, ConfigLoader, ProjectType};
 use crate::storage::{Database, GraphStore};
-use crate::types::{Result, WeaveError};
+use crate::types::{Edge, Node, Result, WeaveError};
 
 pub fn run(full: bool, path: Option<PathBuf>, skip_docs: bool) -> Result<()> {
     let root = path.unwrap_or_else(|| PathBuf::from("."));
@@ -26,6 +32,8 @@ pub fn run(full: bool, path: Option<PathBuf>, skip_docs: bool) -> Result<()> {
 
     let config = load_config()?;
     let db = Database::open(weavewiki_dir.join("graph/graph.db"))?;
+    // Graphs built by older versions gain new tables and the search index
+    db.initialize()?;
     let graph_store = GraphStore::new(&db);
 
     println!("Starting analysis...");
@@ -37,56 +45,58 @@ pub fn run(full: bool, path: Option<PathBuf>, skip_docs: bool) -> Result<()> {
     }
 
     // Step 1: Scan files
-    let scanner = FileScanner::new(&root)
-        .with_exclude(config.analysis.exclude.clone())
-        .with_max_file_size(config.analysis.max_file_size as u64);
+    let scanner = FileScanner::for_project(&root, &config.analysis);
     let files = scanner.scan()?;
     println!("Found {} files to analyze", files.len());
 
-    // Step 2: Parse files and build graph
-    let mut total_nodes = 0;
-    let mut total_edges = 0;
-    let mut processed = 0;
-    let mut language_counts: std::collections::HashMap<&str, u32> =
-        std::collections::HashMap::new();
-
-    for file in &files {
-        let lang = Language::from_path(&file.path);
-
-        if let Some(result) = parse_file(&file.path, lang)? {
-            for node in &result.nodes {
-                graph_store.insert_node(node)?;
-            }
-            for edge in &result.edges {
-                graph_store.insert_edge(edge)?;
+    // Package manifests decide how cross-package imports resolve
+    let workspace = Workspace::discover(&root);
+    let project_type = config.project.project_type.resolve(workspace.is_monorepo());
+    if project_type == ProjectType::Monorepo {
+        if workspace.packages.is_empty() {
+            println!("  Warning: project type is monorepo but no workspace packages were found");
+        } else {
+            println!("  Monorepo with {} packages:", workspace.packages.len());
+            for package in &workspace.packages {
+                println!("    {} ({})", package.name, package.dir);
             }
-            total_nodes += result.nodes.len();
-            total_edges += result.edges.len();
-
-            let lang_name = match lang {
-                Language::TypeScript | Language::JavaScript => "TypeScript/JavaScript",
-                Language::Python => "Python",
-                Language::Rust => "Rust",
-                Language::Go => "Go",
-                Language::Java => "Java",
-                Language::Kotlin => "Kotlin",
-                Language::Ruby => "Ruby",
-                Language::C | Language::Cpp => "C/C++",
-                Language::Bash => "Bash",
-                _ => "Other",
-            };
-            *language_counts.entry(lang_name).or_insert(0) += 1;
         }
+    }
 
-        processed += 1;
-        if processed % 100 == 0 {
-            println!("  Processed {} files...", processed);
-        }
+    // Step 2: Compare content hashes with the previous build
+    let all_paths: Vec<String> = files
+        .iter()
+        .map(|f| f.path.to_string_lossy().to_string())
+        .collect();
+    let paths: Vec<String> = all_paths.iter().filter(|p| is_parsed(p)).cloned().collect();
+    let current = hash_files(&paths);
+    let changes = FileChanges::diff(&graph_store.get_file_hashes()?, &current);
+    println!(
+        "  {} added, {} changed, {} removed, {} unchanged",
+        changes.added.len(),
+        changes.changed.len(),
+        changes.removed.len(),
+        changes.unchanged.len()
+    );
+
+    // Step 3: Re-parse what changed and replace it in the graph
+    if changes.is_empty() {
+        println!("Graph is up to date");
+    } else {
+        update_graph(&db, &changes, &current, &workspace, &all_paths)?;
     }
 
-    println!("Parsed {} nodes and {} edges", total_nodes, total_edges);
+    let mut language_counts: HashMap<&str, u32> = HashMap::new();
+    for path in current.keys() {
+        *language_counts
+            .entry(match config_kind(path) {
+                Some(_) => "Configuration",
+                None => language_label(Language::from_path(path)),
+            })
+            .or_insert(0) += 1;
+    }
 
-    // Step 3: Structure analysis (universal, no pattern matching)
+    // Step 4: Structure analysis (universal, no pattern matching)
     println!("Analyzing code structure...");
     let analyzer = StructureAnalyzer::new(&db);
     let structure = analyzer.analyze()?;
@@ -97,6 +107,13 @@ pub fn run(full: bool, path: Option<PathBuf>, skip_docs: bool) -> Result<()> {
         structure.entry_points.len(),
         structure.hotspots.len()
     );
+    if !structure.cycles.is_empty() {
+        println!(
+            "  Found {} dependency cycles ({} files)",
+            structure.cycles.len(),
+            structure.cycles.iter().map(Vec::len).sum::<usize>()
+        );
+    }
 
     // Print language summary
     if !language_counts.is_empty() {
@@ -121,29 +138,162 @@ fn load_config() -> Result<Config> {
     ConfigLoader::load()
 }
 
-fn parse_file(path: &Path, lang: Language) -> Result<Option<ParseResult>> {
-    let content = match fs::read_to_string(path) {
-        Ok(c) => c,
-        Err(_) => return Ok(None),
-    };
-
-    let path_str = path.to_string_lossy();
+/// Parse the changed files in parallel, resolve them against the rest of
+/// the graph and swap them in with one transaction.
+fn update_graph(
+    db: &Database,
+    changes: &FileChanges,
+    current: &HashMap<String, String>,
+    workspace: &Workspace,
+    all_paths: &[String],
+) -> Result<()> {
+    let graph_store = GraphStore::new(db);
+    let to_parse: Vec<String> = changes.files_to_parse(&graph_store)?.into_iter().collect();
+    println!("Parsing {} files...", to_parse.len());
+    let mut results = parse_files(&to_parse)?;
+    let parsed = results.len();
 
-    let result = match lang {
-        Language::TypeScript | Language::JavaScript => {
-            TypeScriptParser::new()?.parse(&path_str, &content)?
+    // Unchanged files take part in resolution through their stored nodes
+    for path in &changes.unchanged {
+        if to_parse.binary_search(path).is_err() {
+            let nodes = graph_store.get_nodes_by_path(path)?;
+            results.push(ParseResult {
+                nodes,
+                ..ParseResult::new()
+            });
         }
-        Language::Python => PythonParser::new()?.parse(&path_str, &content)?,
-        Language::Rust => RustParser::new()?.parse(&path_str, &content)?,
-        Language::Go => GoParser::new()?.parse(&path_str, &content)?,
-        Language::Java => JavaParser::new()?.parse(&path_str, &content)?,
-        Language::Kotlin => KotlinParser::new()?.parse(&path_str, &content)?,
-        Language::Ruby => RubyParser::new()?.parse(&path_str, &content)?,
-        Language::C => CLangParser::new()?.parse(&path_str, &content)?,
-        Language::Cpp => CppLangParser::new()?.parse(&path_str, &content)?,
-        Language::Bash => BashParser::new()?.parse(&path_str, &content)?,
-        _ => return Ok(None),
-    };
-
-    Ok(Some(result))
+    }
+    let stored = parsed..results.len();
+
+    // Imports can only be resolved once every file has been parsed
+    resolve_imports(&mut results, workspace);
+    resolve_calls(&mut results);
+    resolve_route_handlers(&mut results);
+    resolve_test_subjects(&mut results);
+    resolve_type_references(&mut results);
+
+    if !workspace.packages.is_empty() {
+        results.push(workspace.package_graph(all_paths.iter().map(String::as_str)));
+    }
+    let packages = ExternalPackages::collect(&results, workspace).graph(&results);
+    results.push(packages);
+
+    // Recognize moved and renamed code before its previous nodes are dropped
+    let stale: Vec<String> = to_parse.iter().chain(&changes.removed).cloned().collect();
+    let mut previous = Vec::new();
+    for path in &stale {
+        previous.extend(graph_store.get_nodes_by_path(path)?);
+    }
+    let moves = carry_forward(&previous, &mut results[..parsed]);
+    if !moves.files.is_empty() || !moves.symbols.is_empty() {
+        println!(
+            "  {} files moved, {} symbols renamed or moved",
+            moves.files.len(),
+            moves.symbols.len()
+        );
+    }
+
+    // Stored nodes are already in the graph; edges resolution added to them are not
+    let nodes: Vec<&Node> = results
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !stored.contains(i))
+        .flat_map(|(_, r)| &r.nodes)
+        .chain(&moves.carried)
+        .collect();
+    let edges: Vec<&Edge> = results.iter().flat_map(|r| &r.edges).collect();
+
+    let hashes: Vec<(String, String)> = to_parse
+        .iter()
+        .filter_map(|p| Some((p.clone(), current.get(p)?.clone())))
+        .collect();
+    graph_store.replace_files(
+        &stale,
+        &moves.symbols,
+        &moves.files,
+        &hashes,
+        &nodes,
+        &edges,
+    )?;
+
+    println!("Stored {} nodes and {} edges", nodes.len(), edges.len());
+    Ok(())
+}
+
+fn language_label(lang: Language) -> &'static str {
+    match lang {
+        Language::TypeScript | Language::JavaScript => "TypeScript/JavaScript",
+        Language::Python => "Python",
+        Language::Rust => "Rust",
+        Language::Go => "Go",
+        Language::Java => "Java",
+        Language::Kotlin => "Kotlin",
+        Language::Ruby => "Ruby",
+        Language::C | Language::Cpp => "C/C++",
+        Language::Bash => "Bash",
+        Language::Swift => "Swift",
+        Language::Php => "PHP",
+        Language::CSharp => "C#",
+        Language::Scala => "Scala",
+        Language::Lua => "Lua",
+        Language::Vue => "Vue",
+        Language::Svelte => "Svelte",
+        Language::Sql => "SQL",
+        Language::Prisma => "Prisma",
+        _ => "Other",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::incremental::content_hash;
+
+    /// Build the graph for the files under `dir` as `weavewiki build` would.
+    fn build(db: &Database, dir: &std::path::Path, paths: &[String]) {
+        let current: HashMap<String, String> = paths
+            .iter()
+            .map(|p| {
+                (
+                    p.clone(),
+                    content_hash(&std::fs::read(dir.join(p)).unwrap()),
+                )
+            })
+            .collect();
+        let changes = FileChanges::diff(&GraphStore::new(db).get_file_hashes().unwrap(), &current);
+        update_graph(db, &changes, &current, &Workspace::default(), paths).unwrap();
+    }
+
+    #[test]
+    fn test_incremental_build_keeps_second_degree_edges() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let write = |name: &str, content: &str| {
+            std::fs::write(dir.path().join(name), content).unwrap();
+            dir.path().join(name).to_string_lossy().to_string()
+        };
+        let paths = vec![
+            write("a.ts", "export function a() {}\n"),
+            write(
+                "b.ts",
+                "import { a } from './a';\nexport function b() { a(); }\n",
+            ),
+            write("c.ts", "import { b } from './b';\nb();\n"),
+        ];
+        let db = Database::open_in_memory().unwrap();
+        db.initialize().unwrap();
+        let store = GraphStore::new(&db);
+        let imports = |file: &str| -> Vec<String> {
+            store.get_dependencies(&format!("file:{}", file)).unwrap()
+        };
+
+        build(&db, dir.path(), &paths);
+        assert_eq!(imports(&paths[1]), vec![format!("file:{}", paths[0])]);
+        assert_eq!(imports(&paths[2]), vec![format!("file:{}", paths[1])]);
+
+        // Editing a.ts re-parses b.ts but not c.ts, whose import must survive
+        write("a.ts", "export function a() { return 1; }\n");
+        build(&db, dir.path(), &paths);
+        assert_eq!(imports(&paths[1]), vec![format!("file:{}", paths[0])]);
+        assert_eq!(imports(&paths[2]), vec![format!("file:{}", paths[1])]);
+    }
 }
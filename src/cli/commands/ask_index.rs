@@ -0,0 +1,33 @@
+//! Ask-Index Command
+//!
+//! Builds the offline documentation search index over file insights,
+//! domain summaries and generated wiki pages, searched with
+//! `weavewiki search --docs`.
+
+use std::path::PathBuf;
+
+use crate::cli::util::{WIKI_PATH, require_graph_db_path, weavewiki_dir};
+use crate::storage::Database;
+use crate::types::Result;
+
+pub fn run(wiki: Option<PathBuf>, embeddings: bool) -> Result<()> {
+    let db_path = require_graph_db_path()?;
+    let db = Database::open(&db_path)?;
+    // Creates the index tables in databases built before they existed
+    db.initialize()?;
+
+    let wiki_dir = wiki.unwrap_or_else(|| weavewiki_dir().join(WIKI_PATH));
+    let stats = db.rebuild_doc_index(&wiki_dir, embeddings)?;
+
+    println!(
+        "Indexed {} sections from {} file insights, {} domain summaries and {} wiki pages",
+        stats.sections, stats.insights, stats.domains, stats.pages
+    );
+    if stats.embedded {
+        println!("  Computed local embeddings for every section");
+    }
+    if stats.sections == 0 {
+        println!("\nNothing to index yet. Generate documentation first: weavewiki generate");
+    }
+    Ok(())
+}
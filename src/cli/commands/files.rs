@@ -0,0 +1,71 @@
+//! Files Command
+//!
+//! Lists the files `build` and `generate` analyze, or explains why a single
+//! file is selected or skipped.
+
+use std::path::{Path, PathBuf};
+
+use crate::analyzer::parser::{config_kind, is_parsed};
+use crate::analyzer::scanner::FileScanner;
+use crate::config::{AnalysisConfig, ConfigLoader};
+use crate::types::Result;
+
+pub fn run(explain: Option<PathBuf>, path: Option<PathBuf>) -> Result<()> {
+    let root = path.unwrap_or_else(|| PathBuf::from("."));
+    let config = ConfigLoader::load()?;
+
+    match explain {
+        Some(file) => explain_file(&root, &config.analysis, &file),
+        None => list_files(&root, &config.analysis),
+    }
+}
+
+fn list_files(root: &Path, analysis: &AnalysisConfig) -> Result<()> {
+    let scanner = FileScanner::for_project(root, analysis);
+    let mut paths = scanner.paths()?;
+    paths.sort();
+
+    let mut parsed = 0;
+    for path in &paths {
+        if is_parsed(path) {
+            parsed += 1;
+        }
+        println!("{}", path);
+    }
+
+    let documented = scanner.source_only().count();
+    println!(
+        "\n{} files selected ({} parsed by build, {} analyzed by generate)",
+        paths.len(),
+        parsed,
+        documented
+    );
+    Ok(())
+}
+
+fn explain_file(root: &Path, analysis: &AnalysisConfig, file: &Path) -> Result<()> {
+    let selection = FileScanner::for_project(root, analysis).explain(file);
+    println!("{}: {}", file.display(), selection);
+    if !selection.is_included() {
+        return Ok(());
+    }
+
+    let path = file.to_string_lossy();
+    if let Some(kind) = config_kind(&path) {
+        println!("  build:    parsed as {} configuration", kind.as_str());
+    } else if is_parsed(&path) {
+        println!("  build:    parsed into the knowledge graph");
+    } else {
+        println!("  build:    not parsed (no parser for this file type)");
+    }
+
+    let source = FileScanner::for_project(root, analysis)
+        .source_only()
+        .explain(file);
+    if source.is_included() {
+        println!("  generate: analyzed");
+    } else {
+        println!("  generate: {}", source);
+    }
+    Ok(())
+}
@@ -1,8 +1,11 @@
 pub mod analyze;
+pub mod ask_index;
 pub mod clean;
 pub mod config;
+pub mod files;
 pub mod init;
 pub mod query;
+pub mod search;
 pub mod status;
 pub mod validate;
 pub mod wiki;
@@ -1,29 +1,82 @@
 //! Query Command
 //!
-//! Query the knowledge graph for nodes and their relationships.
+//! Query the knowledge graph for nodes and what they transitively depend on
+//! or are depended on by, answer structural questions written in the graph
+//! query language, list the files a change can affect, trace the paths from
+//! one node to another, or rank functions by complexity.
 
+use crate::analyzer::DEPENDENCY_EDGES;
+use crate::analyzer::resolver::normalize_path;
+use crate::cli::commands::search::print_hits;
 use crate::cli::util::require_graph_db_path;
-use crate::storage::GraphStore;
-use crate::types::Result;
+use crate::storage::{Database, Direction, GraphQuery, GraphStore, Hop, QueryRows, Reached};
+use crate::types::{EdgeType, Result, WeaveError, enum_to_str};
 
-pub fn run(query: &str, depth: u32, format: &str) -> Result<()> {
+/// Search hits shown when a queried node does not exist.
+const SUGGESTIONS: usize = 10;
+
+pub fn run(
+    query: Option<&str>,
+    depth: u32,
+    format: &str,
+    complex: Option<usize>,
+    impact: Option<&str>,
+    edge_types: &[EdgeType],
+) -> Result<()> {
     let db_path = require_graph_db_path()?;
-    let db = crate::storage::Database::open(&db_path)?;
+    let db = Database::open(&db_path)?;
+    // Indexes graphs built before the search table existed
+    db.initialize()?;
+    if let Some(limit) = complex {
+        return rank_complexity(&db, query, limit, format);
+    }
     let store = GraphStore::new(&db);
-
-    let node_id = if query.contains(':') {
-        query.to_string()
+    if let Some(file) = impact {
+        let edge_types = if edge_types.is_empty() {
+            DEPENDENCY_EDGES
+        } else {
+            edge_types
+        };
+        return print_impact(&store, file, edge_types, depth, format);
+    }
+    let query = query.ok_or_else(|| {
+        WeaveError::Config(
+            "A node ID or path is required unless --complex or --impact is given".to_string(),
+        )
+    })?;
+    if GraphQuery::is_query(query) {
+        let rows = store.query(&GraphQuery::parse(query)?)?;
+        return print_rows(&rows, format);
+    }
+    let edge_types = if edge_types.is_empty() {
+        &[EdgeType::DependsOn]
     } else {
-        format!("file:{}", query)
+        edge_types
     };
 
-    let node = store.get_node(&node_id)?;
+    let mut node_id = node_id(&store, query)?;
+    let mut node = store.get_node(&node_id)?;
+
+    // Without an exact ID, a single search hit stands in for it
+    if node.is_none() {
+        let hits = store.search(query, SUGGESTIONS)?;
+        if let [hit] = hits.as_slice() {
+            node_id = hit.id.clone();
+            node = store.get_node(&node_id)?;
+        } else if !hits.is_empty() && format != "json" {
+            println!("Node not found: {}. Closest matches:", query);
+            print_hits(&hits);
+            return Ok(());
+        }
+    }
 
     match format {
         "json" => {
             if let Some(n) = node {
-                let deps = store.get_dependencies(&node_id)?;
-                let dependents = store.get_dependents(&node_id)?;
+                let deps = store.traverse(&node_id, Direction::Outgoing, edge_types, depth)?;
+                let dependents =
+                    store.traverse(&node_id, Direction::Incoming, edge_types, depth)?;
+                let type_users = store.get_type_users(&node_id)?;
 
                 let output = serde_json::json!({
                     "node": {
@@ -33,11 +86,11 @@ pub fn run(query: &str, depth: u32, format: &str) -> Result<()> {
                         "path": n.path
                     },
                     "dependencies": deps,
-                    "dependents": dependents
+                    "dependents": dependents,
+                    "type_users": type_users
                 });
 
-                let json = serde_json::to_string_pretty(&output)
-                    .map_err(crate::types::WeaveError::Json)?;
+                let json = serde_json::to_string_pretty(&output).map_err(WeaveError::Json)?;
                 println!("{}", json);
             } else {
                 println!("{{\"error\": \"Node not found\"}}");
@@ -51,26 +104,26 @@ pub fn run(query: &str, depth: u32, format: &str) -> Result<()> {
                 println!("  Path: {}", n.path);
                 println!();
 
-                let deps = store.get_dependencies(&node_id)?;
+                let deps = store.traverse(&node_id, Direction::Outgoing, edge_types, depth)?;
                 if !deps.is_empty() {
                     println!("Dependencies ({}):", deps.len());
-                    for dep in deps.iter().take(depth as usize) {
-                        println!("  → {}", dep);
-                    }
-                    if deps.len() > depth as usize {
-                        println!("  ... and {} more", deps.len() - depth as usize);
-                    }
+                    print_levels(&deps, "→");
                 }
 
-                let dependents = store.get_dependents(&node_id)?;
+                let dependents =
+                    store.traverse(&node_id, Direction::Incoming, edge_types, depth)?;
                 if !dependents.is_empty() {
                     println!();
                     println!("Dependents ({}):", dependents.len());
-                    for dep in dependents.iter().take(depth as usize) {
-                        println!("  ← {}", dep);
-                    }
-                    if dependents.len() > depth as usize {
-                        println!("  ... and {} more", dependents.len() - depth as usize);
+                    print_levels(&dependents, "←");
+                }
+
+                let type_users = store.get_type_users(&node_id)?;
+                if !type_users.is_empty() {
+                    println!();
+                    println!("Used as a type by ({}):", type_users.len());
+                    for user in &type_users {
+                        println!("  ← {}", user);
                     }
                 }
             } else {
@@ -81,3 +134,317 @@ pub fn run(query: &str, depth: u32, format: &str) -> Result<()> {
 
     Ok(())
 }
+
+/// Print query results as an aligned table, JSON objects or CSV.
+fn print_rows(rows: &QueryRows, format: &str) -> Result<()> {
+    let text = |value: &serde_json::Value| match value {
+        serde_json::Value::Null => String::new(),
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    };
+
+    match format {
+        "json" => {
+            let objects: Vec<serde_json::Map<String, serde_json::Value>> = rows
+                .rows
+                .iter()
+                .map(|row| {
+                    rows.columns
+                        .iter()
+                        .cloned()
+                        .zip(row.iter().cloned())
+                        .collect()
+                })
+                .collect();
+            let json = serde_json::to_string_pretty(&objects).map_err(WeaveError::Json)?;
+            println!("{}", json);
+        }
+        "csv" => {
+            let field = |s: String| {
+                if s.contains([',', '"', '\n']) {
+                    format!("\"{}\"", s.replace('"', "\"\""))
+                } else {
+                    s
+                }
+            };
+            println!("{}", rows.columns.join(","));
+            for row in &rows.rows {
+                let cells: Vec<String> = row.iter().map(|v| field(text(v))).collect();
+                println!("{}", cells.join(","));
+            }
+        }
+        _ => {
+            if rows.rows.is_empty() {
+                println!("No matching nodes.");
+                return Ok(());
+            }
+            let cells: Vec<Vec<String>> = rows
+                .rows
+                .iter()
+                .map(|row| row.iter().map(text).collect())
+                .collect();
+            let widths: Vec<usize> = rows
+                .columns
+                .iter()
+                .enumerate()
+                .map(|(i, c)| {
+                    cells
+                        .iter()
+                        .map(|r| r[i].len())
+                        .max()
+                        .unwrap_or(0)
+                        .max(c.len())
+                })
+                .collect();
+            let line = |values: &[String]| -> String {
+                values
+                    .iter()
+                    .zip(&widths)
+                    .map(|(v, w)| format!("{:<w$}", v, w = w))
+                    .collect::<Vec<_>>()
+                    .join("  ")
+                    .trim_end()
+                    .to_string()
+            };
+            println!("{}", line(&rows.columns));
+            for row in &cells {
+                println!("{}", line(row));
+            }
+            println!();
+            println!("{} nodes", rows.rows.len());
+        }
+    }
+    Ok(())
+}
+
+/// Print how `from` comes to depend on `to`: the shortest path, or up to
+/// `all` simple paths when given.
+pub fn run_path(
+    from: &str,
+    to: &str,
+    all: Option<usize>,
+    depth: u32,
+    edge_types: &[EdgeType],
+    format: &str,
+) -> Result<()> {
+    let db_path = require_graph_db_path()?;
+    let db = Database::open(&db_path)?;
+    let store = GraphStore::new(&db);
+
+    // A file endpoint already stands for the symbols it declares, so
+    // ownership is only followed when asked for
+    let edge_types = if edge_types.is_empty() {
+        DEPENDENCY_EDGES
+    } else {
+        edge_types
+    };
+    let from = node_id(&store, from)?;
+    let to = node_id(&store, to)?;
+    for id in [&from, &to] {
+        if store.get_node(id)?.is_none() {
+            return Err(WeaveError::Config(format!("Node not found: {}", id)));
+        }
+    }
+
+    let paths = match all {
+        Some(limit) => store.simple_paths(&from, &to, edge_types, depth, limit)?,
+        None => store
+            .shortest_path(&from, &to, edge_types, depth)?
+            .into_iter()
+            .collect(),
+    };
+
+    match format {
+        "json" => {
+            let output = serde_json::json!({
+                "from": from,
+                "to": to,
+                "paths": paths
+            });
+            let json = serde_json::to_string_pretty(&output).map_err(WeaveError::Json)?;
+            println!("{}", json);
+        }
+        "mermaid" => print!("{}", paths_mermaid(&paths)),
+        _ => {
+            if paths.is_empty() {
+                println!("No path from {} to {} within {} hops", from, to, depth);
+                return Ok(());
+            }
+            for (i, hops) in paths.iter().enumerate() {
+                if i > 0 {
+                    println!();
+                }
+                let unit = if hops.len() == 1 { "hop" } else { "hops" };
+                println!("Path {} ({} {}):", i + 1, hops.len(), unit);
+                println!("  {}", hops[0].from);
+                for hop in hops {
+                    println!(
+                        "    --{}--> {}  ({}:{})",
+                        enum_to_str(&hop.edge_type),
+                        hop.to,
+                        normalize_path(&hop.evidence.file),
+                        hop.evidence.start_line
+                    );
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Mermaid flowchart of every hop in `paths`, with nodes shared between
+/// paths drawn once.
+fn paths_mermaid(paths: &[Vec<Hop>]) -> String {
+    let mut ids: Vec<&str> = Vec::new();
+    let mut edges: Vec<(usize, usize, String)> = Vec::new();
+    for hop in paths.iter().flatten() {
+        let [from, to] = [hop.from.as_str(), hop.to.as_str()].map(|id| {
+            ids.iter().position(|i| *i == id).unwrap_or_else(|| {
+                ids.push(id);
+                ids.len() - 1
+            })
+        });
+        let edge = (from, to, enum_to_str(&hop.edge_type));
+        if !edges.contains(&edge) {
+            edges.push(edge);
+        }
+    }
+
+    let mut output = String::from("```mermaid\nflowchart LR\n");
+    for (n, id) in ids.iter().enumerate() {
+        output.push_str(&format!("    n{}[\"{}\"]\n", n, id.replace('"', "'")));
+    }
+    for (from, to, edge_type) in edges {
+        output.push_str(&format!("    n{} -->|{}| n{}\n", from, edge_type, to));
+    }
+    output.push_str("```\n");
+    output
+}
+
+/// Node ID for a query: an ID as given, or the file node of a path.
+fn node_id(store: &GraphStore, query: &str) -> Result<String> {
+    if query.contains(':') {
+        Ok(query.to_string())
+    } else {
+        Ok(format!("file:{}", stored_path(store, query)?))
+    }
+}
+
+/// The path the graph stores for `path`, which scans record relative to the
+/// project root (`./src/main.rs`) while users usually type `src/main.rs`.
+fn stored_path(store: &GraphStore, path: &str) -> Result<String> {
+    if path.starts_with("./") || path.starts_with('/') {
+        return Ok(path.to_string());
+    }
+    let prefixed = format!("./{}", path);
+    if store.get_node(&format!("file:{}", prefixed))?.is_some() {
+        return Ok(prefixed);
+    }
+    Ok(path.to_string())
+}
+
+/// Print reached nodes under one heading per distance.
+fn print_levels(reached: &[Reached], arrow: &str) {
+    let mut current = 0;
+    for r in reached {
+        if r.depth != current {
+            current = r.depth;
+            let count = reached.iter().filter(|x| x.depth == current).count();
+            println!("  Depth {} ({}):", current, count);
+        }
+        println!("    {} {}", arrow, r.id);
+    }
+}
+
+/// List every file a change to `file` can affect, nearest first.
+fn print_impact(
+    store: &GraphStore,
+    file: &str,
+    edge_types: &[EdgeType],
+    depth: u32,
+    format: &str,
+) -> Result<()> {
+    let path = stored_path(store, file)?;
+    if store.get_nodes_by_path(&path)?.is_empty() {
+        return Err(WeaveError::Config(format!(
+            "File not in the knowledge graph: {}",
+            file
+        )));
+    }
+    let impacted: Vec<Reached> = store
+        .get_impacted_files(&path, edge_types, depth)?
+        .into_iter()
+        .map(|r| Reached {
+            id: normalize_path(&r.id),
+            depth: r.depth,
+        })
+        .collect();
+
+    if format == "json" {
+        let output = serde_json::json!({
+            "file": normalize_path(&path),
+            "impacted": impacted
+        });
+        let json = serde_json::to_string_pretty(&output).map_err(WeaveError::Json)?;
+        println!("{}", json);
+        return Ok(());
+    }
+
+    if impacted.is_empty() {
+        println!("No other file depends on {}", normalize_path(&path));
+        return Ok(());
+    }
+    println!(
+        "Files affected by a change to {} ({}):",
+        normalize_path(&path),
+        impacted.len()
+    );
+    print_levels(&impacted, "←");
+    Ok(())
+}
+
+/// List the `limit` most complex functions, under `path` if given.
+fn rank_complexity(db: &Database, path: Option<&str>, limit: usize, format: &str) -> Result<()> {
+    let functions = db.get_complex_functions(path, limit)?;
+
+    if format == "json" {
+        let output: Vec<_> = functions
+            .iter()
+            .map(|f| {
+                serde_json::json!({
+                    "name": f.name,
+                    "path": f.path,
+                    "line": f.line,
+                    "cyclomatic": f.cyclomatic,
+                    "max_nesting": f.max_nesting,
+                    "parameters": f.parameters,
+                    "lines": f.lines,
+                    "early_returns": f.early_returns
+                })
+            })
+            .collect();
+        let json = serde_json::to_string_pretty(&output).map_err(WeaveError::Json)?;
+        println!("{}", json);
+        return Ok(());
+    }
+
+    if functions.is_empty() {
+        println!("No function metrics found. Run `weavewiki build` first.");
+        return Ok(());
+    }
+    println!("Most complex functions ({}):", functions.len());
+    for f in &functions {
+        println!(
+            "  {:>3}  {}:{} {}  (nesting {}, {} lines, {} params, {} early returns)",
+            f.cyclomatic,
+            f.path,
+            f.line,
+            f.name,
+            f.max_nesting,
+            f.lines,
+            f.parameters,
+            f.early_returns
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,105 @@
+//! Search Command
+//!
+//! Finds knowledge graph nodes by approximate name, path, signature or
+//! description, or, with `--docs`, the documentation sections indexed by
+//! `weavewiki ask-index`.
+
+use crate::analyzer::resolver::normalize_path;
+use crate::cli::util::require_graph_db_path;
+use crate::storage::{Database, DocHit, GraphStore, SearchHit};
+use crate::types::{Result, WeaveError};
+
+pub fn run(term: &str, limit: usize, format: &str) -> Result<()> {
+    let db_path = require_graph_db_path()?;
+    let db = Database::open(&db_path)?;
+    // Indexes graphs built before the search table existed
+    db.initialize()?;
+    let hits = GraphStore::new(&db).search(term, limit)?;
+
+    if format == "json" {
+        let json = serde_json::to_string_pretty(&hits).map_err(WeaveError::Json)?;
+        println!("{}", json);
+        return Ok(());
+    }
+
+    if hits.is_empty() {
+        println!("No nodes match '{}'", term);
+        return Ok(());
+    }
+    print_hits(&hits);
+    Ok(())
+}
+
+/// Source files listed per documentation hit before the rest are counted.
+const MAX_LISTED_SOURCES: usize = 3;
+
+pub fn run_docs(query: &str, limit: usize, format: &str) -> Result<()> {
+    let db_path = require_graph_db_path()?;
+    let db = Database::open(&db_path)?;
+    db.initialize()?;
+    let hits = db.search_docs(query, limit)?;
+
+    if format == "json" {
+        let json = serde_json::to_string_pretty(&hits).map_err(WeaveError::Json)?;
+        println!("{}", json);
+        return Ok(());
+    }
+
+    if hits.is_empty() {
+        println!("No documentation matches '{}'", query);
+        println!("  Index generated documentation with: weavewiki ask-index");
+        return Ok(());
+    }
+    print_doc_hits(&hits);
+    Ok(())
+}
+
+/// Each hit as its title and heading, where to read it, the source files
+/// it covers and the line best matching the query.
+fn print_doc_hits(hits: &[DocHit]) {
+    for (rank, hit) in hits.iter().enumerate() {
+        let heading = hit
+            .heading
+            .as_ref()
+            .map(|h| format!(" › {}", h))
+            .unwrap_or_default();
+        println!("{:>3}. {}{}  [{}]", rank + 1, hit.title, heading, hit.kind);
+        println!("     {}", hit.location);
+        if !hit.sources.is_empty() && hit.sources != [hit.location.clone()] {
+            let mut sources: Vec<&str> = hit
+                .sources
+                .iter()
+                .take(MAX_LISTED_SOURCES)
+                .map(String::as_str)
+                .collect();
+            let more = format!(
+                "+{} more",
+                hit.sources.len().saturating_sub(MAX_LISTED_SOURCES)
+            );
+            if hit.sources.len() > MAX_LISTED_SOURCES {
+                sources.push(&more);
+            }
+            println!("     sources: {}", sources.join(", "));
+        }
+        if !hit.snippet.is_empty() {
+            println!("     {}", hit.snippet);
+        }
+        println!();
+    }
+}
+
+/// One line per hit: name, kind, location and node ID.
+pub fn print_hits(hits: &[SearchHit]) {
+    let width = hits.iter().map(|h| h.name.len()).max().unwrap_or(0);
+    for hit in hits {
+        println!(
+            "  {:<width$}  {:<9}  {}:{}  {}",
+            hit.name,
+            hit.node_type,
+            normalize_path(&hit.path),
+            hit.line,
+            hit.id,
+            width = width
+        );
+    }
+}
@@ -9,6 +9,7 @@ use crate::storage::Database;
 use crate::types::{
     Claim, ClaimEvidence, ClaimType, InformationTier, IssueSeverity, Result, WeaveError,
 };
+use crate::verifier::rules::SettingsRule;
 use crate::verifier::{Reporter, VerificationEngine};
 
 pub fn run(path: Option<PathBuf>, report_path: &Path, severity: &str) -> Result<()> {
@@ -40,6 +41,12 @@ pub fn run(path: Option<PathBuf>, report_path: &Path, severity: &str) -> Result<
         report.add_issue(issue);
     }
 
+    let settings_issues =
+        SettingsRule::unread_documented(&db.get_config_files()?, &db.get_settings()?);
+    for issue in settings_issues {
+        report.add_issue(issue);
+    }
+
     let min_severity = match severity.to_lowercase().as_str() {
         "error" => IssueSeverity::Error,
         "warning" => IssueSeverity::Warning,
@@ -74,8 +81,12 @@ pub fn run(path: Option<PathBuf>, report_path: &Path, severity: &str) -> Result<
 
 fn load_claims_from_graph(db: &Database) -> Result<Vec<Claim>> {
     let conn = db.connection()?;
-    let mut stmt =
-        conn.prepare("SELECT id, node_type, path, name, metadata, evidence FROM nodes LIMIT 1000")?;
+    // Workspace packages are modules named after a manifest, with no exports
+    let mut stmt = conn.prepare(
+        "SELECT id, node_type, path, name, metadata, evidence FROM nodes
+         WHERE json_extract(metadata, '$.workspace_package') IS NULL
+         LIMIT 1000",
+    )?;
 
     let claims: Vec<Claim> = stmt
         .query_map([], |row| {
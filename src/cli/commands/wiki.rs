@@ -265,7 +265,11 @@ fn run_resume(
         llm_provider,
         &project_root,
         output_dir,
-    );
+    )
+    .with_config(MultiAgentConfig {
+        analysis: config.analysis.clone(),
+        ..MultiAgentConfig::default()
+    });
 
     // Load checkpoint and resume
     let checkpoint = pipeline.load_checkpoint()?;
@@ -365,6 +369,7 @@ fn run_generate(
         show_progress: true,
         verbose: options.verbose,
         dry_run: options.dry_run,
+        analysis: config.analysis.clone(),
     };
 
     // Create runtime for async operations
@@ -428,6 +433,15 @@ fn print_multi_agent_result(
     println!("  Pages Generated:  {}", result.pages_generated);
     println!("  Markdown Files:   {}", md_file_count);
     println!("  Duration:         {}s", result.duration_secs);
+    if !result.untested_core_files.is_empty() {
+        println!(
+            "  Untested Core:    {} files",
+            result.untested_core_files.len()
+        );
+        for file in result.untested_core_files.iter().take(5) {
+            println!("    - {}", file);
+        }
+    }
     println!();
     println!("  Output: {}", output_dir.display());
 }
@@ -293,17 +293,23 @@ version = "1.0"
 name = "{}"
 type = "auto"
 
-# Analysis settings
+# Analysis settings (shared by `build` and `generate`; globs are relative to
+# the project root, and `.weavewikiignore` files use .gitignore syntax)
 [analysis]
 include = ["**/*"]
 exclude = [
     "node_modules/**",
     "dist/**",
     ".git/**",
+    ".weavewiki/**",
     "target/**",
     "build/**",
 ]
 
+# Per-language toggles
+# [analysis.languages]
+# python = false
+
 # Documentation output
 [documentation]
 output_dir = "wiki"
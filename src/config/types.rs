@@ -4,8 +4,11 @@
 //! Supports global (~/.weavewiki/) and project (.weavewiki/) level configuration.
 
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
 use std::path::PathBuf;
 
+use crate::analyzer::parser::Language;
+
 /// Root configuration structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -61,6 +64,19 @@ impl Config {
             ));
         }
 
+        // Language toggles must name a known language
+        if let Some(name) = self
+            .analysis
+            .languages
+            .keys()
+            .find(|name| !name.parse::<Language>().is_ok_and(|l| l.is_known()))
+        {
+            return Err(crate::types::WeaveError::Config(format!(
+                "Unknown language in analysis.languages: {}",
+                name
+            )));
+        }
+
         // Session checkpoint interval
         if self.session.checkpoint_interval == 0 {
             return Err(crate::types::WeaveError::Config(
@@ -107,6 +123,18 @@ pub enum ProjectType {
     Auto,
 }
 
+impl ProjectType {
+    /// Resolve `Auto` using what the analyzer discovered on disk.
+    ///
+    /// Explicitly configured types are kept as-is.
+    pub fn resolve(self, is_monorepo: bool) -> Self {
+        match self {
+            ProjectType::Auto if is_monorepo => ProjectType::Monorepo,
+            other => other,
+        }
+    }
+}
+
 // =============================================================================
 // Analysis Mode & Project Scale (Multi-Agent Pipeline)
 // =============================================================================
@@ -332,6 +360,21 @@ pub struct AnalysisConfig {
 
     /// Maximum file size in bytes
     pub max_file_size: usize,
+
+    /// Per-language toggles by name (`python: false` skips Python files)
+    pub languages: BTreeMap<String, bool>,
+}
+
+impl AnalysisConfig {
+    /// Languages turned off in `languages`; unknown names are ignored here
+    /// and rejected by [`Config::validate`].
+    pub fn disabled_languages(&self) -> HashSet<Language> {
+        self.languages
+            .iter()
+            .filter(|(_, enabled)| !**enabled)
+            .filter_map(|(name, _)| name.parse().ok())
+            .collect()
+    }
 }
 
 impl Default for AnalysisConfig {
@@ -342,6 +385,7 @@ impl Default for AnalysisConfig {
                 "node_modules/**".to_string(),
                 "dist/**".to_string(),
                 ".git/**".to_string(),
+                ".weavewiki/**".to_string(),
                 "target/**".to_string(),
                 "vendor/**".to_string(),
                 "__pycache__/**".to_string(),
@@ -349,6 +393,7 @@ impl Default for AnalysisConfig {
                 "build/**".to_string(),
             ],
             max_file_size: 1_048_576, // 1MB
+            languages: BTreeMap::new(),
         }
     }
 }
@@ -464,6 +509,34 @@ mod tests {
         assert_eq!("deep".parse::<AnalysisMode>().unwrap(), AnalysisMode::Deep);
     }
 
+    #[test]
+    fn test_project_type_resolve() {
+        assert_eq!(ProjectType::Auto.resolve(true), ProjectType::Monorepo);
+        assert_eq!(ProjectType::Auto.resolve(false), ProjectType::Auto);
+        assert_eq!(ProjectType::Library.resolve(true), ProjectType::Library);
+    }
+
+    #[test]
+    fn test_language_toggles() {
+        let mut config = Config::default();
+        config
+            .analysis
+            .languages
+            .insert("Python".to_string(), false);
+        config.analysis.languages.insert("rust".to_string(), true);
+        assert!(config.validate().is_ok());
+        assert_eq!(
+            config.analysis.disabled_languages(),
+            HashSet::from([Language::Python])
+        );
+
+        config
+            .analysis
+            .languages
+            .insert("fortran".to_string(), false);
+        assert!(config.validate().is_err());
+    }
+
     #[test]
     fn test_project_scale() {
         assert_eq!(ProjectScale::from_file_count(10), ProjectScale::Small);
@@ -31,6 +31,16 @@ fn parse_project_scale(s: &str) -> Result<weavewiki::config::ProjectScale, Strin
     }
 }
 
+fn parse_edge_type(s: &str) -> Result<weavewiki::types::EdgeType, String> {
+    serde_json::from_value(serde_json::Value::String(s.to_lowercase())).map_err(|_| {
+        format!(
+            "Invalid edge type '{}'. Valid values: depends_on, calls, implements, extends, \
+             renders, routes_to, uses_type, tests, reads, owns, exposes, persists, validates",
+            s
+        )
+    })
+}
+
 #[derive(Parser)]
 #[command(name = "weavewiki")]
 #[command(
@@ -59,7 +69,7 @@ enum Commands {
         force: bool,
     },
 
-    /// Build knowledge graph from source code
+    /// Build knowledge graph from source code, re-parsing only changed files
     Build {
         #[arg(long, help = "Run full build from scratch (clear existing graph)")]
         full: bool,
@@ -67,6 +77,18 @@ enum Commands {
         path: Option<PathBuf>,
     },
 
+    /// List the files selected for analysis
+    Files {
+        #[arg(
+            long,
+            value_name = "PATH",
+            help = "Explain why a file is included or skipped"
+        )]
+        explain: Option<PathBuf>,
+        #[arg(long, help = "Project root to scan")]
+        path: Option<PathBuf>,
+    },
+
     /// Generate AI-driven wiki documentation
     Generate {
         #[arg(long, short, help = "Output directory for wiki")]
@@ -96,16 +118,72 @@ enum Commands {
     },
 
     /// Query the knowledge graph (structural)
+    #[command(args_conflicts_with_subcommands = true)]
     Query {
-        #[arg(help = "Node ID or path to query")]
-        query: String,
+        #[command(subcommand)]
+        command: Option<QueryCommand>,
+        #[arg(help = "Node ID, path, or a query such as \"functions where complexity>10\"")]
+        query: Option<String>,
         #[arg(
             short = 'd',
             long,
             default_value = "10",
-            help = "Maximum depth for dependencies"
+            help = "Maximum number of hops to follow edges"
         )]
         depth: u32,
+        #[arg(
+            short = 'f',
+            long,
+            default_value = "text",
+            help = "Output format: text, json, csv (csv for queries only)"
+        )]
+        format: String,
+        #[arg(
+            long,
+            help = "Rank functions by cyclomatic complexity, optionally under the queried path"
+        )]
+        complex: bool,
+        #[arg(
+            short = 'l',
+            long,
+            default_value = "20",
+            help = "Maximum number of functions listed with --complex"
+        )]
+        limit: usize,
+        #[arg(
+            long,
+            value_name = "FILE",
+            help = "List every file a change to FILE can affect, grouped by distance"
+        )]
+        impact: Option<String>,
+        #[arg(
+            short = 'e',
+            long = "edge-types",
+            value_delimiter = ',',
+            value_parser = parse_edge_type,
+            help = "Edge types to follow, comma-separated (default: depends_on; every dependency edge for --impact)"
+        )]
+        edge_types: Vec<weavewiki::types::EdgeType>,
+    },
+
+    /// Find nodes by approximate name, or documentation sections with --docs
+    Search {
+        #[arg(
+            help = "Name or words to look for, e.g. parseRequest, parse_req, prsreq; a question with --docs"
+        )]
+        term: String,
+        #[arg(
+            long,
+            help = "Search generated wiki pages and file insights (index them with ask-index)"
+        )]
+        docs: bool,
+        #[arg(
+            short = 'l',
+            long,
+            default_value = "20",
+            help = "Maximum number of results"
+        )]
+        limit: usize,
         #[arg(
             short = 'f',
             long,
@@ -115,6 +193,17 @@ enum Commands {
         format: String,
     },
 
+    /// Index wiki pages and file insights for `search --docs`, offline
+    AskIndex {
+        #[arg(long, help = "Wiki directory to index (default: .weavewiki/wiki)")]
+        wiki: Option<PathBuf>,
+        #[arg(
+            long,
+            help = "Also compute local embeddings to match related words the text index misses"
+        )]
+        embeddings: bool,
+    },
+
     /// Validate knowledge base against source code
     Validate {
         #[arg(help = "Path to validate")]
@@ -161,6 +250,48 @@ enum Commands {
     },
 }
 
+#[derive(Subcommand)]
+enum QueryCommand {
+    /// Show how one node or file comes to depend on another
+    Path {
+        #[arg(help = "Node ID or path the paths start from")]
+        from: String,
+        #[arg(help = "Node ID or path the paths lead to")]
+        to: String,
+        #[arg(long, help = "List every simple path instead of one shortest path")]
+        all: bool,
+        #[arg(
+            short = 'd',
+            long,
+            default_value = "6",
+            help = "Maximum number of hops in a path"
+        )]
+        depth: u32,
+        #[arg(
+            short = 'l',
+            long,
+            default_value = "20",
+            help = "Maximum number of paths listed with --all"
+        )]
+        limit: usize,
+        #[arg(
+            short = 'e',
+            long = "edge-types",
+            value_delimiter = ',',
+            value_parser = parse_edge_type,
+            help = "Edge types to follow, comma-separated (default: every dependency edge)"
+        )]
+        edge_types: Vec<weavewiki::types::EdgeType>,
+        #[arg(
+            short = 'f',
+            long,
+            default_value = "text",
+            help = "Output format: text, json, mermaid"
+        )]
+        format: String,
+    },
+}
+
 #[derive(Subcommand)]
 enum ConfigAction {
     /// Show current configuration (merged from all sources)
@@ -267,6 +398,9 @@ fn run_cli() -> anyhow::Result<()> {
         Commands::Build { full, path } => {
             weavewiki::cli::commands::analyze::run(full, path, false)?;
         }
+        Commands::Files { explain, path } => {
+            weavewiki::cli::commands::files::run(explain, path)?;
+        }
         Commands::Generate {
             output,
             provider,
@@ -307,11 +441,60 @@ fn run_cli() -> anyhow::Result<()> {
             })?;
         }
         Commands::Query {
+            command:
+                Some(QueryCommand::Path {
+                    from,
+                    to,
+                    all,
+                    depth,
+                    limit,
+                    edge_types,
+                    format,
+                }),
+            ..
+        } => {
+            weavewiki::cli::commands::query::run_path(
+                &from,
+                &to,
+                all.then_some(limit),
+                depth,
+                &edge_types,
+                &format,
+            )?;
+        }
+        Commands::Query {
+            command: None,
             query,
             depth,
             format,
+            complex,
+            limit,
+            impact,
+            edge_types,
         } => {
-            weavewiki::cli::commands::query::run(&query, depth, &format)?;
+            weavewiki::cli::commands::query::run(
+                query.as_deref(),
+                depth,
+                &format,
+                complex.then_some(limit),
+                impact.as_deref(),
+                &edge_types,
+            )?;
+        }
+        Commands::Search {
+            term,
+            docs,
+            limit,
+            format,
+        } => {
+            if docs {
+                weavewiki::cli::commands::search::run_docs(&term, limit, &format)?;
+            } else {
+                weavewiki::cli::commands::search::run(&term, limit, &format)?;
+            }
+        }
+        Commands::AskIndex { wiki, embeddings } => {
+            weavewiki::cli::commands::ask_index::run(wiki, embeddings)?;
         }
         Commands::Validate {
             path,
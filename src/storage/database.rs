@@ -6,6 +6,7 @@
 //! - Version-tracked migrations
 //! - WAL mode for optimal read/write performance
 
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 use std::sync::Arc;
 
@@ -13,7 +14,11 @@ use r2d2::{Pool, PooledConnection};
 use r2d2_sqlite::SqliteConnectionManager;
 use rusqlite::{Connection, params};
 
-use crate::types::{Edge, Node, Result, ResultExt, WeaveError, log_filter_error};
+use super::doc_index::{DocHit, DocIndexStats};
+use crate::types::{
+    ApiMetadata, ConfigMetadata, Edge, EdgeMetadata, EntityMetadata, Node, Result, ResultExt,
+    WeaveError, log_filter_error,
+};
 
 /// Shared database handle for async contexts.
 pub type SharedDatabase = Arc<Database>;
@@ -21,7 +26,7 @@ pub type SharedDatabase = Arc<Database>;
 const SCHEMA: &str = include_str!("schema.sql");
 
 /// Current schema version for migration tracking
-const SCHEMA_VERSION: u32 = 3;
+const SCHEMA_VERSION: u32 = 4;
 
 /// Migration definitions
 struct Migration {
@@ -47,6 +52,15 @@ const MIGRATIONS: &[Migration] = &[
         description: "Add WAL checkpoint settings",
         up: "PRAGMA wal_autocheckpoint = 1000",
     },
+    Migration {
+        version: 4,
+        description: "Add graph file hashes for incremental builds",
+        up: "CREATE TABLE IF NOT EXISTS graph_files (
+                 path TEXT PRIMARY KEY,
+                 content_hash TEXT NOT NULL,
+                 updated_at TEXT DEFAULT CURRENT_TIMESTAMP
+             )",
+    },
 ];
 
 /// Generic agent insight for checkpoint storage
@@ -84,6 +98,99 @@ pub struct StoredFileInsight {
     pub key_insights: Vec<String>,
 }
 
+/// Call edge touching a file, with both endpoints resolved to names
+#[derive(Debug, Clone)]
+pub struct CallEdgeRow {
+    pub caller: String,
+    pub caller_path: String,
+    pub callee: String,
+    pub callee_path: String,
+    pub call_count: u32,
+    pub is_async: bool,
+}
+
+/// Test edge into a file. Names are `None` for file-level links, where a
+/// whole test file covers the subject file.
+#[derive(Debug, Clone)]
+pub struct TestLinkRow {
+    pub test: Option<String>,
+    pub test_path: String,
+    pub subject: Option<String>,
+    pub subject_path: String,
+}
+
+/// Function or method with the complexity metrics its parser measured
+#[derive(Debug, Clone)]
+pub struct FunctionComplexityRow {
+    pub name: String,
+    pub path: String,
+    pub line: u32,
+    pub cyclomatic: u32,
+    pub max_nesting: u32,
+    pub parameters: u32,
+    pub lines: u32,
+    pub early_returns: u32,
+}
+
+/// HTTP endpoint extracted from route declarations, with its handler
+#[derive(Debug, Clone)]
+pub struct ApiEndpointRow {
+    /// File declaring the route
+    pub file: String,
+    pub line: u32,
+    pub api: ApiMetadata,
+    /// Handler expression as written at the route (`getUser`, `photos#show`)
+    pub handler: Option<String>,
+    /// File defining the handler, when it resolved to a graph node
+    pub handler_path: Option<String>,
+}
+
+/// Persistent entity extracted from ORM models or schema declarations
+#[derive(Debug, Clone)]
+pub struct EntityRow {
+    /// File declaring the entity
+    pub file: String,
+    pub line: u32,
+    pub name: String,
+    pub entity: EntityMetadata,
+}
+
+/// Configuration or infrastructure file with the facts its parser read
+#[derive(Debug, Clone)]
+pub struct ConfigFileRow {
+    pub file: String,
+    pub config: ConfigMetadata,
+}
+
+/// Environment variable, configuration key or feature flag read in one file
+#[derive(Debug, Clone)]
+pub struct SettingRow {
+    pub name: String,
+    /// `env`, `config_key` or `feature_flag`
+    pub kind: String,
+    pub file: String,
+    pub line: u32,
+    /// Functions reading it; empty when only top-level code does
+    pub readers: Vec<String>,
+}
+
+/// Third-party package with the files importing it
+#[derive(Debug, Clone)]
+pub struct ExternalPackageRow {
+    pub name: String,
+    /// `cargo`, `npm`, `pypi` or `go`
+    pub ecosystem: String,
+    /// Version a lockfile pins
+    pub version: Option<String>,
+    /// Requirement as the manifest writes it
+    pub requirement: Option<String>,
+    /// Scope in the declaring manifest; `None` when only a lockfile names it
+    pub scope: Option<String>,
+    /// Manifest (or lockfile) naming the package
+    pub manifest: String,
+    pub importers: Vec<String>,
+}
+
 /// Type alias for file insight row data (path, language, line_count, complexity, purpose, sections, insights)
 type FileInsightRow = (String, Option<String>, i64, String, String, String, String);
 
@@ -226,6 +333,7 @@ impl Database {
         // Set version to current since schema.sql includes all columns
         conn.pragma_update(None, "user_version", SCHEMA_VERSION)
             .with_context("Failed to set schema version")?;
+        super::search::backfill(&conn)?;
 
         drop(conn);
         // Migrations only needed for existing databases with older versions
@@ -986,6 +1094,81 @@ impl Database {
         Ok(dependents)
     }
 
+    /// Get call edges where either the caller or the callee lives in this file.
+    pub fn get_file_calls(&self, file_path: &str) -> Result<Vec<CallEdgeRow>> {
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(
+            r#"SELECT s.name, s.path, t.name, t.path, e.metadata FROM edges e
+               JOIN nodes s ON e.source_id = s.id
+               JOIN nodes t ON e.target_id = t.id
+               WHERE e.edge_type = 'calls' AND e.tier = 'fact'
+               AND (s.path = ?1 OR t.path = ?1)
+               ORDER BY s.name, t.name"#,
+        )?;
+
+        let calls = stmt
+            .query_map(params![file_path], |row| {
+                let metadata: String = row.get(4)?;
+                let metadata: EdgeMetadata = serde_json::from_str(&metadata).unwrap_or_default();
+                Ok(CallEdgeRow {
+                    caller: row.get(0)?,
+                    caller_path: row.get(1)?,
+                    callee: row.get(2)?,
+                    callee_path: row.get(3)?,
+                    call_count: metadata.call_count.unwrap_or(1),
+                    is_async: metadata.is_async.unwrap_or(false),
+                })
+            })?
+            .filter_map(|r| log_filter_error(r, "reading call edge"))
+            .collect();
+
+        Ok(calls)
+    }
+
+    /// Get the tests covering this file or the symbols declared in it.
+    pub fn get_file_tests(&self, file_path: &str) -> Result<Vec<TestLinkRow>> {
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(
+            r#"SELECT s.name, s.path, s.node_type, t.name, t.path, t.node_type FROM edges e
+               JOIN nodes s ON e.source_id = s.id
+               JOIN nodes t ON e.target_id = t.id
+               WHERE e.edge_type = 'tests' AND e.tier = 'fact' AND t.path = ?1
+               ORDER BY s.path, s.name, t.name"#,
+        )?;
+
+        let name = |name: String, node_type: String| (node_type != "file").then_some(name);
+        let tests = stmt
+            .query_map(params![file_path], |row| {
+                Ok(TestLinkRow {
+                    test: name(row.get(0)?, row.get(2)?),
+                    test_path: row.get(1)?,
+                    subject: name(row.get(3)?, row.get(5)?),
+                    subject_path: row.get(4)?,
+                })
+            })?
+            .filter_map(|r| log_filter_error(r, "reading test edge"))
+            .collect();
+
+        Ok(tests)
+    }
+
+    /// Get the paths of every file covered by at least one test.
+    pub fn get_tested_files(&self) -> Result<HashSet<String>> {
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(
+            r#"SELECT DISTINCT t.path FROM edges e
+               JOIN nodes t ON e.target_id = t.id
+               WHERE e.edge_type = 'tests' AND e.tier = 'fact'"#,
+        )?;
+
+        let files = stmt
+            .query_map([], |row| row.get(0))?
+            .filter_map(|r| log_filter_error(r, "reading tested file"))
+            .collect();
+
+        Ok(files)
+    }
+
     /// Get trait/interface implementations for a file.
     pub fn get_file_implements(&self, file_path: &str) -> Result<Vec<String>> {
         let file_id = format!("file:{}", file_path);
@@ -1004,6 +1187,287 @@ impl Database {
         Ok(implements)
     }
 
+    /// Get every extracted HTTP endpoint, ordered by declaring file.
+    pub fn get_api_endpoints(&self) -> Result<Vec<ApiEndpointRow>> {
+        use crate::types::node::{EvidenceLocation, NodeMetadata};
+
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(
+            r#"SELECT n.path, n.metadata, n.evidence, h.path FROM nodes n
+               LEFT JOIN edges e ON e.source_id = n.id AND e.edge_type = 'routes_to'
+               LEFT JOIN nodes h ON e.target_id = h.id
+               WHERE n.node_type = 'api' AND n.tier = 'fact'
+               ORDER BY n.path, n.name"#,
+        )?;
+
+        let endpoints = stmt
+            .query_map([], |row| {
+                let metadata: String = row.get(1)?;
+                let evidence: String = row.get(2)?;
+                let metadata: NodeMetadata = serde_json::from_str(&metadata).unwrap_or_default();
+                let line = serde_json::from_str::<EvidenceLocation>(&evidence)
+                    .map(|e| e.start_line)
+                    .unwrap_or_default();
+                Ok(metadata.api_metadata.map(|api| ApiEndpointRow {
+                    file: row.get(0).unwrap_or_default(),
+                    line,
+                    api,
+                    handler: metadata
+                        .extra
+                        .get("handler")
+                        .and_then(|h| h.as_str())
+                        .map(str::to_string),
+                    handler_path: row.get(3).ok().flatten(),
+                }))
+            })?
+            .filter_map(|r| log_filter_error(r, "reading api endpoint"))
+            .flatten()
+            .collect();
+
+        Ok(endpoints)
+    }
+
+    /// Get the most complex functions and methods, by cyclomatic complexity
+    /// then length, optionally only those declared under `path_prefix`.
+    pub fn get_complex_functions(
+        &self,
+        path_prefix: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<FunctionComplexityRow>> {
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(
+            r#"SELECT name, path, json_extract(evidence, '$.start_line'),
+                      json_extract(metadata, '$.metrics.cyclomatic'),
+                      json_extract(metadata, '$.metrics.max_nesting'),
+                      json_extract(metadata, '$.metrics.parameters'),
+                      json_extract(metadata, '$.metrics.lines'),
+                      json_extract(metadata, '$.metrics.early_returns')
+               FROM nodes
+               WHERE node_type IN ('function', 'method') AND tier = 'fact'
+                 AND json_extract(metadata, '$.metrics') IS NOT NULL
+                 AND (?1 IS NULL OR path LIKE ?1 || '%' OR path LIKE './' || ?1 || '%')
+               ORDER BY 4 DESC, 7 DESC, path, name
+               LIMIT ?2"#,
+        )?;
+
+        let functions = stmt
+            .query_map(params![path_prefix, limit as i64], |row| {
+                Ok(FunctionComplexityRow {
+                    name: row.get(0)?,
+                    path: row.get(1)?,
+                    line: row.get::<_, Option<u32>>(2)?.unwrap_or_default(),
+                    cyclomatic: row.get(3)?,
+                    max_nesting: row.get(4)?,
+                    parameters: row.get(5)?,
+                    lines: row.get(6)?,
+                    early_returns: row.get(7)?,
+                })
+            })?
+            .filter_map(|r| log_filter_error(r, "reading function metrics"))
+            .collect();
+
+        Ok(functions)
+    }
+
+    /// Cyclomatic complexity of the most complex function or method of every
+    /// file that has measured ones, keyed by path.
+    pub fn get_max_cyclomatic_by_file(&self) -> Result<HashMap<String, u32>> {
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(
+            r#"SELECT path, MAX(json_extract(metadata, '$.metrics.cyclomatic'))
+               FROM nodes
+               WHERE node_type IN ('function', 'method') AND tier = 'fact'
+                 AND json_extract(metadata, '$.metrics.cyclomatic') IS NOT NULL
+               GROUP BY path"#,
+        )?;
+
+        let files = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .filter_map(|r| log_filter_error(r, "reading function metrics"))
+            .collect();
+
+        Ok(files)
+    }
+
+    /// Get every extracted entity, ordered by declaring file.
+    pub fn get_entities(&self) -> Result<Vec<EntityRow>> {
+        use crate::types::node::{EvidenceLocation, NodeMetadata};
+
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(
+            r#"SELECT path, name, metadata, evidence FROM nodes
+               WHERE node_type = 'entity' AND tier = 'fact'
+               ORDER BY path, name"#,
+        )?;
+
+        let entities = stmt
+            .query_map([], |row| {
+                let metadata: String = row.get(2)?;
+                let evidence: String = row.get(3)?;
+                let metadata: NodeMetadata = serde_json::from_str(&metadata).unwrap_or_default();
+                let line = serde_json::from_str::<EvidenceLocation>(&evidence)
+                    .map(|e| e.start_line)
+                    .unwrap_or_default();
+                Ok(metadata.entity_metadata.map(|entity| EntityRow {
+                    file: row.get(0).unwrap_or_default(),
+                    line,
+                    name: row.get(1).unwrap_or_default(),
+                    entity,
+                }))
+            })?
+            .filter_map(|r| log_filter_error(r, "reading entity"))
+            .flatten()
+            .collect();
+
+        Ok(entities)
+    }
+
+    /// Get every parsed configuration file, ordered by path.
+    pub fn get_config_files(&self) -> Result<Vec<ConfigFileRow>> {
+        use crate::types::node::NodeMetadata;
+
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(
+            r#"SELECT path, metadata FROM nodes
+               WHERE node_type = 'config' AND tier = 'fact'
+               ORDER BY path"#,
+        )?;
+
+        let files = stmt
+            .query_map([], |row| {
+                let metadata: String = row.get(1)?;
+                let metadata: NodeMetadata = serde_json::from_str(&metadata).unwrap_or_default();
+                Ok(metadata.config_metadata.map(|config| ConfigFileRow {
+                    file: row.get(0).unwrap_or_default(),
+                    config,
+                }))
+            })?
+            .filter_map(|r| log_filter_error(r, "reading config file"))
+            .flatten()
+            .collect();
+
+        Ok(files)
+    }
+
+    /// Get every setting read by code with the functions reading it, ordered
+    /// by name then file.
+    pub fn get_settings(&self) -> Result<Vec<SettingRow>> {
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(
+            r#"SELECT n.id, n.name, n.path, json_extract(n.metadata, '$.setting_kind'),
+                      json_extract(n.evidence, '$.start_line'),
+                      CASE WHEN r.node_type = 'file' THEN NULL ELSE r.name END
+               FROM nodes n
+               LEFT JOIN edges e ON e.target_id = n.id AND e.edge_type = 'reads'
+               LEFT JOIN nodes r ON e.source_id = r.id
+               WHERE n.node_type = 'setting' AND n.tier = 'fact'
+               ORDER BY n.name, n.path, r.name"#,
+        )?;
+
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    SettingRow {
+                        name: row.get(1)?,
+                        kind: row.get::<_, Option<String>>(3)?.unwrap_or_default(),
+                        file: row.get::<_, Option<String>>(2)?.unwrap_or_default(),
+                        line: row.get::<_, Option<u32>>(4)?.unwrap_or_default(),
+                        readers: Vec::new(),
+                    },
+                    row.get::<_, Option<String>>(5)?,
+                ))
+            })?
+            .filter_map(|r| log_filter_error(r, "reading setting"));
+
+        // One row per read edge; fold them into one row per setting node
+        let mut settings: Vec<SettingRow> = Vec::new();
+        let mut last_id = String::new();
+        for (id, setting, reader) in rows {
+            if id != last_id {
+                settings.push(setting);
+                last_id = id;
+            }
+            if let (Some(reader), Some(current)) = (reader, settings.last_mut())
+                && !current.readers.contains(&reader)
+            {
+                current.readers.push(reader);
+            }
+        }
+
+        Ok(settings)
+    }
+
+    /// Get every third-party package with the files importing it, ordered
+    /// by ecosystem then name.
+    pub fn get_external_packages(&self) -> Result<Vec<ExternalPackageRow>> {
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(
+            r#"SELECT n.id, n.name, n.path,
+                      json_extract(n.metadata, '$.ecosystem'),
+                      json_extract(n.metadata, '$.version'),
+                      json_extract(n.metadata, '$.requirement'),
+                      json_extract(n.metadata, '$.scope'),
+                      s.path
+               FROM nodes n
+               LEFT JOIN edges e ON e.target_id = n.id AND e.edge_type = 'depends_on'
+               LEFT JOIN nodes s ON e.source_id = s.id
+               WHERE n.node_type = 'package' AND n.tier = 'fact'
+               ORDER BY 4, n.name, n.id, s.path"#,
+        )?;
+
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    ExternalPackageRow {
+                        name: row.get(1)?,
+                        manifest: row.get::<_, Option<String>>(2)?.unwrap_or_default(),
+                        ecosystem: row.get::<_, Option<String>>(3)?.unwrap_or_default(),
+                        version: row.get(4)?,
+                        requirement: row.get(5)?,
+                        scope: row.get(6)?,
+                        importers: Vec::new(),
+                    },
+                    row.get::<_, Option<String>>(7)?,
+                ))
+            })?
+            .filter_map(|r| log_filter_error(r, "reading external package"));
+
+        // One row per import edge; fold them into one row per package
+        let mut packages: Vec<ExternalPackageRow> = Vec::new();
+        let mut last_id = String::new();
+        for (id, package, importer) in rows {
+            if id != last_id {
+                packages.push(package);
+                last_id = id;
+            }
+            if let (Some(importer), Some(current)) = (importer, packages.last_mut())
+                && !current.importers.contains(&importer)
+            {
+                current.importers.push(importer);
+            }
+        }
+
+        Ok(packages)
+    }
+
+    // =========================================================================
+    // Documentation Search
+    // =========================================================================
+
+    /// Rebuild the documentation search index from the stored file
+    /// insights, domain summaries and the markdown pages under `wiki_dir`,
+    /// with locally computed embeddings if `embeddings` is set.
+    pub fn rebuild_doc_index(&self, wiki_dir: &Path, embeddings: bool) -> Result<DocIndexStats> {
+        self.transaction(|conn| super::doc_index::rebuild(conn, wiki_dir, embeddings))
+    }
+
+    /// Search the documentation index, best sections first.
+    pub fn search_docs(&self, query: &str, limit: usize) -> Result<Vec<DocHit>> {
+        super::doc_index::search(&*self.conn()?, query, limit)
+    }
+
     // =========================================================================
     // Checkpoint State Loading
     // =========================================================================
@@ -1112,6 +1576,25 @@ pub struct CheckpointState {
     pub files: Vec<String>,
 }
 
+/// Point the stored analysis and tracking of moved files, in every session,
+/// at their new paths, within the caller's transaction. Rows already present
+/// under a new path are kept.
+pub(super) fn move_file_records(conn: &Connection, moves: &[(String, String)]) -> Result<()> {
+    for (old, new) in moves {
+        conn.execute(
+            "UPDATE OR IGNORE file_analysis SET file_path = ?2 WHERE file_path = ?1",
+            params![old, new],
+        )
+        .with_context("Failed to move file analysis")?;
+        conn.execute(
+            "UPDATE OR IGNORE file_tracking SET file_path = ?2 WHERE file_path = ?1",
+            params![old, new],
+        )
+        .with_context("Failed to move file tracking")?;
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
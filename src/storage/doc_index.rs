@@ -0,0 +1,712 @@
+//! Documentation Search
+//!
+//! Makes what the wiki pipeline wrote searchable offline. The purpose and
+//! markdown sections of every analyzed file (`file_analysis`), the domain
+//! summaries (`domain_summaries`) and the generated wiki pages are split at
+//! their headings into `doc_sections` rows, with the `doc_search` FTS5
+//! table over them. Each section records the source files it covers: the
+//! analyzed file itself, the domain's files, or the project files a page
+//! section names.
+//!
+//! Sections can also carry an embedding computed locally by feature
+//! hashing: words, split at camelCase and snake_case boundaries, and their
+//! character trigrams are hashed into a fixed-size vector, so a query such
+//! as `retryHandling`, one unknown word to the full-text index, still finds
+//! the section on retry handling. A search ranks sections by full-text relevance and,
+//! when embeddings exist, by cosine similarity, then fuses the two rankings
+//! by reciprocal rank.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rusqlite::{Connection, params};
+use serde::Serialize;
+use serde_json::Value;
+
+use super::search::split_words;
+use crate::types::{Result, log_filter_error};
+
+/// Dimensions of a section embedding.
+const EMBEDDING_DIMS: usize = 256;
+
+/// Candidates each ranking contributes before fusion.
+const MAX_CANDIDATES: usize = 200;
+
+/// Cosine similarity below which an embedding match is ignored.
+const MIN_SIMILARITY: f32 = 0.15;
+
+/// Reciprocal rank fusion constant; larger values flatten rank weights.
+const RRF_K: f64 = 60.0;
+
+/// Characters of a section shown with a hit.
+const SNIPPET_CHARS: usize = 160;
+
+/// Words too common in questions to search for.
+const STOPWORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "by", "do", "does", "for", "from", "how", "in",
+    "is", "it", "of", "on", "or", "the", "to", "what", "when", "where", "which", "who", "why",
+    "with",
+];
+
+/// A headed section of a file insight, domain summary or wiki page.
+#[derive(Debug, Clone, PartialEq)]
+struct DocSection {
+    kind: &'static str,
+    title: String,
+    heading: Option<String>,
+    body: String,
+    location: String,
+    sources: Vec<String>,
+}
+
+/// What a rebuild indexed.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DocIndexStats {
+    pub insights: usize,
+    pub domains: usize,
+    pub pages: usize,
+    pub sections: usize,
+    pub embedded: bool,
+}
+
+/// A documentation section a search found.
+#[derive(Debug, Clone, Serialize)]
+pub struct DocHit {
+    /// `insight`, `domain` or `page`
+    pub kind: String,
+    /// File path, domain label or page title
+    pub title: String,
+    pub heading: Option<String>,
+    /// Source file, domain label or `page.md#anchor`
+    pub location: String,
+    /// Source files the section covers
+    pub sources: Vec<String>,
+    pub snippet: String,
+    pub score: f64,
+}
+
+/// Replace the index with the current file insights, domain summaries and
+/// the markdown pages under `wiki_dir`, embedding each section if asked.
+pub(super) fn rebuild(
+    conn: &Connection,
+    wiki_dir: &Path,
+    embeddings: bool,
+) -> Result<DocIndexStats> {
+    conn.execute_batch("DELETE FROM doc_search; DELETE FROM doc_sections;")?;
+
+    let mut sections = Vec::new();
+    let mut stats = DocIndexStats {
+        insights: add_insights(conn, &mut sections)?,
+        domains: add_domains(conn, &mut sections)?,
+        embedded: embeddings,
+        ..Default::default()
+    };
+
+    let known = known_files(conn)?;
+    for page in wiki_pages(wiki_dir) {
+        let markdown = match fs::read_to_string(&page) {
+            Ok(markdown) => markdown,
+            Err(e) => {
+                tracing::warn!("Failed to read wiki page {}: {}", page.display(), e);
+                continue;
+            }
+        };
+        let stem = page.file_stem().and_then(|s| s.to_str()).unwrap_or("page");
+        sections.extend(page_sections(
+            &page.display().to_string(),
+            stem,
+            &markdown,
+            &known,
+        ));
+        stats.pages += 1;
+    }
+
+    let mut insert = conn.prepare(
+        r#"INSERT INTO doc_sections (kind, title, heading, body, location, sources, embedding)
+           VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)"#,
+    )?;
+    let mut insert_text = conn
+        .prepare("INSERT INTO doc_search (rowid, title, heading, body) VALUES (?1, ?2, ?3, ?4)")?;
+    for section in &sections {
+        let embedding = embeddings.then(|| {
+            let text = format!(
+                "{} {} {}",
+                section.title,
+                section.heading.as_deref().unwrap_or_default(),
+                section.body
+            );
+            to_blob(&embed(&text))
+        });
+        insert.execute(params![
+            section.kind,
+            section.title,
+            section.heading,
+            section.body,
+            section.location,
+            serde_json::to_string(&section.sources)?,
+            embedding
+        ])?;
+        insert_text.execute(params![
+            conn.last_insert_rowid(),
+            section.title,
+            section.heading,
+            section.body
+        ])?;
+    }
+    stats.sections = sections.len();
+    Ok(stats)
+}
+
+/// Add the latest analysis of each file; returns the number of files.
+fn add_insights(conn: &Connection, sections: &mut Vec<DocSection>) -> Result<usize> {
+    // SQLite takes the bare columns from the row holding MAX(analyzed_at)
+    let mut stmt = conn.prepare(
+        r#"SELECT file_path, purpose_summary, sections, MAX(analyzed_at)
+           FROM file_analysis GROUP BY file_path ORDER BY file_path"#,
+    )?;
+    let rows: Vec<(String, Option<String>, String)> = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
+        .filter_map(|r| log_filter_error(r, "reading file insight to index"))
+        .collect();
+
+    for (path, purpose, content) in &rows {
+        let file = path.trim_start_matches("./").to_string();
+        let section = |heading: Option<String>, body: String| DocSection {
+            kind: "insight",
+            title: file.clone(),
+            heading,
+            body,
+            location: file.clone(),
+            sources: vec![file.clone()],
+        };
+        if let Some(purpose) = purpose.as_ref().filter(|p| !p.trim().is_empty()) {
+            sections.push(section(Some("Purpose".to_string()), purpose.clone()));
+        }
+        for (heading, body) in stored_sections(content) {
+            sections.push(section(heading, body));
+        }
+    }
+    Ok(rows.len())
+}
+
+/// Add the latest summary of each domain; returns the number of domains.
+fn add_domains(conn: &Connection, sections: &mut Vec<DocSection>) -> Result<usize> {
+    let mut stmt = conn.prepare(
+        r#"SELECT domain_label, domain_description, source_paths, sections, MAX(created_at)
+           FROM domain_summaries GROUP BY domain_label ORDER BY domain_label"#,
+    )?;
+    let rows: Vec<(String, Option<String>, Option<String>, String)> = stmt
+        .query_map([], |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
+        })?
+        .filter_map(|r| log_filter_error(r, "reading domain summary to index"))
+        .collect();
+
+    for (label, description, source_paths, content) in &rows {
+        let sources: Vec<String> = source_paths
+            .as_deref()
+            .and_then(|s| serde_json::from_str::<Vec<String>>(s).ok())
+            .unwrap_or_default()
+            .iter()
+            .map(|p| p.trim_start_matches("./").to_string())
+            .collect();
+        let lead = description
+            .as_ref()
+            .filter(|d| !d.trim().is_empty())
+            .map(|d| (None, d.clone()));
+        for (heading, body) in lead.into_iter().chain(stored_sections(content)) {
+            sections.push(DocSection {
+                kind: "domain",
+                title: label.clone(),
+                heading,
+                body,
+                location: label.clone(),
+                sources: sources.clone(),
+            });
+        }
+    }
+    Ok(rows.len())
+}
+
+/// Project files by the path pages write them (`src/main.rs`).
+fn known_files(conn: &Connection) -> Result<HashSet<String>> {
+    let mut stmt = conn.prepare(
+        "SELECT path FROM nodes WHERE node_type = 'file' UNION SELECT file_path FROM file_analysis",
+    )?;
+    let files = stmt
+        .query_map([], |row| row.get::<_, String>(0))?
+        .filter_map(|r| log_filter_error(r, "reading file path"))
+        .map(|p| p.trim_start_matches("./").to_string())
+        .collect();
+    Ok(files)
+}
+
+/// Markdown pages under `dir`, in path order. A missing directory has none.
+fn wiki_pages(dir: &Path) -> Vec<PathBuf> {
+    let mut pages = Vec::new();
+    let Ok(entries) = fs::read_dir(dir) else {
+        return pages;
+    };
+    let mut entries: Vec<PathBuf> = entries.filter_map(|e| e.ok()).map(|e| e.path()).collect();
+    entries.sort();
+    for path in entries {
+        if path.is_dir() {
+            pages.extend(wiki_pages(&path));
+        } else if path.extension().is_some_and(|e| e == "md") {
+            pages.push(path);
+        }
+    }
+    pages
+}
+
+/// Sections of a wiki page. The page's `#` heading is its title; sections
+/// naming no project file fall back to the files the whole page names.
+fn page_sections(
+    page: &str,
+    fallback_title: &str,
+    markdown: &str,
+    known: &HashSet<String>,
+) -> Vec<DocSection> {
+    let title = markdown_title(markdown).unwrap_or_else(|| fallback_title.to_string());
+    let page_sources = mentioned_files(markdown, known);
+
+    markdown_sections(markdown)
+        .into_iter()
+        .map(|(heading, body)| {
+            let heading = heading.filter(|h| *h != title);
+            let mut sources = mentioned_files(&body, known);
+            if sources.is_empty() {
+                sources = page_sources.clone();
+            }
+            let location = match &heading {
+                Some(heading) => format!("{}#{}", page, anchor(heading)),
+                None => page.to_string(),
+            };
+            DocSection {
+                kind: "page",
+                title: title.clone(),
+                heading,
+                body,
+                location,
+                sources,
+            }
+        })
+        .collect()
+}
+
+/// Text of the first `#` heading outside code blocks.
+fn markdown_title(markdown: &str) -> Option<String> {
+    let mut fenced = false;
+    for line in markdown.lines() {
+        let line = line.trim_start();
+        if line.starts_with("```") || line.starts_with("~~~") {
+            fenced = !fenced;
+        } else if !fenced && let Some(title) = line.strip_prefix("# ") {
+            return Some(title.trim().trim_end_matches('#').trim().to_string());
+        }
+    }
+    None
+}
+
+/// Markdown split at its `#`, `##` and `###` headings outside code blocks,
+/// as (heading, body) pairs. Text before the first heading has no heading;
+/// sections with no body are dropped.
+fn markdown_sections(markdown: &str) -> Vec<(Option<String>, String)> {
+    let mut sections = Vec::new();
+    let mut heading: Option<String> = None;
+    let mut body = String::new();
+    let mut fenced = false;
+
+    let mut flush = |heading: Option<String>, body: &mut String| {
+        let text = body.trim();
+        if !text.is_empty() {
+            sections.push((heading, text.to_string()));
+        }
+        body.clear();
+    };
+    for line in markdown.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            fenced = !fenced;
+        }
+        let level = trimmed.chars().take_while(|c| *c == '#').count();
+        let is_heading = !fenced && (1..=3).contains(&level) && trimmed[level..].starts_with(' ');
+        if is_heading {
+            flush(heading.take(), &mut body);
+            heading = Some(
+                trimmed[level..]
+                    .trim()
+                    .trim_end_matches('#')
+                    .trim()
+                    .to_string(),
+            );
+        } else {
+            body.push_str(line);
+            body.push('\n');
+        }
+    }
+    flush(heading, &mut body);
+    sections
+}
+
+/// Sections of stored AI content: a JSON string of markdown, a JSON array
+/// of markdown strings or `{title, content}` objects, or plain markdown.
+fn stored_sections(stored: &str) -> Vec<(Option<String>, String)> {
+    let object_section = |fields: &serde_json::Map<String, Value>| {
+        let text = |keys: &[&str]| {
+            keys.iter()
+                .find_map(|k| fields.get(*k)?.as_str())
+                .map(String::from)
+        };
+        text(&["content", "body", "text"])
+            .filter(|body| !body.trim().is_empty())
+            .map(|body| (text(&["title", "heading", "name"]), body))
+    };
+    match serde_json::from_str::<Value>(stored) {
+        Ok(Value::String(markdown)) => markdown_sections(&markdown),
+        Ok(Value::Array(items)) => items
+            .iter()
+            .flat_map(|item| match item {
+                Value::String(markdown) => markdown_sections(markdown),
+                Value::Object(fields) => object_section(fields).into_iter().collect(),
+                _ => Vec::new(),
+            })
+            .collect(),
+        Ok(_) => Vec::new(),
+        Err(_) => markdown_sections(stored),
+    }
+}
+
+/// Project files `text` names, as plain paths, links or `path:line`.
+fn mentioned_files(text: &str, known: &HashSet<String>) -> Vec<String> {
+    let files: BTreeSet<&str> = text
+        .split(|c: char| c.is_whitespace() || "`'\"()[]<>|,*".contains(c))
+        .filter_map(|token| token.split(':').next())
+        .map(|token| token.trim_start_matches("./").trim_end_matches(['.', ';']))
+        .filter(|token| known.contains(*token))
+        .collect();
+    files.into_iter().map(String::from).collect()
+}
+
+/// GitHub-style anchor of a heading: `Retry Policy` gives `retry-policy`.
+fn anchor(heading: &str) -> String {
+    heading
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            c if c.is_alphanumeric() || c == '-' || c == '_' => Some(c),
+            _ => None,
+        })
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Sections matching `query`, best first.
+pub(super) fn search(conn: &Connection, query: &str, limit: usize) -> Result<Vec<DocHit>> {
+    let words = query_words(query);
+    if words.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    // Section IDs in rank order, one list per ranking
+    let mut rankings: Vec<Vec<i64>> = Vec::new();
+
+    let fts_query = words
+        .iter()
+        .map(|w| format!("\"{}\"", w))
+        .collect::<Vec<_>>()
+        .join(" OR ");
+    let mut stmt = conn.prepare(
+        r#"SELECT rowid FROM doc_search WHERE doc_search MATCH ?1
+           ORDER BY bm25(doc_search, 5.0, 3.0, 1.0) LIMIT ?2"#,
+    )?;
+    rankings.push(
+        stmt.query_map(params![fts_query, MAX_CANDIDATES as i64], |row| row.get(0))?
+            .filter_map(|r| log_filter_error(r, "reading docs search hit"))
+            .collect(),
+    );
+
+    let embedded: bool = conn.query_row(
+        "SELECT EXISTS (SELECT 1 FROM doc_sections WHERE embedding IS NOT NULL)",
+        [],
+        |row| row.get(0),
+    )?;
+    if embedded {
+        let target = embed(query);
+        let mut stmt =
+            conn.prepare("SELECT id, embedding FROM doc_sections WHERE embedding IS NOT NULL")?;
+        let mut similar: Vec<(i64, f32)> = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get::<_, Vec<u8>>(1)?)))?
+            .filter_map(|r| log_filter_error(r, "reading section embedding"))
+            .map(|(id, blob)| (id, cosine(&target, &from_blob(&blob))))
+            .filter(|(_, similarity)| *similarity >= MIN_SIMILARITY)
+            .collect();
+        similar.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        similar.truncate(MAX_CANDIDATES);
+        rankings.push(similar.into_iter().map(|(id, _)| id).collect());
+    }
+
+    let mut scores: HashMap<i64, f64> = HashMap::new();
+    for ranking in &rankings {
+        for (rank, id) in ranking.iter().enumerate() {
+            *scores.entry(*id).or_default() += 1.0 / (RRF_K + rank as f64 + 1.0);
+        }
+    }
+    let mut ranked: Vec<(i64, f64)> = scores.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+    ranked.truncate(limit);
+
+    let mut stmt = conn.prepare(
+        "SELECT kind, title, heading, body, location, sources FROM doc_sections WHERE id = ?1",
+    )?;
+    let mut hits = Vec::with_capacity(ranked.len());
+    for (id, score) in ranked {
+        let (kind, title, heading, body, location, sources): (
+            String,
+            String,
+            Option<String>,
+            String,
+            String,
+            String,
+        ) = stmt.query_row(params![id], |row| {
+            Ok((
+                row.get(0)?,
+                row.get(1)?,
+                row.get(2)?,
+                row.get(3)?,
+                row.get(4)?,
+                row.get(5)?,
+            ))
+        })?;
+        hits.push(DocHit {
+            kind,
+            title,
+            heading,
+            location,
+            sources: serde_json::from_str(&sources).unwrap_or_default(),
+            snippet: snippet(&body, &words),
+            score,
+        });
+    }
+    Ok(hits)
+}
+
+/// Lowercase words of a query without stopwords, unless it has nothing else.
+fn query_words(query: &str) -> Vec<String> {
+    let words: Vec<String> = query
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+    let content: Vec<String> = words
+        .iter()
+        .filter(|w| !STOPWORDS.contains(&w.as_str()))
+        .cloned()
+        .collect();
+    if content.is_empty() { words } else { content }
+}
+
+/// The body line naming the most query words, cut to `SNIPPET_CHARS`.
+fn snippet(body: &str, words: &[String]) -> String {
+    // Word starts stand in for stems: `handled` finds `handler`
+    let stems: Vec<String> = words.iter().map(|w| w.chars().take(4).collect()).collect();
+    let lines = body
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with("```"));
+    let best = lines
+        .clone()
+        .map(|line| {
+            let lower = line.to_lowercase();
+            (stems.iter().filter(|s| lower.contains(s.as_str())).count(), line)
+        })
+        .filter(|(matched, _)| *matched > 0)
+        // First of the best lines
+        .fold(None, |best: Option<(usize, &str)>, (matched, line)| match best {
+            Some((most, _)) if most >= matched => best,
+            _ => Some((matched, line)),
+        })
+        .map(|(_, line)| line)
+        .or_else(|| lines.clone().next())
+        .unwrap_or_default();
+
+    let text = best.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.chars().count() > SNIPPET_CHARS {
+        let cut: String = text.chars().take(SNIPPET_CHARS).collect();
+        format!("{}…", cut.trim_end())
+    } else {
+        text
+    }
+}
+
+/// Feature-hashed vector of the words of `text` and their character
+/// trigrams, scaled to unit length. Stopwords are left out.
+pub fn embed(text: &str) -> Vec<f32> {
+    let mut vector = vec![0.0f32; EMBEDDING_DIMS];
+    for word in split_words(text) {
+        if word.len() < 2 || STOPWORDS.contains(&word.as_str()) {
+            continue;
+        }
+        add_feature(&mut vector, &word, 1.0);
+        let padded: Vec<char> = format!("^{}$", word).chars().collect();
+        for gram in padded.windows(3) {
+            add_feature(&mut vector, &gram.iter().collect::<String>(), 0.5);
+        }
+    }
+    let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        vector.iter_mut().for_each(|v| *v /= norm);
+    }
+    vector
+}
+
+fn add_feature(vector: &mut [f32], feature: &str, weight: f32) {
+    let hash = fnv1a(feature.as_bytes());
+    let sign = if hash >> 63 == 0 { 1.0 } else { -1.0 };
+    vector[(hash % EMBEDDING_DIMS as u64) as usize] += sign * weight;
+}
+
+/// 64-bit FNV-1a, stable across builds so stored vectors stay comparable.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, b| {
+        (hash ^ u64::from(*b)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Cosine similarity of two unit vectors.
+fn cosine(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn to_blob(vector: &[f32]) -> Vec<u8> {
+    vector.iter().flat_map(|v| v.to_le_bytes()).collect()
+}
+
+fn from_blob(blob: &[u8]) -> Vec<f32> {
+    blob.chunks_exact(4)
+        .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::{Database, FileAnalysisCheckpoint};
+
+    #[test]
+    fn test_markdown_sections() {
+        let md = "# Client\n\nLead text.\n\n## Retry Policy\n\nBacks off.\n\n```rust\n# not a heading\n```\n\n### Empty\n\n#### Deep\nKept in body.\n";
+        let sections = markdown_sections(md);
+        assert_eq!(
+            sections,
+            vec![
+                (Some("Client".to_string()), "Lead text.".to_string()),
+                (
+                    Some("Retry Policy".to_string()),
+                    "Backs off.\n\n```rust\n# not a heading\n```".to_string()
+                ),
+                (
+                    Some("Empty".to_string()),
+                    "#### Deep\nKept in body.".to_string()
+                ),
+            ]
+        );
+        assert_eq!(markdown_title(md).as_deref(), Some("Client"));
+        assert_eq!(anchor("Retry Policy (HTTP)"), "retry-policy-http");
+    }
+
+    #[test]
+    fn test_embeddings_relate_word_forms() {
+        let query = embed("how is retrying handled");
+        let related = embed("The client retries failed requests with backoff");
+        let unrelated = embed("Parses TOML configuration files");
+        assert!(cosine(&query, &related) > cosine(&query, &unrelated));
+        assert!(cosine(&query, &related) >= MIN_SIMILARITY);
+        assert_eq!(from_blob(&to_blob(&query)), query);
+    }
+
+    #[test]
+    fn test_docs_search() {
+        let db = Database::open_in_memory().unwrap();
+        db.initialize().unwrap();
+        db.execute(
+            "INSERT INTO doc_sessions (id, project_path, status, started_at) VALUES (?1, ?2, ?3, ?4)",
+            &[&"s1", &"/project", &"completed", &"2026-01-01T00:00:00Z"],
+        )
+        .unwrap();
+        let analysis = |path: &str, purpose: &str, content: &str| FileAnalysisCheckpoint {
+            file_path: path.to_string(),
+            language: Some("rust".to_string()),
+            line_count: 10,
+            complexity: "medium".to_string(),
+            purpose_summary: purpose.to_string(),
+            sections_json: serde_json::to_string(content).unwrap(),
+            key_insights_json: "[]".to_string(),
+            research_iterations_json: None,
+            research_aspects_json: None,
+        };
+        for checkpoint in [
+            analysis(
+                "./src/http/client.rs",
+                "HTTP client for LLM providers.",
+                "## Retry Handling\n\nFailed requests are retried with exponential backoff.\n\n## Timeouts\n\nEach request gets 30 seconds.",
+            ),
+            analysis(
+                "./src/config.rs",
+                "Loads TOML configuration.",
+                "## Layers\n\nDefaults, then the project file, then environment variables.",
+            ),
+        ] {
+            db.checkpoint_file_analysis("s1", &checkpoint, &[], &[])
+                .unwrap();
+        }
+
+        let wiki = tempfile::TempDir::new().unwrap();
+        fs::create_dir_all(wiki.path().join("domains")).unwrap();
+        fs::write(
+            wiki.path().join("domains/llm.md"),
+            "# LLM Integration\n\n- [src/http/client.rs](src/http/client.rs)\n\nTalks to providers.\n\n\
+             ## Resilience\n\nTransient errors are retried by `src/http/client.rs:40` before the chain falls back.\n",
+        )
+        .unwrap();
+        fs::write(
+            wiki.path().join("index.md"),
+            "# Overview\n\nA wiki generator.\n",
+        )
+        .unwrap();
+
+        let stats = db.rebuild_doc_index(wiki.path(), false).unwrap();
+        assert_eq!((stats.insights, stats.domains, stats.pages), (2, 0, 2));
+        assert_eq!(stats.sections, 8);
+
+        let hits = db.search_docs("how is retry handled", 10).unwrap();
+        let found: Vec<(&str, Option<&str>)> = hits
+            .iter()
+            .map(|h| (h.title.as_str(), h.heading.as_deref()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("src/http/client.rs", Some("Retry Handling")),
+                ("LLM Integration", Some("Resilience")),
+            ]
+        );
+        assert_eq!(hits[0].location, "src/http/client.rs");
+        assert_eq!(
+            hits[0].snippet,
+            "Failed requests are retried with exponential backoff."
+        );
+        let page = &hits[1];
+        assert!(page.location.ends_with("domains/llm.md#resilience"));
+        assert_eq!(page.sources, vec!["src/http/client.rs"]);
+
+        // Embeddings add matches the full-text index misses; rebuilding
+        // replaces the rows
+        assert!(db.search_docs("retryHandling", 10).unwrap().is_empty());
+        let stats = db.rebuild_doc_index(wiki.path(), true).unwrap();
+        assert_eq!(stats.sections, 8);
+        let hits = db.search_docs("retryHandling", 10).unwrap();
+        assert_eq!(hits[0].heading.as_deref(), Some("Retry Handling"));
+    }
+}
@@ -0,0 +1,667 @@
+//! Graph Query Language
+//!
+//! A small language for ad-hoc structural questions, compiled to a single
+//! SQL statement over `nodes` and `edges`:
+//!
+//! ```text
+//! functions where visibility=public and path~"src/ai" and complexity>10
+//! classes implementing LlmProvider
+//! files with no dependents
+//! settings read by load_config order by name limit 20
+//! ```
+//!
+//! A query starts with a node kind (`files`, `functions`, `classes`, ...,
+//! or `nodes` for any kind) followed by clauses, optionally separated by
+//! `where` and `and`:
+//!
+//! - `<field> <op> <value>` with `=`, `!=`, `<`, `<=`, `>`, `>=`, `~`
+//!   (contains) or `!~`. Fields are node columns (`name`, `path`, `type`),
+//!   metric shorthands (`complexity`, `nesting`, `lines`), edge counts
+//!   (`dependents`, `callers`, ...) or any metadata key (`setting_kind`,
+//!   `metrics.max_nesting`).
+//! - `<relation> <name>`: `implementing`, `extending`, `calling`,
+//!   `importing`, `using`, `reading`, `rendering`, `testing`, and the
+//!   incoming forms `called by`, `imported by`, `used by`, ... The name
+//!   matches a node ID, a node name or the last segment of an edge target.
+//! - `with <count>` / `with no <count>`, short for `<count> > 0` / `= 0`.
+//! - `order by <field> [asc|desc]` and `limit <n>`.
+
+use rusqlite::types::{Value, ValueRef};
+use serde::Serialize;
+
+use super::Direction;
+use crate::types::{EdgeType, Result, WeaveError, enum_to_str};
+
+/// Node kinds a query can start with, singular and plural.
+const KINDS: &[(&str, &str)] = &[
+    ("file", "file"),
+    ("function", "function"),
+    ("method", "method"),
+    ("class", "class"),
+    ("interface", "interface"),
+    ("type", "type"),
+    ("enum", "enum"),
+    ("module", "module"),
+    ("api", "api"),
+    ("entity", "entity"),
+    ("entitie", "entity"),
+    ("component", "component"),
+    ("route", "route"),
+    ("config", "config"),
+    ("setting", "setting"),
+    ("package", "package"),
+];
+
+/// Relation words, their edge type and the direction they follow from the
+/// queried node. Incoming relations are written `<word> by`.
+const RELATIONS: &[(&str, EdgeType, Direction)] = &[
+    ("implementing", EdgeType::Implements, Direction::Outgoing),
+    ("extending", EdgeType::Extends, Direction::Outgoing),
+    ("calling", EdgeType::Calls, Direction::Outgoing),
+    ("importing", EdgeType::DependsOn, Direction::Outgoing),
+    ("using", EdgeType::UsesType, Direction::Outgoing),
+    ("reading", EdgeType::Reads, Direction::Outgoing),
+    ("rendering", EdgeType::Renders, Direction::Outgoing),
+    ("testing", EdgeType::Tests, Direction::Outgoing),
+    ("implemented", EdgeType::Implements, Direction::Incoming),
+    ("extended", EdgeType::Extends, Direction::Incoming),
+    ("called", EdgeType::Calls, Direction::Incoming),
+    ("imported", EdgeType::DependsOn, Direction::Incoming),
+    ("used", EdgeType::UsesType, Direction::Incoming),
+    ("read", EdgeType::Reads, Direction::Incoming),
+    ("rendered", EdgeType::Renders, Direction::Incoming),
+    ("tested", EdgeType::Tests, Direction::Incoming),
+];
+
+/// Countable edges, by the name of what is counted.
+const COUNTS: &[(&str, EdgeType, Direction)] = &[
+    ("dependents", EdgeType::DependsOn, Direction::Incoming),
+    ("dependencies", EdgeType::DependsOn, Direction::Outgoing),
+    ("callers", EdgeType::Calls, Direction::Incoming),
+    ("callees", EdgeType::Calls, Direction::Outgoing),
+    ("tests", EdgeType::Tests, Direction::Incoming),
+    ("readers", EdgeType::Reads, Direction::Incoming),
+    ("users", EdgeType::UsesType, Direction::Incoming),
+    ("implementors", EdgeType::Implements, Direction::Incoming),
+];
+
+/// Field shorthands for node columns and well-known metadata.
+const FIELDS: &[(&str, &str)] = &[
+    ("id", "n.id"),
+    ("name", "n.name"),
+    ("path", "n.path"),
+    ("type", "n.node_type"),
+    ("tier", "n.tier"),
+    ("status", "n.status"),
+    ("confidence", "n.confidence"),
+    ("line", "json_extract(n.evidence, '$.start_line')"),
+    ("visibility", "json_extract(n.metadata, '$.visibility')"),
+    ("async", "json_extract(n.metadata, '$.signature.async')"),
+    (
+        "returns",
+        "json_extract(n.metadata, '$.signature.return_type')",
+    ),
+    (
+        "complexity",
+        "json_extract(n.metadata, '$.metrics.cyclomatic')",
+    ),
+    (
+        "cyclomatic",
+        "json_extract(n.metadata, '$.metrics.cyclomatic')",
+    ),
+    (
+        "nesting",
+        "json_extract(n.metadata, '$.metrics.max_nesting')",
+    ),
+    (
+        "parameters",
+        "json_extract(n.metadata, '$.metrics.parameters')",
+    ),
+    ("lines", "json_extract(n.metadata, '$.metrics.lines')"),
+    (
+        "early_returns",
+        "json_extract(n.metadata, '$.metrics.early_returns')",
+    ),
+];
+
+/// Columns every result has.
+const BASE_COLUMNS: &[&str] = &["id", "type", "name", "path", "line"];
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Str(String),
+    Op(&'static str),
+}
+
+/// A parsed query, ready to run with [`super::GraphStore::query`].
+#[derive(Debug, Clone)]
+pub struct GraphQuery {
+    kind: Option<&'static str>,
+    /// SQL conditions joined with `AND`
+    filters: Vec<String>,
+    params: Vec<Value>,
+    /// Fields shown besides the base columns, with their SQL
+    columns: Vec<(String, String)>,
+    order: Option<(String, bool)>,
+    limit: Option<i64>,
+}
+
+/// Rows a query returned, with one name per column.
+#[derive(Debug, Clone, Serialize)]
+pub struct QueryRows {
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<serde_json::Value>>,
+}
+
+impl GraphQuery {
+    /// Whether `text` reads as a query rather than a node ID or path: it
+    /// starts with a node kind.
+    pub fn is_query(text: &str) -> bool {
+        text.split_whitespace()
+            .next()
+            .is_some_and(|word| word == "nodes" || kind(word).is_some())
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut tokens = tokenize(text)?.into_iter().peekable();
+        let kind = match tokens.next() {
+            Some(Token::Word(word)) if word == "nodes" => None,
+            Some(Token::Word(word)) => Some(kind(&word).ok_or_else(|| {
+                syntax(format!(
+                    "unknown node kind '{}', expected e.g. files, functions",
+                    word
+                ))
+            })?),
+            _ => return Err(syntax("a query starts with a node kind")),
+        };
+        let mut query = Self {
+            kind,
+            filters: Vec::new(),
+            params: Vec::new(),
+            columns: Vec::new(),
+            order: None,
+            limit: None,
+        };
+
+        while let Some(token) = tokens.next() {
+            let Token::Word(word) = token else {
+                return Err(syntax(format!("unexpected {}", describe(&token))));
+            };
+            match word.as_str() {
+                "where" | "and" => {}
+                "with" => {
+                    let mut count = expect_word(tokens.next(), "a count after 'with'")?;
+                    let none = count == "no";
+                    if none {
+                        count = expect_word(tokens.next(), "a count after 'with no'")?;
+                    }
+                    let sql = count_sql(&count).ok_or_else(|| {
+                        syntax(format!(
+                            "unknown count '{}', expected e.g. dependents",
+                            count
+                        ))
+                    })?;
+                    query
+                        .filters
+                        .push(format!("{} {}", sql, if none { "= 0" } else { "> 0" }));
+                    query.show(&count, sql);
+                }
+                "order" => {
+                    if expect_word(tokens.next(), "'by' after 'order'")? != "by" {
+                        return Err(syntax("expected 'by' after 'order'"));
+                    }
+                    let field = expect_word(tokens.next(), "a field after 'order by'")?;
+                    let sql = field_sql(&field)?;
+                    let descending = match tokens.peek() {
+                        Some(Token::Word(w)) if w == "desc" || w == "asc" => {
+                            let desc = w == "desc";
+                            tokens.next();
+                            desc
+                        }
+                        _ => false,
+                    };
+                    query.show(&field, sql.clone());
+                    query.order = Some((sql, descending));
+                }
+                "limit" => {
+                    let n = expect_word(tokens.next(), "a number after 'limit'")?;
+                    query.limit = Some(
+                        n.parse()
+                            .map_err(|_| syntax(format!("limit must be a number, got '{}'", n)))?,
+                    );
+                }
+                _ => {
+                    if let Some(&(_, edge_type, direction)) =
+                        RELATIONS.iter().find(|(name, _, _)| *name == word)
+                    {
+                        if direction == Direction::Incoming
+                            && expect_word(tokens.next(), &format!("'by' after '{}'", word))?
+                                != "by"
+                        {
+                            return Err(syntax(format!("expected 'by' after '{}'", word)));
+                        }
+                        let target = match tokens.next() {
+                            Some(Token::Word(w) | Token::Str(w)) => w,
+                            _ => return Err(syntax(format!("expected a name after '{}'", word))),
+                        };
+                        query.relation(edge_type, direction, target);
+                        continue;
+                    }
+
+                    let op = match tokens.next() {
+                        Some(Token::Op(op)) => op,
+                        _ => {
+                            return Err(syntax(format!(
+                                "expected an operator after '{}', or a relation such as 'implementing'",
+                                word
+                            )));
+                        }
+                    };
+                    let value = match tokens.next() {
+                        Some(Token::Word(w)) => literal(&w),
+                        Some(Token::Str(s)) => Value::Text(s),
+                        _ => {
+                            return Err(syntax(format!("expected a value after '{}{}'", word, op)));
+                        }
+                    };
+                    query.condition(&word, op, value)?;
+                }
+            }
+        }
+        Ok(query)
+    }
+
+    /// The SQL statement and its parameters.
+    pub fn to_sql(&self) -> (String, Vec<Value>) {
+        let mut sql = String::from(
+            "SELECT n.id, n.node_type, n.name, n.path, json_extract(n.evidence, '$.start_line')",
+        );
+        for (_, column) in &self.columns {
+            sql.push_str(", ");
+            sql.push_str(column);
+        }
+        sql.push_str(" FROM nodes n WHERE 1 = 1");
+        let mut params = Vec::new();
+        if let Some(kind) = self.kind {
+            sql.push_str(" AND n.node_type = ?");
+            params.push(Value::Text(kind.to_string()));
+        }
+        for filter in &self.filters {
+            sql.push_str(" AND ");
+            sql.push_str(filter);
+        }
+        params.extend(self.params.iter().cloned());
+
+        match &self.order {
+            Some((field, descending)) => sql.push_str(&format!(
+                " ORDER BY {} {}, n.path, n.name",
+                field,
+                if *descending { "DESC" } else { "ASC" }
+            )),
+            None => sql.push_str(" ORDER BY n.path, 5, n.name"),
+        }
+        if let Some(limit) = self.limit {
+            sql.push_str(" LIMIT ?");
+            params.push(Value::Integer(limit));
+        }
+        (sql, params)
+    }
+
+    /// Names of the result columns.
+    pub fn column_names(&self) -> Vec<String> {
+        BASE_COLUMNS
+            .iter()
+            .map(|c| c.to_string())
+            .chain(self.columns.iter().map(|(name, _)| name.clone()))
+            .collect()
+    }
+
+    /// Show `field` as a result column unless it already is one.
+    fn show(&mut self, field: &str, sql: String) {
+        if !BASE_COLUMNS.contains(&field) && !self.columns.iter().any(|(name, _)| name == field) {
+            self.columns.push((field.to_string(), sql));
+        }
+    }
+
+    fn condition(&mut self, field: &str, op: &str, value: Value) -> Result<()> {
+        let sql = field_sql(field)?;
+        let filter = match op {
+            "~" => format!("{} LIKE '%' || ? || '%'", sql),
+            "!~" => format!("{} NOT LIKE '%' || ? || '%'", sql),
+            // Scans store paths relative to the root (`./src/main.rs`)
+            "=" if field == "path" => {
+                self.params.push(value.clone());
+                format!("({} = ? OR {} = './' || ?)", sql, sql)
+            }
+            _ => format!("{} {} ?", sql, op),
+        };
+        self.params.push(value);
+        self.filters.push(filter);
+        self.show(field, sql);
+        Ok(())
+    }
+
+    fn relation(&mut self, edge_type: EdgeType, direction: Direction, target: String) {
+        let edge_type = enum_to_str(&edge_type);
+        let filter = match direction {
+            Direction::Outgoing => {
+                let mut filter = format!(
+                    "EXISTS (SELECT 1 FROM edges e LEFT JOIN nodes t ON t.id = e.target_id \
+                     WHERE e.source_id = n.id AND e.edge_type = '{}' AND (e.target_id = ? \
+                     OR t.name = ? OR substr(e.target_id, -length(?) - 1) = ':' || ?))",
+                    edge_type
+                );
+                self.params
+                    .extend(std::iter::repeat_n(Value::Text(target.clone()), 4));
+                // Parsers also record declared supertypes on the node
+                if edge_type == "implements" {
+                    filter = format!(
+                        "({} OR EXISTS (SELECT 1 FROM json_each(n.metadata, '$.implements') \
+                         WHERE value = ?))",
+                        filter
+                    );
+                    self.params.push(Value::Text(target));
+                } else if edge_type == "extends" {
+                    filter = format!("({} OR json_extract(n.metadata, '$.extends') = ?)", filter);
+                    self.params.push(Value::Text(target));
+                }
+                filter
+            }
+            Direction::Incoming => {
+                self.params
+                    .extend(std::iter::repeat_n(Value::Text(target), 2));
+                format!(
+                    "EXISTS (SELECT 1 FROM edges e JOIN nodes s ON s.id = e.source_id \
+                     WHERE e.target_id = n.id AND e.edge_type = '{}' AND (s.id = ? OR s.name = ?))",
+                    edge_type
+                )
+            }
+        };
+        self.filters.push(filter);
+    }
+}
+
+/// Convert a result cell to JSON.
+pub(super) fn cell(value: ValueRef) -> serde_json::Value {
+    match value {
+        ValueRef::Null => serde_json::Value::Null,
+        ValueRef::Integer(i) => i.into(),
+        ValueRef::Real(f) => f.into(),
+        ValueRef::Text(t) | ValueRef::Blob(t) => String::from_utf8_lossy(t).into_owned().into(),
+    }
+}
+
+fn kind(word: &str) -> Option<&'static str> {
+    let word = word.to_lowercase();
+    let singular = word
+        .strip_suffix("es")
+        .filter(|w| w.ends_with("ss") || w.ends_with("sh") || w.ends_with("ch"))
+        .or_else(|| word.strip_suffix('s'))
+        .unwrap_or(&word);
+    KINDS
+        .iter()
+        .find(|(name, _)| *name == singular || *name == word)
+        .map(|(_, node_type)| *node_type)
+}
+
+/// SQL for a field name.
+fn field_sql(field: &str) -> Result<String> {
+    if let Some((_, sql)) = FIELDS.iter().find(|(name, _)| *name == field) {
+        return Ok(sql.to_string());
+    }
+    if let Some(sql) = count_sql(field) {
+        return Ok(sql);
+    }
+    // Any other metadata key; checked so it can be inlined into the SQL
+    let valid = field.split('.').all(|part| {
+        part.chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    });
+    if !valid {
+        return Err(syntax(format!("invalid field '{}'", field)));
+    }
+    Ok(format!("json_extract(n.metadata, '$.{}')", field))
+}
+
+fn count_sql(count: &str) -> Option<String> {
+    let (_, edge_type, direction) = COUNTS.iter().find(|(name, _, _)| *name == count)?;
+    let column = match direction {
+        Direction::Incoming => "target_id",
+        Direction::Outgoing => "source_id",
+    };
+    Some(format!(
+        "(SELECT COUNT(*) FROM edges c WHERE c.{} = n.id AND c.edge_type = '{}')",
+        column,
+        enum_to_str(edge_type)
+    ))
+}
+
+/// A bare value: a boolean, a number or text.
+fn literal(word: &str) -> Value {
+    match word {
+        "true" => Value::Integer(1),
+        "false" => Value::Integer(0),
+        _ => word
+            .parse::<i64>()
+            .map(Value::Integer)
+            .or_else(|_| word.parse::<f64>().map(Value::Real))
+            .unwrap_or_else(|_| Value::Text(word.to_string())),
+    }
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>> {
+    const OPS: &[&str] = &["!=", ">=", "<=", "!~", "=", ">", "<", "~"];
+
+    let mut tokens = Vec::new();
+    let mut rest = text.trim_start();
+    while !rest.is_empty() {
+        if let Some(op) = OPS.iter().find(|op| rest.starts_with(**op)) {
+            tokens.push(Token::Op(op));
+            rest = &rest[op.len()..];
+        } else if let Some(quote) = rest.chars().next().filter(|c| *c == '"' || *c == '\'') {
+            let end = rest[1..]
+                .find(quote)
+                .ok_or_else(|| syntax("unterminated string"))?;
+            tokens.push(Token::Str(rest[1..=end].to_string()));
+            rest = &rest[end + 2..];
+        } else {
+            let end = rest
+                .find(|c: char| c.is_whitespace() || "=!<>~\"'".contains(c))
+                .unwrap_or(rest.len());
+            if end == 0 {
+                return Err(syntax(format!("unexpected '{}'", &rest[..1])));
+            }
+            tokens.push(Token::Word(rest[..end].to_string()));
+            rest = &rest[end..];
+        }
+        rest = rest.trim_start();
+    }
+    Ok(tokens)
+}
+
+fn expect_word(token: Option<Token>, expected: &str) -> Result<String> {
+    match token {
+        Some(Token::Word(word)) => Ok(word),
+        Some(other) => Err(syntax(format!(
+            "expected {}, got {}",
+            expected,
+            describe(&other)
+        ))),
+        None => Err(syntax(format!("expected {}", expected))),
+    }
+}
+
+fn describe(token: &Token) -> String {
+    match token {
+        Token::Word(w) => format!("'{}'", w),
+        Token::Str(s) => format!("\"{}\"", s),
+        Token::Op(op) => format!("'{}'", op),
+    }
+}
+
+fn syntax(message: impl Into<String>) -> WeaveError {
+    WeaveError::Config(format!("Invalid query: {}", message.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::graph_store::tests::{edge, node_in};
+    use crate::storage::{Database, GraphStore};
+    use crate::types::{Node, NodeType};
+
+    fn node(id: &str, node_type: NodeType, path: &str, name: &str, extra: &str) -> Node {
+        let mut node = Node {
+            node_type,
+            name: name.to_string(),
+            ..node_in(id, path)
+        };
+        if !extra.is_empty() {
+            node.metadata = serde_json::from_str(extra).unwrap();
+        }
+        node
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(GraphQuery::is_query("functions where complexity>10"));
+        assert!(GraphQuery::is_query("classes"));
+        assert!(!GraphQuery::is_query("src/main.rs"));
+
+        for bad in [
+            "gadgets",
+            "functions where complexity",
+            "functions where name=",
+            "functions order name",
+            "functions limit ten",
+            "functions called main",
+            "functions with nothing",
+            "functions where path~\"src",
+            "functions where a;b=1",
+        ] {
+            assert!(GraphQuery::parse(bad).is_err(), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn test_queries() {
+        let db = Database::open_in_memory().unwrap();
+        db.initialize().unwrap();
+        let store = GraphStore::new(&db);
+        let nodes = [
+            node(
+                "file:./src/ai/llm.rs",
+                NodeType::File,
+                "./src/ai/llm.rs",
+                "llm.rs",
+                "",
+            ),
+            node(
+                "file:./src/main.rs",
+                NodeType::File,
+                "./src/main.rs",
+                "main.rs",
+                "",
+            ),
+            node(
+                "function:./src/ai/llm.rs:complete",
+                NodeType::Function,
+                "./src/ai/llm.rs",
+                "complete",
+                r#"{"visibility": "public", "metrics": {"cyclomatic": 14, "max_nesting": 3}}"#,
+            ),
+            node(
+                "function:./src/ai/llm.rs:retry",
+                NodeType::Function,
+                "./src/ai/llm.rs",
+                "retry",
+                r#"{"visibility": "private", "metrics": {"cyclomatic": 12, "max_nesting": 2}}"#,
+            ),
+            node(
+                "function:./src/main.rs:main",
+                NodeType::Function,
+                "./src/main.rs",
+                "main",
+                r#"{"visibility": "public", "metrics": {"cyclomatic": 2, "max_nesting": 1}}"#,
+            ),
+            node(
+                "class:./src/ai/llm.rs:OpenAi",
+                NodeType::Class,
+                "./src/ai/llm.rs",
+                "OpenAi",
+                "",
+            ),
+        ];
+        for n in &nodes {
+            store.insert_node(n).unwrap();
+        }
+        for e in [
+            edge(
+                EdgeType::DependsOn,
+                "file:./src/main.rs",
+                "file:./src/ai/llm.rs",
+            ),
+            edge(
+                EdgeType::Implements,
+                "class:./src/ai/llm.rs:OpenAi",
+                "trait:LlmProvider",
+            ),
+            edge(
+                EdgeType::Calls,
+                "function:./src/main.rs:main",
+                "function:./src/ai/llm.rs:complete",
+            ),
+        ] {
+            store.insert_edge(&e).unwrap();
+        }
+        let names = |text: &str| -> Vec<String> {
+            let rows = store.query(&GraphQuery::parse(text).unwrap()).unwrap();
+            rows.rows
+                .iter()
+                .map(|r| r[2].as_str().unwrap().to_string())
+                .collect()
+        };
+
+        assert_eq!(
+            names(r#"functions where visibility=public and path~"src/ai" and complexity>10"#),
+            vec!["complete"]
+        );
+        assert_eq!(names("classes implementing LlmProvider"), vec!["OpenAi"]);
+        assert_eq!(names("files with no dependents"), vec!["main.rs"]);
+        assert_eq!(names("file where path=src/main.rs"), vec!["main.rs"]);
+        assert_eq!(names("functions called by main"), vec!["complete"]);
+        assert_eq!(
+            names("functions with no callers and complexity >= 12"),
+            vec!["retry"]
+        );
+        assert_eq!(
+            names("functions order by complexity desc limit 2"),
+            vec!["complete", "retry"]
+        );
+        assert_eq!(names("nodes where name~Open"), vec!["OpenAi"]);
+
+        let rows = store
+            .query(
+                &GraphQuery::parse("functions where nesting>1 order by metrics.max_nesting")
+                    .unwrap(),
+            )
+            .unwrap();
+        assert_eq!(
+            rows.columns,
+            vec![
+                "id",
+                "type",
+                "name",
+                "path",
+                "line",
+                "nesting",
+                "metrics.max_nesting"
+            ]
+        );
+        assert_eq!(rows.rows.len(), 2);
+        assert_eq!(rows.rows[0][5], serde_json::json!(2));
+    }
+}
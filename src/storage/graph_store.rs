@@ -1,81 +1,64 @@
-use rusqlite::params;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use rusqlite::{Connection, params};
+use serde::Serialize;
 
 use super::Database;
-use crate::types::{Edge, Node, ParseWithDefault, Result, enum_to_str, log_filter_error};
+use super::graph_query::{GraphQuery, QueryRows, cell};
+use super::search::{self, SearchHit};
+use crate::types::{
+    Edge, EdgeType, EvidenceLocation, Node, ParseWithDefault, Result, enum_to_str, log_filter_error,
+};
 
 pub struct GraphStore<'a> {
     db: &'a Database,
 }
 
+/// An edge between two stored nodes, with the files declaring them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkedEdge {
+    pub source_id: String,
+    pub source_path: String,
+    pub target_id: String,
+    pub target_path: String,
+}
+
+/// Which way a traversal follows edges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// From source to target: what a node depends on
+    Outgoing,
+    /// From target to source: what depends on a node
+    Incoming,
+}
+
+/// A node or file a traversal reached, `depth` edges away from its start.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Reached {
+    pub id: String,
+    pub depth: u32,
+}
+
+/// One edge of a path, with where its source declares it.
+#[derive(Debug, Clone, Serialize)]
+pub struct Hop {
+    pub from: String,
+    pub to: String,
+    pub edge_type: EdgeType,
+    pub evidence: EvidenceLocation,
+}
+
 impl<'a> GraphStore<'a> {
     pub fn new(db: &'a Database) -> Self {
         Self { db }
     }
 
     pub fn insert_node(&self, node: &Node) -> Result<()> {
-        let metadata = serde_json::to_string(&node.metadata)?;
-        let evidence = serde_json::to_string(&node.evidence)?;
-
-        self.db.execute(
-            r#"
-            INSERT INTO nodes (id, node_type, path, name, metadata, evidence, tier, confidence, last_verified, status)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
-            ON CONFLICT(id) DO UPDATE SET
-                node_type = excluded.node_type,
-                path = excluded.path,
-                name = excluded.name,
-                metadata = excluded.metadata,
-                evidence = excluded.evidence,
-                tier = excluded.tier,
-                confidence = excluded.confidence,
-                last_verified = excluded.last_verified,
-                status = excluded.status,
-                updated_at = CURRENT_TIMESTAMP
-            "#,
-            &[
-                &node.id,
-                &enum_to_str(&node.node_type),
-                &node.path,
-                &node.name,
-                &metadata,
-                &evidence,
-                &enum_to_str(&node.tier),
-                &node.confidence,
-                &node.last_verified.to_rfc3339(),
-                &enum_to_str(&node.status),
-            ],
-        )?;
-        Ok(())
+        upsert_node(&*self.db.connection()?, node)
     }
 
     pub fn insert_edge(&self, edge: &Edge) -> Result<()> {
-        let metadata = serde_json::to_string(&edge.metadata)?;
-        let evidence = serde_json::to_string(&edge.evidence)?;
-
-        self.db.execute(
-            r#"
-            INSERT INTO edges (id, edge_type, source_id, target_id, metadata, evidence, tier, confidence, last_verified)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
-            ON CONFLICT(edge_type, source_id, target_id) DO UPDATE SET
-                metadata = excluded.metadata,
-                evidence = excluded.evidence,
-                tier = excluded.tier,
-                confidence = excluded.confidence,
-                last_verified = excluded.last_verified
-            "#,
-            &[
-                &edge.id,
-                &enum_to_str(&edge.edge_type),
-                &edge.source_id,
-                &edge.target_id,
-                &metadata,
-                &evidence,
-                &enum_to_str(&edge.tier),
-                &edge.confidence,
-                &edge.last_verified.to_rfc3339(),
-            ],
-        )?;
-        Ok(())
+        upsert_edge(&*self.db.connection()?, edge)
     }
 
     pub fn get_node(&self, id: &str) -> Result<Option<Node>> {
@@ -121,10 +104,369 @@ impl<'a> GraphStore<'a> {
         Ok(ids)
     }
 
-    /// Clear all nodes and edges from the graph
+    /// Nodes whose signature or fields mention the type `node_id`.
+    pub fn get_type_users(&self, node_id: &str) -> Result<Vec<String>> {
+        let conn = self.db.connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT source_id FROM edges WHERE target_id = ?1 AND edge_type = 'uses_type' ORDER BY source_id",
+        )?;
+
+        let ids: Vec<String> = stmt
+            .query_map(params![node_id], |row| row.get(0))?
+            .filter_map(|r| log_filter_error(r, "reading type user"))
+            .collect();
+
+        Ok(ids)
+    }
+
+    /// Every node reachable from `start` over edges of `edge_types` within
+    /// `max_depth` hops, nearest first. Each node is reported once, at its
+    /// shortest distance, so cycles end the walk instead of repeating it.
+    /// Edge targets that are not stored nodes (unresolved imports) are
+    /// reached but lead nowhere.
+    pub fn traverse(
+        &self,
+        start: &str,
+        direction: Direction,
+        edge_types: &[EdgeType],
+        max_depth: u32,
+    ) -> Result<Vec<Reached>> {
+        let conn = self.db.connection()?;
+        let mut stmt = conn.prepare(match direction {
+            Direction::Outgoing => {
+                "SELECT target_id FROM edges WHERE source_id = ?1 AND edge_type = ?2"
+            }
+            Direction::Incoming => {
+                "SELECT source_id FROM edges WHERE target_id = ?1 AND edge_type = ?2"
+            }
+        })?;
+
+        breadth_first(start, max_depth, |id| {
+            let mut next = Vec::new();
+            for edge_type in edge_types {
+                next.extend(
+                    stmt.query_map(params![id, enum_to_str(edge_type)], |row| row.get(0))?
+                        .filter_map(|r| log_filter_error(r, "reading neighbour")),
+                );
+            }
+            Ok(next)
+        })
+    }
+
+    /// Files a change to `path` can affect: files with a symbol that has an
+    /// edge of `edge_types` into a symbol of `path`, then the files depending
+    /// on those, up to `max_depth` hops. Each file is reported once, at its
+    /// shortest distance.
+    pub fn get_impacted_files(
+        &self,
+        path: &str,
+        edge_types: &[EdgeType],
+        max_depth: u32,
+    ) -> Result<Vec<Reached>> {
+        let conn = self.db.connection()?;
+        let mut stmt = conn.prepare(
+            r#"SELECT DISTINCT s.path FROM nodes t
+               JOIN edges e ON e.target_id = t.id
+               JOIN nodes s ON s.id = e.source_id
+               WHERE t.path = ?1 AND e.edge_type = ?2 AND s.path != ?1"#,
+        )?;
+
+        breadth_first(path, max_depth, |file| {
+            let mut next = Vec::new();
+            for edge_type in edge_types {
+                next.extend(
+                    stmt.query_map(params![file, enum_to_str(edge_type)], |row| row.get(0))?
+                        .filter_map(|r| log_filter_error(r, "reading impacted file")),
+                );
+            }
+            Ok(next)
+        })
+    }
+
+    /// Fewest-hop path from `from` to `to` over edges of `edge_types`, no
+    /// longer than `max_depth`. A file node stands for every symbol it
+    /// declares, so a path may leave from any symbol of a `from` file and
+    /// ends at the first symbol of a `to` file it reaches.
+    pub fn shortest_path(
+        &self,
+        from: &str,
+        to: &str,
+        edge_types: &[EdgeType],
+        max_depth: u32,
+    ) -> Result<Option<Vec<Hop>>> {
+        let conn = self.db.connection()?;
+        let starts = path_ends(&conn, from)?;
+        let goal = Goal::new(&conn, to)?;
+
+        let mut seen: HashSet<String> = starts.iter().cloned().collect();
+        let mut queue: VecDeque<(String, u32)> = starts.into_iter().map(|s| (s, 0)).collect();
+        let mut parents: HashMap<String, Hop> = HashMap::new();
+        while let Some((id, depth)) = queue.pop_front() {
+            if depth == max_depth {
+                continue;
+            }
+            for (hop, path) in out_hops(&conn, &id, edge_types)? {
+                if !seen.insert(hop.to.clone()) {
+                    continue;
+                }
+                let target = hop.to.clone();
+                let arrived = goal.is_reached(&target, path.as_deref());
+                parents.insert(target.clone(), hop);
+                if arrived {
+                    let mut hops = Vec::new();
+                    let mut current = target;
+                    while let Some(hop) = parents.remove(&current) {
+                        current = hop.from.clone();
+                        hops.push(hop);
+                    }
+                    hops.reverse();
+                    return Ok(Some(hops));
+                }
+                queue.push_back((target, depth + 1));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Paths from `from` to `to` over edges of `edge_types` that visit no
+    /// node twice, each at most `max_depth` hops long. The search stops
+    /// after `limit` paths; they are returned shortest first. Endpoints
+    /// that are files stand for their symbols as in [`Self::shortest_path`].
+    pub fn simple_paths(
+        &self,
+        from: &str,
+        to: &str,
+        edge_types: &[EdgeType],
+        max_depth: u32,
+        limit: usize,
+    ) -> Result<Vec<Vec<Hop>>> {
+        let conn = self.db.connection()?;
+        let starts = path_ends(&conn, from)?;
+        let mut walk = PathWalk {
+            conn: &conn,
+            edge_types,
+            goal: Goal::new(&conn, to)?,
+            max_depth,
+            limit,
+            out: HashMap::new(),
+            // Leaving a `from` file and coming back only lengthens a path
+            on_path: starts.iter().cloned().collect(),
+            hops: Vec::new(),
+            paths: Vec::new(),
+        };
+        for start in &starts {
+            walk.visit(start)?;
+        }
+
+        let mut paths = walk.paths;
+        paths.sort_by_key(Vec::len);
+        Ok(paths)
+    }
+
+    /// Nodes whose name, path, signature or description match `term`,
+    /// best first. See [`super::search`] for the ranking.
+    pub fn search(&self, term: &str, limit: usize) -> Result<Vec<SearchHit>> {
+        search::search(&*self.db.connection()?, term, limit)
+    }
+
+    /// Run a query written in the graph query language.
+    pub fn query(&self, query: &GraphQuery) -> Result<QueryRows> {
+        let (sql, params) = query.to_sql();
+        let columns = query.column_names();
+        let conn = self.db.connection()?;
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt
+            .query_map(rusqlite::params_from_iter(params), |row| {
+                (0..columns.len())
+                    .map(|i| Ok(cell(row.get_ref(i)?)))
+                    .collect()
+            })?
+            .filter_map(|r| log_filter_error(r, "reading query row"))
+            .collect();
+
+        Ok(QueryRows { columns, rows })
+    }
+
+    /// Get every node declared in `path`.
+    pub fn get_nodes_by_path(&self, path: &str) -> Result<Vec<Node>> {
+        let conn = self.db.connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, node_type, path, name, metadata, evidence, tier, confidence, last_verified, status FROM nodes WHERE path = ?1"
+        )?;
+
+        let nodes: Vec<Node> = stmt
+            .query_map(params![path], |row| Ok(self.row_to_node(row)))?
+            .filter_map(|r| log_filter_error(r, "reading node"))
+            .filter_map(|r| log_filter_error(r, "decoding node"))
+            .collect();
+
+        Ok(nodes)
+    }
+
+    /// Edges of the given types whose source and target are both nodes of
+    /// source code. Packages, configuration and settings are not part of
+    /// the project's own code, and their nodes take the path of a manifest
+    /// or config file, so edges into them are left out.
+    pub fn get_linked_edges(&self, edge_types: &[EdgeType]) -> Result<Vec<LinkedEdge>> {
+        let conn = self.db.connection()?;
+        let mut stmt = conn.prepare(
+            r#"SELECT s.id, s.path, t.id, t.path FROM edges e
+               JOIN nodes s ON s.id = e.source_id
+               JOIN nodes t ON t.id = e.target_id
+               WHERE e.edge_type = ?1
+                 AND t.node_type NOT IN ('package', 'config', 'setting')"#,
+        )?;
+
+        let mut edges = Vec::new();
+        for edge_type in edge_types {
+            edges.extend(
+                stmt.query_map(params![enum_to_str(edge_type)], |row| {
+                    Ok(LinkedEdge {
+                        source_id: row.get(0)?,
+                        source_path: row.get(1)?,
+                        target_id: row.get(2)?,
+                        target_path: row.get(3)?,
+                    })
+                })?
+                .filter_map(|r| log_filter_error(r, "reading linked edge")),
+            );
+        }
+
+        Ok(edges)
+    }
+
+    /// Content hash of every file as of the build that last stored it.
+    pub fn get_file_hashes(&self) -> Result<HashMap<String, String>> {
+        let conn = self.db.connection()?;
+        let mut stmt = conn.prepare("SELECT path, content_hash FROM graph_files")?;
+
+        let hashes = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .filter_map(|r| log_filter_error(r, "reading file hash"))
+            .collect();
+
+        Ok(hashes)
+    }
+
+    /// Files other than `paths` that own an edge into a node of `paths`.
+    pub fn get_dependent_files(&self, paths: &[String]) -> Result<HashSet<String>> {
+        let conn = self.db.connection()?;
+        let mut stmt = conn.prepare(
+            r#"SELECT DISTINCT s.path FROM nodes t
+               JOIN edges e ON e.target_id = t.id
+               JOIN nodes s ON s.id = e.source_id
+               WHERE t.path = ?1 AND s.path != ?1"#,
+        )?;
+
+        let mut files = HashSet::new();
+        for path in paths {
+            files.extend(
+                stmt.query_map(params![path], |row| row.get::<_, String>(0))?
+                    .filter_map(|r| log_filter_error(r, "reading dependent file")),
+            );
+        }
+        for path in paths {
+            files.remove(path);
+        }
+
+        Ok(files)
+    }
+
+    /// Files with an import that did not resolve to a node when they were
+    /// last built.
+    pub fn get_files_with_unresolved_imports(&self) -> Result<HashSet<String>> {
+        let conn = self.db.connection()?;
+        let mut stmt = conn.prepare(
+            r#"SELECT DISTINCT s.path FROM edges e
+               JOIN nodes s ON s.id = e.source_id
+               WHERE e.edge_type = 'depends_on'
+                 AND NOT EXISTS (SELECT 1 FROM nodes t WHERE t.id = e.target_id)"#,
+        )?;
+
+        let files = stmt
+            .query_map([], |row| row.get(0))?
+            .filter_map(|r| log_filter_error(r, "reading file"))
+            .collect();
+
+        Ok(files)
+    }
+
+    /// Replace what the graph holds for a set of files in one transaction.
+    ///
+    /// Nodes of every `stale` path are deleted together with the edges
+    /// leaving them, then `nodes` and `edges` are upserted and `hashes`
+    /// recorded. Edges entering a stale file come from files that need not
+    /// have been re-parsed, so they are kept while their target exists,
+    /// follow `moved` symbols as `(old ID, new ID)` to their new ID, and are
+    /// dropped otherwise. Stale paths without a new hash are forgotten, and
+    /// the stored analysis of `moved_files`, as `(old path, new path)`,
+    /// follows them to their new path.
+    pub fn replace_files(
+        &self,
+        stale: &[String],
+        moved: &[(String, String)],
+        moved_files: &[(String, String)],
+        hashes: &[(String, String)],
+        nodes: &[&Node],
+        edges: &[&Edge],
+    ) -> Result<()> {
+        self.db.transaction(|conn| {
+            let mut previous: Vec<String> = Vec::new();
+            for path in stale {
+                let mut stmt = conn.prepare_cached("SELECT id FROM nodes WHERE path = ?1")?;
+                previous.extend(
+                    stmt.query_map(params![path], |row| row.get::<_, String>(0))?
+                        .filter_map(|r| log_filter_error(r, "reading stale node")),
+                );
+                conn.execute(
+                    "DELETE FROM node_search WHERE rowid IN (SELECT rowid FROM nodes WHERE path = ?1)",
+                    params![path],
+                )?;
+                conn.execute(
+                    "DELETE FROM edges WHERE source_id IN (SELECT id FROM nodes WHERE path = ?1)",
+                    params![path],
+                )?;
+                conn.execute("DELETE FROM nodes WHERE path = ?1", params![path])?;
+                conn.execute("DELETE FROM graph_files WHERE path = ?1", params![path])?;
+            }
+            for node in nodes {
+                upsert_node(conn, node)?;
+            }
+            for edge in edges {
+                upsert_edge(conn, edge)?;
+            }
+            for (old, new) in moved {
+                conn.execute(
+                    "UPDATE OR IGNORE edges SET target_id = ?2 WHERE target_id = ?1",
+                    params![old, new],
+                )?;
+            }
+            for id in &previous {
+                conn.execute(
+                    r#"DELETE FROM edges WHERE target_id = ?1
+                       AND NOT EXISTS (SELECT 1 FROM nodes WHERE id = ?1)"#,
+                    params![id],
+                )?;
+            }
+            for (path, hash) in hashes {
+                conn.execute(
+                    r#"INSERT INTO graph_files (path, content_hash) VALUES (?1, ?2)
+                       ON CONFLICT(path) DO UPDATE SET
+                           content_hash = excluded.content_hash,
+                           updated_at = CURRENT_TIMESTAMP"#,
+                    params![path, hash],
+                )?;
+            }
+            super::database::move_file_records(conn, moved_files)
+        })
+    }
+
+    /// Clear all nodes, edges, file hashes and the search index from the graph
     pub fn clear(&self) -> Result<()> {
         self.db.execute("DELETE FROM edges", &[])?;
+        self.db.execute("DELETE FROM node_search", &[])?;
         self.db.execute("DELETE FROM nodes", &[])?;
+        self.db.execute("DELETE FROM graph_files", &[])?;
         Ok(())
     }
 
@@ -173,8 +515,245 @@ impl<'a> GraphStore<'a> {
     }
 }
 
+/// Where a path search ends: the node `to`, or any symbol of it when it is
+/// a file.
+struct Goal {
+    id: String,
+    file: Option<String>,
+}
+
+impl Goal {
+    fn new(conn: &Connection, id: &str) -> Result<Self> {
+        Ok(Self {
+            id: id.to_string(),
+            file: file_path(conn, id)?,
+        })
+    }
+
+    fn is_reached(&self, id: &str, path: Option<&str>) -> bool {
+        id == self.id || (self.file.is_some() && self.file.as_deref() == path)
+    }
+}
+
+/// Depth-first enumeration of simple paths for [`GraphStore::simple_paths`].
+struct PathWalk<'c, 't> {
+    conn: &'c Connection,
+    edge_types: &'t [EdgeType],
+    goal: Goal,
+    max_depth: u32,
+    limit: usize,
+    /// Outgoing hops by node, loaded once each
+    out: HashMap<String, Vec<(Hop, Option<String>)>>,
+    on_path: HashSet<String>,
+    hops: Vec<Hop>,
+    paths: Vec<Vec<Hop>>,
+}
+
+impl PathWalk<'_, '_> {
+    fn visit(&mut self, id: &str) -> Result<()> {
+        if self.paths.len() >= self.limit || self.hops.len() as u32 >= self.max_depth {
+            return Ok(());
+        }
+        if !self.out.contains_key(id) {
+            let hops = out_hops(self.conn, id, self.edge_types)?;
+            self.out.insert(id.to_string(), hops);
+        }
+
+        for (hop, path) in self.out[id].clone() {
+            if self.paths.len() >= self.limit {
+                break;
+            }
+            if self.on_path.contains(&hop.to) {
+                continue;
+            }
+            let target = hop.to.clone();
+            let arrived = self.goal.is_reached(&target, path.as_deref());
+            self.hops.push(hop);
+            if arrived {
+                self.paths.push(self.hops.clone());
+            } else {
+                self.on_path.insert(target.clone());
+                self.visit(&target)?;
+                self.on_path.remove(&target);
+            }
+            self.hops.pop();
+        }
+        Ok(())
+    }
+}
+
+/// Path of `id` if it is a file node.
+fn file_path(conn: &Connection, id: &str) -> Result<Option<String>> {
+    match conn.query_row(
+        "SELECT path FROM nodes WHERE id = ?1 AND node_type = 'file'",
+        params![id],
+        |row| row.get(0),
+    ) {
+        Ok(path) => Ok(Some(path)),
+        Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Nodes a path search leaves from: `id` itself first, then the symbols it
+/// declares when it is a file.
+fn path_ends(conn: &Connection, id: &str) -> Result<Vec<String>> {
+    let mut ends = vec![id.to_string()];
+    if let Some(path) = file_path(conn, id)? {
+        let mut stmt =
+            conn.prepare("SELECT id FROM nodes WHERE path = ?1 AND id != ?2 ORDER BY id")?;
+        ends.extend(
+            stmt.query_map(params![path, id], |row| row.get(0))?
+                .filter_map(|r| log_filter_error(r, "reading file symbol")),
+        );
+    }
+    Ok(ends)
+}
+
+/// Edges of `edge_types` leaving `id`, with the path of each target node
+/// (`None` for unresolved targets).
+fn out_hops(
+    conn: &Connection,
+    id: &str,
+    edge_types: &[EdgeType],
+) -> Result<Vec<(Hop, Option<String>)>> {
+    let mut stmt = conn.prepare_cached(
+        r#"SELECT e.target_id, e.edge_type, e.evidence, t.path FROM edges e
+           LEFT JOIN nodes t ON t.id = e.target_id
+           WHERE e.source_id = ?1 ORDER BY e.target_id"#,
+    )?;
+    let rows = stmt
+        .query_map(params![id], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, Option<String>>(3)?,
+            ))
+        })?
+        .filter_map(|r| log_filter_error(r, "reading outgoing edge"));
+
+    let mut hops = Vec::new();
+    for (target, edge_type, evidence, path) in rows {
+        let Some(edge_type) = edge_types
+            .iter()
+            .find(|t| enum_to_str(*t) == edge_type)
+            .copied()
+        else {
+            continue;
+        };
+        let evidence =
+            serde_json::from_str(&evidence).unwrap_or_else(|_| EvidenceLocation::empty());
+        hops.push((
+            Hop {
+                from: id.to_string(),
+                to: target,
+                edge_type,
+                evidence,
+            },
+            path,
+        ));
+    }
+    Ok(hops)
+}
+
+/// Breadth-first walk from `start`, asking `next` for the neighbours of
+/// each node. Results are ordered by depth, then ID, and exclude `start`.
+fn breadth_first<F>(start: &str, max_depth: u32, mut next: F) -> Result<Vec<Reached>>
+where
+    F: FnMut(&str) -> Result<Vec<String>>,
+{
+    let mut seen: HashSet<String> = HashSet::from([start.to_string()]);
+    let mut queue = VecDeque::from([(start.to_string(), 0)]);
+    let mut reached = Vec::new();
+
+    while let Some((id, depth)) = queue.pop_front() {
+        if depth == max_depth {
+            continue;
+        }
+        for neighbour in next(&id)? {
+            if seen.insert(neighbour.clone()) {
+                reached.push(Reached {
+                    id: neighbour.clone(),
+                    depth: depth + 1,
+                });
+                queue.push_back((neighbour, depth + 1));
+            }
+        }
+    }
+
+    reached.sort_by(|a, b| a.depth.cmp(&b.depth).then_with(|| a.id.cmp(&b.id)));
+    Ok(reached)
+}
+
+fn upsert_node(conn: &Connection, node: &Node) -> Result<()> {
+    let metadata = serde_json::to_string(&node.metadata)?;
+    let evidence = serde_json::to_string(&node.evidence)?;
+
+    conn.execute(
+        r#"
+        INSERT INTO nodes (id, node_type, path, name, metadata, evidence, tier, confidence, last_verified, status)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+        ON CONFLICT(id) DO UPDATE SET
+            node_type = excluded.node_type,
+            path = excluded.path,
+            name = excluded.name,
+            metadata = excluded.metadata,
+            evidence = excluded.evidence,
+            tier = excluded.tier,
+            confidence = excluded.confidence,
+            last_verified = excluded.last_verified,
+            status = excluded.status,
+            updated_at = CURRENT_TIMESTAMP
+        "#,
+        params![
+            node.id,
+            enum_to_str(&node.node_type),
+            node.path,
+            node.name,
+            metadata,
+            evidence,
+            enum_to_str(&node.tier),
+            node.confidence,
+            node.last_verified.to_rfc3339(),
+            enum_to_str(&node.status),
+        ],
+    )?;
+    search::index_node(conn, node)
+}
+
+fn upsert_edge(conn: &Connection, edge: &Edge) -> Result<()> {
+    let metadata = serde_json::to_string(&edge.metadata)?;
+    let evidence = serde_json::to_string(&edge.evidence)?;
+
+    conn.execute(
+        r#"
+        INSERT INTO edges (id, edge_type, source_id, target_id, metadata, evidence, tier, confidence, last_verified)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+        ON CONFLICT(edge_type, source_id, target_id) DO UPDATE SET
+            metadata = excluded.metadata,
+            evidence = excluded.evidence,
+            tier = excluded.tier,
+            confidence = excluded.confidence,
+            last_verified = excluded.last_verified
+        "#,
+        params![
+            edge.id,
+            enum_to_str(&edge.edge_type),
+            edge.source_id,
+            edge.target_id,
+            metadata,
+            evidence,
+            enum_to_str(&edge.tier),
+            edge.confidence,
+            edge.last_verified.to_rfc3339(),
+        ],
+    )?;
+    Ok(())
+}
+
 #[cfg(test)]
-mod tests {
+pub(super) mod tests {
     use super::*;
     use crate::types::node::*;
 
@@ -249,4 +828,257 @@ mod tests {
 
         assert_eq!(retrieved.name, "updated");
     }
+
+    /// File node `id` declared in `path`, shared with the query tests.
+    pub(in crate::storage) fn node_in(id: &str, path: &str) -> Node {
+        Node {
+            path: path.to_string(),
+            ..create_test_node(id)
+        }
+    }
+
+    pub(in crate::storage) fn edge(edge_type: EdgeType, source: &str, target: &str) -> Edge {
+        Edge {
+            id: format!("{}->{}", source, target),
+            edge_type,
+            source_id: source.to_string(),
+            target_id: target.to_string(),
+            metadata: crate::types::EdgeMetadata::default(),
+            evidence: create_test_node(source).evidence,
+            tier: InformationTier::Fact,
+            confidence: 1.0,
+            last_verified: chrono::Utc::now(),
+        }
+    }
+
+    fn reached(items: &[(&str, u32)]) -> Vec<Reached> {
+        items
+            .iter()
+            .map(|(id, depth)| Reached {
+                id: id.to_string(),
+                depth: *depth,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_traverse_and_impact() {
+        let db = Database::open_in_memory().expect("Failed to open database");
+        db.initialize().expect("Failed to initialize");
+        let store = GraphStore::new(&db);
+
+        // api -> service -> db -> service (cycle); cli calls into db
+        for (id, path) in [
+            ("file:api.rs", "api.rs"),
+            ("file:service.rs", "service.rs"),
+            ("file:db.rs", "db.rs"),
+            ("function:db.rs:query", "db.rs"),
+            ("function:cli.rs:main", "cli.rs"),
+        ] {
+            store.insert_node(&node_in(id, path)).unwrap();
+        }
+        for e in [
+            edge(EdgeType::DependsOn, "file:api.rs", "file:service.rs"),
+            edge(EdgeType::DependsOn, "file:service.rs", "file:db.rs"),
+            edge(EdgeType::DependsOn, "file:db.rs", "file:service.rs"),
+            edge(EdgeType::DependsOn, "file:api.rs", "module:serde"),
+            edge(
+                EdgeType::Calls,
+                "function:cli.rs:main",
+                "function:db.rs:query",
+            ),
+        ] {
+            store.insert_edge(&e).unwrap();
+        }
+
+        let deps = [EdgeType::DependsOn];
+        assert_eq!(
+            store
+                .traverse("file:api.rs", Direction::Outgoing, &deps, 10)
+                .unwrap(),
+            reached(&[
+                ("file:service.rs", 1),
+                ("module:serde", 1),
+                ("file:db.rs", 2)
+            ])
+        );
+        assert_eq!(
+            store
+                .traverse("file:db.rs", Direction::Incoming, &deps, 1)
+                .unwrap(),
+            reached(&[("file:service.rs", 1)])
+        );
+
+        assert_eq!(
+            store.get_impacted_files("db.rs", &deps, 10).unwrap(),
+            reached(&[("service.rs", 1), ("api.rs", 2)])
+        );
+        assert_eq!(
+            store
+                .get_impacted_files("db.rs", &[EdgeType::DependsOn, EdgeType::Calls], 10)
+                .unwrap(),
+            reached(&[("cli.rs", 1), ("service.rs", 1), ("api.rs", 2)])
+        );
+        assert!(
+            store
+                .get_impacted_files("api.rs", &deps, 10)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_replace_files_moves_file_records() {
+        let db = Database::open_in_memory().expect("Failed to open database");
+        db.initialize().expect("Failed to initialize");
+        let store = GraphStore::new(&db);
+
+        db.execute(
+            "INSERT INTO doc_sessions (id, project_path, status, started_at) VALUES ('s1', '.', 'running', '2026-01-01')",
+            &[],
+        )
+        .unwrap();
+        db.execute(
+            r#"INSERT INTO file_tracking (session_id, file_path, content_hash, line_count, discovered_at)
+               VALUES ('s1', './src/old.rs', 'h', 1, '2026-01-01')"#,
+            &[],
+        )
+        .unwrap();
+
+        let new = node_in("file:./src/new.rs", "./src/new.rs");
+        store
+            .replace_files(
+                &["./src/old.rs".to_string()],
+                &[],
+                &[("./src/old.rs".to_string(), "./src/new.rs".to_string())],
+                &[("./src/new.rs".to_string(), "h".to_string())],
+                &[&new],
+                &[],
+            )
+            .unwrap();
+
+        let conn = db.connection().unwrap();
+        let path: String = conn
+            .query_row("SELECT file_path FROM file_tracking", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(path, "./src/new.rs");
+    }
+
+    #[test]
+    fn test_linked_edges_skip_packages_and_config() {
+        let db = Database::open_in_memory().expect("Failed to open database");
+        db.initialize().expect("Failed to initialize");
+        let store = GraphStore::new(&db);
+
+        for (id, node_type, path) in [
+            ("file:a.ts", NodeType::File, "a.ts"),
+            ("file:b.ts", NodeType::File, "b.ts"),
+            ("external:npm:react", NodeType::Package, "package.json"),
+            ("config:tsconfig.json", NodeType::Config, "tsconfig.json"),
+        ] {
+            store
+                .insert_node(&Node {
+                    node_type,
+                    ..node_in(id, path)
+                })
+                .unwrap();
+        }
+        for e in [
+            edge(EdgeType::DependsOn, "file:a.ts", "file:b.ts"),
+            edge(EdgeType::DependsOn, "file:a.ts", "external:npm:react"),
+            edge(EdgeType::DependsOn, "file:b.ts", "config:tsconfig.json"),
+        ] {
+            store.insert_edge(&e).unwrap();
+        }
+
+        let linked: Vec<(String, String)> = store
+            .get_linked_edges(&[EdgeType::DependsOn])
+            .unwrap()
+            .into_iter()
+            .map(|e| (e.source_path, e.target_path))
+            .collect();
+        assert_eq!(linked, vec![("a.ts".to_string(), "b.ts".to_string())]);
+    }
+
+    #[test]
+    fn test_paths_between_files() {
+        let db = Database::open_in_memory().expect("Failed to open database");
+        db.initialize().expect("Failed to initialize");
+        let store = GraphStore::new(&db);
+
+        for (id, path) in [
+            ("file:a.rs", "a.rs"),
+            ("file:b.rs", "b.rs"),
+            ("file:c.rs", "c.rs"),
+            ("function:a.rs:run", "a.rs"),
+            ("function:b.rs:query", "b.rs"),
+            ("function:c.rs:helper", "c.rs"),
+        ] {
+            store.insert_node(&node_in(id, path)).unwrap();
+        }
+        for e in [
+            edge(EdgeType::DependsOn, "file:a.rs", "file:b.rs"),
+            edge(EdgeType::DependsOn, "file:b.rs", "file:a.rs"),
+            edge(EdgeType::Owns, "file:a.rs", "function:a.rs:run"),
+            edge(EdgeType::Calls, "function:a.rs:run", "function:c.rs:helper"),
+            edge(
+                EdgeType::Calls,
+                "function:c.rs:helper",
+                "function:b.rs:query",
+            ),
+        ] {
+            store.insert_edge(&e).unwrap();
+        }
+        let route = |hops: &[Hop]| -> Vec<String> {
+            hops.iter()
+                .map(|h| format!("{} -{}-> {}", h.from, enum_to_str(&h.edge_type), h.to))
+                .collect()
+        };
+
+        let deps = [EdgeType::DependsOn, EdgeType::Calls];
+        let shortest = store
+            .shortest_path("file:a.rs", "file:b.rs", &deps, 10)
+            .unwrap()
+            .unwrap();
+        assert_eq!(route(&shortest), vec!["file:a.rs -depends_on-> file:b.rs"]);
+        assert_eq!(shortest[0].evidence.file, "test.rs");
+
+        let all = store
+            .simple_paths("file:a.rs", "file:b.rs", &deps, 10, 10)
+            .unwrap();
+        assert_eq!(all.len(), 2);
+        assert_eq!(
+            route(&all[1]),
+            vec![
+                "function:a.rs:run -calls-> function:c.rs:helper",
+                "function:c.rs:helper -calls-> function:b.rs:query",
+            ]
+        );
+        assert_eq!(
+            store
+                .simple_paths("file:a.rs", "file:b.rs", &deps, 1, 10)
+                .unwrap()
+                .len(),
+            1
+        );
+
+        // Files in between stand for their symbols only through `owns`
+        assert!(
+            store
+                .shortest_path("file:b.rs", "file:c.rs", &deps, 10)
+                .unwrap()
+                .is_none()
+        );
+        let through_owner = store
+            .shortest_path(
+                "file:b.rs",
+                "file:c.rs",
+                &[EdgeType::DependsOn, EdgeType::Calls, EdgeType::Owns],
+                10,
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(through_owner.len(), 3);
+        assert_eq!(through_owner[2].to, "function:c.rs:helper");
+    }
 }
@@ -1,8 +1,15 @@
 pub mod database;
+pub mod doc_index;
+pub mod graph_query;
 pub mod graph_store;
+pub mod search;
 
 pub use database::{
-    AgentInsight, CheckpointState, Database, FileAnalysisCheckpoint, SharedDatabase,
-    StoredFileInsight,
+    AgentInsight, ApiEndpointRow, CallEdgeRow, CheckpointState, ConfigFileRow, Database, EntityRow,
+    ExternalPackageRow, FileAnalysisCheckpoint, FunctionComplexityRow, SettingRow, SharedDatabase,
+    StoredFileInsight, TestLinkRow,
 };
-pub use graph_store::GraphStore;
+pub use doc_index::{DocHit, DocIndexStats};
+pub use graph_query::{GraphQuery, QueryRows};
+pub use graph_store::{Direction, GraphStore, Hop, LinkedEdge, Reached};
+pub use search::SearchHit;
@@ -37,6 +37,17 @@ CREATE INDEX IF NOT EXISTS idx_nodes_path_tier ON nodes(path, tier);
 -- Performance index for status/tier filtering
 CREATE INDEX IF NOT EXISTS idx_nodes_status_tier ON nodes(status, tier);
 
+-- Symbol search over node names, their camelCase/snake_case words, paths,
+-- signatures and descriptions. Rows share the rowid of their node.
+CREATE VIRTUAL TABLE IF NOT EXISTS node_search USING fts5(
+    name,
+    words,
+    path,
+    signature,
+    description,
+    tokenize = 'unicode61'
+);
+
 -- Edges: Relationships between nodes
 CREATE TABLE IF NOT EXISTS edges (
     id TEXT PRIMARY KEY,
@@ -58,6 +69,13 @@ CREATE UNIQUE INDEX IF NOT EXISTS idx_edges_unique ON edges(edge_type, source_id
 -- Performance index for dependency queries by source and type
 CREATE INDEX IF NOT EXISTS idx_edges_source_type ON edges(source_id, edge_type);
 
+-- Graph File Hashes: content of each file as of its last build
+CREATE TABLE IF NOT EXISTS graph_files (
+    path TEXT PRIMARY KEY,        -- File path as stored on its nodes
+    content_hash TEXT NOT NULL,   -- SHA-256 of the file content
+    updated_at TEXT DEFAULT CURRENT_TIMESTAMP
+);
+
 -- =============================================================================
 -- Pipeline Sessions
 -- =============================================================================
@@ -265,6 +283,28 @@ CREATE TABLE IF NOT EXISTS domain_summaries (
 CREATE INDEX IF NOT EXISTS idx_domain_session ON domain_summaries(session_id);
 CREATE INDEX IF NOT EXISTS idx_domain_label ON domain_summaries(domain_label);
 
+-- Documentation Sections: file insights, domain summaries and generated wiki
+-- pages split at their headings, rebuilt by `weavewiki ask-index`
+CREATE TABLE IF NOT EXISTS doc_sections (
+    id INTEGER PRIMARY KEY,
+    kind TEXT NOT NULL,             -- insight, domain, page
+    title TEXT NOT NULL,            -- file path, domain label or page title
+    heading TEXT,                   -- section heading, NULL for the lead text
+    body TEXT NOT NULL,
+    location TEXT NOT NULL,         -- source file, domain or page#anchor
+    sources TEXT NOT NULL,          -- JSON: source files the section covers
+    embedding BLOB                  -- optional local vector (f32 little-endian)
+);
+
+-- Full-text search over documentation sections. Rows share the rowid of
+-- their section; porter stemming lets `retry` match `retries`.
+CREATE VIRTUAL TABLE IF NOT EXISTS doc_search USING fts5(
+    title,
+    heading,
+    body,
+    tokenize = 'porter unicode61'
+);
+
 -- =============================================================================
 -- Metrics & Monitoring
 -- =============================================================================
@@ -0,0 +1,368 @@
+//! Symbol Search
+//!
+//! Finds nodes without knowing their ID format. Every node is indexed in
+//! the `node_search` FTS5 table under its name, the words of that name
+//! (`parseHttpRequest` and `parse_http_request` both give `parse http
+//! request`), its path, its signature and its description.
+//!
+//! A search takes the nodes the index matches with all of the term's words
+//! as prefixes, adds nodes whose name holds the term's letters in order (for
+//! abbreviations such as `prsreq`), and ranks them by how closely the name
+//! matches: exact, same words in another case style, prefix, word prefixes,
+//! initials, substring, then scattered letters. Nodes matched only by path,
+//! signature or description come last.
+
+use std::collections::HashMap;
+
+use rusqlite::{Connection, params};
+use serde::Serialize;
+
+use crate::types::{Node, NodeMetadata, Result, log_filter_error};
+
+/// Index candidates taken per search before ranking.
+const MAX_CANDIDATES: usize = 200;
+
+/// A node a search found, with how well it matched.
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchHit {
+    pub id: String,
+    pub name: String,
+    pub node_type: String,
+    pub path: String,
+    pub line: u32,
+    pub score: f64,
+}
+
+/// Index `node`, which must already be stored, replacing its previous entry.
+pub(super) fn index_node(conn: &Connection, node: &Node) -> Result<()> {
+    let rowid: i64 = conn.query_row(
+        "SELECT rowid FROM nodes WHERE id = ?1",
+        params![node.id],
+        |row| row.get(0),
+    )?;
+    index(conn, rowid, &node.name, &node.path, &node.metadata)
+}
+
+/// Index nodes stored before the search table existed.
+pub(super) fn backfill(conn: &Connection) -> Result<()> {
+    let indexed: bool = conn.query_row("SELECT EXISTS (SELECT 1 FROM node_search)", [], |row| {
+        row.get(0)
+    })?;
+    if indexed {
+        return Ok(());
+    }
+
+    let mut stmt = conn.prepare("SELECT rowid, name, path, metadata FROM nodes")?;
+    let rows: Vec<(i64, String, String, Option<String>)> = stmt
+        .query_map([], |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
+        })?
+        .filter_map(|r| log_filter_error(r, "reading node to index"))
+        .collect();
+    for (rowid, name, path, metadata) in rows {
+        let metadata: NodeMetadata = metadata
+            .and_then(|m| serde_json::from_str(&m).ok())
+            .unwrap_or_default();
+        index(conn, rowid, &name, &path, &metadata)?;
+    }
+    Ok(())
+}
+
+fn index(
+    conn: &Connection,
+    rowid: i64,
+    name: &str,
+    path: &str,
+    metadata: &NodeMetadata,
+) -> Result<()> {
+    let signature = metadata.signature.as_ref().map(|s| {
+        let mut text: Vec<String> = s
+            .parameters
+            .iter()
+            .map(|p| match &p.param_type {
+                Some(t) => format!("{}: {}", p.name, t),
+                None => p.name.clone(),
+            })
+            .collect();
+        text.extend(s.return_type.clone());
+        text.join(" ")
+    });
+
+    conn.execute(
+        r#"INSERT OR REPLACE INTO node_search (rowid, name, words, path, signature, description)
+           VALUES (?1, ?2, ?3, ?4, ?5, ?6)"#,
+        params![
+            rowid,
+            name,
+            split_words(name).join(" "),
+            path,
+            signature,
+            metadata.description
+        ],
+    )?;
+    Ok(())
+}
+
+/// Nodes matching `term`, best first.
+pub(super) fn search(conn: &Connection, term: &str, limit: usize) -> Result<Vec<SearchHit>> {
+    let words = split_words(term);
+    if words.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    // Candidates by ID, with whether the index matched them
+    let mut candidates: HashMap<String, (SearchHit, bool)> = HashMap::new();
+    let to_hit = |row: &rusqlite::Row| -> rusqlite::Result<SearchHit> {
+        Ok(SearchHit {
+            id: row.get(0)?,
+            name: row.get(1)?,
+            node_type: row.get(2)?,
+            path: row.get(3)?,
+            line: row.get::<_, Option<u32>>(4)?.unwrap_or_default(),
+            score: 0.0,
+        })
+    };
+
+    let fts_query = words
+        .iter()
+        .map(|w| format!("\"{}\"*", w))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let mut stmt = conn.prepare(
+        r#"SELECT n.id, n.name, n.node_type, n.path, json_extract(n.evidence, '$.start_line')
+           FROM node_search JOIN nodes n ON n.rowid = node_search.rowid
+           WHERE node_search MATCH ?1
+           ORDER BY bm25(node_search, 10.0, 5.0, 1.0, 1.0, 0.5)
+           LIMIT ?2"#,
+    )?;
+    for hit in stmt
+        .query_map(params![fts_query, MAX_CANDIDATES as i64], to_hit)?
+        .filter_map(|r| log_filter_error(r, "reading search hit"))
+    {
+        candidates.insert(hit.id.clone(), (hit, true));
+    }
+
+    // Abbreviations the index cannot match by prefix
+    let letters: String = words.concat();
+    if letters.len() >= 2 {
+        let mut stmt = conn.prepare(
+            "SELECT id, name, node_type, path, json_extract(evidence, '$.start_line') FROM nodes",
+        )?;
+        for hit in stmt
+            .query_map([], to_hit)?
+            .filter_map(|r| log_filter_error(r, "reading node name"))
+        {
+            if !candidates.contains_key(&hit.id) && subsequence_span(&letters, &hit.name).is_some()
+            {
+                candidates.insert(hit.id.clone(), (hit, false));
+            }
+        }
+    }
+
+    let mut hits: Vec<SearchHit> = candidates
+        .into_values()
+        .filter_map(|(mut hit, indexed)| {
+            hit.score = match name_score(term, &hit.name) {
+                Some(score) => score,
+                // Matched by path, signature or description only
+                None if indexed => 10.0,
+                None => return None,
+            };
+            Some(hit)
+        })
+        .collect();
+    hits.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| a.name.len().cmp(&b.name.len()))
+            .then_with(|| a.path.cmp(&b.path))
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    hits.truncate(limit);
+    Ok(hits)
+}
+
+/// Lowercase words of an identifier, split at non-alphanumeric characters
+/// and camelCase boundaries (`HTTPServer` gives `http`, `server`).
+pub fn split_words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    for part in name.split(|c: char| !c.is_alphanumeric()) {
+        let chars: Vec<char> = part.chars().collect();
+        let mut start = 0;
+        for i in 1..chars.len() {
+            let boundary = (chars[i].is_uppercase() && chars[i - 1].is_lowercase())
+                || (chars[i].is_uppercase()
+                    && chars[i - 1].is_uppercase()
+                    && chars.get(i + 1).is_some_and(|c| c.is_lowercase()));
+            if boundary {
+                words.push(chars[start..i].iter().collect::<String>().to_lowercase());
+                start = i;
+            }
+        }
+        if start < chars.len() {
+            words.push(chars[start..].iter().collect::<String>().to_lowercase());
+        }
+    }
+    words
+}
+
+/// How well `name` matches `term`, from 100 for the same name down to 20
+/// for scattered letters; `None` when the name does not hold the term's
+/// letters in order.
+fn name_score(term: &str, name: &str) -> Option<f64> {
+    let term_lower = term.to_lowercase();
+    let name_lower = name.to_lowercase();
+    let term_words = split_words(term);
+    let name_words = split_words(name);
+    let letters: String = term_words.concat();
+
+    if name_lower == term_lower {
+        return Some(100.0);
+    }
+    if term_words == name_words {
+        return Some(95.0);
+    }
+    // Longer names matched by the same prefix rank lower
+    let coverage = letters.len() as f64 / name_words.concat().len().max(1) as f64;
+    if name_lower.starts_with(&term_lower) || name_words.concat().starts_with(&letters) {
+        return Some(80.0 + 10.0 * coverage);
+    }
+    if words_in_order(&term_words, &name_words) {
+        return Some(70.0 + 5.0 * coverage);
+    }
+    let initials: String = name_words.iter().filter_map(|w| w.chars().next()).collect();
+    if letters.len() >= 2 && initials.starts_with(&letters) {
+        return Some(65.0);
+    }
+    if name_lower.contains(&term_lower) {
+        return Some(50.0 + 10.0 * coverage);
+    }
+    let span = subsequence_span(&letters, name)?;
+    Some(20.0 + 20.0 * letters.chars().count() as f64 / span as f64)
+}
+
+/// Whether each term word starts a name word, in order.
+fn words_in_order(term_words: &[String], name_words: &[String]) -> bool {
+    let mut names = name_words.iter();
+    term_words
+        .iter()
+        .all(|t| names.by_ref().any(|n| n.starts_with(t.as_str())))
+}
+
+/// Length of the shortest stretch of `name` that holds the letters of
+/// `letters` in order, ignoring case and non-alphanumeric characters.
+fn subsequence_span(letters: &str, name: &str) -> Option<usize> {
+    let letters: Vec<char> = letters.chars().collect();
+    let name: Vec<char> = name
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect();
+    let first = *letters.first()?;
+
+    let mut best: Option<usize> = None;
+    for start in (0..name.len()).filter(|&i| name[i] == first) {
+        let mut matched = 1;
+        let mut end = start;
+        for (i, c) in name.iter().enumerate().skip(start + 1) {
+            if matched == letters.len() {
+                break;
+            }
+            if *c == letters[matched] {
+                matched += 1;
+                end = i;
+            }
+        }
+        if matched == letters.len() {
+            let span = end - start + 1;
+            best = Some(best.map_or(span, |b| b.min(span)));
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::{Database, GraphStore};
+    use crate::types::NodeType;
+
+    #[test]
+    fn test_split_words() {
+        assert_eq!(
+            split_words("parseHttpRequest"),
+            vec!["parse", "http", "request"]
+        );
+        assert_eq!(
+            split_words("parse_http_request"),
+            vec!["parse", "http", "request"]
+        );
+        assert_eq!(split_words("HTTPServer"), vec!["http", "server"]);
+        assert_eq!(split_words("./src/main.rs"), vec!["src", "main", "rs"]);
+    }
+
+    #[test]
+    fn test_ranking() {
+        let score = |term, name| name_score(term, name).unwrap_or(0.0);
+        assert!(score("parse_request", "parseRequest") > score("parse", "parseRequest"));
+        assert!(score("parse", "parse") > score("parse", "parseRequest"));
+        assert!(score("parse", "parseRequest") > score("parse", "parseRequestBody"));
+        assert!(score("httpReq", "parseHttpRequest") > score("req", "unrequested"));
+        assert!(score("phr", "parse_http_request") > score("phr", "graphreader"));
+        assert!(score("prsreq", "parseRequest") > 0.0);
+        assert!(name_score("xyz", "parseRequest").is_none());
+    }
+
+    #[test]
+    fn test_search_index() {
+        let db = Database::open_in_memory().unwrap();
+        db.initialize().unwrap();
+        let store = GraphStore::new(&db);
+
+        let node = |node_type, path: &str, name: &str, description: Option<&str>| {
+            let mut node = Node::new(node_type, path.to_string(), name.to_string());
+            node.metadata.description = description.map(String::from);
+            store.insert_node(&node).unwrap();
+            node
+        };
+        node(
+            NodeType::Function,
+            "./src/http.rs",
+            "parseHttpRequest",
+            None,
+        );
+        node(NodeType::Function, "./src/http.rs", "parse_headers", None);
+        node(
+            NodeType::Class,
+            "./src/server.rs",
+            "HttpServer",
+            Some("Accepts TCP connections"),
+        );
+        let renamed = node(NodeType::Function, "./src/old.rs", "legacyHandler", None);
+
+        let names = |term: &str| -> Vec<String> {
+            store
+                .search(term, 10)
+                .unwrap()
+                .into_iter()
+                .map(|h| h.name)
+                .collect()
+        };
+        assert_eq!(names("parse_http_request"), vec!["parseHttpRequest"]);
+        assert_eq!(names("parse")[..2], ["parse_headers", "parseHttpRequest"]);
+        assert_eq!(names("HttpSrv"), vec!["HttpServer"]);
+        assert_eq!(names("prshdr"), vec!["parse_headers"]);
+        assert_eq!(names("tcp"), vec!["HttpServer"]);
+
+        // Re-indexing replaces the entry; replacing the file drops it
+        let mut updated = renamed.clone();
+        updated.name = "modernHandler".to_string();
+        store.insert_node(&updated).unwrap();
+        assert!(names("legacy").is_empty());
+        assert_eq!(names("handler"), vec!["modernHandler"]);
+        store
+            .replace_files(&["./src/old.rs".to_string()], &[], &[], &[], &[], &[])
+            .unwrap();
+        assert!(names("handler").is_empty());
+    }
+}
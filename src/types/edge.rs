@@ -31,6 +31,9 @@ pub enum EdgeType {
     Validates,
     RoutesTo,
     Renders,
+    Tests,
+    UsesType,
+    Reads,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
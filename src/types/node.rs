@@ -34,6 +34,8 @@ impl Node {
             NodeType::Component => "component",
             NodeType::Route => "route",
             NodeType::Config => "config",
+            NodeType::Setting => "setting",
+            NodeType::Package => "package",
         };
 
         Self {
@@ -97,6 +99,10 @@ pub enum NodeType {
     Component,
     Route,
     Config,
+    /// Environment variable, configuration key or feature flag read by code
+    Setting,
+    /// Third-party package a manifest declares or a lockfile pins
+    Package,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -109,6 +115,11 @@ pub struct NodeMetadata {
     pub api_metadata: Option<ApiMetadata>,
     pub component_metadata: Option<ComponentMetadata>,
     pub entity_metadata: Option<EntityMetadata>,
+    #[serde(default)]
+    pub config_metadata: Option<ConfigMetadata>,
+    /// Typed fields of a struct, class or interface
+    #[serde(default)]
+    pub fields: Option<Vec<FieldDefinition>>,
     #[serde(flatten)]
     pub extra: HashMap<String, serde_json::Value>,
 }
@@ -160,6 +171,38 @@ pub enum HttpMethod {
     Delete,
     Options,
     Head,
+    /// Any method (`app.all`, a bare `http.HandleFunc`, `@RequestMapping` without `method`)
+    Any,
+}
+
+impl HttpMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HttpMethod::Get => "GET",
+            HttpMethod::Post => "POST",
+            HttpMethod::Put => "PUT",
+            HttpMethod::Patch => "PATCH",
+            HttpMethod::Delete => "DELETE",
+            HttpMethod::Options => "OPTIONS",
+            HttpMethod::Head => "HEAD",
+            HttpMethod::Any => "ANY",
+        }
+    }
+
+    /// Parse a method name as written in route code (`get`, `GET`, `Post`, ...)
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_uppercase().as_str() {
+            "GET" => Some(HttpMethod::Get),
+            "POST" => Some(HttpMethod::Post),
+            "PUT" => Some(HttpMethod::Put),
+            "PATCH" => Some(HttpMethod::Patch),
+            "DELETE" => Some(HttpMethod::Delete),
+            "OPTIONS" => Some(HttpMethod::Options),
+            "HEAD" => Some(HttpMethod::Head),
+            "ANY" | "ALL" => Some(HttpMethod::Any),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -175,6 +218,9 @@ pub struct ComponentMetadata {
     pub props: Option<Vec<PropDefinition>>,
     pub state: Option<StateDefinition>,
     pub hooks: Option<Vec<String>>,
+    /// Events the component emits to its parent
+    #[serde(default)]
+    pub emits: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -214,6 +260,97 @@ pub struct RelationDefinition {
     pub target: String,
 }
 
+/// Facts declared by a configuration or infrastructure file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigMetadata {
+    pub kind: ConfigKind,
+    /// Package or project name the manifest declares
+    pub name: Option<String>,
+    #[serde(default)]
+    pub dependencies: Vec<DeclaredDependency>,
+    /// Ports as written (`8080`, `8080:80`, `53/udp`)
+    #[serde(default)]
+    pub ports: Vec<String>,
+    /// Names of the environment variables the file sets or expects
+    #[serde(default)]
+    pub env_vars: Vec<String>,
+    #[serde(default)]
+    pub services: Vec<ServiceDefinition>,
+    #[serde(default)]
+    pub scripts: Vec<ScriptDefinition>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigKind {
+    Cargo,
+    Npm,
+    Pyproject,
+    Dockerfile,
+    Compose,
+    Kubernetes,
+    GithubActions,
+    Env,
+    Lockfile,
+}
+
+impl ConfigKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConfigKind::Cargo => "Cargo",
+            ConfigKind::Npm => "npm",
+            ConfigKind::Pyproject => "pyproject",
+            ConfigKind::Dockerfile => "Dockerfile",
+            ConfigKind::Compose => "Docker Compose",
+            ConfigKind::Kubernetes => "Kubernetes",
+            ConfigKind::GithubActions => "GitHub Actions",
+            ConfigKind::Env => "Environment",
+            ConfigKind::Lockfile => "Lockfile",
+        }
+    }
+
+    /// Whether the file describes how the project is built, shipped or run
+    /// rather than what it depends on
+    pub fn is_deployment(&self) -> bool {
+        matches!(
+            self,
+            ConfigKind::Dockerfile
+                | ConfigKind::Compose
+                | ConfigKind::Kubernetes
+                | ConfigKind::GithubActions
+        )
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DeclaredDependency {
+    pub name: String,
+    /// Version requirement as written (`^1.2`, `>=2.0`, an image tag)
+    pub version: Option<String>,
+    /// `runtime`, `dev`, `build`, `peer`, `optional`, `image`, `action`, or
+    /// `locked` for versions a lockfile pins
+    pub scope: String,
+}
+
+/// Service, workload or job declared by an infrastructure file
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ServiceDefinition {
+    pub name: String,
+    /// Kubernetes object kind, or `job` for CI jobs
+    pub kind: Option<String>,
+    pub image: Option<String>,
+    #[serde(default)]
+    pub ports: Vec<String>,
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ScriptDefinition {
+    pub name: String,
+    pub command: String,
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum InformationTier {
@@ -130,6 +130,8 @@ impl ParseWithDefault for NodeType {
             "component" => Some(NodeType::Component),
             "route" => Some(NodeType::Route),
             "config" => Some(NodeType::Config),
+            "setting" => Some(NodeType::Setting),
+            "package" => Some(NodeType::Package),
             _ => None,
         }
     }
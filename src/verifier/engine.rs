@@ -1,9 +1,10 @@
 use std::path::Path;
 
+use crate::analyzer::parser::create_parser_for_path;
 use crate::constants::verification::STALE_FILE_THRESHOLD_SECS;
 use crate::types::{
-    Claim, ClaimType, InformationTier, IssueSeverity, Result, VerificationIssue,
-    VerificationReport, VerificationStatus,
+    ApiMetadata, Claim, ClaimType, HttpMethod, InformationTier, IssueSeverity, NodeType, Result,
+    VerificationIssue, VerificationReport, VerificationStatus,
 };
 
 use super::cache::FileContentCache;
@@ -149,7 +150,30 @@ impl VerificationEngine {
         file_path: &Path,
     ) -> Result<(VerificationStatus, Option<VerificationIssue>)> {
         let content = self.cache.get_or_load(file_path)?;
-        let endpoint = &claim.statement;
+        let endpoint = claim.statement.trim();
+
+        // Prefer the routes the extractor finds in the current source; only
+        // files with no recognizable router fall back to text patterns.
+        let declared = declared_endpoints(&claim.evidence.file, &content);
+        if !declared.is_empty() {
+            if declared.iter().any(|api| endpoint_matches(api, endpoint)) {
+                return Ok((VerificationStatus::Verified, None));
+            }
+            return Ok((
+                VerificationStatus::Stale,
+                Some(
+                    VerificationIssue::new(
+                        &claim.id,
+                        IssueSeverity::Warning,
+                        format!(
+                            "API endpoint '{}' is no longer declared in {}",
+                            endpoint, claim.evidence.file
+                        ),
+                    )
+                    .with_suggestion("Update API catalog with current endpoints"),
+                ),
+            ));
+        }
 
         let patterns = [
             format!("@Get('{}')", endpoint),
@@ -219,3 +243,63 @@ impl VerificationEngine {
         Ok(issues)
     }
 }
+
+/// Endpoints declared in a file, as found by the parser's route extraction.
+fn declared_endpoints(path: &str, content: &str) -> Vec<ApiMetadata> {
+    let Some(parser) = create_parser_for_path(path) else {
+        return Vec::new();
+    };
+    let Ok(result) = parser.parse(path, content) else {
+        return Vec::new();
+    };
+    result
+        .nodes
+        .into_iter()
+        .filter(|n| n.node_type == NodeType::Api)
+        .filter_map(|n| n.metadata.api_metadata)
+        .collect()
+}
+
+/// Match a claimed endpoint written as `GET /users/:id` or just `/users/:id`.
+fn endpoint_matches(api: &ApiMetadata, endpoint: &str) -> bool {
+    if let Some((method, path)) = endpoint.split_once(' ')
+        && let Some(method) = HttpMethod::from_name(method)
+    {
+        return path.trim() == api.path && (api.method == method || api.method == HttpMethod::Any);
+    }
+    endpoint == api.path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ClaimEvidence;
+
+    #[test]
+    fn test_api_endpoint_uses_extracted_routes() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("app.py"),
+            "@app.get(\"/users/{id}\")\ndef get_user(id):\n    return \"/orders\"\n",
+        )
+        .unwrap();
+        let engine = VerificationEngine::new(dir.path());
+
+        let claim = |statement: &str| {
+            let mut claim = Claim::new("c", ClaimType::ApiEndpoint, "api", statement);
+            claim.evidence = ClaimEvidence::new("app.py");
+            claim
+        };
+
+        let (status, _) = engine.verify_claim(&claim("GET /users/{id}")).unwrap();
+        assert_eq!(status, VerificationStatus::Verified);
+
+        // A string literal is not a route once the file has a real router
+        let (status, issue) = engine.verify_claim(&claim("/orders")).unwrap();
+        assert_eq!(status, VerificationStatus::Stale);
+        assert!(issue.is_some());
+
+        let (status, _) = engine.verify_claim(&claim("DELETE /users/{id}")).unwrap();
+        assert_eq!(status, VerificationStatus::Stale);
+    }
+}
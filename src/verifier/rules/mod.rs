@@ -1,5 +1,7 @@
 pub mod reference;
+pub mod settings;
 pub mod signature;
 
 pub use reference::ReferenceRule;
+pub use settings::SettingsRule;
 pub use signature::SignatureRule;
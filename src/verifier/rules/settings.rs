@@ -0,0 +1,108 @@
+//! Settings Rule
+//!
+//! Flags environment variables documented in `.env`-style files that no
+//! code reads anymore.
+
+use crate::storage::{ConfigFileRow, SettingRow};
+use crate::types::{ConfigKind, IssueSeverity, VerificationIssue};
+
+pub struct SettingsRule;
+
+impl SettingsRule {
+    /// Variables declared in env files but never read by code, directly or
+    /// through a prefix read (`APP_*`). Variables set by Dockerfiles,
+    /// compose files or manifests are not checked, since the images they
+    /// run may read them. Nothing is flagged when no code reads any
+    /// environment variable, as the reading language is then likely one
+    /// the parsers do not cover.
+    pub fn unread_documented(
+        files: &[ConfigFileRow],
+        settings: &[SettingRow],
+    ) -> Vec<VerificationIssue> {
+        let reads: Vec<&str> = settings
+            .iter()
+            .filter(|s| s.kind == "env")
+            .map(|s| s.name.as_str())
+            .collect();
+        if reads.is_empty() {
+            return Vec::new();
+        }
+        let is_read = |name: &str| {
+            reads.iter().any(|read| match read.strip_suffix('*') {
+                Some(prefix) => name.starts_with(prefix),
+                None => *read == name,
+            })
+        };
+
+        let mut issues = Vec::new();
+        for row in files.iter().filter(|f| f.config.kind == ConfigKind::Env) {
+            for name in row.config.env_vars.iter().filter(|n| !is_read(n)) {
+                issues.push(
+                    VerificationIssue::new(
+                        format!("config:{}", row.file),
+                        IssueSeverity::Warning,
+                        format!(
+                            "Environment variable {} is documented in {} but no code reads it",
+                            name, row.file
+                        ),
+                    )
+                    .with_suggestion(format!(
+                        "Remove {} from {} or restore its use",
+                        name, row.file
+                    )),
+                );
+            }
+        }
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ConfigMetadata;
+
+    fn config(file: &str, kind: ConfigKind, env_vars: &[&str]) -> ConfigFileRow {
+        ConfigFileRow {
+            file: file.to_string(),
+            config: ConfigMetadata {
+                kind,
+                name: None,
+                dependencies: Vec::new(),
+                ports: Vec::new(),
+                env_vars: env_vars.iter().map(|v| v.to_string()).collect(),
+                services: Vec::new(),
+                scripts: Vec::new(),
+            },
+        }
+    }
+
+    fn read(name: &str) -> SettingRow {
+        SettingRow {
+            name: name.to_string(),
+            kind: "env".to_string(),
+            file: "src/main.rs".to_string(),
+            line: 1,
+            readers: vec!["main".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_unread_documented_variables() {
+        let files = [
+            config(
+                "./.env.example",
+                ConfigKind::Env,
+                &["PORT", "APP_NAME", "LEGACY_TOKEN"],
+            ),
+            config("./Dockerfile", ConfigKind::Dockerfile, &["NODE_ENV"]),
+        ];
+
+        let issues = SettingsRule::unread_documented(&files, &[read("PORT"), read("APP_*")]);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("LEGACY_TOKEN"));
+        assert_eq!(issues[0].severity, IssueSeverity::Warning);
+
+        assert!(SettingsRule::unread_documented(&files, &[]).is_empty());
+    }
+}
@@ -0,0 +1,152 @@
+//! API Catalog Generator
+//!
+//! Renders `api-catalog.md` from the HTTP endpoints the parsers extracted
+//! from route declarations. Unlike the other pages it needs no LLM output:
+//! every row is a parser fact with a source location.
+
+use std::collections::BTreeMap;
+
+use crate::storage::ApiEndpointRow;
+use crate::types::HttpMethod;
+
+/// Generator for the API catalog page
+pub struct ApiCatalogGenerator;
+
+impl ApiCatalogGenerator {
+    /// Generate the catalog, grouped by the first segment of each route path
+    pub fn generate_md(endpoints: &[ApiEndpointRow]) -> String {
+        let mut output = String::new();
+
+        output.push_str("# API Catalog\n\n");
+        output.push_str(&format!(
+            "{} HTTP endpoints extracted from route declarations.\n\n",
+            endpoints.len()
+        ));
+
+        if endpoints.is_empty() {
+            output.push_str("No HTTP routes detected.\n");
+            return output;
+        }
+
+        let mut by_resource: BTreeMap<&str, Vec<&ApiEndpointRow>> = BTreeMap::new();
+        for endpoint in endpoints {
+            by_resource
+                .entry(resource_of(&endpoint.api.path))
+                .or_default()
+                .push(endpoint);
+        }
+
+        for (resource, mut rows) in by_resource {
+            rows.sort_by(|a, b| {
+                (a.api.path.as_str(), method_rank(a.api.method))
+                    .cmp(&(b.api.path.as_str(), method_rank(b.api.method)))
+            });
+
+            output.push_str(&format!("## `{}`\n\n", resource));
+            output.push_str("| Method | Path | Handler | Auth | Source |\n");
+            output.push_str("|--------|------|---------|------|--------|\n");
+            for row in rows {
+                output.push_str(&format!(
+                    "| {} | `{}` | {} | {} | `{}:{}` |\n",
+                    row.api.method.as_str(),
+                    row.api.path,
+                    handler_cell(row),
+                    auth_cell(row),
+                    row.file.trim_start_matches("./"),
+                    row.line
+                ));
+            }
+            output.push('\n');
+        }
+
+        output
+    }
+}
+
+/// First path segment (`/users/:id` → `/users`), or `/` for the root.
+fn resource_of(path: &str) -> &str {
+    match path[1.min(path.len())..].find('/') {
+        Some(end) => &path[..end + 1],
+        None => path,
+    }
+}
+
+fn method_rank(method: HttpMethod) -> u8 {
+    match method {
+        HttpMethod::Get => 0,
+        HttpMethod::Post => 1,
+        HttpMethod::Put => 2,
+        HttpMethod::Patch => 3,
+        HttpMethod::Delete => 4,
+        HttpMethod::Head => 5,
+        HttpMethod::Options => 6,
+        HttpMethod::Any => 7,
+    }
+}
+
+fn handler_cell(row: &ApiEndpointRow) -> String {
+    match (&row.handler, &row.handler_path) {
+        (Some(handler), Some(path)) if *path != row.file => {
+            format!("`{}` ({})", handler, path.trim_start_matches("./"))
+        }
+        (Some(handler), _) => format!("`{}`", handler),
+        (None, _) => "inline".to_string(),
+    }
+}
+
+fn auth_cell(row: &ApiEndpointRow) -> String {
+    let Some(auth) = &row.api.auth else {
+        return "-".to_string();
+    };
+    let mut cell = auth
+        .auth_type
+        .clone()
+        .unwrap_or_else(|| "required".to_string());
+    if let Some(roles) = &auth.roles {
+        cell.push_str(&format!(" ({})", roles.join(", ")));
+    }
+    cell
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::parser::{Parser, TypeScriptParser};
+    use crate::storage::{Database, GraphStore};
+
+    #[test]
+    fn test_catalog_from_graph() {
+        let db = Database::open_in_memory().unwrap();
+        db.initialize().unwrap();
+        let store = GraphStore::new(&db);
+
+        let code = r#"
+function listUsers(req, res) {}
+router.get('/users', listUsers);
+router.delete('/users/:id', requireAuth, (req, res) => {});
+app.get('/', (req, res) => {});
+"#;
+        let result = TypeScriptParser::new()
+            .unwrap()
+            .parse("./src/routes.ts", code)
+            .unwrap();
+        for node in &result.nodes {
+            store.insert_node(node).unwrap();
+        }
+        for edge in &result.edges {
+            store.insert_edge(edge).unwrap();
+        }
+
+        let endpoints = db.get_api_endpoints().unwrap();
+        assert_eq!(endpoints.len(), 3);
+
+        let md = ApiCatalogGenerator::generate_md(&endpoints);
+        assert!(md.contains("3 HTTP endpoints"));
+        assert!(md.contains("## `/users`"));
+        assert!(md.contains("| GET | `/users` | `listUsers` | - | `src/routes.ts:3` |"));
+        assert!(
+            md.contains("| DELETE | `/users/:id` | inline | requireAuth | `src/routes.ts:4` |")
+        );
+        assert!(md.find("## `/`").unwrap() < md.find("## `/users`").unwrap());
+    }
+}
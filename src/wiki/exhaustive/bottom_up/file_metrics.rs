@@ -4,6 +4,7 @@
 //! - Structural complexity (functions, classes)
 //! - Dependency graph position (imports, dependents)
 //! - Interface implementations
+//! - Per-function cyclomatic complexity measured by the parsers
 //!
 //! This provides a data-driven approach to tier classification that
 //! complements the heuristic-based prioritizer.
@@ -51,9 +52,14 @@
 //! # }
 //! ```
 
+use crate::analyzer::parser::FunctionMetrics;
 use crate::storage::Database;
 use crate::types::Result;
 
+/// Cyclomatic complexity from which a single function makes its file
+/// Important, however few files depend on it
+pub(super) const COMPLEX_FUNCTION: u32 = 15;
+
 /// Enriched metrics for a file, computed from AST and graph data
 #[derive(Debug, Clone)]
 pub struct FileMetrics {
@@ -66,6 +72,7 @@ pub struct FileMetrics {
     pub implements_count: usize, // Trait/interface implementations
     pub is_entry_point: bool,
     pub complexity_score: f32, // Computed from above metrics (0-1)
+    pub max_cyclomatic: u32,   // Most complex function in the file
 }
 
 impl FileMetrics {
@@ -100,6 +107,13 @@ impl FileMetrics {
             })
             .count();
 
+        let max_cyclomatic = nodes
+            .iter()
+            .filter_map(FunctionMetrics::of)
+            .map(|m| m.cyclomatic)
+            .max()
+            .unwrap_or(0);
+
         // Query dependencies (DependsOn edges outgoing from this file)
         let dependencies = db.get_file_dependencies(file_path)?;
         let import_count = dependencies.len();
@@ -134,6 +148,7 @@ impl FileMetrics {
             implements_count,
             is_entry_point,
             complexity_score,
+            max_cyclomatic,
         })
     }
 
@@ -168,13 +183,17 @@ impl FileMetrics {
     ///
     /// Classification rules:
     /// - Core: Entry points or highly depended-upon files (10+ dependents)
-    /// - Important: Moderately depended-upon (5+ dependents) or high complexity (>0.6)
+    /// - Important: Moderately depended-upon (5+ dependents), high complexity (>0.6)
+    ///   or a function with cyclomatic complexity of 15+
     /// - Standard: Some structure (3+ functions or 1+ class)
     /// - Leaf: Everything else (utilities, simple helpers)
     pub fn suggested_tier(&self) -> &'static str {
         if self.is_entry_point || self.dependent_count > 10 {
             "core"
-        } else if self.dependent_count > 5 || self.complexity_score > 0.6 {
+        } else if self.dependent_count > 5
+            || self.complexity_score > 0.6
+            || self.max_cyclomatic >= COMPLEX_FUNCTION
+        {
             "important"
         } else if self.function_count > 3 || self.class_count > 1 {
             "standard"
@@ -224,6 +243,7 @@ mod tests {
             implements_count: 0,
             is_entry_point: false,
             complexity_score: 0.05,
+            max_cyclomatic: 4,
         };
         assert_eq!(metrics.suggested_tier(), "leaf");
 
@@ -238,6 +258,7 @@ mod tests {
             implements_count: 0,
             is_entry_point: false,
             complexity_score: 0.25,
+            max_cyclomatic: 4,
         };
         assert_eq!(metrics.suggested_tier(), "standard");
 
@@ -252,6 +273,22 @@ mod tests {
             implements_count: 2,
             is_entry_point: false,
             complexity_score: 0.65,
+            max_cyclomatic: 4,
+        };
+        assert_eq!(metrics.suggested_tier(), "important");
+
+        // Important tier: one genuinely complex function
+        let metrics = FileMetrics {
+            path: "parser/lexer.rs".to_string(),
+            lines_of_code: 300,
+            function_count: 2,
+            class_count: 0,
+            import_count: 1,
+            dependent_count: 1,
+            implements_count: 0,
+            is_entry_point: false,
+            complexity_score: 0.05,
+            max_cyclomatic: 22,
         };
         assert_eq!(metrics.suggested_tier(), "important");
 
@@ -266,6 +303,7 @@ mod tests {
             implements_count: 3,
             is_entry_point: false,
             complexity_score: 0.55,
+            max_cyclomatic: 4,
         };
         assert_eq!(metrics.suggested_tier(), "core");
 
@@ -280,6 +318,7 @@ mod tests {
             implements_count: 0,
             is_entry_point: true,
             complexity_score: 0.15,
+            max_cyclomatic: 4,
         };
         assert_eq!(metrics.suggested_tier(), "core");
     }
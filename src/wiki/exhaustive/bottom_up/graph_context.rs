@@ -17,6 +17,8 @@ use crate::types::node::{NodeType, Visibility};
 /// Structural context for a single file, ready for prompt injection
 #[derive(Debug, Clone, Default)]
 pub struct FileStructuralContext {
+    /// File-level documentation (`//!`, module docstring, package comment)
+    pub module_doc: Option<String>,
     /// Functions declared in this file
     pub functions: Vec<FunctionFact>,
     /// Structs/Classes declared in this file
@@ -25,21 +27,34 @@ pub struct FileStructuralContext {
     pub enums: Vec<TypeFact>,
     /// Traits/Interfaces declared in this file
     pub traits: Vec<TypeFact>,
+    /// UI components (Vue/Svelte single-file components)
+    pub components: Vec<ComponentFact>,
     /// Internal dependencies (crate::/super:: imports)
     pub internal_deps: Vec<DependencyFact>,
     /// Trait implementations
     pub implements: Vec<ImplementsFact>,
+    /// Calls made from this file's functions
+    pub calls: Vec<CallFact>,
+    /// Calls into this file from other files
+    pub callers: Vec<CallFact>,
+    /// Tests exercising this file
+    pub tested_by: Vec<TestFact>,
 }
 
 impl FileStructuralContext {
     /// Check if context has any structural data
     pub fn is_empty(&self) -> bool {
-        self.functions.is_empty()
+        self.module_doc.is_none()
+            && self.functions.is_empty()
             && self.structs.is_empty()
             && self.enums.is_empty()
             && self.traits.is_empty()
+            && self.components.is_empty()
             && self.internal_deps.is_empty()
             && self.implements.is_empty()
+            && self.calls.is_empty()
+            && self.callers.is_empty()
+            && self.tested_by.is_empty()
     }
 
     /// Format context for prompt inclusion
@@ -52,6 +67,11 @@ impl FileStructuralContext {
         output.push_str("\n## Structural Facts (Parser-Extracted)\n");
         output.push_str("These facts are already extracted by the parser. Use them to inform your analysis:\n\n");
 
+        if let Some(ref doc) = self.module_doc {
+            output.push_str("### Module Documentation (author-written)\n");
+            output.push_str(&format!("> {}\n\n", doc_summary(doc)));
+        }
+
         // Functions
         if !self.functions.is_empty() {
             output.push_str("### Functions\n");
@@ -67,6 +87,7 @@ impl FileStructuralContext {
                     "- `{}{}fn {}({})` [line {}]\n",
                     visibility, async_marker, f.name, f.params_summary, f.line
                 ));
+                push_doc(&mut output, f.description.as_deref());
             }
             output.push('\n');
         }
@@ -85,6 +106,7 @@ impl FileStructuralContext {
                     "- `{} struct {}` [line {}]\n",
                     visibility, s.name, s.line
                 ));
+                push_doc(&mut output, s.description.as_deref());
             }
             output.push('\n');
         }
@@ -103,6 +125,7 @@ impl FileStructuralContext {
                     "- `{} enum {}` [line {}]\n",
                     visibility, e.name, e.line
                 ));
+                push_doc(&mut output, e.description.as_deref());
             }
             output.push('\n');
         }
@@ -112,6 +135,22 @@ impl FileStructuralContext {
             output.push_str("### Traits\n");
             for t in &self.traits {
                 output.push_str(&format!("- `trait {}` [line {}]\n", t.name, t.line));
+                push_doc(&mut output, t.description.as_deref());
+            }
+            output.push('\n');
+        }
+
+        // Components
+        if !self.components.is_empty() {
+            output.push_str("### Components\n");
+            for c in &self.components {
+                output.push_str(&format!("- `<{}>` [line {}]\n", c.name, c.line));
+                if !c.props.is_empty() {
+                    output.push_str(&format!("  - props: {}\n", c.props.join(", ")));
+                }
+                if !c.emits.is_empty() {
+                    output.push_str(&format!("  - emits: {}\n", c.emits.join(", ")));
+                }
             }
             output.push('\n');
         }
@@ -134,10 +173,72 @@ impl FileStructuralContext {
             output.push('\n');
         }
 
+        // Call graph
+        if !self.calls.is_empty() {
+            output.push_str("### Calls\n");
+            for c in &self.calls {
+                output.push_str(&format!(
+                    "- `{}` → `{}`{}\n",
+                    c.caller,
+                    c.callee,
+                    c.annotation()
+                ));
+            }
+            output.push('\n');
+        }
+
+        if !self.callers.is_empty() {
+            output.push_str("### Called From Other Files\n");
+            for c in &self.callers {
+                output.push_str(&format!(
+                    "- `{}` → `{}`{}\n",
+                    c.caller,
+                    c.callee,
+                    c.annotation()
+                ));
+            }
+            output.push('\n');
+        }
+
+        if !self.tested_by.is_empty() {
+            output.push_str("### Tested By\n");
+            for t in &self.tested_by {
+                match t.subject {
+                    Some(ref subject) => {
+                        output.push_str(&format!("- `{}` → `{}`\n", t.test, subject))
+                    }
+                    None => output.push_str(&format!("- `{}`\n", t.test)),
+                }
+            }
+            output.push('\n');
+        }
+
         output
     }
 }
 
+/// Longest doc summary included per item, in characters
+const MAX_DOC_SUMMARY: usize = 300;
+
+/// First paragraph of a doc comment on a single line.
+fn doc_summary(doc: &str) -> String {
+    let paragraph = doc.split("\n\n").next().unwrap_or(doc);
+    let line = paragraph.split_whitespace().collect::<Vec<_>>().join(" ");
+    if line.chars().count() > MAX_DOC_SUMMARY {
+        let cut: String = line.chars().take(MAX_DOC_SUMMARY).collect();
+        format!("{}…", cut.trim_end())
+    } else {
+        line
+    }
+}
+
+/// Append an item's doc comment as a quoted line under its bullet.
+fn push_doc(output: &mut String, description: Option<&str>) {
+    if let Some(doc) = description {
+        output.push_str(&format!("  > {}\n", doc_summary(doc)));
+    }
+}
+
 /// Function fact from parser
 #[derive(Debug, Clone)]
 pub struct FunctionFact {
@@ -146,6 +247,8 @@ pub struct FunctionFact {
     pub visibility: Visibility,
     pub is_async: bool,
     pub line: u32,
+    /// Author-written doc comment
+    pub description: Option<String>,
 }
 
 /// Type fact (struct/enum/trait) from parser
@@ -154,6 +257,18 @@ pub struct TypeFact {
     pub name: String,
     pub visibility: Visibility,
     pub line: u32,
+    /// Author-written doc comment
+    pub description: Option<String>,
+}
+
+/// UI component fact from parser
+#[derive(Debug, Clone)]
+pub struct ComponentFact {
+    pub name: String,
+    pub line: u32,
+    /// Props as `name: type`, with `?` marking optional ones
+    pub props: Vec<String>,
+    pub emits: Vec<String>,
 }
 
 /// Dependency fact from parser
@@ -170,6 +285,42 @@ pub struct ImplementsFact {
     pub trait_name: String,
 }
 
+/// Call relationship fact from parser
+#[derive(Debug, Clone)]
+pub struct CallFact {
+    /// Caller name, qualified with its path when outside the current file
+    pub caller: String,
+    pub callee: String,
+    pub call_count: u32,
+    pub is_async: bool,
+}
+
+impl CallFact {
+    fn annotation(&self) -> String {
+        let mut parts = Vec::new();
+        if self.call_count > 1 {
+            parts.push(format!("{} sites", self.call_count));
+        }
+        if self.is_async {
+            parts.push("async".to_string());
+        }
+        if parts.is_empty() {
+            String::new()
+        } else {
+            format!(" ({})", parts.join(", "))
+        }
+    }
+}
+
+/// Test covering a file, from `tests` edges
+#[derive(Debug, Clone)]
+pub struct TestFact {
+    /// Test function as `path::name`, or the test file's path
+    pub test: String,
+    /// Function or class under test; `None` when the test covers the file
+    pub subject: Option<String>,
+}
+
 /// Query structural context from the Knowledge Graph
 pub struct GraphContextProvider<'a> {
     db: &'a Database,
@@ -196,6 +347,9 @@ impl<'a> GraphContextProvider<'a> {
         // Categorize nodes by type
         for node in nodes {
             match node.node_type {
+                NodeType::File => {
+                    ctx.module_doc = node.metadata.description.clone();
+                }
                 NodeType::Function | NodeType::Method => {
                     ctx.functions.push(self.node_to_function_fact(&node));
                 }
@@ -208,6 +362,9 @@ impl<'a> GraphContextProvider<'a> {
                 NodeType::Interface => {
                     ctx.traits.push(self.node_to_type_fact(&node));
                 }
+                NodeType::Component => {
+                    ctx.components.push(self.node_to_component_fact(&node));
+                }
                 _ => {}
             }
         }
@@ -219,6 +376,40 @@ impl<'a> GraphContextProvider<'a> {
             }
         }
 
+        // Get call graph
+        if let Ok(calls) = self.db.get_file_calls(file_path) {
+            for call in calls {
+                if call.caller_path == file_path {
+                    ctx.calls.push(CallFact {
+                        caller: call.caller,
+                        callee: call.callee,
+                        call_count: call.call_count,
+                        is_async: call.is_async,
+                    });
+                } else {
+                    ctx.callers.push(CallFact {
+                        caller: format!("{}::{}", call.caller_path, call.caller),
+                        callee: call.callee,
+                        call_count: call.call_count,
+                        is_async: call.is_async,
+                    });
+                }
+            }
+        }
+
+        if let Ok(tests) = self.db.get_file_tests(file_path) {
+            ctx.tested_by = tests
+                .into_iter()
+                .map(|t| TestFact {
+                    test: match t.test {
+                        Some(name) => format!("{}::{}", t.test_path, name),
+                        None => t.test_path,
+                    },
+                    subject: t.subject,
+                })
+                .collect();
+        }
+
         ctx
     }
 
@@ -257,6 +448,30 @@ impl<'a> GraphContextProvider<'a> {
             visibility,
             is_async,
             line: node.evidence.start_line,
+            description: node.metadata.description.clone(),
+        }
+    }
+
+    fn node_to_component_fact(&self, node: &Node) -> ComponentFact {
+        let meta = node.metadata.component_metadata.as_ref();
+        let props = meta
+            .and_then(|m| m.props.as_ref())
+            .map(|props| {
+                props
+                    .iter()
+                    .map(|p| {
+                        let optional = if p.required { "" } else { "?" };
+                        format!("{}{}: {}", p.name, optional, p.prop_type)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        ComponentFact {
+            name: node.name.clone(),
+            line: node.evidence.start_line,
+            props,
+            emits: meta.and_then(|m| m.emits.clone()).unwrap_or_default(),
         }
     }
 
@@ -267,6 +482,7 @@ impl<'a> GraphContextProvider<'a> {
             name: node.name.clone(),
             visibility,
             line: node.evidence.start_line,
+            description: node.metadata.description.clone(),
         }
     }
 }
@@ -292,6 +508,7 @@ mod tests {
                     visibility: Visibility::Public,
                     is_async: true,
                     line: 34,
+                    description: Some("Analyzes one file.\n\nDetails follow.".to_string()),
                 },
                 FunctionFact {
                     name: "parse_result".to_string(),
@@ -299,6 +516,7 @@ mod tests {
                     visibility: Visibility::Private,
                     is_async: false,
                     line: 77,
+                    description: None,
                 },
             ],
             ..Default::default()
@@ -309,6 +527,8 @@ mod tests {
         assert!(prompt.contains("### Functions"));
         assert!(prompt.contains("analyze_file"));
         assert!(prompt.contains("line 34"));
+        assert!(prompt.contains("  > Analyzes one file.\n"));
+        assert!(!prompt.contains("Details follow."));
     }
 
     #[test]
@@ -318,16 +538,19 @@ mod tests {
                 name: "FileAnalyzer".to_string(),
                 visibility: Visibility::Public,
                 line: 23,
+                description: None,
             }],
             enums: vec![TypeFact {
                 name: "Complexity".to_string(),
                 visibility: Visibility::Public,
                 line: 10,
+                description: None,
             }],
             traits: vec![TypeFact {
                 name: "Parser".to_string(),
                 visibility: Visibility::Public,
                 line: 5,
+                description: None,
             }],
             ..Default::default()
         };
@@ -340,4 +563,69 @@ mod tests {
         assert!(prompt.contains("### Traits"));
         assert!(prompt.contains("Parser"));
     }
+
+    #[test]
+    fn test_context_with_components() {
+        let ctx = FileStructuralContext {
+            components: vec![ComponentFact {
+                name: "UserCard".to_string(),
+                line: 1,
+                props: vec!["user: User".to_string(), "compact?: boolean".to_string()],
+                emits: vec!["select".to_string()],
+            }],
+            ..Default::default()
+        };
+
+        let prompt = ctx.to_prompt_section();
+        assert!(prompt.contains("### Components"));
+        assert!(prompt.contains("- `<UserCard>` [line 1]"));
+        assert!(prompt.contains("props: user: User, compact?: boolean"));
+        assert!(prompt.contains("emits: select"));
+    }
+
+    #[test]
+    fn test_context_with_calls() {
+        let ctx = FileStructuralContext {
+            calls: vec![CallFact {
+                caller: "run".to_string(),
+                callee: "step".to_string(),
+                call_count: 2,
+                is_async: true,
+            }],
+            callers: vec![CallFact {
+                caller: "src/main.rs::main".to_string(),
+                callee: "run".to_string(),
+                call_count: 1,
+                is_async: false,
+            }],
+            ..Default::default()
+        };
+
+        let prompt = ctx.to_prompt_section();
+        assert!(prompt.contains("### Calls"));
+        assert!(prompt.contains("- `run` → `step` (2 sites, async)"));
+        assert!(prompt.contains("### Called From Other Files"));
+        assert!(prompt.contains("- `src/main.rs::main` → `run`\n"));
+    }
+
+    #[test]
+    fn test_context_with_tests() {
+        let ctx = FileStructuralContext {
+            tested_by: vec![
+                TestFact {
+                    test: "src/lib.rs::adds".to_string(),
+                    subject: Some("add".to_string()),
+                },
+                TestFact {
+                    test: "tests/lib.rs".to_string(),
+                    subject: None,
+                },
+            ],
+            ..Default::default()
+        };
+
+        let prompt = ctx.to_prompt_section();
+        assert!(prompt.contains("### Tested By\n- `src/lib.rs::adds` → `add`\n"));
+        assert!(prompt.contains("- `tests/lib.rs`\n"));
+    }
 }
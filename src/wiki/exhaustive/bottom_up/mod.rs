@@ -31,8 +31,9 @@ pub use prioritizer::{BatchPrioritizer, PrioritizedFile};
 pub use types::*;
 
 use crate::ai::provider::SharedProvider;
+use crate::analyzer::DependencyGraph;
 use crate::config::ModeConfig;
-use crate::storage::SharedDatabase;
+use crate::storage::{GraphStore, SharedDatabase};
 use crate::types::error::WeaveError;
 use crate::wiki::exhaustive::characterization::profile::ProjectProfile;
 use crate::wiki::exhaustive::checkpoint::CheckpointContext;
@@ -201,7 +202,7 @@ impl BottomUpAnalyzer {
         }
 
         // Prioritize files with metadata
-        let prioritizer = BatchPrioritizer::new(&self.profile);
+        let prioritizer = self.prioritizer();
         let prioritized = prioritizer.prioritize_with_metadata(remaining);
 
         tracing::info!(
@@ -429,6 +430,31 @@ impl BottomUpAnalyzer {
         Ok(())
     }
 
+    /// Prioritizer for this project, ranking files by their centrality in
+    /// the knowledge graph and the complexity of their functions when one
+    /// has been built.
+    fn prioritizer(&self) -> BatchPrioritizer {
+        let prioritizer = BatchPrioritizer::new(&self.profile);
+        let Some(ctx) = &self.checkpoint else {
+            return prioritizer;
+        };
+        let prioritizer = match ctx.db.get_max_cyclomatic_by_file() {
+            Ok(max_cyclomatic) => prioritizer.with_complexity(&max_cyclomatic),
+            Err(e) => {
+                tracing::warn!("Bottom-Up: Failed to load function complexity: {}", e);
+                prioritizer
+            }
+        };
+        match DependencyGraph::files(&GraphStore::new(&ctx.db)) {
+            Ok(graph) if !graph.is_empty() => prioritizer.with_centrality(&graph.pagerank()),
+            Ok(_) => prioritizer,
+            Err(e) => {
+                tracing::warn!("Bottom-Up: Failed to load the file graph: {}", e);
+                prioritizer
+            }
+        }
+    }
+
     /// Load set of already-analyzed file paths (for resume)
     fn load_completed_files(&self) -> Result<HashSet<String>, WeaveError> {
         let Some(ctx) = &self.checkpoint else {
@@ -7,12 +7,21 @@
 //! 4. Critical files last (entry points, core architecture)
 //!
 //! This ensures parent/core modules can link to already-documented child modules.
+//! When the knowledge graph is available, files with the highest PageRank
+//! are raised to the Important and Core tiers whatever their path, and files
+//! declaring a highly complex function to the Important tier.
+//! Test files are always Leaf: their documentation is a short summary of
+//! what they cover.
 
+use std::collections::{HashMap, HashSet};
+
+use crate::analyzer::parser::is_test_path;
+use crate::analyzer::resolver::normalize_path;
 use crate::storage::Database;
 use crate::wiki::exhaustive::characterization::profile::{KeyArea, ProjectProfile};
 use crate::wiki::exhaustive::types::Importance;
 
-use super::file_metrics::FileMetrics;
+use super::file_metrics::{COMPLEX_FUNCTION, FileMetrics};
 use super::types::ProcessingTier;
 
 /// File with its processing metadata
@@ -24,17 +33,64 @@ pub struct PrioritizedFile {
     pub depth: usize,
 }
 
+/// Share of files, by PageRank, placed in the Core tier
+const CORE_CENTRALITY_SHARE: f64 = 0.05;
+/// Share of files, by PageRank, placed in the Important tier or above
+const IMPORTANT_CENTRALITY_SHARE: f64 = 0.20;
+
 pub struct BatchPrioritizer {
     key_areas: Vec<KeyArea>,
+    /// Minimum tier of the most central files, keyed by normalized path
+    central: HashMap<String, ProcessingTier>,
+    /// Files declaring a highly complex function, keyed by normalized path
+    complex: HashSet<String>,
 }
 
 impl BatchPrioritizer {
     pub fn new(profile: &ProjectProfile) -> Self {
         Self {
             key_areas: profile.key_areas.clone(),
+            central: HashMap::new(),
+            complex: HashSet::new(),
         }
     }
 
+    /// Raise the most central files, by file-graph PageRank, to the
+    /// Important or Core tier.
+    pub fn with_centrality(mut self, ranks: &HashMap<String, f64>) -> Self {
+        let mut ranked: Vec<(&String, f64)> = ranks.iter().map(|(f, r)| (f, *r)).collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+
+        let count = ranked.len() as f64;
+        let core = (count * CORE_CENTRALITY_SHARE).ceil() as usize;
+        let important = (count * IMPORTANT_CENTRALITY_SHARE).ceil() as usize;
+        self.central = ranked
+            .into_iter()
+            .take(important)
+            .enumerate()
+            .map(|(i, (file, _))| {
+                let tier = if i < core {
+                    ProcessingTier::Core
+                } else {
+                    ProcessingTier::Important
+                };
+                (normalize_path(file), tier)
+            })
+            .collect();
+        self
+    }
+
+    /// Raise files whose most complex function, by cyclomatic complexity,
+    /// reaches [`COMPLEX_FUNCTION`] to the Important tier.
+    pub fn with_complexity(mut self, max_cyclomatic: &HashMap<String, u32>) -> Self {
+        self.complex = max_cyclomatic
+            .iter()
+            .filter(|(_, cyclomatic)| **cyclomatic >= COMPLEX_FUNCTION)
+            .map(|(file, _)| normalize_path(file))
+            .collect();
+        self
+    }
+
     /// Prioritize files with full metadata for processing
     pub fn prioritize_with_metadata(&self, files: Vec<String>) -> Vec<PrioritizedFile> {
         let mut prioritized: Vec<PrioritizedFile> = files
@@ -75,31 +131,48 @@ impl BatchPrioritizer {
 
     /// Get processing tier for a file
     pub fn get_tier(&self, file: &str) -> ProcessingTier {
+        if is_test_path(file) {
+            return ProcessingTier::Leaf;
+        }
+
         // Entry points are always Core tier
         if self.is_entry_point(file) {
             return ProcessingTier::Core;
         }
 
-        // Check against key areas
-        for area in &self.key_areas {
-            if file.starts_with(&area.path) || file.contains(&area.path) {
-                return match area.importance {
-                    Importance::Critical => ProcessingTier::Core,
-                    Importance::High => ProcessingTier::Important,
-                    Importance::Medium => ProcessingTier::Standard,
-                    Importance::Low => ProcessingTier::Leaf,
-                };
-            }
+        // Check against key areas, falling back to path heuristics
+        let tier = self
+            .key_areas
+            .iter()
+            .find(|area| file.starts_with(&area.path) || file.contains(&area.path))
+            .map(|area| match area.importance {
+                Importance::Critical => ProcessingTier::Core,
+                Importance::High => ProcessingTier::Important,
+                Importance::Medium => ProcessingTier::Standard,
+                Importance::Low => ProcessingTier::Leaf,
+            })
+            .unwrap_or_else(|| self.infer_tier_from_path(file));
+
+        // Central files are never ranked below their place in the graph
+        let file = normalize_path(file);
+        let tier = match self.central.get(&file) {
+            Some(&central) if (central as u8) > (tier as u8) => central,
+            _ => tier,
+        };
+        if self.complex.contains(&file) && (tier as u8) < (ProcessingTier::Important as u8) {
+            return ProcessingTier::Important;
         }
-
-        // Default tier based on path heuristics
-        self.infer_tier_from_path(file)
+        tier
     }
 
     /// Get processing tier using graph-based metrics if available.
     ///
     /// Falls back to heuristic-based tier if metrics cannot be computed.
     pub fn get_tier_with_metrics(&self, file: &str, db: Option<&Database>) -> ProcessingTier {
+        if is_test_path(file) {
+            return ProcessingTier::Leaf;
+        }
+
         // Try graph-based metrics first if database is available
         if let Some(database) = db
             && let Ok(metrics) = FileMetrics::from_database(database, file)
@@ -372,6 +445,49 @@ mod tests {
         assert_eq!(prioritizer.get_tier("src/main.rs"), ProcessingTier::Core);
     }
 
+    #[test]
+    fn test_central_files_are_raised() {
+        let profile = make_profile();
+        let ranks: HashMap<String, f64> = (0..20)
+            .map(|i| (format!("src/utils/f{:02}.rs", i), 1.0 / (i + 1) as f64))
+            .collect();
+        let prioritizer = BatchPrioritizer::new(&profile).with_centrality(&ranks);
+
+        assert_eq!(
+            prioritizer.get_tier("./src/utils/f00.rs"),
+            ProcessingTier::Core
+        );
+        assert_eq!(
+            prioritizer.get_tier("src/utils/f03.rs"),
+            ProcessingTier::Important
+        );
+        assert_eq!(
+            prioritizer.get_tier("src/utils/f04.rs"),
+            ProcessingTier::Leaf
+        );
+        // Key areas above the graph tier keep theirs
+        assert_eq!(
+            prioritizer.get_tier("src/core/engine.rs"),
+            ProcessingTier::Core
+        );
+    }
+
+    #[test]
+    fn test_test_files_are_leaf() {
+        let profile = make_profile();
+        let ranks = HashMap::from([("src/core/engine_test.go".to_string(), 1.0)]);
+        let prioritizer = BatchPrioritizer::new(&profile).with_centrality(&ranks);
+
+        assert_eq!(
+            prioritizer.get_tier("src/core/engine_test.go"),
+            ProcessingTier::Leaf
+        );
+        assert_eq!(
+            prioritizer.get_tier("src/core/tests/main.rs"),
+            ProcessingTier::Leaf
+        );
+    }
+
     #[test]
     fn test_prioritize_with_metadata() {
         let profile = make_profile();
@@ -390,4 +506,34 @@ mod tests {
         assert_eq!(prioritized[1].tier, ProcessingTier::Core);
         assert!(prioritized[1].is_entry_point);
     }
+
+    #[test]
+    fn test_complex_files_are_raised() {
+        let profile = make_profile();
+        let max_cyclomatic = HashMap::from([
+            ("./src/utils/parse.rs".to_string(), COMPLEX_FUNCTION),
+            ("./src/utils/format.rs".to_string(), COMPLEX_FUNCTION - 1),
+            ("./src/main.rs".to_string(), COMPLEX_FUNCTION + 10),
+        ]);
+        let prioritizer = BatchPrioritizer::new(&profile).with_complexity(&max_cyclomatic);
+
+        let files = vec![
+            "src/main.rs".to_string(),
+            "src/utils/parse.rs".to_string(),
+            "src/utils/format.rs".to_string(),
+        ];
+        let tiers: Vec<(String, ProcessingTier)> = prioritizer
+            .prioritize_with_metadata(files)
+            .into_iter()
+            .map(|f| (f.path, f.tier))
+            .collect();
+        assert_eq!(
+            tiers,
+            vec![
+                ("src/utils/format.rs".to_string(), ProcessingTier::Leaf),
+                ("src/utils/parse.rs".to_string(), ProcessingTier::Important),
+                ("src/main.rs".to_string(), ProcessingTier::Core),
+            ]
+        );
+    }
 }
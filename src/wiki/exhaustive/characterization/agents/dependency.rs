@@ -25,6 +25,20 @@ impl DependencyAgent {
                 file.language.as_deref().unwrap_or("unknown")
             ));
         }
+        if !context.packages.is_empty() {
+            data.push_str(
+                "\nThird-party packages (ecosystem, name, locked version, importing files):\n",
+            );
+            for package in &context.packages {
+                data.push_str(&format!(
+                    "- {} {} {} ({} files)\n",
+                    package.ecosystem,
+                    package.name,
+                    package.version.as_deref().unwrap_or("unlocked"),
+                    package.importers.len()
+                ));
+            }
+        }
         data
     }
 
@@ -97,7 +111,14 @@ impl DependencyAgent {
 
         DependencyInsight {
             internal_deps,
-            external_deps: vec![],
+            external_deps: context
+                .packages
+                .iter()
+                .map(|p| match &p.version {
+                    Some(version) => format!("{} {}", p.name, version),
+                    None => p.name.clone(),
+                })
+                .collect(),
             framework_indicators: indicators,
             circular_deps,
         }
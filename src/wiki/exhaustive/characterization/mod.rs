@@ -16,8 +16,9 @@ pub mod schemas;
 pub mod synthesis;
 
 use crate::ai::provider::SharedProvider;
+use crate::analyzer::scanner::FileScanner;
 use crate::config::{AnalysisMode, ModeConfig, ProjectScale};
-use crate::storage::SharedDatabase;
+use crate::storage::{ExternalPackageRow, SharedDatabase};
 use crate::types::error::WeaveError;
 use crate::wiki::exhaustive::checkpoint::CheckpointContext;
 pub use profile::ProjectProfile;
@@ -32,6 +33,10 @@ pub struct CharacterizationAnalyzer {
     config: ModeConfig,
     /// LLM provider for agent interactions
     provider: SharedProvider,
+    /// Selects the files the profile is built from
+    scanner: Option<FileScanner>,
+    /// Third-party packages from the code graph, for the dependency agent
+    packages: Vec<ExternalPackageRow>,
     checkpoint: Option<CheckpointContext>,
 }
 
@@ -49,10 +54,26 @@ impl CharacterizationAnalyzer {
             scale,
             config,
             provider,
+            scanner: None,
+            packages: Vec::new(),
             checkpoint: None,
         }
     }
 
+    /// Scan with the project's file selection instead of the default
+    /// source-file scanner
+    pub fn with_scanner(mut self, scanner: FileScanner) -> Self {
+        self.scanner = Some(scanner);
+        self
+    }
+
+    /// Describe dependencies from the packages the code graph records
+    /// instead of from manifest file listings alone
+    pub fn with_packages(mut self, packages: Vec<ExternalPackageRow>) -> Self {
+        self.packages = packages;
+        self
+    }
+
     /// Enable checkpoint/resume with database storage
     pub fn with_checkpoint(mut self, db: SharedDatabase, session_id: String) -> Self {
         self.checkpoint = Some(CheckpointContext::new(db, session_id));
@@ -252,6 +273,7 @@ impl CharacterizationAnalyzer {
             project_root: self.project_root.clone(),
             files: files.to_vec(),
             prior_insights,
+            packages: self.packages.clone(),
             provider: self.provider.clone(),
         };
 
@@ -279,10 +301,10 @@ impl CharacterizationAnalyzer {
 
     /// Collect file information from project root
     fn collect_file_info(&self) -> Result<Vec<FileInfo>, WeaveError> {
-        use crate::analyzer::scanner::FileScanner;
-
-        let scanner = FileScanner::source_files(&self.project_root);
-        let scanned_files = scanner.scan()?;
+        let scanned_files = match &self.scanner {
+            Some(scanner) => scanner.scan()?,
+            None => FileScanner::source_files(&self.project_root).scan()?,
+        };
         let mut files = Vec::new();
 
         for entry in scanned_files {
@@ -336,6 +358,7 @@ impl CharacterizationAnalyzer {
             project_root: self.project_root.clone(),
             files: files.to_vec(),
             prior_insights: vec![],
+            packages: self.packages.clone(),
             provider: self.provider.clone(),
         };
 
@@ -412,6 +435,7 @@ impl CharacterizationAnalyzer {
             project_root: self.project_root.clone(),
             files: files.to_vec(),
             prior_insights: turn1_outputs.to_vec(),
+            packages: self.packages.clone(),
             provider: self.provider.clone(),
         };
 
@@ -483,6 +507,7 @@ impl CharacterizationAnalyzer {
             project_root: self.project_root.clone(),
             files: files.to_vec(),
             prior_insights: prior_outputs.to_vec(),
+            packages: self.packages.clone(),
             provider: self.provider.clone(),
         };
 
@@ -768,6 +793,8 @@ pub struct CharacterizationContext {
     pub files: Vec<FileInfo>,
     /// Previous turn insights (empty for Turn 1)
     pub prior_insights: Vec<AgentOutput>,
+    /// Third-party packages with locked versions and importing files
+    pub packages: Vec<ExternalPackageRow>,
     /// LLM provider
     pub provider: SharedProvider,
 }
@@ -0,0 +1,375 @@
+//! Configuration and Deployment Pages
+//!
+//! Renders `configuration.md` (package manifests, their dependencies and
+//! scripts, and a reference of every environment variable, configuration key
+//! and feature flag) and `deployment.md` (container images, services, ports
+//! and CI workflows) from the facts the config parser read and the setting
+//! reads the code parsers found. Like the API catalog, neither page needs
+//! LLM output.
+
+use std::collections::BTreeMap;
+
+use crate::storage::{ConfigFileRow, SettingRow};
+use crate::types::{ConfigKind, ConfigMetadata};
+
+/// Generator for the configuration and deployment pages
+pub struct ConfigPagesGenerator;
+
+impl ConfigPagesGenerator {
+    /// Whether any file belongs on the deployment page
+    pub fn has_deployment(files: &[ConfigFileRow]) -> bool {
+        files.iter().any(|f| f.config.kind.is_deployment())
+    }
+
+    /// Generate `configuration.md`: manifests, then the configuration
+    /// reference
+    pub fn generate_configuration_md(files: &[ConfigFileRow], settings: &[SettingRow]) -> String {
+        let mut output = String::new();
+        output.push_str("# Configuration\n\n");
+        output.push_str(&format!(
+            "{} configuration files read from the repository.\n\n",
+            files.len()
+        ));
+
+        let manifests: Vec<&ConfigFileRow> = files
+            .iter()
+            .filter(|f| {
+                !f.config.kind.is_deployment()
+                    && !matches!(f.config.kind, ConfigKind::Env | ConfigKind::Lockfile)
+            })
+            .collect();
+        if !manifests.is_empty() {
+            output.push_str("## Package Manifests\n\n");
+        }
+        for row in manifests {
+            let config = &row.config;
+            output.push_str(&format!("### `{}`\n\n", display_path(&row.file)));
+            match &config.name {
+                Some(name) => {
+                    output.push_str(&format!("{} package `{}`.\n\n", config.kind.as_str(), name))
+                }
+                None => output.push_str(&format!("{} manifest.\n\n", config.kind.as_str())),
+            }
+
+            if !config.dependencies.is_empty() {
+                output.push_str("| Dependency | Version | Scope |\n");
+                output.push_str("|------------|---------|-------|\n");
+                for dep in &config.dependencies {
+                    output.push_str(&format!(
+                        "| `{}` | {} | {} |\n",
+                        dep.name,
+                        dep.version.as_deref().map_or("-".to_string(), cell),
+                        dep.scope
+                    ));
+                }
+                output.push('\n');
+            }
+            push_scripts(&mut output, config);
+        }
+
+        let reference = configuration_reference(files, settings);
+        output.push_str("## Configuration Reference\n\n");
+        if reference.is_empty() {
+            output
+                .push_str("No environment variables, configuration keys or feature flags found.\n");
+            return output;
+        }
+        output.push_str("| Variable | Kind | Read by | Declared in |\n");
+        output.push_str("|----------|------|---------|-------------|\n");
+        for (name, entry) in reference {
+            output.push_str(&format!(
+                "| `{}` | {} | {} | {} |\n",
+                name,
+                kind_label(entry.kind),
+                list_or_dash(&entry.readers),
+                list_or_dash(&entry.declared_in)
+            ));
+        }
+        output
+    }
+
+    /// Generate `deployment.md`: images, services, ports and CI workflows
+    pub fn generate_deployment_md(files: &[ConfigFileRow]) -> String {
+        let mut output = String::new();
+        output.push_str("# Deployment\n\n");
+
+        let of_kind = |kinds: &[ConfigKind]| -> Vec<&ConfigFileRow> {
+            files
+                .iter()
+                .filter(|f| kinds.contains(&f.config.kind))
+                .collect()
+        };
+
+        let dockerfiles = of_kind(&[ConfigKind::Dockerfile]);
+        if !dockerfiles.is_empty() {
+            output.push_str("## Container Images\n\n");
+            output.push_str("| Dockerfile | Base images | Exposed ports | Entrypoint |\n");
+            output.push_str("|------------|-------------|---------------|------------|\n");
+            for row in dockerfiles {
+                let images: Vec<String> = row
+                    .config
+                    .dependencies
+                    .iter()
+                    .map(|d| match &d.version {
+                        Some(tag) => format!("`{}:{}`", d.name, tag),
+                        None => format!("`{}`", d.name),
+                    })
+                    .collect();
+                let entrypoint = row
+                    .config
+                    .scripts
+                    .iter()
+                    .map(|s| format!("`{}`", cell(&s.command)))
+                    .collect::<Vec<_>>();
+                output.push_str(&format!(
+                    "| `{}` | {} | {} | {} |\n",
+                    display_path(&row.file),
+                    list_or_dash(&images),
+                    list_or_dash(&row.config.ports),
+                    list_or_dash(&entrypoint)
+                ));
+            }
+            output.push('\n');
+        }
+
+        let orchestration = of_kind(&[ConfigKind::Compose, ConfigKind::Kubernetes]);
+        if !orchestration.is_empty() {
+            output.push_str("## Services\n\n");
+            output.push_str("| Service | Kind | Image | Ports | Depends on | Source |\n");
+            output.push_str("|---------|------|-------|-------|------------|--------|\n");
+            for row in orchestration {
+                for service in &row.config.services {
+                    let kind = service.kind.as_deref().unwrap_or(row.config.kind.as_str());
+                    output.push_str(&format!(
+                        "| `{}` | {} | {} | {} | {} | `{}` |\n",
+                        service.name,
+                        kind,
+                        service
+                            .image
+                            .as_deref()
+                            .map_or("-".to_string(), |i| format!("`{}`", i)),
+                        list_or_dash(&service.ports),
+                        list_or_dash(&service.depends_on),
+                        display_path(&row.file)
+                    ));
+                }
+            }
+            output.push('\n');
+        }
+
+        let workflows = of_kind(&[ConfigKind::GithubActions]);
+        if !workflows.is_empty() {
+            output.push_str("## CI Workflows\n\n");
+        }
+        for row in workflows {
+            let config = &row.config;
+            let title = config.name.as_deref().unwrap_or("Workflow");
+            output.push_str(&format!(
+                "### {} (`{}`)\n\n",
+                title,
+                display_path(&row.file)
+            ));
+            output.push_str("| Job | Needs | Container |\n");
+            output.push_str("|-----|-------|-----------|\n");
+            for job in &config.services {
+                output.push_str(&format!(
+                    "| `{}` | {} | {} |\n",
+                    job.name,
+                    list_or_dash(&job.depends_on),
+                    job.image
+                        .as_deref()
+                        .map_or("-".to_string(), |i| format!("`{}`", i))
+                ));
+            }
+            output.push('\n');
+
+            let actions: Vec<String> = config
+                .dependencies
+                .iter()
+                .filter(|d| d.scope == "action")
+                .map(|d| match &d.version {
+                    Some(version) => format!("`{}@{}`", d.name, version),
+                    None => format!("`{}`", d.name),
+                })
+                .collect();
+            if !actions.is_empty() {
+                output.push_str(&format!("Actions: {}\n\n", actions.join(", ")));
+            }
+            push_scripts(&mut output, config);
+        }
+
+        output
+    }
+}
+
+fn push_scripts(output: &mut String, config: &ConfigMetadata) {
+    if config.scripts.is_empty() {
+        return;
+    }
+    output.push_str("| Script | Command |\n");
+    output.push_str("|--------|---------|\n");
+    for script in &config.scripts {
+        output.push_str(&format!(
+            "| {} | `{}` |\n",
+            cell(&script.name),
+            cell(&script.command)
+        ));
+    }
+    output.push('\n');
+}
+
+#[derive(Default)]
+struct ReferenceEntry<'a> {
+    kind: &'a str,
+    readers: Vec<String>,
+    declared_in: Vec<String>,
+}
+
+/// Every setting by name, with the code reading it and the config files
+/// declaring it. Variables only declared are environment variables.
+fn configuration_reference<'a>(
+    files: &'a [ConfigFileRow],
+    settings: &'a [SettingRow],
+) -> BTreeMap<&'a str, ReferenceEntry<'a>> {
+    let mut reference: BTreeMap<&str, ReferenceEntry> = BTreeMap::new();
+    for setting in settings {
+        let entry = reference.entry(&setting.name).or_default();
+        if entry.kind.is_empty() {
+            entry.kind = &setting.kind;
+        }
+        let file = display_path(&setting.file);
+        if setting.readers.is_empty() {
+            entry.readers.push(format!("`{}`", file));
+        }
+        for reader in &setting.readers {
+            entry.readers.push(format!("`{}` (`{}`)", reader, file));
+        }
+    }
+    for row in files {
+        for name in &row.config.env_vars {
+            let entry = reference.entry(name).or_default();
+            if entry.kind.is_empty() {
+                entry.kind = "env";
+            }
+            entry
+                .declared_in
+                .push(format!("`{}`", display_path(&row.file)));
+        }
+    }
+    reference
+}
+
+fn kind_label(kind: &str) -> &str {
+    match kind {
+        "env" => "Environment",
+        "config_key" => "Config key",
+        "feature_flag" => "Feature flag",
+        other => other,
+    }
+}
+
+fn display_path(path: &str) -> &str {
+    path.trim_start_matches("./")
+}
+
+/// Text fit for a table cell: first line only, pipes escaped.
+fn cell(text: &str) -> String {
+    let mut lines = text.lines();
+    let first = lines.next().unwrap_or_default().replace('|', "\\|");
+    if lines.next().is_some() {
+        format!("{} …", first)
+    } else {
+        first
+    }
+}
+
+fn list_or_dash(items: &[String]) -> String {
+    if items.is_empty() {
+        "-".to_string()
+    } else {
+        items.join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::parser::{ConfigParser, Parser, RustParser, config_kind};
+    use crate::storage::{Database, GraphStore};
+
+    #[test]
+    fn test_pages_from_graph() {
+        let db = Database::open_in_memory().unwrap();
+        db.initialize().unwrap();
+        let store = GraphStore::new(&db);
+
+        let files = [
+            (
+                "./package.json",
+                r#"{"name": "web", "dependencies": {"react": "^18.2.0"},
+                    "scripts": {"build": "vite build | tee log"}}"#,
+            ),
+            ("./.env.example", "API_URL=\nPORT=3000\n"),
+            (
+                "./Dockerfile",
+                "FROM node:20-alpine\nENV PORT=3000\nEXPOSE 3000\nCMD [\"node\", \"server.js\"]\n",
+            ),
+            (
+                "./docker-compose.yml",
+                "services:\n  web:\n    build: .\n    ports: [\"3000:3000\"]\n    depends_on: [redis]\n  redis:\n    image: redis:7\n",
+            ),
+        ];
+        for (path, content) in files {
+            let result = ConfigParser::new(config_kind(path).unwrap())
+                .parse(path, content)
+                .unwrap();
+            for node in &result.nodes {
+                store.insert_node(node).unwrap();
+            }
+        }
+
+        let code = RustParser::new()
+            .unwrap()
+            .parse(
+                "./src/main.rs",
+                "fn main() {\n    let port = std::env::var(\"PORT\");\n}\n\n\
+                 #[cfg(feature = \"metrics\")]\nconst LEVEL: &str = env!(\"LOG_LEVEL\");\n",
+            )
+            .unwrap();
+        for node in &code.nodes {
+            store.insert_node(node).unwrap();
+        }
+        for edge in &code.edges {
+            store.insert_edge(edge).unwrap();
+        }
+
+        let rows = db.get_config_files().unwrap();
+        assert_eq!(rows.len(), 4);
+        assert!(ConfigPagesGenerator::has_deployment(&rows));
+        let settings = db.get_settings().unwrap();
+        assert_eq!(settings.len(), 3);
+
+        let configuration = ConfigPagesGenerator::generate_configuration_md(&rows, &settings);
+        assert!(configuration.contains("### `package.json`\n\nnpm package `web`."));
+        assert!(configuration.contains("| `react` | ^18.2.0 | runtime |"));
+        assert!(configuration.contains("| build | `vite build \\| tee log` |"));
+        assert!(configuration.contains(
+            "| `PORT` | Environment | `main` (`src/main.rs`) | `.env.example`, `Dockerfile` |"
+        ));
+        assert!(configuration.contains("| `API_URL` | Environment | - | `.env.example` |"));
+        assert!(configuration.contains("| `LOG_LEVEL` | Environment | `src/main.rs` | - |"));
+        assert!(configuration.contains("| `metrics` | Feature flag | `src/main.rs` | - |"));
+        assert!(!configuration.contains("docker-compose"));
+
+        let deployment = ConfigPagesGenerator::generate_deployment_md(&rows);
+        assert!(
+            deployment.contains("| `Dockerfile` | `node:20-alpine` | 3000 | `node server.js` |")
+        );
+        assert!(
+            deployment.contains(
+                "| `web` | Docker Compose | - | 3000:3000 | redis | `docker-compose.yml` |"
+            )
+        );
+        assert!(deployment.contains("| `redis` | Docker Compose | `redis:7` | - | - |"));
+    }
+}
@@ -0,0 +1,207 @@
+//! ER Diagram for Consolidation
+//!
+//! Builds a domain's data model diagram from the entities the parsers
+//! extracted, so table names, columns and relations come from declarations
+//! rather than from the synthesis prompt.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::storage::EntityRow;
+use crate::types::{FieldDefinition, RelationDefinition};
+
+/// One box in the diagram. Declarations of the same table in several files
+/// (a model class and its migration) are merged into one.
+struct Table<'a> {
+    name: &'a str,
+    table: Option<&'a str>,
+    fields: Vec<&'a FieldDefinition>,
+    relations: Vec<&'a RelationDefinition>,
+}
+
+/// Render a mermaid `erDiagram` (without the code fence) for `entities`,
+/// or `None` when there are none.
+pub fn er_diagram(entities: &[&EntityRow]) -> Option<String> {
+    if entities.is_empty() {
+        return None;
+    }
+
+    let mut tables: Vec<Table> = Vec::new();
+    let mut by_key: HashMap<String, usize> = HashMap::new();
+    for row in entities {
+        let table = row.entity.table_name.as_deref();
+        let keys = [Some(row.name.as_str()), table];
+        let existing = keys
+            .iter()
+            .flatten()
+            .find_map(|k| by_key.get(&k.to_lowercase()).copied());
+        let index = existing.unwrap_or_else(|| {
+            tables.push(Table {
+                name: &row.name,
+                table,
+                fields: Vec::new(),
+                relations: Vec::new(),
+            });
+            tables.len() - 1
+        });
+
+        let entry = &mut tables[index];
+        // A model name reads better than the table it maps to
+        if Some(entry.name) == entry.table && Some(row.name.as_str()) != table {
+            entry.name = &row.name;
+        }
+        entry.table = entry.table.or(table);
+        for field in row.entity.fields.iter().flatten() {
+            if !entry.fields.iter().any(|f| f.name == field.name) {
+                entry.fields.push(field);
+            }
+        }
+        entry
+            .relations
+            .extend(row.entity.relations.iter().flatten());
+
+        for key in keys.into_iter().flatten() {
+            by_key.insert(key.to_lowercase(), index);
+        }
+    }
+
+    let mut output = String::from("erDiagram\n");
+    for table in &tables {
+        output.push_str(&format!("    {} {{\n", identifier(table.name)));
+        for field in &table.fields {
+            let mut keys = Vec::new();
+            if field.primary_key == Some(true) {
+                keys.push("PK");
+            }
+            if table.relations.iter().any(|r| r.name == field.name) {
+                keys.push("FK");
+            }
+            output.push_str(&format!(
+                "        {} {}{}{}\n",
+                identifier(&field.field_type),
+                identifier(&field.name),
+                if keys.is_empty() { "" } else { " " },
+                keys.join(", ")
+            ));
+        }
+        output.push_str("    }\n");
+    }
+
+    // Both sides usually declare a relation; draw each pair once
+    let mut drawn = HashSet::new();
+    for table in &tables {
+        let from = identifier(table.name);
+        for relation in &table.relations {
+            let to = by_key
+                .get(&relation.target.to_lowercase())
+                .map(|i| identifier(tables[*i].name))
+                .unwrap_or_else(|| identifier(&relation.target));
+            let pair = if from <= to {
+                (from.clone(), to.clone())
+            } else {
+                (to.clone(), from.clone())
+            };
+            if !drawn.insert(pair) {
+                continue;
+            }
+            output.push_str(&format!(
+                "    {} {} {} : \"{}\"\n",
+                from,
+                cardinality(&relation.relation_type),
+                to,
+                relation.name
+            ));
+        }
+    }
+
+    Some(output.trim_end().to_string())
+}
+
+fn cardinality(relation_type: &str) -> &'static str {
+    match relation_type {
+        "one_to_one" => "||--o|",
+        "one_to_many" => "||--o{",
+        "many_to_one" => "}o--||",
+        _ => "}o--o{",
+    }
+}
+
+/// Mermaid entity, type and attribute names are single words.
+fn identifier(text: &str) -> String {
+    let word: String = text
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let word = word.trim_matches('_');
+    if word.is_empty() {
+        "unknown".to_string()
+    } else {
+        word.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::parser::{Language, Parser, RubyParser, SchemaParser};
+    use crate::storage::{Database, GraphStore};
+
+    #[test]
+    fn test_er_diagram_merges_models_and_tables() {
+        let db = Database::open_in_memory().unwrap();
+        db.initialize().unwrap();
+        let store = GraphStore::new(&db);
+
+        let ruby = RubyParser::new().unwrap();
+        let results = [
+            ruby.parse(
+                "./app/models/user.rb",
+                "class User < ApplicationRecord\n  has_many :posts\nend\n",
+            )
+            .unwrap(),
+            ruby.parse(
+                "./app/models/post.rb",
+                "class Post < ApplicationRecord\n  belongs_to :user\nend\n",
+            )
+            .unwrap(),
+            SchemaParser::new(Language::Sql)
+                .unwrap()
+                .parse(
+                    "./db/schema.sql",
+                    "CREATE TABLE users (id BIGINT PRIMARY KEY, email VARCHAR(255) NOT NULL);\n\
+                     CREATE TABLE posts (id BIGINT PRIMARY KEY, user_id BIGINT REFERENCES users (id));",
+                )
+                .unwrap(),
+        ];
+        for result in &results {
+            for node in &result.nodes {
+                store.insert_node(node).unwrap();
+            }
+        }
+
+        let entities = db.get_entities().unwrap();
+        assert_eq!(entities.len(), 4);
+        let rows: Vec<&EntityRow> = entities.iter().collect();
+        let diagram = er_diagram(&rows).unwrap();
+
+        assert!(diagram.starts_with("erDiagram\n"));
+        assert!(
+            diagram.contains("    User {\n        BIGINT id PK\n        VARCHAR_255 email\n    }")
+        );
+        assert!(diagram.contains("        BIGINT user_id FK\n"));
+        assert!(
+            !diagram.contains("users {"),
+            "tables merge into their models"
+        );
+        assert_eq!(
+            diagram.matches("||--o{").count() + diagram.matches("}o--||").count(),
+            1
+        );
+        assert!(er_diagram(&[]).is_none());
+    }
+}
@@ -2,14 +2,17 @@
 //!
 //! Groups file insights by semantic domain using LLM-based analysis.
 //! Leverages project profile context (organization_style, domain_traits, terminology)
-//! to inform intelligent grouping decisions.
+//! to inform intelligent grouping decisions. Communities detected in the
+//! knowledge graph seed the groups, so files that depend on each other tend to
+//! land in the same domain even when they live in different directories.
 
 use crate::ai::provider::SharedProvider;
+use crate::analyzer::resolver::normalize_path;
 use crate::types::error::WeaveError;
 use crate::wiki::exhaustive::bottom_up::FileInsight;
 use crate::wiki::exhaustive::characterization::profile::ProjectProfile;
 use serde_json::json;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// LLM-based semantic domain grouper
 ///
@@ -19,11 +22,23 @@ use std::collections::HashMap;
 pub struct SemanticDomainGrouper<'a> {
     profile: &'a ProjectProfile,
     provider: SharedProvider,
+    /// Normalized file path -> name of its graph community
+    communities: HashMap<String, String>,
 }
 
 impl<'a> SemanticDomainGrouper<'a> {
     pub fn new(profile: &'a ProjectProfile, provider: SharedProvider) -> Self {
-        Self { profile, provider }
+        Self {
+            profile,
+            provider,
+            communities: HashMap::new(),
+        }
+    }
+
+    /// Seed grouping with file-graph communities.
+    pub fn with_communities(mut self, communities: &[Vec<String>]) -> Self {
+        self.communities = community_domains(communities);
+        self
     }
 
     /// Group file insights by semantic domain using LLM
@@ -53,6 +68,7 @@ impl<'a> SemanticDomainGrouper<'a> {
     ) -> Result<HashMap<String, Vec<FileInsight>>, WeaveError> {
         let file_summaries = self.build_file_summaries(insights);
         let profile_context = self.build_profile_context();
+        let communities = self.build_community_hints(insights);
 
         let prompt = format!(
             r#"Group these source files into semantic domains based on their purpose and functionality.
@@ -61,7 +77,7 @@ impl<'a> SemanticDomainGrouper<'a> {
 {}
 
 ## Files to Group
-{}
+{}{}
 
 ## Instructions
 1. Analyze each file's purpose and relationships
@@ -73,7 +89,7 @@ impl<'a> SemanticDomainGrouper<'a> {
 7. DO NOT create a catch-all "other" domain - every file should have a meaningful categorization
 
 Return a JSON object with domain assignments."#,
-            profile_context, file_summaries
+            profile_context, file_summaries, communities
         );
 
         let schema = json!({
@@ -123,7 +139,11 @@ Return a JSON object with domain assignments."#,
         let mut groups: HashMap<String, Vec<&FileInsight>> = HashMap::new();
 
         for insight in insights {
-            let domain = self.extract_path_domain(&insight.file_path);
+            let domain = self
+                .communities
+                .get(&normalize_path(&insight.file_path))
+                .cloned()
+                .unwrap_or_else(|| Self::extract_path_domain(&insight.file_path));
             groups.entry(domain).or_default().push(insight);
         }
 
@@ -132,7 +152,7 @@ Return a JSON object with domain assignments."#,
 
     /// Extract domain from path (for pre-grouping)
     /// Uses deeper path hierarchy (2-3 levels) for more meaningful grouping
-    fn extract_path_domain(&self, path: &str) -> String {
+    fn extract_path_domain(path: &str) -> String {
         let parts: Vec<&str> = path.split('/').collect();
 
         // Skip common prefixes and find meaningful domain
@@ -261,6 +281,29 @@ Return the complete refined grouping with ALL files assigned."#,
         summaries
     }
 
+    /// Graph communities among `insights`, as a prompt section (empty when
+    /// the graph offers none)
+    fn build_community_hints(&self, insights: &[FileInsight]) -> String {
+        let mut members: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+        for fi in insights {
+            if let Some(name) = self.communities.get(&normalize_path(&fi.file_path)) {
+                members.entry(name).or_default().push(&fi.file_path);
+            }
+        }
+        members.retain(|_, files| files.len() > 1);
+        if members.is_empty() {
+            return String::new();
+        }
+
+        let mut hints = String::from(
+            "\n## Dependency Communities\nFiles on one line depend heavily on each other; prefer keeping them together.\n",
+        );
+        for files in members.values() {
+            hints.push_str(&format!("- {}\n", files.join(", ")));
+        }
+        hints
+    }
+
     /// Build profile context for LLM prompt
     fn build_profile_context(&self) -> String {
         let mut ctx = String::new();
@@ -449,6 +492,42 @@ Return the complete refined grouping with ALL files assigned."#,
     }
 }
 
+/// Name each community after the path domain most of its files share,
+/// keyed by normalized file path. Repeated names get a numeric suffix.
+fn community_domains(communities: &[Vec<String>]) -> HashMap<String, String> {
+    let mut used: HashMap<String, usize> = HashMap::new();
+    let mut domains = HashMap::new();
+
+    for files in communities {
+        let mut votes: BTreeMap<String, usize> = BTreeMap::new();
+        for file in files {
+            *votes
+                .entry(SemanticDomainGrouper::extract_path_domain(file))
+                .or_default() += 1;
+        }
+        let Some(base) = votes
+            .iter()
+            .max_by(|a, b| a.1.cmp(b.1).then_with(|| b.0.cmp(a.0)))
+            .map(|(name, _)| name.clone())
+        else {
+            continue;
+        };
+
+        let seen = used.entry(base.clone()).or_default();
+        *seen += 1;
+        let name = if *seen == 1 {
+            base
+        } else {
+            format!("{}-{}", base, seen)
+        };
+        for file in files {
+            domains.insert(normalize_path(file), name.clone());
+        }
+    }
+
+    domains
+}
+
 #[cfg(test)]
 mod tests {
     fn test_extract_domain(path: &str) -> String {
@@ -594,4 +673,23 @@ mod tests {
             "user-authentication"
         );
     }
+
+    #[test]
+    fn test_community_domains() {
+        let communities = vec![
+            vec![
+                "src/api/routes.rs".to_string(),
+                "src/api/handlers.rs".to_string(),
+                "src/auth/token.rs".to_string(),
+            ],
+            vec![
+                "./src/api/admin.rs".to_string(),
+                "src/api/audit.rs".to_string(),
+            ],
+        ];
+        let domains = super::community_domains(&communities);
+
+        assert_eq!(domains["src/auth/token.rs"], "api");
+        assert_eq!(domains["src/api/admin.rs"], "api-2");
+    }
 }
@@ -9,11 +9,13 @@
 //! into a unified domain narrative, linking to individual files rather
 //! than duplicating their content.
 
+pub mod er_diagram;
 pub mod gap_detector;
 pub mod grouping;
 
 use crate::ai::provider::SharedProvider;
-use crate::storage::SharedDatabase;
+use crate::analyzer::DependencyGraph;
+use crate::storage::{Database, EntityRow, GraphStore, SharedDatabase};
 use crate::types::error::WeaveError;
 use crate::wiki::exhaustive::bottom_up::{FileInsight, Importance, RelatedFile};
 use crate::wiki::exhaustive::characterization::profile::ProjectProfile;
@@ -23,7 +25,7 @@ use futures::stream::StreamExt;
 use grouping::SemanticDomainGrouper;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::sync::Arc;
 
 /// Maximum files to include in full detail in synthesis prompt
@@ -75,7 +77,8 @@ impl ConsolidationAnalyzer {
         }
 
         // 1. Group file insights by semantic domain
-        let grouper = SemanticDomainGrouper::new(&self.profile, self.provider.clone());
+        let grouper = SemanticDomainGrouper::new(&self.profile, self.provider.clone())
+            .with_communities(&self.load_communities());
         let grouped = grouper.group(&file_insights).await?;
 
         tracing::info!(
@@ -116,7 +119,31 @@ impl ConsolidationAnalyzer {
             summary.gaps = gap_detector::detect_gaps(summary);
         }
 
-        // 4. Store results
+        // 4. Attach data model diagrams built from extracted entities
+        let entities = self.load_entities();
+        if !entities.is_empty() {
+            for summary in &mut summaries {
+                let files: HashSet<&str> = summary
+                    .files
+                    .iter()
+                    .map(|f| f.trim_start_matches("./"))
+                    .collect();
+                let domain_entities: Vec<&EntityRow> = entities
+                    .iter()
+                    .filter(|e| files.contains(e.file.trim_start_matches("./")))
+                    .collect();
+                summary.data_model = er_diagram::er_diagram(&domain_entities);
+            }
+        }
+
+        // 5. List the tests covering each domain
+        if let Some(ctx) = &self.checkpoint {
+            for summary in &mut summaries {
+                summary.tested_by = domain_tests(&ctx.db, &summary.files);
+            }
+        }
+
+        // 6. Store results
         self.store_domain_summaries(&summaries)?;
 
         tracing::info!(
@@ -401,6 +428,30 @@ Make the domain documentation genuinely useful:
         Ok(summary)
     }
 
+    /// Entities in the knowledge graph; empty without a database
+    fn load_entities(&self) -> Vec<EntityRow> {
+        let Some(ctx) = &self.checkpoint else {
+            return Vec::new();
+        };
+        ctx.db.get_entities().unwrap_or_else(|e| {
+            tracing::warn!("Failed to load entities for data model diagrams: {}", e);
+            Vec::new()
+        })
+    }
+
+    /// Communities of the file graph; empty without a database
+    fn load_communities(&self) -> Vec<Vec<String>> {
+        let Some(ctx) = &self.checkpoint else {
+            return Vec::new();
+        };
+        DependencyGraph::files(&GraphStore::new(&ctx.db))
+            .map(|graph| graph.communities())
+            .unwrap_or_else(|e| {
+                tracing::warn!("Failed to load the file graph for domain grouping: {}", e);
+                Vec::new()
+            })
+    }
+
     /// Load domain summaries from checkpoint
     fn load_domain_summaries(&self) -> Result<Option<Vec<DomainInsight>>, WeaveError> {
         let Some(ctx) = &self.checkpoint else {
@@ -491,6 +542,32 @@ Make the domain documentation genuinely useful:
     }
 }
 
+/// Tests covering each of `files`, as `path::test` or the test file's path
+fn domain_tests(db: &Database, files: &[String]) -> BTreeMap<String, Vec<String>> {
+    let mut tested_by = BTreeMap::new();
+    for file in files {
+        let tests = match db.get_file_tests(file) {
+            Ok(tests) => tests,
+            Err(e) => {
+                tracing::warn!("Failed to load tests for {}: {}", file, e);
+                continue;
+            }
+        };
+        let mut names: Vec<String> = tests
+            .into_iter()
+            .map(|t| match t.test {
+                Some(name) => format!("{}::{}", t.test_path, name),
+                None => t.test_path,
+            })
+            .collect();
+        names.dedup();
+        if !names.is_empty() {
+            tested_by.insert(file.clone(), names);
+        }
+    }
+    tested_by
+}
+
 /// Schema for domain synthesis
 fn domain_synthesis_schema() -> serde_json::Value {
     json!({
@@ -536,6 +613,14 @@ pub struct DomainInsight {
     /// Domain-level diagram
     pub diagram: Option<String>,
 
+    /// ER diagram of the domain's entities, generated from the graph
+    #[serde(default)]
+    pub data_model: Option<String>,
+
+    /// Tests covering the domain's files, from the graph: file -> tests
+    #[serde(default)]
+    pub tested_by: BTreeMap<String, Vec<String>>,
+
     /// Cross-domain relationships
     pub related_files: Vec<RelatedFile>,
 
@@ -556,6 +641,8 @@ impl Default for DomainInsight {
             files: Vec::new(),
             content: String::new(),
             diagram: None,
+            data_model: None,
+            tested_by: BTreeMap::new(),
             related_files: Vec::new(),
             gaps: Vec::new(),
             token_count: 0,
@@ -603,6 +690,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_domain_tests() {
+        use crate::analyzer::parser::{Parser, RustParser};
+        use crate::analyzer::resolver::resolve_test_subjects;
+
+        let db = Database::open_in_memory().unwrap();
+        db.initialize().unwrap();
+        let store = GraphStore::new(&db);
+        let parser = RustParser::new().unwrap();
+        let mut results = vec![
+            parser
+                .parse(
+                    "./src/lib.rs",
+                    "pub fn add(a: i32) -> i32 { a }\n\n#[cfg(test)]\nmod tests {\n    \
+                     use super::*;\n\n    #[test]\n    fn adds() { assert_eq!(add(1), 1); }\n}\n",
+                )
+                .unwrap(),
+            parser.parse("./src/util.rs", "pub fn noop() {}\n").unwrap(),
+        ];
+        resolve_test_subjects(&mut results);
+        for result in &results {
+            for node in &result.nodes {
+                store.insert_node(node).unwrap();
+            }
+            for edge in &result.edges {
+                store.insert_edge(edge).unwrap();
+            }
+        }
+
+        let files = vec!["./src/lib.rs".to_string(), "./src/util.rs".to_string()];
+        let tested_by = domain_tests(&db, &files);
+        assert_eq!(tested_by.len(), 1);
+        assert_eq!(tested_by["./src/lib.rs"], vec!["./src/lib.rs::adds"]);
+    }
+
     #[test]
     fn test_domain_insight_creation() {
         let insight = DomainInsight::new("test-domain".to_string());
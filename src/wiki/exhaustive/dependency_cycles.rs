@@ -0,0 +1,103 @@
+//! Dependency Cycles Page
+//!
+//! Renders `dependency-cycles.md` from the strongly connected components of
+//! the file graph. Each cycle lists its files with their PageRank and the
+//! dependencies that close the loop, plus a flowchart of them.
+
+use std::collections::HashMap;
+
+use crate::analyzer::DependencyGraph;
+
+/// Cycles larger than this are listed without a diagram.
+const MAX_DIAGRAM_FILES: usize = 20;
+
+/// Generator for the dependency cycles page
+pub struct DependencyCyclesGenerator;
+
+impl DependencyCyclesGenerator {
+    /// Generate the page for the cycles of a file graph
+    pub fn generate_md(files: &DependencyGraph) -> String {
+        let cycles = files.cycles();
+        let ranks = files.pagerank();
+        let mut output = String::new();
+
+        output.push_str("# Dependency Cycles\n\n");
+        if cycles.is_empty() {
+            output.push_str("No dependency cycles between files.\n");
+            return output;
+        }
+        output.push_str(&format!(
+            "{} groups of files depend on each other in a cycle ({} files in total). \
+             Files in a cycle cannot be understood, tested or changed in isolation.\n\n",
+            cycles.len(),
+            cycles.iter().map(Vec::len).sum::<usize>()
+        ));
+
+        for (i, cycle) in cycles.iter().enumerate() {
+            let index: HashMap<&str, usize> = cycle
+                .iter()
+                .enumerate()
+                .map(|(n, f)| (f.as_str(), n))
+                .collect();
+            let edges: Vec<(&str, &str)> = files
+                .edges()
+                .filter(|(from, to)| index.contains_key(from) && index.contains_key(to))
+                .collect();
+
+            output.push_str(&format!("## Cycle {} ({} files)\n\n", i + 1, cycle.len()));
+            output.push_str("| File | PageRank | Depends on (within cycle) |\n");
+            output.push_str("|------|----------|---------------------------|\n");
+            for file in cycle {
+                let targets: Vec<String> = edges
+                    .iter()
+                    .filter(|(from, _)| from == file)
+                    .map(|(_, to)| format!("`{}`", to))
+                    .collect();
+                output.push_str(&format!(
+                    "| `{}` | {:.4} | {} |\n",
+                    file,
+                    ranks.get(file).copied().unwrap_or(0.0),
+                    targets.join(", ")
+                ));
+            }
+            output.push('\n');
+
+            if cycle.len() <= MAX_DIAGRAM_FILES {
+                output.push_str("```mermaid\nflowchart LR\n");
+                for (n, file) in cycle.iter().enumerate() {
+                    output.push_str(&format!("    f{}[\"{}\"]\n", n, file.replace('"', "'")));
+                }
+                for (from, to) in &edges {
+                    output.push_str(&format!("    f{} --> f{}\n", index[from], index[to]));
+                }
+                output.push_str("```\n\n");
+            }
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cycles_page() {
+        let graph = DependencyGraph::from_edges([
+            ("src/a.rs", "src/b.rs"),
+            ("src/b.rs", "src/a.rs"),
+            ("src/b.rs", "src/util.rs"),
+        ]);
+        let page = DependencyCyclesGenerator::generate_md(&graph);
+
+        assert!(page.contains("## Cycle 1 (2 files)"));
+        assert!(page.contains("| `src/b.rs` | "));
+        assert!(page.contains("| `src/a.rs` |"));
+        assert!(page.contains("    f0 --> f1\n    f1 --> f0\n"));
+        assert!(!page.contains("src/util.rs"));
+
+        let acyclic = DependencyGraph::from_edges([("a", "b")]);
+        assert!(DependencyCyclesGenerator::generate_md(&acyclic).contains("No dependency cycles"));
+    }
+}
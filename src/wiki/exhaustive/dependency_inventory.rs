@@ -0,0 +1,206 @@
+//! Dependency Inventory Page
+//!
+//! Renders `dependencies.md` from the third-party package nodes: one table
+//! per ecosystem with the pinned version, the manifest requirement and the
+//! files importing each package. Packages only a lockfile names appear when
+//! code imports them directly.
+
+use crate::storage::ExternalPackageRow;
+
+/// Importing files listed per package before the rest are counted.
+const MAX_LISTED_IMPORTERS: usize = 5;
+
+/// Generator for the dependency inventory page
+pub struct DependencyInventoryGenerator;
+
+impl DependencyInventoryGenerator {
+    /// Generate the page for a set of packages ordered by ecosystem
+    pub fn generate_md(packages: &[ExternalPackageRow]) -> String {
+        let mut output = String::new();
+        output.push_str("# Dependencies\n\n");
+        if packages.is_empty() {
+            output.push_str("No third-party packages declared.\n");
+            return output;
+        }
+        let imported = packages.iter().filter(|p| !p.importers.is_empty()).count();
+        output.push_str(&format!(
+            "{} third-party packages, {} imported directly by project files.\n",
+            packages.len(),
+            imported
+        ));
+
+        let mut current = "";
+        for package in packages {
+            if package.ecosystem != current {
+                current = &package.ecosystem;
+                output.push_str(&format!("\n## {}\n\n", ecosystem_label(current)));
+                output.push_str("| Package | Version | Requirement | Scope | Imported by |\n");
+                output.push_str("|---------|---------|-------------|-------|-------------|\n");
+            }
+            output.push_str(&format!(
+                "| `{}` | {} | {} | {} | {} |\n",
+                package.name,
+                package.version.as_deref().unwrap_or("-"),
+                package
+                    .requirement
+                    .as_deref()
+                    .map_or("-".to_string(), |r| format!("`{}`", r)),
+                package.scope.as_deref().unwrap_or("transitive"),
+                importers(&package.importers)
+            ));
+        }
+        output
+    }
+}
+
+fn ecosystem_label(ecosystem: &str) -> &str {
+    match ecosystem {
+        "cargo" => "Rust (Cargo)",
+        "npm" => "JavaScript (npm)",
+        "pypi" => "Python (PyPI)",
+        "go" => "Go modules",
+        other => other,
+    }
+}
+
+fn importers(files: &[String]) -> String {
+    if files.is_empty() {
+        return "-".to_string();
+    }
+    let mut listed: Vec<String> = files
+        .iter()
+        .take(MAX_LISTED_IMPORTERS)
+        .map(|f| format!("`{}`", f.trim_start_matches("./")))
+        .collect();
+    if files.len() > MAX_LISTED_IMPORTERS {
+        listed.push(format!("+{} more", files.len() - MAX_LISTED_IMPORTERS));
+    }
+    listed.join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::Workspace;
+    use crate::analyzer::parser::{
+        ConfigParser, ParseResult, Parser, PythonParser, RustParser, TypeScriptParser, config_kind,
+    };
+    use crate::analyzer::resolver::{ExternalPackages, resolve_imports};
+    use crate::storage::{Database, GraphStore};
+
+    #[test]
+    fn test_inventory_from_manifests_lockfiles_and_imports() {
+        let configs = [
+            (
+                "./Cargo.toml",
+                "[package]\nname = \"app\"\n\n[dependencies]\nserde_json = \"1\"\nanyhow = \"1.0\"\n",
+            ),
+            (
+                "./Cargo.lock",
+                "[[package]]\nname = \"serde_json\"\nversion = \"1.0.128\"\nsource = \"registry\"\n",
+            ),
+            (
+                "./web/package.json",
+                r#"{"name": "web", "dependencies": {"@tanstack/react-query": "^5.0.0"}}"#,
+            ),
+            (
+                "./pyproject.toml",
+                "[project]\ndependencies = [\"PyYAML>=6\"]\n",
+            ),
+            (
+                "./poetry.lock",
+                "[[package]]\nname = \"pyyaml\"\nversion = \"6.0.2\"\n\n\
+                 [[package]]\nname = \"requests\"\nversion = \"2.32.3\"\n",
+            ),
+        ];
+        let mut results: Vec<ParseResult> = configs
+            .iter()
+            .map(|(path, content)| {
+                ConfigParser::new(config_kind(path).unwrap())
+                    .parse(path, content)
+                    .unwrap()
+            })
+            .collect();
+        results.push(
+            RustParser::new()
+                .unwrap()
+                .parse(
+                    "./src/main.rs",
+                    "use serde_json::Value;\nuse std::fs;\n\nfn main() {}\n",
+                )
+                .unwrap(),
+        );
+        results.push(
+            TypeScriptParser::new()
+                .unwrap()
+                .parse(
+                    "./web/src/api.ts",
+                    "import { useQuery } from '@tanstack/react-query/build';\nimport x from 'lodash';\n",
+                )
+                .unwrap(),
+        );
+        results.push(
+            PythonParser::new()
+                .unwrap()
+                .parse(
+                    "./tools/gen.py",
+                    "import os\nimport requests\nimport yaml\n",
+                )
+                .unwrap(),
+        );
+
+        let workspace = Workspace::default();
+        resolve_imports(&mut results, &workspace);
+        let packages = ExternalPackages::collect(&results, &workspace).graph(&results);
+
+        let deps = |path: &str| -> Vec<String> {
+            results
+                .iter()
+                .flat_map(|r| &r.edges)
+                .filter(|e| e.source_id == format!("file:{}", path))
+                .filter(|e| e.edge_type == crate::types::EdgeType::DependsOn)
+                .map(|e| e.target_id.clone())
+                .collect()
+        };
+        assert_eq!(deps("./src/main.rs"), vec!["external:cargo:serde_json"]);
+        assert_eq!(
+            deps("./web/src/api.ts"),
+            vec!["external:npm:@tanstack/react-query", "module:lodash"]
+        );
+        // `yaml` is not how PyYAML is imported; `requests` is only locked
+        assert_eq!(deps("./tools/gen.py"), vec!["external:pypi:requests"]);
+
+        let db = Database::open_in_memory().unwrap();
+        db.initialize().unwrap();
+        let store = GraphStore::new(&db);
+        for result in results.iter().chain([&packages]) {
+            for node in &result.nodes {
+                store.insert_node(node).unwrap();
+            }
+            for edge in &result.edges {
+                store.insert_edge(edge).unwrap();
+            }
+        }
+
+        let rows = db.get_external_packages().unwrap();
+        let names: Vec<&str> = rows.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "anyhow",
+                "serde_json",
+                "@tanstack/react-query",
+                "PyYAML",
+                "requests"
+            ]
+        );
+
+        let md = DependencyInventoryGenerator::generate_md(&rows);
+        assert!(md.contains("5 third-party packages, 3 imported directly"));
+        assert!(md.contains("## Rust (Cargo)"));
+        assert!(md.contains("| `serde_json` | 1.0.128 | `1` | runtime | `src/main.rs` |"));
+        assert!(md.contains("| `anyhow` | - | `1.0` | runtime | - |"));
+        assert!(md.contains("| `PyYAML` | 6.0.2 | `>=6` | runtime | - |"));
+        assert!(md.contains("| `requests` | 2.32.3 | - | transitive | `tools/gen.py` |"));
+    }
+}
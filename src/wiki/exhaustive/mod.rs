@@ -28,6 +28,10 @@
 //! - AI-driven flexible structure
 
 // Utilities
+mod api_catalog;
+mod config_pages;
+mod dependency_cycles;
+mod dependency_inventory;
 mod llms_txt;
 mod mermaid;
 mod patterns;
@@ -47,7 +51,11 @@ pub mod research;
 pub mod top_down;
 
 // Public exports
+pub use api_catalog::ApiCatalogGenerator;
 pub use checkpoint::{CheckpointContext, CheckpointManager, PipelinePhase};
+pub use config_pages::ConfigPagesGenerator;
+pub use dependency_cycles::DependencyCyclesGenerator;
+pub use dependency_inventory::DependencyInventoryGenerator;
 pub use llms_txt::LlmsTxtGenerator;
 pub use mermaid::{MermaidValidation, MermaidValidator};
 pub use patterns::PatternExtractor;
@@ -65,16 +73,18 @@ use tracing::{info, instrument};
 use crate::ai::budget::{SharedBudget, create_shared_budget};
 use crate::ai::metrics::{SharedMetrics, create_shared_metrics};
 use crate::ai::provider::SharedProvider;
+use crate::analyzer::DependencyGraph;
+use crate::analyzer::parser::is_test_path;
 use crate::analyzer::scanner::FileScanner;
 use crate::constants::budget as budget_constants;
-use crate::storage::SharedDatabase;
+use crate::storage::{GraphStore, SharedDatabase};
 use crate::types::Result;
 
 // =============================================================================
 // Multi-Agent Pipeline
 // =============================================================================
 
-use crate::config::{AnalysisMode, ProjectScale, get_mode_config};
+use crate::config::{AnalysisConfig, AnalysisMode, ProjectScale, get_mode_config};
 use bottom_up::BottomUpAnalyzer;
 use characterization::CharacterizationAnalyzer;
 use consolidation::ConsolidationAnalyzer;
@@ -98,6 +108,8 @@ pub struct MultiAgentConfig {
     pub verbose: bool,
     /// Dry run (show config only)
     pub dry_run: bool,
+    /// File selection settings shared with `weavewiki build`
+    pub analysis: AnalysisConfig,
 }
 
 impl Default for MultiAgentConfig {
@@ -110,6 +122,7 @@ impl Default for MultiAgentConfig {
             show_progress: true,
             verbose: false,
             dry_run: false,
+            analysis: AnalysisConfig::default(),
         }
     }
 }
@@ -132,6 +145,8 @@ pub struct MultiAgentResult {
     pub budget_utilization: f64,
     /// Estimated cost in USD (based on model pricing)
     pub estimated_cost_usd: f64,
+    /// Core files not covered by any detected test
+    pub untested_core_files: Vec<String>,
 }
 
 /// Multi-Agent Pipeline orchestrator
@@ -354,9 +369,14 @@ impl MultiAgentPipeline {
         }
     }
 
+    /// Source files selected by the project's analysis settings
+    fn scanner(&self) -> FileScanner {
+        FileScanner::for_project(&self.project_root, &self.config.analysis).source_only()
+    }
+
     /// Auto-detect project scale from file count
     pub fn detect_scale(&self) -> ProjectScale {
-        let count = FileScanner::source_files(&self.project_root).count();
+        let count = self.scanner().count();
         ProjectScale::from_file_count(count)
     }
 
@@ -429,6 +449,7 @@ impl MultiAgentPipeline {
                 tokens_consumed: 0,
                 budget_utilization: 0.0,
                 estimated_cost_usd: 0.0,
+                untested_core_files: Vec::new(),
             });
         }
 
@@ -450,6 +471,8 @@ impl MultiAgentPipeline {
                 mode_config.clone(),
                 self.provider.clone(),
             )
+            .with_scanner(self.scanner())
+            .with_packages(self.db.get_external_packages().unwrap_or_default())
             .with_checkpoint(self.db.clone(), self.session_id.clone());
             let profile = char_analyzer.run().await?;
 
@@ -467,9 +490,7 @@ impl MultiAgentPipeline {
         // ===== PHASE 2: File Discovery =====
         let files = if resume_from < 2 {
             info!("Phase 2: Discovering source files");
-            let files = FileScanner::source_files(&self.project_root)
-                .paths()
-                .unwrap_or_default();
+            let files = self.scanner().paths().unwrap_or_default();
             info!("Found {} source files", files.len());
 
             checkpoint.files = files.clone();
@@ -607,8 +628,22 @@ impl MultiAgentPipeline {
         adjusted_config.refinement_quality_target = quality_target;
         adjusted_config.refinement_max_turns = max_turns;
 
+        // Load file_insights from checkpoint for the coverage check and additional generators
+        let file_insights_for_extra: Vec<bottom_up::FileInsight> = checkpoint
+            .file_insights_json
+            .as_ref()
+            .and_then(|json| serde_json::from_str(json).ok())
+            .unwrap_or_default();
+
+        let core_files: Vec<String> = file_insights_for_extra
+            .iter()
+            .filter(|f| f.tier == bottom_up::ProcessingTier::Core && !is_test_path(&f.file_path))
+            .map(|f| f.file_path.clone())
+            .collect();
+
         let refinement = RefinementAnalyzer::new(&self.project_root, adjusted_config, mode, scale)
-            .with_checkpoint(self.db.clone(), self.session_id.clone());
+            .with_checkpoint(self.db.clone(), self.session_id.clone())
+            .with_core_files(core_files);
         let refinement_insight = refinement.run(domain_insights).await?;
 
         // Load project_insights from checkpoint for hierarchical generator
@@ -699,12 +734,84 @@ impl MultiAgentPipeline {
             info!("Generated terminology.md at {}", terminology_path.display());
         }
 
-        // Load file_insights from checkpoint for additional generators
-        let file_insights_for_extra: Vec<bottom_up::FileInsight> = checkpoint
-            .file_insights_json
-            .as_ref()
-            .and_then(|json| serde_json::from_str(json).ok())
-            .unwrap_or_default();
+        // The API catalog comes straight from parser-extracted routes
+        match self.db.get_api_endpoints() {
+            Ok(endpoints) if !endpoints.is_empty() => {
+                let catalog_md = ApiCatalogGenerator::generate_md(&endpoints);
+                let catalog_path = self.output_path.join("api-catalog.md");
+                if let Err(e) = std::fs::write(&catalog_path, catalog_md) {
+                    tracing::warn!("Failed to write api-catalog.md: {}", e);
+                } else {
+                    info!("Generated api-catalog.md at {}", catalog_path.display());
+                }
+            }
+            Ok(_) => {}
+            Err(e) => tracing::warn!("Failed to load API endpoints: {}", e),
+        }
+
+        // Configuration and deployment pages come from parsed config files
+        // and the settings code reads
+        let settings = self.db.get_settings().unwrap_or_else(|e| {
+            tracing::warn!("Failed to load settings: {}", e);
+            Vec::new()
+        });
+        match self.db.get_config_files() {
+            Ok(files) if !files.is_empty() || !settings.is_empty() => {
+                let mut pages = vec![(
+                    "configuration.md",
+                    ConfigPagesGenerator::generate_configuration_md(&files, &settings),
+                )];
+                if ConfigPagesGenerator::has_deployment(&files) {
+                    pages.push((
+                        "deployment.md",
+                        ConfigPagesGenerator::generate_deployment_md(&files),
+                    ));
+                }
+                for (name, md) in pages {
+                    let path = self.output_path.join(name);
+                    if let Err(e) = std::fs::write(&path, md) {
+                        tracing::warn!("Failed to write {}: {}", name, e);
+                    } else {
+                        info!("Generated {} at {}", name, path.display());
+                    }
+                }
+            }
+            Ok(_) => {}
+            Err(e) => tracing::warn!("Failed to load configuration files: {}", e),
+        }
+
+        // The dependency inventory comes from manifests, lockfiles and imports
+        match self.db.get_external_packages() {
+            Ok(packages) if !packages.is_empty() => {
+                let inventory_md = DependencyInventoryGenerator::generate_md(&packages);
+                let inventory_path = self.output_path.join("dependencies.md");
+                if let Err(e) = std::fs::write(&inventory_path, inventory_md) {
+                    tracing::warn!("Failed to write dependencies.md: {}", e);
+                } else {
+                    info!("Generated dependencies.md at {}", inventory_path.display());
+                }
+            }
+            Ok(_) => {}
+            Err(e) => tracing::warn!("Failed to load external packages: {}", e),
+        }
+
+        // Cycles are found in the file graph, without LLM input
+        match DependencyGraph::files(&GraphStore::new(&self.db)) {
+            Ok(graph) if !graph.cycles().is_empty() => {
+                let cycles_md = DependencyCyclesGenerator::generate_md(&graph);
+                let cycles_path = self.output_path.join("dependency-cycles.md");
+                if let Err(e) = std::fs::write(&cycles_path, cycles_md) {
+                    tracing::warn!("Failed to write dependency-cycles.md: {}", e);
+                } else {
+                    info!(
+                        "Generated dependency-cycles.md at {}",
+                        cycles_path.display()
+                    );
+                }
+            }
+            Ok(_) => {}
+            Err(e) => tracing::warn!("Failed to load the file graph: {}", e),
+        }
 
         // Generate llms.txt
         if !file_insights_for_extra.is_empty() {
@@ -793,6 +900,7 @@ impl MultiAgentPipeline {
             tokens_consumed: budget_stats.consumed,
             budget_utilization: budget_stats.utilization,
             estimated_cost_usd: metrics_summary.total_cost_usd,
+            untested_core_files: refinement_insight.untested_core_files,
         })
     }
 
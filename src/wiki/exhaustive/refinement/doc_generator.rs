@@ -100,6 +100,23 @@ impl DocGenerator {
             output.push_str("\n\n");
         }
 
+        // Data model (generated from extracted entities, not the LLM)
+        if let Some(ref data_model) = domain.data_model {
+            output.push_str("## Data Model\n\n```mermaid\n");
+            output.push_str(data_model);
+            output.push_str("\n```\n\n");
+        }
+
+        // Tests covering the domain's files (from tests edges in the graph)
+        if !domain.tested_by.is_empty() {
+            output.push_str("## Tests\n\n");
+            for (file, tests) in &domain.tested_by {
+                let tests: Vec<String> = tests.iter().map(|t| format!("`{}`", t)).collect();
+                output.push_str(&format!("- `{}`: {}\n", file, tests.join(", ")));
+            }
+            output.push('\n');
+        }
+
         // Documentation gaps (if any)
         if !domain.gaps.is_empty() {
             output.push_str("## Documentation Gaps\n\n");
@@ -13,6 +13,7 @@ use crate::types::error::WeaveError;
 use crate::wiki::exhaustive::checkpoint::CheckpointContext;
 use crate::wiki::exhaustive::consolidation::DomainInsight;
 use quality_scorer::{CrossRefIssue, QualityScore, QualityScorer};
+use std::collections::HashSet;
 use std::path::Path;
 
 pub struct RefinementAnalyzer {
@@ -21,6 +22,8 @@ pub struct RefinementAnalyzer {
     mode: AnalysisMode,
     scale: ProjectScale,
     checkpoint: Option<CheckpointContext>,
+    /// Core-tier files, checked for test coverage
+    core_files: Vec<String>,
 }
 
 impl RefinementAnalyzer {
@@ -36,6 +39,7 @@ impl RefinementAnalyzer {
             mode,
             scale,
             checkpoint: None,
+            core_files: Vec::new(),
         }
     }
 
@@ -44,6 +48,12 @@ impl RefinementAnalyzer {
         self
     }
 
+    /// Core files to flag when no test covers them
+    pub fn with_core_files(mut self, core_files: Vec<String>) -> Self {
+        self.core_files = core_files;
+        self
+    }
+
     /// Run quality assessment on domain insights
     pub async fn run(
         &self,
@@ -102,15 +112,49 @@ impl RefinementAnalyzer {
             );
         }
 
+        let untested_core_files = self.untested_core_files();
+        if !untested_core_files.is_empty() {
+            tracing::warn!(
+                "Refinement: {} core files have no tests",
+                untested_core_files.len()
+            );
+        }
+
         Ok(RefinementInsight {
             domain_insights,
             quality_scores,
             turns_used: turn,
             target_met,
             cross_ref_issues,
+            untested_core_files,
         })
     }
 
+    /// Core files no `tests` edge points at. Empty without a database, or
+    /// when the project has no detected tests at all.
+    fn untested_core_files(&self) -> Vec<String> {
+        let Some(ctx) = &self.checkpoint else {
+            return Vec::new();
+        };
+        let tested = match ctx.db.get_tested_files() {
+            Ok(tested) => tested,
+            Err(e) => {
+                tracing::warn!("Refinement: Failed to load tested files: {}", e);
+                return Vec::new();
+            }
+        };
+        if tested.is_empty() {
+            return Vec::new();
+        }
+
+        let tested: HashSet<&str> = tested.iter().map(|f| f.trim_start_matches("./")).collect();
+        self.core_files
+            .iter()
+            .filter(|f| !tested.contains(f.trim_start_matches("./")))
+            .cloned()
+            .collect()
+    }
+
     /// Load previous refinement state (for resume)
     fn load_refinement_state(&self) -> Result<(usize, Vec<QualityScore>), WeaveError> {
         let Some(ctx) = &self.checkpoint else {
@@ -176,4 +220,6 @@ pub struct RefinementInsight {
     pub turns_used: usize,
     pub target_met: bool,
     pub cross_ref_issues: Vec<CrossRefIssue>,
+    /// Core files not covered by any detected test
+    pub untested_core_files: Vec<String>,
 }
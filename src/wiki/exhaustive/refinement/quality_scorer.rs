@@ -800,6 +800,8 @@ mod tests {
             } else {
                 None
             },
+            data_model: None,
+            tested_by: Default::default(),
             related_files: vec![],
             gaps: vec![],
             token_count: 0,
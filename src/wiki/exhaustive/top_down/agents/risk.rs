@@ -83,6 +83,23 @@ impl RiskAgent {
             summary = "No explicit risks documented. Analyze based on code patterns.".to_string();
         }
 
+        if !context.complex_functions.is_empty() {
+            summary.push_str("\n## Most Complex Functions (parser-measured)\n");
+            for f in &context.complex_functions {
+                summary.push_str(&format!(
+                    "- {}:{} `{}`: cyclomatic {}, nesting {}, {} lines, {} params, {} early returns\n",
+                    f.path,
+                    f.line,
+                    f.name,
+                    f.cyclomatic,
+                    f.max_nesting,
+                    f.lines,
+                    f.parameters,
+                    f.early_returns
+                ));
+            }
+        }
+
         summary
     }
 
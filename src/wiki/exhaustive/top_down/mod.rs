@@ -8,7 +8,7 @@ pub mod insights;
 
 use crate::ai::provider::SharedProvider;
 use crate::config::{ModeConfig, ProjectScale};
-use crate::storage::SharedDatabase;
+use crate::storage::{FunctionComplexityRow, SharedDatabase};
 use crate::types::error::WeaveError;
 use crate::wiki::exhaustive::bottom_up::FileInsight;
 use crate::wiki::exhaustive::characterization::profile::ProjectProfile;
@@ -21,6 +21,9 @@ use std::collections::HashSet;
 use std::path::Path;
 use std::sync::Arc;
 
+/// Functions listed to agents as complexity facts
+const COMPLEX_FUNCTION_LIMIT: usize = 15;
+
 /// Orchestrator for top-down analysis with checkpoint/resume
 pub struct TopDownAnalyzer {
     project_root: std::path::PathBuf,
@@ -88,6 +91,7 @@ impl TopDownAnalyzer {
             project_root: self.project_root.clone(),
             profile: self.profile.clone(),
             file_insights: file_insights.to_vec(),
+            complex_functions: self.load_complex_functions(),
             provider: self.provider.clone(),
         });
 
@@ -206,6 +210,19 @@ impl TopDownAnalyzer {
         agents
     }
 
+    /// Most complex functions of the knowledge graph, for the risk agent
+    fn load_complex_functions(&self) -> Vec<FunctionComplexityRow> {
+        let Some(ctx) = &self.checkpoint else {
+            return Vec::new();
+        };
+        ctx.db
+            .get_complex_functions(None, COMPLEX_FUNCTION_LIMIT)
+            .unwrap_or_else(|e| {
+                tracing::warn!("Top-Down: Failed to load function metrics: {}", e);
+                Vec::new()
+            })
+    }
+
     /// Load completed agent names (for resume)
     fn load_completed_agents(&self) -> Result<HashSet<String>, WeaveError> {
         let Some(ctx) = &self.checkpoint else {
@@ -278,6 +295,8 @@ pub struct TopDownContext {
     pub profile: Arc<ProjectProfile>,
     /// File insights from bottom-up analysis
     pub file_insights: Vec<FileInsight>,
+    /// Most complex functions, as measured by the parsers
+    pub complex_functions: Vec<FunctionComplexityRow>,
     /// LLM provider
     pub provider: SharedProvider,
 }